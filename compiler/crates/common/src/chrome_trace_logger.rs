@@ -0,0 +1,190 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::cell::Cell;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::time::Instant;
+
+use log::debug;
+use serde_json::Map;
+use serde_json::Value;
+use serde_json::json;
+
+use super::console_logger::print_time;
+use super::perf_logger::PerfLogEvent;
+use super::perf_logger::PerfLogger;
+
+/// Name of the event field used to group spans by project.
+const PROJECT_FIELD: &str = "project";
+
+/// A `PerfLogger` that records every timer as a span, and writes them in the
+/// Chrome Trace Event format, which can be loaded in Perfetto or
+/// `chrome://tracing`.
+///
+/// Spans of events with a `project` string are grouped in a process per
+/// project, and spans recorded on the same thread nest by time. Timings are
+/// also printed to the debug log, like `ConsoleLogger`.
+pub struct ChromeTraceLogger {
+    start: Instant,
+    spans: Arc<Mutex<Vec<TraceSpan>>>,
+}
+
+pub struct ChromeTraceLogEvent {
+    name: &'static str,
+    start: Instant,
+    spans: Arc<Mutex<Vec<TraceSpan>>>,
+    fields: Mutex<Map<String, Value>>,
+}
+
+struct TraceSpan {
+    name: &'static str,
+    category: &'static str,
+    thread_id: u64,
+    start_us: u128,
+    duration_us: u128,
+    fields: Map<String, Value>,
+}
+
+impl ChromeTraceLogger {
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            spans: Default::default(),
+        }
+    }
+
+    /// Build the trace document of all spans recorded so far.
+    pub fn to_json(&self) -> Value {
+        let spans = self.spans.lock().unwrap();
+        let mut projects: Vec<&str> = vec![];
+        let mut trace_events = vec![json!({
+            "name": "process_name",
+            "ph": "M",
+            "pid": 0,
+            "args": { "name": "relay-compiler" },
+        })];
+
+        for span in spans.iter() {
+            let pid = match span.fields.get(PROJECT_FIELD).and_then(Value::as_str) {
+                Some(project) => match projects.iter().position(|name| *name == project) {
+                    Some(index) => index + 1,
+                    None => {
+                        projects.push(project);
+                        trace_events.push(json!({
+                            "name": "process_name",
+                            "ph": "M",
+                            "pid": projects.len(),
+                            "args": { "name": format!("project: {project}") },
+                        }));
+                        projects.len()
+                    }
+                },
+                None => 0,
+            };
+            trace_events.push(json!({
+                "name": span.name,
+                "cat": span.category,
+                "ph": "X",
+                "pid": pid,
+                "tid": span.thread_id,
+                "ts": span.start_us,
+                "dur": span.duration_us,
+                "args": span.fields,
+            }));
+        }
+
+        json!({
+            "traceEvents": trace_events,
+            "displayTimeUnit": "ms",
+        })
+    }
+
+    /// Write the trace document of all spans recorded so far to `path`.
+    pub fn write_to_file(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, serde_json::to_vec(&self.to_json())?)
+    }
+}
+
+impl Default for ChromeTraceLogger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PerfLogger for ChromeTraceLogger {
+    type PerfLogEvent = ChromeTraceLogEvent;
+    fn create_event(&self, name: &'static str) -> Self::PerfLogEvent {
+        ChromeTraceLogEvent {
+            name,
+            start: self.start,
+            spans: Arc::clone(&self.spans),
+            fields: Default::default(),
+        }
+    }
+}
+
+impl ChromeTraceLogEvent {
+    fn set_field(&self, name: &'static str, value: Value) {
+        self.fields.lock().unwrap().insert(name.to_string(), value);
+    }
+}
+
+impl PerfLogEvent for ChromeTraceLogEvent {
+    type Timer = (&'static str, Instant, u64);
+    fn number(&self, name: &'static str, number: usize) {
+        debug!("{name}: {number}");
+        self.set_field(name, number.into());
+    }
+    fn bool(&self, name: &'static str, value: bool) {
+        debug!("{name}: {value}");
+        self.set_field(name, value.into());
+    }
+    fn string(&self, name: &'static str, value: String) {
+        debug!("{name}: {value}");
+        self.set_field(name, value.into());
+    }
+    fn start(&self, name: &'static str) -> Self::Timer {
+        (name, Instant::now(), current_thread_id())
+    }
+    fn stop(&self, timer: Self::Timer) {
+        let (name, time, thread_id) = timer;
+        print_time(name, time);
+        // Fields are read when the timer stops, so spans started before the
+        // project name is logged are still attributed to the project.
+        let span = TraceSpan {
+            name,
+            category: self.name,
+            thread_id,
+            start_us: time.duration_since(self.start).as_micros(),
+            duration_us: time.elapsed().as_micros(),
+            fields: self.fields.lock().unwrap().clone(),
+        };
+        self.spans.lock().unwrap().push(span);
+    }
+    fn complete(self) {}
+}
+
+/// Small sequential thread ids, which trace viewers display more readably
+/// than the hashed `ThreadId`.
+fn current_thread_id() -> u64 {
+    static NEXT_THREAD_ID: AtomicU64 = AtomicU64::new(1);
+    thread_local! {
+        static THREAD_ID: Cell<u64> = const { Cell::new(0) };
+    }
+    THREAD_ID.with(|id| {
+        if id.get() == 0 {
+            id.set(NEXT_THREAD_ID.fetch_add(1, Ordering::Relaxed));
+        }
+        id.get()
+    })
+}
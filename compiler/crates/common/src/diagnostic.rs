@@ -110,6 +110,26 @@ impl Diagnostic {
         Diagnostic::with_severity(DiagnosticSeverity::WARNING, message, location, tags)
     }
 
+    /// Creates a new Diagnostic with a severity of Warning and additional
+    /// data that can be used in IDE code actions
+    pub fn warning_with_data<T: 'static + DiagnosticDisplay + WithDiagnosticData>(
+        message: T,
+        location: Location,
+        tags: Vec<DiagnosticTag>,
+    ) -> Self {
+        let data = message.get_data();
+        Self(Box::new(DiagnosticData {
+            message: Box::new(message),
+            location,
+            tags,
+            severity: DiagnosticSeverity::WARNING,
+            related_information: Vec::new(),
+            data,
+            machine_readable: BTreeMap::new(),
+            message_type_name: std::any::type_name::<T>().to_string(),
+        }))
+    }
+
     /// Creates a new Diagnostic with a severity of Information
     /// Additional locations can be added with the `.annotate()` function.
     pub fn info<T: 'static + DiagnosticDisplay>(
@@ -123,6 +123,12 @@ pub struct FeatureFlags {
     #[serde(default)]
     pub disable_schema_validation: bool,
 
+    /// Also validate the directives applied in the composite schema, default
+    /// values, input object cycles, `@oneOf` input objects and deprecated
+    /// required arguments.
+    #[serde(default)]
+    pub enable_strict_schema_validation: bool,
+
     /// Feature flag to prefer `fetch_MyType()` generatior over `node()` query generator
     /// in @refetchable transform
     #[serde(default)]
@@ -175,6 +181,15 @@ pub struct FeatureFlags {
     #[serde(default)]
     pub disable_deduping_common_structures_in_artifacts: FeatureFlag,
 
+    /// Generate a `<FragmentName>$normalizationSelections` module with the
+    /// normalization selections of each enabled fragment, and import it in
+    /// the artifacts of enabled operations containing the same selections,
+    /// instead of repeating them in every artifact.
+    ///
+    /// Fragments with arguments are not shared.
+    #[serde(default)]
+    pub share_normalization_selections: FeatureFlag,
+
     /// The `path` field in `@required` Reader AST nodes is no longer used. But
     /// removing them in one diff is too large of a change to ship at once.
     ///
@@ -242,6 +257,7 @@ impl Default for FeatureFlags {
             enable_fragment_argument_transform: Default::default(),
             allow_resolver_non_nullable_return_type: Default::default(),
             disable_schema_validation: Default::default(),
+            enable_strict_schema_validation: Default::default(),
             prefer_fetchable_in_refetch_queries: Default::default(),
             disable_edge_type_name_validation_on_declerative_connection_directives:
                 Default::default(),
@@ -249,6 +265,7 @@ impl Default for FeatureFlags {
             use_reader_module_imports: Default::default(),
             omit_resolver_type_assertions_for_confirmed_types: Default::default(),
             disable_deduping_common_structures_in_artifacts: Default::default(),
+            share_normalization_selections: Default::default(),
             legacy_include_path_in_required_reader_nodes: Default::default(),
             disallow_required_action_throw_on_semantically_nullable_fields: Default::default(),
             enable_shadow_resolvers: Default::default(),
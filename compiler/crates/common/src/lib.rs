@@ -11,6 +11,7 @@
 // This warning is triggered by code generated by derive macro typetag-impl-0.2.15
 // Supressing for now to get Relay building
 #![allow(non_local_definitions)]
+mod chrome_trace_logger;
 mod console_logger;
 mod diagnostic;
 mod diagnostic_check;
@@ -24,6 +25,8 @@ mod span;
 pub mod sync;
 mod text_source;
 
+pub use chrome_trace_logger::ChromeTraceLogEvent;
+pub use chrome_trace_logger::ChromeTraceLogger;
 pub use console_logger::ConsoleLogEvent;
 pub use console_logger::ConsoleLogger;
 pub use console_logger::print_time;
@@ -0,0 +1,89 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::ChromeTraceLogger;
+use common::PerfLogEvent;
+use common::PerfLogger;
+use serde_json::Value;
+
+fn complete_events(trace: &Value) -> Vec<&Value> {
+    trace["traceEvents"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|event| event["ph"] == "X")
+        .collect()
+}
+
+#[test]
+fn records_nested_timers() {
+    let logger = ChromeTraceLogger::new();
+    let event = logger.create_event("build_project");
+    let outer = event.start("build_project_time");
+    event.time("build_schema_time", || {});
+    event.stop(outer);
+    event.complete();
+
+    let trace = logger.to_json();
+    let events = complete_events(&trace);
+    assert_eq!(events.len(), 2);
+
+    let (inner, outer) = (events[0], events[1]);
+    assert_eq!(inner["name"], "build_schema_time");
+    assert_eq!(outer["name"], "build_project_time");
+    assert_eq!(outer["cat"], "build_project");
+    assert_eq!(inner["tid"], outer["tid"]);
+    let start = |event: &Value| event["ts"].as_u64().unwrap();
+    let end = |event: &Value| start(event) + event["dur"].as_u64().unwrap();
+    assert!(start(outer) <= start(inner));
+    assert!(end(inner) <= end(outer));
+}
+
+#[test]
+fn groups_spans_by_project() {
+    let logger = ChromeTraceLogger::new();
+    for project in ["first", "second", "first"] {
+        let event = logger.create_event("build_project");
+        let timer = event.start("build_project_time");
+        event.string("project", project.to_string());
+        event.number("documents", 3);
+        event.stop(timer);
+        event.complete();
+    }
+    let event = logger.create_event("compiler_setup");
+    event.time("setup_time", || {});
+    event.complete();
+
+    let trace = logger.to_json();
+    let process_names = trace["traceEvents"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|event| event["ph"] == "M")
+        .map(|event| {
+            (
+                event["pid"].as_u64().unwrap(),
+                event["args"]["name"].clone(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        process_names,
+        vec![
+            (0, "relay-compiler".into()),
+            (1, "project: first".into()),
+            (2, "project: second".into()),
+        ]
+    );
+
+    let pids = complete_events(&trace)
+        .iter()
+        .map(|event| event["pid"].as_u64().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(pids, vec![1, 2, 1, 0]);
+    assert_eq!(complete_events(&trace)[0]["args"]["documents"], 3);
+}
@@ -0,0 +1,261 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::collections::BTreeSet;
+use std::collections::VecDeque;
+use std::fmt::Write;
+
+use common::Location;
+use common::NamedItem;
+use graphql_ir::ExecutableDefinitionName;
+use graphql_ir::FragmentSpread;
+use graphql_ir::Program;
+use graphql_ir::Visitor;
+use relay_transforms::INLINE_DIRECTIVE_NAME;
+use relay_transforms::MATCH_CONSTANTS;
+use relay_transforms::RelayDirective;
+use rustc_hash::FxHashSet;
+use schema::Schema;
+use serde::Serialize;
+
+use crate::get_program_definition_references;
+
+/// An operation or fragment of the graph.
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GraphNode {
+    pub name: String,
+    /// `query`, `mutation`, `subscription` or `fragment`.
+    pub kind: String,
+    /// Type condition of a fragment, or root type of an operation.
+    pub type_condition: String,
+    /// Path of the source file, relative to the root directory.
+    pub file: Option<String>,
+    /// Fragment annotated with `@relay(plural: true)`.
+    pub plural: bool,
+    /// Fragment annotated with `@inline`.
+    pub inline: bool,
+}
+
+/// A fragment spread of an operation or fragment.
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GraphEdge {
+    pub from: String,
+    pub to: String,
+    /// At least one spread is a `@module` spread.
+    pub module: bool,
+}
+
+/// The graph of the fragment spreads of operations and fragments, with nodes
+/// and edges sorted by name.
+#[derive(Clone, Serialize)]
+pub struct DefinitionGraph {
+    pub nodes: Vec<GraphNode>,
+    pub edges: Vec<GraphEdge>,
+}
+
+impl DefinitionGraph {
+    pub fn from_program(program: &Program) -> Self {
+        let mut nodes = vec![];
+        let mut module_spreads = ModuleSpreadsCollector::default();
+
+        for operation in program.operations() {
+            nodes.push(GraphNode {
+                name: operation.name.item.0.to_string(),
+                kind: operation.kind.to_string(),
+                type_condition: program.schema.get_type_name(operation.type_).to_string(),
+                file: source_file(&operation.name.location),
+                plural: false,
+                inline: false,
+            });
+            module_spreads.from = Some(operation.name.item.into());
+            module_spreads.visit_operation(operation);
+        }
+        for fragment in program.fragments() {
+            nodes.push(GraphNode {
+                name: fragment.name.item.0.to_string(),
+                kind: "fragment".to_string(),
+                type_condition: program
+                    .schema
+                    .get_type_name(fragment.type_condition)
+                    .to_string(),
+                file: source_file(&fragment.name.location),
+                plural: RelayDirective::find(&fragment.directives)
+                    .is_some_and(|directive| directive.plural),
+                inline: fragment.directives.named(*INLINE_DIRECTIVE_NAME).is_some(),
+            });
+            module_spreads.from = Some(fragment.name.item.into());
+            module_spreads.visit_fragment(fragment);
+        }
+        nodes.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut edges = get_program_definition_references(program)
+            .into_iter()
+            .flat_map(|(from, references)| {
+                let module_spreads = &module_spreads.spreads;
+                references.into_iter().map(move |to| GraphEdge {
+                    from: from.to_string(),
+                    to: to.to_string(),
+                    module: module_spreads.contains(&(from, to)),
+                })
+            })
+            .collect::<Vec<_>>();
+        edges.sort_by(|a, b| (&a.from, &a.to).cmp(&(&b.from, &b.to)));
+
+        Self { nodes, edges }
+    }
+
+    /// The subgraph of the definitions reachable from `roots`. Returns the
+    /// name of the first root that is not in the graph as error.
+    pub fn subgraph(&self, roots: &[String]) -> Result<Self, String> {
+        let names = self
+            .nodes
+            .iter()
+            .map(|node| node.name.as_str())
+            .collect::<BTreeSet<_>>();
+        if let Some(root) = roots.iter().find(|root| !names.contains(root.as_str())) {
+            return Err(root.clone());
+        }
+
+        let mut reachable = roots.iter().map(String::as_str).collect::<BTreeSet<_>>();
+        let mut queue = roots.iter().map(String::as_str).collect::<VecDeque<_>>();
+        while let Some(name) = queue.pop_front() {
+            for edge in self.edges.iter().filter(|edge| edge.from == name) {
+                if reachable.insert(edge.to.as_str()) {
+                    queue.push_back(edge.to.as_str());
+                }
+            }
+        }
+
+        Ok(Self {
+            nodes: self
+                .nodes
+                .iter()
+                .filter(|node| reachable.contains(node.name.as_str()))
+                .cloned()
+                .collect(),
+            edges: self
+                .edges
+                .iter()
+                .filter(|edge| reachable.contains(edge.from.as_str()))
+                .cloned()
+                .collect(),
+        })
+    }
+
+    /// Print the graph in the Graphviz DOT language. Operations are boxes,
+    /// and `@module` edges are dashed.
+    pub fn to_dot(&self) -> String {
+        let mut out = String::new();
+        writeln!(out, "digraph {{").unwrap();
+        writeln!(out, "  rankdir=LR;").unwrap();
+        for node in &self.nodes {
+            let shape = if node.kind == "fragment" {
+                "ellipse"
+            } else {
+                "box"
+            };
+            writeln!(
+                out,
+                "  \"{}\" [label=\"{}\", shape={}];",
+                node.name,
+                node_label(node).join("\\n").replace('"', "\\\""),
+                shape
+            )
+            .unwrap();
+        }
+        for edge in &self.edges {
+            if edge.module {
+                writeln!(
+                    out,
+                    "  \"{}\" -> \"{}\" [label=\"@module\", style=dashed];",
+                    edge.from, edge.to
+                )
+                .unwrap();
+            } else {
+                writeln!(out, "  \"{}\" -> \"{}\";", edge.from, edge.to).unwrap();
+            }
+        }
+        writeln!(out, "}}").unwrap();
+        out
+    }
+
+    /// Print the graph as a Mermaid flowchart. Operations are rectangles,
+    /// fragments are rounded, and `@module` edges are dotted.
+    pub fn to_mermaid(&self) -> String {
+        let mut out = String::new();
+        writeln!(out, "flowchart LR").unwrap();
+        for node in &self.nodes {
+            let label = node_label(node).join("<br/>").replace('"', "#quot;");
+            if node.kind == "fragment" {
+                writeln!(out, "  {}(\"{}\")", node.name, label).unwrap();
+            } else {
+                writeln!(out, "  {}[\"{}\"]", node.name, label).unwrap();
+            }
+        }
+        for edge in &self.edges {
+            if edge.module {
+                writeln!(out, "  {} -.->|\"@module\"| {}", edge.from, edge.to).unwrap();
+            } else {
+                writeln!(out, "  {} --> {}", edge.from, edge.to).unwrap();
+            }
+        }
+        out
+    }
+}
+
+fn node_label(node: &GraphNode) -> Vec<String> {
+    let mut lines = vec![
+        node.name.clone(),
+        format!("{} on {}", node.kind, node.type_condition),
+    ];
+    if node.plural {
+        lines.push("@relay(plural: true)".to_string());
+    }
+    if node.inline {
+        lines.push("@inline".to_string());
+    }
+    if let Some(file) = &node.file {
+        lines.push(file.clone());
+    }
+    lines
+}
+
+fn source_file(location: &Location) -> Option<String> {
+    let source_location = location.source_location();
+    if source_location.is_generated() {
+        None
+    } else {
+        Some(source_location.path().to_string())
+    }
+}
+
+/// Collects the `@module` spreads of definitions, the other references are
+/// found with `get_program_definition_references`.
+#[derive(Default)]
+struct ModuleSpreadsCollector {
+    from: Option<ExecutableDefinitionName>,
+    spreads: FxHashSet<(ExecutableDefinitionName, ExecutableDefinitionName)>,
+}
+
+impl Visitor for ModuleSpreadsCollector {
+    const NAME: &'static str = "ModuleSpreadsCollector";
+    const VISIT_ARGUMENTS: bool = false;
+    const VISIT_DIRECTIVES: bool = false;
+
+    fn visit_fragment_spread(&mut self, spread: &FragmentSpread) {
+        if let Some(from) = self.from
+            && spread
+                .directives
+                .named(MATCH_CONSTANTS.module_directive_name)
+                .is_some()
+        {
+            self.spreads.insert((from, spread.fragment.item.into()));
+        }
+    }
+}
@@ -56,13 +56,80 @@ pub fn get_reachable_ir(
     schema: &SDLSchema,
     schema_changes: FxHashSet<check::IncrementalBuildSchemaChange>,
     log_event: &impl PerfLogEvent,
+) -> Vec<ExecutableDefinition> {
+    reachable_ir(
+        definitions,
+        base_definition_names,
+        changed_names,
+        schema,
+        schema_changes,
+        log_event,
+        None,
+    )
+}
+
+/// Why a definition returned by `get_reachable_ir_with_reasons` is rebuilt.
+#[derive(Debug)]
+pub struct ReachableIrReason {
+    /// The changed definition, or the definition affected by a schema change,
+    /// the rebuild originates from.
+    pub origin: ExecutableDefinitionName,
+    /// The schema change affecting `origin`, when `origin` itself did not
+    /// change.
+    pub schema_change: Option<check::IncrementalBuildSchemaChange>,
+    /// Dependency chain from `origin` to the rebuilt definition: up through the
+    /// definitions spreading `origin` to a root definition, then down through
+    /// the spreads of that root. When the rebuilt definition itself spreads
+    /// `origin`, the chain stops there.
+    pub path: ExecutableDefinitionNameVec,
+}
+
+/// Same as `get_reachable_ir`, but also returns the reason each of the
+/// reachable definitions is rebuilt.
+pub fn get_reachable_ir_with_reasons(
+    definitions: Vec<ExecutableDefinition>,
+    base_definition_names: ExecutableDefinitionNameSet,
+    changed_names: ExecutableDefinitionNameSet,
+    schema: &SDLSchema,
+    schema_changes: FxHashSet<check::IncrementalBuildSchemaChange>,
+    log_event: &impl PerfLogEvent,
+) -> (
+    Vec<ExecutableDefinition>,
+    ExecutableDefinitionNameMap<ReachableIrReason>,
+) {
+    let mut reasons = Default::default();
+    let definitions = reachable_ir(
+        definitions,
+        base_definition_names,
+        changed_names,
+        schema,
+        schema_changes,
+        log_event,
+        Some(&mut reasons),
+    );
+    (definitions, reasons)
+}
+
+fn reachable_ir(
+    definitions: Vec<ExecutableDefinition>,
+    base_definition_names: ExecutableDefinitionNameSet,
+    changed_names: ExecutableDefinitionNameSet,
+    schema: &SDLSchema,
+    schema_changes: FxHashSet<check::IncrementalBuildSchemaChange>,
+    log_event: &impl PerfLogEvent,
+    reasons: Option<&mut ExecutableDefinitionNameMap<ReachableIrReason>>,
 ) -> Vec<ExecutableDefinition> {
     let timer = log_event.start("get_reachable_ir_time");
     let result = if changed_names.is_empty() && schema_changes.is_empty() {
         vec![]
     } else {
-        let mut all_changed_names: ExecutableDefinitionNameSet =
+        let mut affected_definitions =
             schema_change_analyzer::get_affected_definitions(schema, &definitions, schema_changes);
+        for name in &changed_names {
+            affected_definitions.remove(name);
+        }
+        let mut all_changed_names: ExecutableDefinitionNameVec =
+            affected_definitions.keys().copied().collect();
         all_changed_names.extend(changed_names);
 
         // For each executable definition, define a `Node` indicating its parents and children
@@ -75,6 +142,7 @@ pub fn get_reachable_ir(
 
         let mut visited = Default::default();
         let mut filtered_definitions = Default::default();
+        let mut provenance = reasons.as_ref().map(|_| Provenance::default());
 
         for key in all_changed_names.into_iter() {
             if dependency_graph.contains_key(&key) {
@@ -84,6 +152,22 @@ pub fn get_reachable_ir(
                     &dependency_graph,
                     &base_definition_names,
                     key,
+                    None,
+                    provenance.as_mut(),
+                );
+            }
+        }
+
+        if let (Some(reasons), Some(provenance)) = (reasons, provenance) {
+            for name in filtered_definitions.keys() {
+                let path = provenance.path_to(*name);
+                reasons.insert(
+                    *name,
+                    ReachableIrReason {
+                        origin: path[0],
+                        schema_change: affected_definitions.get(&path[0]).copied(),
+                        path,
+                    },
                 );
             }
         }
@@ -98,6 +182,40 @@ pub fn get_reachable_ir(
     result
 }
 
+/// Records how `add_related_nodes` and `add_descendants` reached each
+/// definition, to reconstruct why it was added.
+#[derive(Default)]
+struct Provenance {
+    /// For each ancestor visited, the child it was visited from. Changed
+    /// definitions have no entry.
+    visited_from_child: ExecutableDefinitionNameMap<ExecutableDefinitionName>,
+    /// For each definition added, the parent it was added from. Root
+    /// definitions have no entry.
+    added_from_parent: ExecutableDefinitionNameMap<ExecutableDefinitionName>,
+}
+
+impl Provenance {
+    fn path_to(&self, name: ExecutableDefinitionName) -> ExecutableDefinitionNameVec {
+        let mut down = vec![name];
+        while let Some(parent) = self.added_from_parent.get(down.last().unwrap()) {
+            down.push(*parent);
+        }
+        let mut path = vec![*down.last().unwrap()];
+        while let Some(child) = self.visited_from_child.get(path.last().unwrap()) {
+            path.push(*child);
+        }
+        path.reverse();
+        // Definitions depending on the origin are rebuilt because of it, not
+        // because a root definition that includes them is rebuilt.
+        if let Some(index) = path.iter().position(|ancestor| *ancestor == name) {
+            path.truncate(index + 1);
+        } else {
+            path.extend(down.into_iter().rev().skip(1));
+        }
+        path
+    }
+}
+
 // Build a dependency graph of that nodes are "doubly linked"
 fn build_dependency_graph(
     schema: &SDLSchema,
@@ -257,10 +375,15 @@ fn add_related_nodes(
     dependency_graph: &ExecutableDefinitionNameMap<Node>,
     base_definition_names: &ExecutableDefinitionNameSet,
     key: ExecutableDefinitionName,
+    from_child: Option<ExecutableDefinitionName>,
+    mut provenance: Option<&mut Provenance>,
 ) {
     if !visited.insert(key) {
         return;
     }
+    if let (Some(provenance), Some(child)) = (provenance.as_deref_mut(), from_child) {
+        provenance.visited_from_child.insert(key, child);
+    }
 
     let parents = match dependency_graph.get(&key) {
         None => {
@@ -270,7 +393,7 @@ fn add_related_nodes(
     };
     if parents.is_empty() {
         if !base_definition_names.contains(&key) {
-            add_descendants(result, dependency_graph, key);
+            add_descendants(result, dependency_graph, key, None, provenance);
         }
     } else {
         for parent in parents {
@@ -280,6 +403,8 @@ fn add_related_nodes(
                 dependency_graph,
                 base_definition_names,
                 *parent,
+                Some(key),
+                provenance.as_deref_mut(),
             );
         }
     }
@@ -290,6 +415,8 @@ fn add_descendants(
     result: &mut ExecutableDefinitionNameMap<ExecutableDefinition>,
     dependency_graph: &ExecutableDefinitionNameMap<Node>,
     key: ExecutableDefinitionName,
+    from_parent: Option<ExecutableDefinitionName>,
+    mut provenance: Option<&mut Provenance>,
 ) {
     if result.contains_key(&key) {
         return;
@@ -301,8 +428,17 @@ fn add_descendants(
             ..
         }) => {
             result.insert(key, def.clone());
+            if let (Some(provenance), Some(parent)) = (provenance.as_deref_mut(), from_parent) {
+                provenance.added_from_parent.insert(key, parent);
+            }
             for child in children {
-                add_descendants(result, dependency_graph, *child);
+                add_descendants(
+                    result,
+                    dependency_graph,
+                    *child,
+                    Some(key),
+                    provenance.as_deref_mut(),
+                );
             }
         }
         Some(Node { ir: None, .. }) => {
@@ -338,45 +474,76 @@ pub fn get_ir_definition_references(
                 ExecutableDefinition::Operation(_) => OperationDefinitionName(name).into(),
                 ExecutableDefinition::Fragment(_) => FragmentDefinitionName(name).into(),
             };
-            let mut selections: Vec<_> = match definition {
+            let selections = match definition {
                 ExecutableDefinition::Operation(definition) => &definition.selections,
                 ExecutableDefinition::Fragment(definition) => &definition.selections,
+            };
+            (name, get_selections_references(schema, selections))
+        })
+        .collect()
+}
+
+/// Get fragment references of each definition of a program, like
+/// `get_ir_definition_references`, without copying the definitions.
+pub fn get_program_definition_references(
+    program: &Program,
+) -> ExecutableDefinitionNameMap<ExecutableDefinitionNameSet> {
+    let definitions = program
+        .operations()
+        .map(|operation| (operation.name.item.into(), &operation.selections))
+        .chain(
+            program
+                .fragments()
+                .map(|fragment| (fragment.name.item.into(), &fragment.selections)),
+        )
+        .collect::<Vec<(ExecutableDefinitionName, _)>>();
+    definitions
+        .par_iter()
+        .map(|(name, selections)| {
+            (
+                *name,
+                get_selections_references(&program.schema, selections),
+            )
+        })
+        .collect()
+}
+
+fn get_selections_references(
+    schema: &SDLSchema,
+    selections: &[Selection],
+) -> ExecutableDefinitionNameSet {
+    let mut selections: Vec<_> = selections.iter().collect();
+    let mut references: ExecutableDefinitionNameSet = Default::default();
+    while let Some(selection) = selections.pop() {
+        match selection {
+            Selection::FragmentSpread(selection) => {
+                references.insert(selection.fragment.item.into());
             }
-            .iter()
-            .collect();
-            let mut references: ExecutableDefinitionNameSet = Default::default();
-            while let Some(selection) = selections.pop() {
-                match selection {
-                    Selection::FragmentSpread(selection) => {
-                        references.insert(selection.fragment.item.into());
-                    }
-                    Selection::LinkedField(selection) => {
-                        for fragment_name in get_all_resolver_fragment_dependency_names(
-                            schema.field(selection.definition.item),
-                            schema,
-                        ) {
-                            references.insert(fragment_name.into());
-                        }
-
-                        selections.extend(&selection.selections);
-                    }
-                    Selection::InlineFragment(selection) => {
-                        selections.extend(&selection.selections);
-                    }
-                    Selection::Condition(selection) => {
-                        selections.extend(&selection.selections);
-                    }
-                    Selection::ScalarField(selection) => {
-                        for fragment_name in get_all_resolver_fragment_dependency_names(
-                            schema.field(selection.definition.item),
-                            schema,
-                        ) {
-                            references.insert(fragment_name.into());
-                        }
-                    }
+            Selection::LinkedField(selection) => {
+                for fragment_name in get_all_resolver_fragment_dependency_names(
+                    schema.field(selection.definition.item),
+                    schema,
+                ) {
+                    references.insert(fragment_name.into());
                 }
+
+                selections.extend(&selection.selections);
             }
-            (name, references)
-        })
-        .collect()
+            Selection::InlineFragment(selection) => {
+                selections.extend(&selection.selections);
+            }
+            Selection::Condition(selection) => {
+                selections.extend(&selection.selections);
+            }
+            Selection::ScalarField(selection) => {
+                for fragment_name in get_all_resolver_fragment_dependency_names(
+                    schema.field(selection.definition.item),
+                    schema,
+                ) {
+                    references.insert(fragment_name.into());
+                }
+            }
+        }
+    }
+    references
 }
@@ -11,6 +11,7 @@
 #![deny(clippy::all)]
 
 mod ast;
+mod definition_graph;
 mod ir;
 mod minimized_executable;
 mod query_stats;
@@ -19,11 +20,21 @@ mod schema_change_analyzer;
 pub use ast::ReachableAst;
 pub use ast::get_definition_references;
 pub use ast::get_reachable_ast;
+pub use definition_graph::DefinitionGraph;
+pub use definition_graph::GraphEdge;
+pub use definition_graph::GraphNode;
 pub use ir::ExecutableDefinitionNameMap;
 pub use ir::ExecutableDefinitionNameSet;
 pub use ir::ExecutableDefinitionNameVec;
+pub use ir::ReachableIrReason;
 pub use ir::get_ir_definition_references;
+pub use ir::get_program_definition_references;
 pub use ir::get_reachable_ir;
+pub use ir::get_reachable_ir_with_reasons;
 pub use minimized_executable::MinProgram;
+pub use query_stats::Distribution;
+pub use query_stats::FragmentStats;
+pub use query_stats::OperationStats;
 pub use query_stats::QueryStatsReport;
+pub use query_stats::compute_program_query_stats;
 pub use query_stats::compute_query_stats;
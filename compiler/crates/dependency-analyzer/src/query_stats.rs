@@ -9,11 +9,17 @@ use std::fmt;
 use std::fmt::Write;
 
 use graphql_ir::ExecutableDefinitionName;
+use graphql_ir::FragmentDefinitionName;
+use graphql_ir::Program;
 use graphql_syntax::ExecutableDefinition;
 use graphql_syntax::OperationKind;
+use intern::string_key::Intern;
+use intern::string_key::StringKey;
+use serde::Serialize;
 
 use crate::ExecutableDefinitionNameMap;
 use crate::ExecutableDefinitionNameSet;
+use crate::get_program_definition_references;
 
 /// Per-operation fragment usage statistics.
 pub struct OperationStats {
@@ -24,19 +30,30 @@ pub struct OperationStats {
     pub max_fragment_depth: usize,
 }
 
+/// Per-fragment usage statistics.
+pub struct FragmentStats {
+    pub name: String,
+    /// Number of operations and fragments that directly reference this fragment.
+    pub fan_in: usize,
+    /// Number of fragments directly referenced by this fragment.
+    pub fan_out: usize,
+}
+
 /// Distribution statistics for a set of values.
-struct Distribution {
-    min: usize,
-    max: usize,
-    mean: f64,
-    median: usize,
-    p90: usize,
-    p99: usize,
+#[derive(Serialize)]
+pub struct Distribution {
+    pub min: usize,
+    pub max: usize,
+    pub mean: f64,
+    pub median: usize,
+    pub p90: usize,
+    pub p99: usize,
 }
 
 /// Aggregated report of per-operation fragment usage across a project.
 pub struct QueryStatsReport {
     pub operations: Vec<OperationStats>,
+    pub fragments: Vec<FragmentStats>,
     pub query_count: usize,
     pub mutation_count: usize,
     pub subscription_count: usize,
@@ -47,6 +64,48 @@ pub struct QueryStatsReport {
 pub fn compute_query_stats(
     definitions: &[ExecutableDefinition],
     dep_map: &ExecutableDefinitionNameMap<ExecutableDefinitionNameSet>,
+) -> QueryStatsReport {
+    let mut operations = Vec::new();
+    let mut fragment_names = Vec::new();
+    for def in definitions {
+        match def {
+            ExecutableDefinition::Operation(op) => {
+                let name_str = op
+                    .name
+                    .as_ref()
+                    .map(|n| n.value.to_string())
+                    .unwrap_or_else(|| "<anonymous>".to_string());
+                operations.push((name_str, op.operation_kind()));
+            }
+            ExecutableDefinition::Fragment(fragment) => {
+                fragment_names.push(fragment.name.value);
+            }
+        }
+    }
+    build_report(operations, fragment_names, dep_map)
+}
+
+/// Compute per-operation fragment statistics of a compiled program, including
+/// implicit dependencies such as Relay Resolver fragments.
+pub fn compute_program_query_stats(program: &Program) -> QueryStatsReport {
+    let dep_map = get_program_definition_references(program);
+    build_report(
+        program
+            .operations()
+            .map(|operation| (operation.name.item.0.to_string(), operation.kind))
+            .collect(),
+        program
+            .fragments()
+            .map(|fragment| fragment.name.item.0)
+            .collect(),
+        &dep_map,
+    )
+}
+
+fn build_report(
+    operation_names: Vec<(String, OperationKind)>,
+    fragment_names: Vec<StringKey>,
+    dep_map: &ExecutableDefinitionNameMap<ExecutableDefinitionNameSet>,
 ) -> QueryStatsReport {
     // Precompute max_depth for every node in the dep_map. This is composable:
     // depth(F) = 0 if F has no refs, else 1 + max(depth(child)).
@@ -59,47 +118,35 @@ pub fn compute_query_stats(
     let mut mutation_count = 0usize;
     let mut subscription_count = 0usize;
 
-    for def in definitions {
-        if let ExecutableDefinition::Operation(op) = def {
-            let name_str = op
-                .name
-                .as_ref()
-                .map(|n| n.value.to_string())
-                .unwrap_or_else(|| "<anonymous>".to_string());
-
-            let kind = op.operation_kind();
-
-            match kind {
-                OperationKind::Query => query_count += 1,
-                OperationKind::Mutation => mutation_count += 1,
-                OperationKind::Subscription => subscription_count += 1,
-            }
-
-            let op_def_name = ExecutableDefinitionName::OperationDefinitionName(
-                graphql_ir::OperationDefinitionName(intern::string_key::Intern::intern(
-                    name_str.as_str(),
-                )),
-            );
-
-            let direct_refs = dep_map.get(&op_def_name);
-            let direct_fragment_count = direct_refs.map_or(0, |refs| refs.len());
-
-            let transitive_fragment_count = count_transitive_fragments(dep_map, &op_def_name);
-            let max_fragment_depth = direct_refs.map_or(0, |refs| {
-                refs.iter()
-                    .map(|f| 1 + depth_cache.get(f).copied().unwrap_or(0))
-                    .max()
-                    .unwrap_or(0)
-            });
-
-            operations.push(OperationStats {
-                name: name_str,
-                kind,
-                direct_fragment_count,
-                transitive_fragment_count,
-                max_fragment_depth,
-            });
+    for (name_str, kind) in operation_names {
+        match kind {
+            OperationKind::Query => query_count += 1,
+            OperationKind::Mutation => mutation_count += 1,
+            OperationKind::Subscription => subscription_count += 1,
         }
+
+        let op_def_name = ExecutableDefinitionName::OperationDefinitionName(
+            graphql_ir::OperationDefinitionName(name_str.as_str().intern()),
+        );
+
+        let direct_refs = dep_map.get(&op_def_name);
+        let direct_fragment_count = direct_refs.map_or(0, |refs| refs.len());
+
+        let transitive_fragment_count = count_transitive_fragments(dep_map, &op_def_name);
+        let max_fragment_depth = direct_refs.map_or(0, |refs| {
+            refs.iter()
+                .map(|f| 1 + depth_cache.get(f).copied().unwrap_or(0))
+                .max()
+                .unwrap_or(0)
+        });
+
+        operations.push(OperationStats {
+            name: name_str,
+            kind,
+            direct_fragment_count,
+            transitive_fragment_count,
+            max_fragment_depth,
+        });
     }
 
     // Sort by transitive fragment count descending, then by name ascending for ties
@@ -109,8 +156,30 @@ pub fn compute_query_stats(
             .then_with(|| a.name.cmp(&b.name))
     });
 
+    let mut fan_in: ExecutableDefinitionNameMap<usize> = Default::default();
+    for refs in dep_map.values() {
+        for child in refs {
+            *fan_in.entry(*child).or_default() += 1;
+        }
+    }
+    let mut fragments = fragment_names
+        .into_iter()
+        .map(|name| {
+            let def_name =
+                ExecutableDefinitionName::FragmentDefinitionName(FragmentDefinitionName(name));
+            FragmentStats {
+                name: name.to_string(),
+                fan_in: fan_in.get(&def_name).copied().unwrap_or(0),
+                fan_out: dep_map.get(&def_name).map_or(0, |refs| refs.len()),
+            }
+        })
+        .collect::<Vec<_>>();
+    // Sort by fan-in descending, then by name ascending for ties
+    fragments.sort_by(|a, b| b.fan_in.cmp(&a.fan_in).then_with(|| a.name.cmp(&b.name)));
+
     QueryStatsReport {
         operations,
+        fragments,
         query_count,
         mutation_count,
         subscription_count,
@@ -181,7 +250,7 @@ fn count_transitive_fragments(
 }
 
 impl Distribution {
-    fn from_values(values: &[usize]) -> Option<Self> {
+    pub fn from_values(values: &[usize]) -> Option<Self> {
         if values.is_empty() {
             return None;
         }
@@ -5,7 +5,7 @@
  * LICENSE file in the root directory of this source tree.
  */
 
-use std::collections::HashSet;
+use std::collections::HashMap;
 use std::sync::LazyLock;
 
 use common::ArgumentName;
@@ -23,21 +23,23 @@ use schema::Schema;
 use schema::definitions::Type;
 use schema_diff::check::IncrementalBuildSchemaChange;
 
-use crate::ExecutableDefinitionNameSet;
+use crate::ExecutableDefinitionNameMap;
 
 static DIRECTIVES_ARG: LazyLock<ArgumentName> =
     LazyLock::new(|| ArgumentName("directives".intern()));
 
+/// Find the definitions affected by `schema_changes`, along with the first
+/// change found to affect each of them.
 pub fn get_affected_definitions(
     schema: &SDLSchema,
     definitions: &[ExecutableDefinition],
     schema_changes: FxHashSet<IncrementalBuildSchemaChange>,
-) -> ExecutableDefinitionNameSet {
+) -> ExecutableDefinitionNameMap<IncrementalBuildSchemaChange> {
     SchemaChangeDefinitionFinder::get_definitions(schema, definitions, schema_changes)
 }
 
 struct SchemaChangeDefinitionFinder<'a, 'b> {
-    changed_definitions: ExecutableDefinitionNameSet,
+    changed_definitions: ExecutableDefinitionNameMap<IncrementalBuildSchemaChange>,
     current_executable: &'a ExecutableDefinition,
     schema: &'b SDLSchema,
     schema_changes: &'b FxHashSet<IncrementalBuildSchemaChange>,
@@ -51,17 +53,17 @@ impl SchemaChangeDefinitionFinder<'_, '_> {
         schema: &SDLSchema,
         definitions: &[ExecutableDefinition],
         schema_changes: FxHashSet<IncrementalBuildSchemaChange>,
-    ) -> ExecutableDefinitionNameSet {
+    ) -> ExecutableDefinitionNameMap<IncrementalBuildSchemaChange> {
         if definitions.is_empty() || schema_changes.is_empty() {
-            return HashSet::default();
+            return HashMap::default();
         }
 
         // Process a chunk of definitions sequentially, accumulating into
         // changed_definitions. Reset visited_input_objects per definition
         // so we traverse into each input type at least once per definition.
-        let process_chunk = |changed: ExecutableDefinitionNameSet,
+        let process_chunk = |changed: ExecutableDefinitionNameMap<IncrementalBuildSchemaChange>,
                              chunk: &[ExecutableDefinition]|
-         -> ExecutableDefinitionNameSet {
+         -> ExecutableDefinitionNameMap<IncrementalBuildSchemaChange> {
             let mut finder = SchemaChangeDefinitionFinder {
                 changed_definitions: changed,
                 current_executable: &chunk[0],
@@ -87,15 +89,15 @@ impl SchemaChangeDefinitionFinder<'_, '_> {
         if definitions.len() > 500 {
             definitions
                 .par_iter()
-                .fold(HashSet::default, |changed, def| {
+                .fold(HashMap::default, |changed, def| {
                     process_chunk(changed, std::slice::from_ref(def))
                 })
-                .reduce(HashSet::default, |mut a, b| {
+                .reduce(HashMap::default, |mut a, b| {
                     a.extend(b);
                     a
                 })
         } else {
-            process_chunk(HashSet::default(), definitions)
+            process_chunk(HashMap::default(), definitions)
         }
     }
 
@@ -110,6 +112,14 @@ impl SchemaChangeDefinitionFinder<'_, '_> {
         }
     }
 
+    fn add_change(&mut self, change: IncrementalBuildSchemaChange) {
+        if self.schema_changes.contains(&change) {
+            self.changed_definitions
+                .entry(self.get_name_from_executable())
+                .or_insert(change);
+        }
+    }
+
     fn add_type_changes_from_value(&mut self, value: &Value) {
         match value {
             Value::Variable(variable) => self.add_type_changes(variable.type_.inner()),
@@ -135,44 +145,20 @@ impl SchemaChangeDefinitionFinder<'_, '_> {
             Type::Object(id) => {
                 let object_type = self.schema.object(id);
                 let key = object_type.name.item.0;
-                if self
-                    .schema_changes
-                    .contains(&IncrementalBuildSchemaChange::Object(key))
-                {
-                    self.changed_definitions
-                        .insert(self.get_name_from_executable());
-                }
+                self.add_change(IncrementalBuildSchemaChange::Object(key));
             }
             Type::Union(id) => {
                 let union_name = self.schema.union(id).name.item.0;
-                if self
-                    .schema_changes
-                    .contains(&IncrementalBuildSchemaChange::Union(union_name))
-                {
-                    self.changed_definitions
-                        .insert(self.get_name_from_executable());
-                }
+                self.add_change(IncrementalBuildSchemaChange::Union(union_name));
             }
             Type::Interface(id) => {
                 let interface_name = self.schema.interface(id).name.item.0;
-                if self
-                    .schema_changes
-                    .contains(&IncrementalBuildSchemaChange::Interface(interface_name))
-                {
-                    self.changed_definitions
-                        .insert(self.get_name_from_executable());
-                }
+                self.add_change(IncrementalBuildSchemaChange::Interface(interface_name));
             }
             Type::Enum(id) => {
                 let enum_type = self.schema.enum_(id);
                 let key = enum_type.name.item.0;
-                if self
-                    .schema_changes
-                    .contains(&IncrementalBuildSchemaChange::Enum(key))
-                {
-                    self.changed_definitions
-                        .insert(self.get_name_from_executable());
-                }
+                self.add_change(IncrementalBuildSchemaChange::Enum(key));
             }
             Type::InputObject(id) => {
                 // When an input type is referenced by a definition, not only do
@@ -190,13 +176,7 @@ impl SchemaChangeDefinitionFinder<'_, '_> {
                 if self.visited_input_objects.insert(id) {
                     let input_object = self.schema.input_object(id);
                     let key = input_object.name.item.0;
-                    if self
-                        .schema_changes
-                        .contains(&IncrementalBuildSchemaChange::InputObject(key))
-                    {
-                        self.changed_definitions
-                            .insert(self.get_name_from_executable());
-                    }
+                    self.add_change(IncrementalBuildSchemaChange::InputObject(key));
                     for field in input_object.fields.iter() {
                         self.add_type_changes(field.type_.inner());
                     }
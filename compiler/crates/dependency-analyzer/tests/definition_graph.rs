@@ -0,0 +1,51 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::sync::Arc;
+
+use common::SourceLocationKey;
+use dependency_analyzer::DefinitionGraph;
+use fixture_tests::Fixture;
+use graphql_ir::Program;
+use graphql_ir::build;
+use graphql_syntax::parse_executable;
+use relay_test_schema::get_test_schema;
+
+/// Prints the graph of the fixture in all formats. A line of the form
+/// `# from: Name1, Name2` restricts the graph to the definitions reachable
+/// from these roots.
+pub async fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let source_location = SourceLocationKey::standalone(fixture.file_name);
+    let document = parse_executable(fixture.content, source_location)
+        .map_err(|diagnostics| format!("{diagnostics:?}"))?;
+    let schema = get_test_schema();
+    let ir =
+        build(&schema, &document.definitions).map_err(|diagnostics| format!("{diagnostics:?}"))?;
+    let program = Program::from_definitions(Arc::clone(&schema), ir);
+
+    let mut graph = DefinitionGraph::from_program(&program);
+    if let Some(roots) = fixture
+        .content
+        .lines()
+        .find_map(|line| line.strip_prefix("# from:"))
+    {
+        let roots = roots
+            .split(',')
+            .map(|root| root.trim().to_string())
+            .collect::<Vec<_>>();
+        graph = graph
+            .subgraph(&roots)
+            .map_err(|root| format!("Unknown root `{root}`"))?;
+    }
+
+    Ok(format!(
+        "{}\n{}\n{}",
+        graph.to_dot(),
+        graph.to_mermaid(),
+        serde_json::to_string_pretty(&graph).unwrap()
+    ))
+}
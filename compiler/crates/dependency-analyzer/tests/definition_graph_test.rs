@@ -0,0 +1,34 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<e74481aab97a27e0439b589134e79a89>>
+ */
+
+mod definition_graph;
+
+use definition_graph::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[tokio::test]
+async fn full_graph() {
+    let input = include_str!("definition_graph/fixtures/full-graph.graphql");
+    let expected = include_str!("definition_graph/fixtures/full-graph.expected");
+    test_fixture(transform_fixture, file!(), "full-graph.graphql", "definition_graph/fixtures/full-graph.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn subgraph_from_fragment() {
+    let input = include_str!("definition_graph/fixtures/subgraph-from-fragment.graphql");
+    let expected = include_str!("definition_graph/fixtures/subgraph-from-fragment.expected");
+    test_fixture(transform_fixture, file!(), "subgraph-from-fragment.graphql", "definition_graph/fixtures/subgraph-from-fragment.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn subgraph_unknown_root_invalid() {
+    let input = include_str!("definition_graph/fixtures/subgraph-unknown-root.invalid.graphql");
+    let expected = include_str!("definition_graph/fixtures/subgraph-unknown-root.invalid.expected");
+    test_fixture(transform_fixture, file!(), "subgraph-unknown-root.invalid.graphql", "definition_graph/fixtures/subgraph-unknown-root.invalid.expected", input, expected).await;
+}
@@ -143,6 +143,44 @@ pub async fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String>
     }
 
     let definitions = build(&schema, &asts).unwrap();
+    if content.contains("%reasons%") {
+        let (result, reasons) = get_reachable_ir_with_reasons(
+            definitions,
+            base_names,
+            changed_names,
+            &schema,
+            schema_changes,
+            &NoopPerfLoggerEvent,
+        );
+        let mut texts = result
+            .into_iter()
+            .map(|definition| {
+                let name: ExecutableDefinitionName = match &definition {
+                    ExecutableDefinition::Operation(operation) => operation.name.item.into(),
+                    ExecutableDefinition::Fragment(fragment) => fragment.name.item.into(),
+                };
+                let reason = &reasons[&name];
+                let path = reason
+                    .path
+                    .iter()
+                    .map(|name| name.to_string())
+                    .collect::<Vec<_>>()
+                    .join(" -> ");
+                match reason.schema_change {
+                    Some(change) => format!(
+                        "{}\n  path: {}\n  schema change: {:?}",
+                        format_definition(definition),
+                        path,
+                        change
+                    ),
+                    None => format!("{}\n  path: {}", format_definition(definition), path),
+                }
+            })
+            .collect::<Vec<_>>();
+        texts.sort();
+        return Ok(texts.join("\n\n"));
+    }
+
     let result = get_reachable_ir(
         definitions,
         base_names,
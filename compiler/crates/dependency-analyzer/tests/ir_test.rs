@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<661220fb6e94e2dfba34d92aa2ebb7b1>>
+ * @generated SignedSource<<26f41fc2b4b69c8e39e3e4bd227ad1cd>>
  */
 
 mod ir;
@@ -96,6 +96,20 @@ async fn query_then_fragment() {
     test_fixture(transform_fixture, file!(), "query-then-fragment.graphql", "ir/fixtures/query-then-fragment.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn reasons_fragment_change() {
+    let input = include_str!("ir/fixtures/reasons-fragment-change.graphql");
+    let expected = include_str!("ir/fixtures/reasons-fragment-change.expected");
+    test_fixture(transform_fixture, file!(), "reasons-fragment-change.graphql", "ir/fixtures/reasons-fragment-change.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn reasons_schema_change() {
+    let input = include_str!("ir/fixtures/reasons-schema-change.graphql");
+    let expected = include_str!("ir/fixtures/reasons-schema-change.expected");
+    test_fixture(transform_fixture, file!(), "reasons-schema-change.graphql", "ir/fixtures/reasons-schema-change.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn recursive_fragments() {
     let input = include_str!("ir/fixtures/recursive-fragments.graphql");
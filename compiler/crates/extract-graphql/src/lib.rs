@@ -117,10 +117,11 @@ fn contains_resolver_tag(text: &str) -> bool {
 }
 
 /// Extract graphql`text` literals and Relay Resolver docblock comments from JS-like code.
-// This should work for Flow or TypeScript alike.
+// This should work for Flow or TypeScript alike, as well as for ReScript,
+// which embeds GraphQL as %relay(`text`).
 pub fn extract(input: &str) -> Vec<JavaScriptSourceFeature> {
     let mut res = Vec::new();
-    if !input.contains("graphql") && !contains_resolver_tag(input) {
+    if !input.contains("graphql") && !input.contains("%relay") && !contains_resolver_tag(input) {
         return res;
     }
     let mut it = CharReader::new(input);
@@ -187,6 +188,39 @@ pub fn extract(input: &str) -> Vec<JavaScriptSourceFeature> {
                     }
                 }
             }
+            '%' => {
+                for expected in ['r', 'e', 'l', 'a', 'y', '('] {
+                    match it.chars.peek() {
+                        Some((_, c)) if *c == expected => {
+                            it.next();
+                        }
+                        _ => continue 'code,
+                    }
+                }
+                loop {
+                    match it.next() {
+                        Some((_, '`')) => break,
+                        Some((_, ' ' | '\n' | '\r' | '\t')) => {}
+                        _ => continue 'code,
+                    }
+                }
+                let line_index = it.line_index;
+                let column_index = it.column_index;
+                let start = match it.chars.peek() {
+                    Some((start, _)) => *start,
+                    None => continue 'code,
+                };
+                for (end, c) in &mut it {
+                    if c == '`' {
+                        res.push(JavaScriptSourceFeature::GraphQL(GraphQLSource::new(
+                            &input[start..end],
+                            line_index,
+                            column_index,
+                        )));
+                        continue 'code;
+                    }
+                }
+            }
             'a'..='z' | 'A'..='Z' | '_' => {
                 consume_identifier(&mut it);
             }
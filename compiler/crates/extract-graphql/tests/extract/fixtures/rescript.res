@@ -0,0 +1,20 @@
+module Query = %relay(`
+  query ProfilePageQuery {
+    me {
+      ...ProfileHeader_user
+    }
+  }
+`)
+
+// %relay(`fragment Commented on User { id }`)
+
+let percent = mod(10, 3)
+let label = "%relay(`fragment InString on User { id }`)"
+
+module Fragment = %relay(  `fragment ProfileHeader_user on User { name }`)
+
+@react.component
+let make = () => {
+  let data = Query.use(~variables=(), ())
+  <Fragment user=data.me />
+}
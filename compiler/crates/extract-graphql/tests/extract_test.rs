@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<3c2cb388d73f2904eee16c3b407de258>>
+ * @generated SignedSource<<bcb3a1e08a51bcfe47682e3b48045bc5>>
  */
 
 mod extract;
@@ -89,6 +89,13 @@ async fn relay_resolver_and_graphql() {
     test_fixture(transform_fixture, file!(), "relay_resolver_and_graphql.js", "extract/fixtures/relay_resolver_and_graphql.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn rescript() {
+    let input = include_str!("extract/fixtures/rescript.res");
+    let expected = include_str!("extract/fixtures/rescript.expected");
+    test_fixture(transform_fixture, file!(), "rescript.res", "extract/fixtures/rescript.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn simple() {
     let input = include_str!("extract/fixtures/simple.flow");
@@ -43,4 +43,25 @@ pub enum ErrorCode {
 
     #[error("Error running node: {}", String::from_utf8_lossy(&output.stderr))]
     NodeExecuteError { output: Output },
+
+    #[error("`extends` must be a path or an array of paths")]
+    InvalidExtends,
+
+    #[error("Extended config {extends:?} does not exist")]
+    ExtendsNotFound { extends: PathBuf },
+
+    #[error(
+        "Config extends itself: {}",
+        cycle.iter().map(|path| format!("{path:?}")).collect::<Vec<_>>().join(" -> ")
+    )]
+    ExtendsCycle { cycle: Vec<PathBuf> },
+
+    #[error("`interpolateEnv` must be a boolean")]
+    InvalidInterpolateEnv,
+
+    #[error("Invalid environment variable reference in `{key}`: \"{value}\"")]
+    InvalidInterpolation { key: String, value: String },
+
+    #[error("Environment variable `{name}` referenced in `{key}` is not set")]
+    UndefinedEnvironmentVariable { name: String, key: String },
 }
@@ -0,0 +1,279 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Resolution of the `extends` key and of `${ENV_VAR}` interpolation.
+//!
+//! A config can extend other config files with `"extends": "./base.json"`
+//! or `"extends": ["./a.json", "./b.json"]`, with paths relative to the
+//! extending file. The configs are deep-merged in order, and the extending
+//! config is merged last:
+//! * objects are merged key by key, recursively;
+//! * arrays, strings, numbers, booleans and `null` replace the base value.
+//!
+//! Other relative paths of a base config, such as the schema location, are
+//! used as is by the extending config: they are relative to the directory of
+//! the config that is loaded, not of the base config.
+//!
+//! A config with `"interpolateEnv": true` has `${NAME}` replaced by the value
+//! of the environment variable `NAME`, which must be set, in each of its
+//! string values. `$${` is an escaped `${`. This only applies to the values
+//! of that config, not to the ones of the configs it extends or that extend
+//! it.
+
+use std::collections::BTreeMap;
+use std::ffi::OsStr;
+use std::path::Path;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::ConfigError;
+use crate::ErrorCode;
+use crate::loader::JsLoader;
+use crate::loader::JsonLoader;
+use crate::loader::Loader;
+use crate::loader::YamlLoader;
+use crate::tracked_value;
+
+const EXTENDS_KEY: &str = "extends";
+const INTERPOLATE_ENV_KEY: &str = "interpolateEnv";
+
+/// A config merged with the configs it extends.
+pub(crate) struct ResolvedConfig {
+    pub value: Value,
+    /// For each key path (`a.b.c`) of `value`, the file it was set by.
+    sources: BTreeMap<String, PathBuf>,
+}
+
+impl ResolvedConfig {
+    /// Deserialize the merged config. On error, also returns the file that
+    /// set the value that failed to deserialize, if it is known.
+    pub fn deserialize<T>(&self) -> Result<T, (serde_json::Error, Option<&Path>)>
+    where
+        T: for<'de> Deserialize<'de>,
+    {
+        tracked_value::deserialize(&self.value)
+            .map_err(|(error, key_path)| (error, self.blame(&key_path)))
+    }
+
+    /// The file that set the value at `key_path`, or the closest parent
+    /// object or array of it.
+    fn blame(&self, key_path: &str) -> Option<&Path> {
+        let mut key_path = key_path;
+        loop {
+            if let Some(path) = self.sources.get(key_path) {
+                return Some(path);
+            }
+            key_path = &key_path[..key_path.rfind(['.', '['])?];
+        }
+    }
+}
+
+/// Interpolate environment variables of the configs that opt in, and merge
+/// the config loaded from `path` with the configs it extends.
+pub(crate) fn resolve(path: &Path, value: Value) -> Result<ResolvedConfig, ConfigError> {
+    let mut layers = vec![];
+    collect_layers(path, value, &mut vec![], &mut layers)?;
+
+    let mut resolved = ResolvedConfig {
+        value: Value::Object(Map::new()),
+        sources: BTreeMap::new(),
+    };
+    for (path, value) in layers {
+        merge(&mut resolved.value, value, &path, "", &mut resolved.sources);
+    }
+    Ok(resolved)
+}
+
+/// Collect the configs to merge, bases first.
+fn collect_layers(
+    path: &Path,
+    mut value: Value,
+    stack: &mut Vec<PathBuf>,
+    layers: &mut Vec<(PathBuf, Value)>,
+) -> Result<(), ConfigError> {
+    let error = |code| ConfigError {
+        path: path.to_path_buf(),
+        code,
+    };
+    match value
+        .as_object_mut()
+        .and_then(|map| map.remove(INTERPOLATE_ENV_KEY))
+    {
+        None | Some(Value::Bool(false)) => {}
+        Some(Value::Bool(true)) => interpolate(&mut value, "").map_err(error)?,
+        Some(_) => return Err(error(ErrorCode::InvalidInterpolateEnv)),
+    }
+
+    let extends = match value
+        .as_object_mut()
+        .and_then(|map| map.remove(EXTENDS_KEY))
+    {
+        None => vec![],
+        Some(Value::String(base)) => vec![base],
+        Some(Value::Array(bases)) => bases
+            .into_iter()
+            .map(|base| match base {
+                Value::String(base) => Ok(base),
+                _ => Err(error(ErrorCode::InvalidExtends)),
+            })
+            .collect::<Result<_, _>>()?,
+        Some(_) => return Err(error(ErrorCode::InvalidExtends)),
+    };
+
+    stack.push(path.canonicalize().unwrap_or_else(|_| path.to_path_buf()));
+    for base in extends {
+        let base_path = path.parent().unwrap_or(Path::new("")).join(&base);
+        let base_path = base_path.canonicalize().map_err(|_| {
+            error(ErrorCode::ExtendsNotFound {
+                extends: base_path.clone(),
+            })
+        })?;
+        if stack.contains(&base_path) {
+            let mut cycle = stack.clone();
+            cycle.push(base_path);
+            return Err(error(ErrorCode::ExtendsCycle { cycle }));
+        }
+        let base_value = load_base(&base_path)
+            .map_err(|code| ConfigError {
+                path: base_path.clone(),
+                code,
+            })?
+            .unwrap_or(Value::Object(Map::new()));
+        collect_layers(&base_path, base_value, stack, layers)?;
+    }
+    stack.pop();
+
+    layers.push((path.to_path_buf(), value));
+    Ok(())
+}
+
+fn load_base(path: &Path) -> Result<Option<Value>, ErrorCode> {
+    match path.extension().and_then(OsStr::to_str) {
+        Some("js" | "cjs" | "mjs") => JsLoader.load(path),
+        Some("yaml" | "yml") => YamlLoader.load(path),
+        _ => JsonLoader.load(path),
+    }
+}
+
+fn merge(
+    target: &mut Value,
+    source: Value,
+    path: &Path,
+    key_path: &str,
+    sources: &mut BTreeMap<String, PathBuf>,
+) {
+    match (target, source) {
+        (Value::Object(target), Value::Object(source)) => {
+            for (key, value) in source {
+                let child_key_path = if key_path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{key_path}.{key}")
+                };
+                match target.get_mut(&key) {
+                    Some(target_value) if target_value.is_object() && value.is_object() => {
+                        merge(target_value, value, path, &child_key_path, sources);
+                    }
+                    _ => {
+                        let prefix = format!("{child_key_path}.");
+                        sources.retain(|key_path, _| !key_path.starts_with(&prefix));
+                        record_sources(&value, path, &child_key_path, sources);
+                        target.insert(key, value);
+                    }
+                }
+            }
+        }
+        (target, source) => *target = source,
+    }
+}
+
+fn record_sources(
+    value: &Value,
+    path: &Path,
+    key_path: &str,
+    sources: &mut BTreeMap<String, PathBuf>,
+) {
+    sources.insert(key_path.to_string(), path.to_path_buf());
+    if let Value::Object(map) = value {
+        for (key, value) in map {
+            record_sources(value, path, &format!("{key_path}.{key}"), sources);
+        }
+    }
+}
+
+/// Replace `${NAME}` in all string values by the environment variable `NAME`.
+fn interpolate(value: &mut Value, key_path: &str) -> Result<(), ErrorCode> {
+    match value {
+        Value::String(string) => {
+            if string.contains('$') {
+                *string = interpolate_string(string, key_path)?;
+            }
+        }
+        Value::Array(values) => {
+            for (index, value) in values.iter_mut().enumerate() {
+                interpolate(value, &format!("{key_path}[{index}]"))?;
+            }
+        }
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                let child_key_path = if key_path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{key_path}.{key}")
+                };
+                interpolate(value, &child_key_path)?;
+            }
+        }
+        Value::Null | Value::Bool(_) | Value::Number(_) => {}
+    }
+    Ok(())
+}
+
+fn interpolate_string(string: &str, key_path: &str) -> Result<String, ErrorCode> {
+    let mut result = String::with_capacity(string.len());
+    let mut rest = string;
+    while let Some(index) = rest.find('$') {
+        result.push_str(&rest[..index]);
+        rest = &rest[index..];
+        if let Some(escaped) = rest.strip_prefix("$${") {
+            result.push_str("${");
+            rest = escaped;
+        } else if let Some(reference) = rest.strip_prefix("${") {
+            let name = reference
+                .split_once('}')
+                .map(|(name, _)| name)
+                .filter(|name| is_variable_name(name))
+                .ok_or_else(|| ErrorCode::InvalidInterpolation {
+                    key: key_path.to_string(),
+                    value: string.to_string(),
+                })?;
+            let value =
+                std::env::var(name).map_err(|_| ErrorCode::UndefinedEnvironmentVariable {
+                    name: name.to_string(),
+                    key: key_path.to_string(),
+                })?;
+            result.push_str(&value);
+            rest = &reference[name.len() + 1..];
+        } else {
+            result.push('$');
+            rest = &rest[1..];
+        }
+    }
+    result.push_str(rest);
+    Ok(result)
+}
+
+fn is_variable_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|char| char.is_ascii_alphanumeric() || char == '_')
+}
@@ -6,7 +6,9 @@
  */
 
 mod error;
+mod extends;
 mod loader;
+mod tracked_value;
 
 use std::fmt::Display;
 use std::path::Path;
@@ -51,7 +53,7 @@ pub fn load<T>(dir: &Path, sources: &[LoaderSource]) -> Result<Option<Config<T>>
 where
     T: for<'de> Deserialize<'de> + 'static,
 {
-    let mut loaders: Vec<(String, Box<dyn Loader<T>>)> = Vec::with_capacity(sources.len());
+    let mut loaders: Vec<(String, Box<dyn Loader>)> = Vec::with_capacity(sources.len());
     for source in sources {
         match source {
             LoaderSource::PackageJson(name) => {
@@ -79,10 +81,23 @@ where
                 match loader.load(&file_path) {
                     Ok(None) => {}
                     Ok(Some(value)) => {
-                        return Ok(Some(Config {
-                            path: file_path,
-                            value,
-                        }));
+                        let resolved = extends::resolve(&file_path, value)?;
+                        return match resolved.deserialize::<T>() {
+                            Ok(value) => Ok(Some(Config {
+                                path: file_path,
+                                value,
+                            })),
+                            Err((error, blamed_path)) => match blamed_path {
+                                Some(path) if path != file_path => Err(ConfigError {
+                                    path: path.to_path_buf(),
+                                    code: ErrorCode::JsonParseError { error },
+                                }),
+                                _ => Err(ConfigError {
+                                    path: file_path,
+                                    code: loader.invalid_value(error),
+                                }),
+                            },
+                        };
                     }
                     Err(code) => {
                         return Err(ConfigError {
@@ -10,48 +10,51 @@ use std::io::BufReader;
 use std::path::Path;
 use std::process::Command;
 
-use serde::Deserialize;
 use serde_json::Value;
 
 use crate::ErrorCode;
 
-pub trait Loader<T> {
-    fn load(&self, path: &Path) -> Result<Option<T>, ErrorCode>;
+pub trait Loader {
+    fn load(&self, path: &Path) -> Result<Option<Value>, ErrorCode>;
+
+    /// The error of a loaded value that is not a valid config.
+    fn invalid_value(&self, error: serde_json::Error) -> ErrorCode {
+        ErrorCode::JsonParseError { error }
+    }
 }
 
 pub struct PackageJsonLoader<'a> {
     pub key: &'a str,
 }
-impl<'a, T: for<'de> Deserialize<'de>> Loader<T> for PackageJsonLoader<'a> {
-    fn load(&self, path: &Path) -> Result<Option<T>, ErrorCode> {
+impl<'a> Loader for PackageJsonLoader<'a> {
+    fn load(&self, path: &Path) -> Result<Option<Value>, ErrorCode> {
         let file = File::open(path).unwrap();
         let reader = BufReader::new(file);
         let mut package_json: Value = serde_json::from_reader(reader)
             .map_err(|error| ErrorCode::PackageJsonParseError { error })?;
-        if let Some(config_value) = package_json.get_mut(self.key) {
-            match serde_json::from_value::<T>(config_value.take()) {
-                Ok(config) => Ok(Some(config)),
-                Err(error) => Err(ErrorCode::PackageJsonInvalidValue {
-                    key: self.key.into(),
-                    error,
-                }),
-            }
-        } else {
-            Ok(None)
+        Ok(package_json
+            .get_mut(self.key)
+            .map(|config_value| config_value.take()))
+    }
+
+    fn invalid_value(&self, error: serde_json::Error) -> ErrorCode {
+        ErrorCode::PackageJsonInvalidValue {
+            key: self.key.into(),
+            error,
         }
     }
 }
 
 pub struct YamlLoader;
-impl<T> Loader<T> for YamlLoader {
-    fn load(&self, _path: &Path) -> Result<Option<T>, ErrorCode> {
+impl Loader for YamlLoader {
+    fn load(&self, _path: &Path) -> Result<Option<Value>, ErrorCode> {
         Err(ErrorCode::YamlFileUnsupported)
     }
 }
 
 pub struct JsonLoader;
-impl<T: for<'de> Deserialize<'de> + 'static> Loader<T> for JsonLoader {
-    fn load(&self, path: &Path) -> Result<Option<T>, ErrorCode> {
+impl Loader for JsonLoader {
+    fn load(&self, path: &Path) -> Result<Option<Value>, ErrorCode> {
         let file = File::open(path).unwrap();
         let reader = BufReader::new(file);
         let config = serde_json::from_reader(reader)?;
@@ -60,8 +63,8 @@ impl<T: for<'de> Deserialize<'de> + 'static> Loader<T> for JsonLoader {
 }
 
 pub struct JsLoader;
-impl<T: for<'de> Deserialize<'de> + 'static> Loader<T> for JsLoader {
-    fn load(&self, path: &Path) -> Result<Option<T>, ErrorCode> {
+impl Loader for JsLoader {
+    fn load(&self, path: &Path) -> Result<Option<Value>, ErrorCode> {
         let output = Command::new("node")
             .arg("-e")
             .arg(r#"process.stdout.write(JSON.stringify(require(process.argv[1])))"#)
@@ -70,7 +73,7 @@ impl<T: for<'de> Deserialize<'de> + 'static> Loader<T> for JsLoader {
             .expect("failed to execute process. Make sure you have Node installed.");
 
         if output.status.success() {
-            let value = serde_json::from_slice::<T>(&output.stdout);
+            let value = serde_json::from_slice(&output.stdout);
             Ok(Some(value.unwrap()))
         } else {
             Err(ErrorCode::NodeExecuteError { output })
@@ -0,0 +1,259 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Deserialization of a JSON value that reports the key path (`a.b[0].c`)
+//! of the value that failed to deserialize, so that errors can be blamed on
+//! the config file that set it.
+
+use std::cell::RefCell;
+
+use serde::Deserialize;
+use serde::de;
+use serde::de::DeserializeSeed;
+use serde::de::IntoDeserializer;
+use serde::de::Unexpected;
+use serde::de::Visitor;
+use serde::de::value::BorrowedStrDeserializer;
+use serde::forward_to_deserialize_any;
+use serde_json::Error;
+use serde_json::Value;
+
+/// Deserialize `value`. On error, also returns the key path of the innermost
+/// value that failed to deserialize, which is empty for the root.
+pub(crate) fn deserialize<T>(value: &Value) -> Result<T, (Error, String)>
+where
+    T: for<'de> Deserialize<'de>,
+{
+    let error_path = RefCell::new(None);
+    T::deserialize(TrackedValue {
+        value,
+        path: String::new(),
+        error_path: &error_path,
+    })
+    .map_err(|error| (error, error_path.into_inner().unwrap_or_default()))
+}
+
+struct TrackedValue<'de> {
+    value: &'de Value,
+    path: String,
+    /// Set by the innermost value whose deserialization failed.
+    error_path: &'de RefCell<Option<String>>,
+}
+
+impl<'de> TrackedValue<'de> {
+    fn child(&self, value: &'de Value, path: String) -> Self {
+        Self {
+            value,
+            path,
+            error_path: self.error_path,
+        }
+    }
+
+    fn key_path(&self, key: &str) -> String {
+        if self.path.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}.{}", self.path, key)
+        }
+    }
+}
+
+fn track<T>(
+    result: Result<T, Error>,
+    path: String,
+    error_path: &RefCell<Option<String>>,
+) -> Result<T, Error> {
+    if result.is_err() {
+        error_path.borrow_mut().get_or_insert(path);
+    }
+    result
+}
+
+fn unexpected(value: &Value) -> Unexpected<'_> {
+    match value {
+        Value::Null => Unexpected::Unit,
+        Value::Bool(value) => Unexpected::Bool(*value),
+        Value::Number(_) => Unexpected::Other("number"),
+        Value::String(value) => Unexpected::Str(value),
+        Value::Array(_) => Unexpected::Seq,
+        Value::Object(_) => Unexpected::Map,
+    }
+}
+
+impl<'de> de::Deserializer<'de> for TrackedValue<'de> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.value {
+            Value::Null => visitor.visit_unit(),
+            Value::Bool(value) => visitor.visit_bool(*value),
+            Value::Number(number) => {
+                if let Some(value) = number.as_u64() {
+                    visitor.visit_u64(value)
+                } else if let Some(value) = number.as_i64() {
+                    visitor.visit_i64(value)
+                } else {
+                    visitor.visit_f64(number.as_f64().unwrap_or_default())
+                }
+            }
+            Value::String(value) => visitor.visit_borrowed_str(value),
+            Value::Array(values) => visitor.visit_seq(TrackedSeq {
+                values: values.iter().enumerate(),
+                parent: self,
+            }),
+            Value::Object(map) => visitor.visit_map(TrackedMap {
+                entries: map.iter(),
+                next_value: None,
+                parent: self,
+            }),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.value {
+            Value::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        match self.value {
+            Value::String(variant) => visitor.visit_enum(variant.as_str().into_deserializer()),
+            Value::Object(map) if map.len() == 1 => {
+                let (variant, value) = map.iter().next().unwrap();
+                let value = self.child(value, self.key_path(variant));
+                visitor.visit_enum(TrackedEnum { variant, value })
+            }
+            value => Err(de::Error::invalid_type(unexpected(value), &visitor)),
+        }
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_unit()
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier
+    }
+}
+
+struct TrackedSeq<'de> {
+    values: std::iter::Enumerate<std::slice::Iter<'de, Value>>,
+    parent: TrackedValue<'de>,
+}
+
+impl<'de> de::SeqAccess<'de> for TrackedSeq<'de> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Error> {
+        match self.values.next() {
+            Some((index, value)) => {
+                let path = format!("{}[{}]", self.parent.path, index);
+                let child = self.parent.child(value, path.clone());
+                track(seed.deserialize(child), path, self.parent.error_path).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+struct TrackedMap<'de> {
+    entries: serde_json::map::Iter<'de>,
+    next_value: Option<(&'de Value, String)>,
+    parent: TrackedValue<'de>,
+}
+
+impl<'de> de::MapAccess<'de> for TrackedMap<'de> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        match self.entries.next() {
+            Some((key, value)) => {
+                let path = self.parent.key_path(key);
+                self.next_value = Some((value, path.clone()));
+                let key = seed.deserialize(BorrowedStrDeserializer::new(key));
+                track(key, path, self.parent.error_path).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        let (value, path) = self
+            .next_value
+            .take()
+            .expect("next_value_seed called before next_key_seed");
+        let child = self.parent.child(value, path.clone());
+        track(seed.deserialize(child), path, self.parent.error_path)
+    }
+}
+
+struct TrackedEnum<'de> {
+    variant: &'de str,
+    value: TrackedValue<'de>,
+}
+
+impl<'de> de::EnumAccess<'de> for TrackedEnum<'de> {
+    type Error = Error;
+    type Variant = TrackedValue<'de>;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, TrackedValue<'de>), Error> {
+        let variant = seed.deserialize(BorrowedStrDeserializer::new(self.variant))?;
+        Ok((variant, self.value))
+    }
+}
+
+impl<'de> de::VariantAccess<'de> for TrackedValue<'de> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<(), Error> {
+        match self.value {
+            Value::Null => Ok(()),
+            value => Err(de::Error::invalid_type(unexpected(value), &"unit variant")),
+        }
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, Error> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value, Error> {
+        de::Deserializer::deserialize_any(self, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        de::Deserializer::deserialize_any(self, visitor)
+    }
+}
@@ -11,6 +11,8 @@ use js_config_loader::ConfigError;
 use js_config_loader::ErrorCode;
 use js_config_loader::search;
 use serde::Deserialize;
+use serde_json::Value;
+use serde_json::json;
 use tempfile::tempdir;
 
 #[derive(Debug, Deserialize)]
@@ -18,6 +20,30 @@ struct TestConfig {
     name: String,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct StrictTestConfig {
+    #[allow(dead_code)]
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+struct NestedTestConfig {
+    name: String,
+    #[serde(default)]
+    persist: Option<PersistTestConfig>,
+    #[serde(default)]
+    flags: Vec<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+struct PersistTestConfig {
+    url: String,
+    concurrency: u32,
+}
+
 #[test]
 fn package_json_and_rc_same_dir() {
     let dir = tempdir().unwrap();
@@ -192,3 +218,318 @@ fn unsupported_file() {
         other => panic!("incorrect error: {:?}", other),
     }
 }
+
+#[test]
+fn extends_deep_merge() {
+    let dir = tempdir().unwrap();
+    let dir_d = dir.path().join("a/b/c/d");
+    create_dir_all(&dir_d).unwrap();
+
+    std::fs::write(
+        dir.path().join("base.json"),
+        r#"
+            {
+                "name": "base",
+                "scalars": { "ID": "string", "URL": "string" },
+                "flags": ["a", "b"],
+                "persist": { "url": "https://base", "params": { "a": 1 } }
+            }
+        "#,
+    )
+    .unwrap();
+    std::fs::write(
+        dir_d.join(".foorc"),
+        r#"
+            {
+                "extends": "../../../../base.json",
+                "scalars": { "URL": "URL" },
+                "flags": ["c"],
+                "persist": { "params": null }
+            }
+        "#,
+    )
+    .unwrap();
+
+    let config = search::<Value>("foo", &dir_d).unwrap().unwrap();
+    assert_eq!(config.path, dir_d.join(".foorc"));
+    assert_eq!(
+        config.value,
+        json!({
+            "name": "base",
+            "scalars": { "ID": "string", "URL": "URL" },
+            "flags": ["c"],
+            "persist": { "url": "https://base", "params": null }
+        })
+    );
+}
+
+#[test]
+fn extends_multiple_in_order() {
+    let dir = tempdir().unwrap();
+
+    std::fs::write(dir.path().join("a.json"), r#"{ "name": "a", "x": 1 }"#).unwrap();
+    std::fs::write(
+        dir.path().join("b.json"),
+        r#"{ "extends": "./a.json", "name": "b" }"#,
+    )
+    .unwrap();
+    std::fs::write(dir.path().join("c.json"), r#"{ "y": 2 }"#).unwrap();
+    std::fs::write(
+        dir.path().join(".foorc"),
+        r#"{ "extends": ["./b.json", "./c.json"], "y": 3 }"#,
+    )
+    .unwrap();
+
+    let config = search::<Value>("foo", dir.path()).unwrap().unwrap();
+    assert_eq!(config.value, json!({ "name": "b", "x": 1, "y": 3 }));
+}
+
+#[test]
+fn extends_cycle() {
+    let dir = tempdir().unwrap();
+
+    std::fs::write(dir.path().join("base.json"), r#"{ "extends": "./.foorc" }"#).unwrap();
+    std::fs::write(
+        dir.path().join(".foorc"),
+        r#"{ "extends": "./base.json", "name": "x" }"#,
+    )
+    .unwrap();
+
+    match search::<TestConfig>("foo", dir.path()).unwrap_err() {
+        ConfigError {
+            code: ErrorCode::ExtendsCycle { cycle },
+            path,
+        } => {
+            assert_eq!(path.file_name().unwrap(), "base.json");
+            let names = cycle
+                .iter()
+                .map(|path| path.file_name().unwrap().to_str().unwrap())
+                .collect::<Vec<_>>();
+            assert_eq!(names, [".foorc", "base.json", ".foorc"]);
+        }
+        other => panic!("incorrect error: {:?}", other),
+    }
+}
+
+#[test]
+fn extends_not_found() {
+    let dir = tempdir().unwrap();
+
+    std::fs::write(
+        dir.path().join(".foorc"),
+        r#"{ "extends": "./missing.json", "name": "x" }"#,
+    )
+    .unwrap();
+
+    match search::<TestConfig>("foo", dir.path()).unwrap_err() {
+        ConfigError {
+            code: ErrorCode::ExtendsNotFound { extends },
+            path,
+        } => {
+            assert_eq!(path.file_name().unwrap(), ".foorc");
+            assert_eq!(extends.file_name().unwrap(), "missing.json");
+        }
+        other => panic!("incorrect error: {:?}", other),
+    }
+}
+
+#[test]
+fn extends_invalid_value_in_base() {
+    let dir = tempdir().unwrap();
+
+    std::fs::write(dir.path().join("base.json"), r#"{ "nmae": "typo" }"#).unwrap();
+    std::fs::write(
+        dir.path().join(".foorc"),
+        r#"{ "extends": "./base.json", "name": "x" }"#,
+    )
+    .unwrap();
+
+    match search::<StrictTestConfig>("foo", dir.path()).unwrap_err() {
+        ConfigError {
+            code: ErrorCode::JsonParseError { error },
+            path,
+        } => {
+            assert_eq!(path.file_name().unwrap(), "base.json");
+            assert!(error.to_string().contains("unknown field `nmae`"));
+        }
+        other => panic!("incorrect error: {:?}", other),
+    }
+}
+
+#[test]
+fn env_var_interpolation() {
+    // SAFETY: the variable is only used by this test.
+    unsafe { std::env::set_var("JS_CONFIG_LOADER_TEST_TOKEN", "secret") };
+    let dir = tempdir().unwrap();
+
+    std::fs::write(
+        dir.path().join("base.json"),
+        r#"{ "interpolateEnv": true, "headers": ["Bearer ${JS_CONFIG_LOADER_TEST_TOKEN}"] }"#,
+    )
+    .unwrap();
+    std::fs::write(
+        dir.path().join(".foorc"),
+        r#"{
+            "extends": "./base.json",
+            "interpolateEnv": true,
+            "name": "${JS_CONFIG_LOADER_TEST_TOKEN}-$${literal}-$5"
+        }"#,
+    )
+    .unwrap();
+
+    let config = search::<Value>("foo", dir.path()).unwrap().unwrap();
+    assert_eq!(
+        config.value,
+        json!({ "headers": ["Bearer secret"], "name": "secret-${literal}-$5" })
+    );
+}
+
+#[test]
+fn env_var_interpolation_undefined() {
+    let dir = tempdir().unwrap();
+
+    std::fs::write(
+        dir.path().join("base.json"),
+        r#"{
+            "interpolateEnv": true,
+            "persist": { "url": "https://${JS_CONFIG_LOADER_TEST_UNDEFINED}" }
+        }"#,
+    )
+    .unwrap();
+    std::fs::write(
+        dir.path().join(".foorc"),
+        r#"{ "extends": "./base.json", "name": "x" }"#,
+    )
+    .unwrap();
+
+    match search::<TestConfig>("foo", dir.path()).unwrap_err() {
+        ConfigError {
+            code: ErrorCode::UndefinedEnvironmentVariable { name, key },
+            path,
+        } => {
+            assert_eq!(path.file_name().unwrap(), "base.json");
+            assert_eq!(name, "JS_CONFIG_LOADER_TEST_UNDEFINED");
+            assert_eq!(key, "persist.url");
+        }
+        other => panic!("incorrect error: {:?}", other),
+    }
+}
+
+#[test]
+fn extends_invalid_type_in_base() {
+    let dir = tempdir().unwrap();
+
+    std::fs::write(
+        dir.path().join("base.json"),
+        r#"{ "persist": { "url": "https://base", "concurrency": "many" } }"#,
+    )
+    .unwrap();
+    std::fs::write(
+        dir.path().join(".foorc"),
+        r#"{ "extends": "./base.json", "name": "x" }"#,
+    )
+    .unwrap();
+
+    match search::<NestedTestConfig>("foo", dir.path()).unwrap_err() {
+        ConfigError {
+            code: ErrorCode::JsonParseError { error },
+            path,
+        } => {
+            assert_eq!(path.file_name().unwrap(), "base.json");
+            assert!(error.to_string().contains("invalid type: string \"many\""));
+        }
+        other => panic!("incorrect error: {:?}", other),
+    }
+}
+
+#[test]
+fn extends_invalid_array_item_in_base() {
+    let dir = tempdir().unwrap();
+
+    std::fs::write(dir.path().join("base.json"), r#"{ "flags": [true, 1] }"#).unwrap();
+    std::fs::write(
+        dir.path().join(".foorc"),
+        r#"{ "extends": "./base.json", "name": "x" }"#,
+    )
+    .unwrap();
+
+    match search::<NestedTestConfig>("foo", dir.path()).unwrap_err() {
+        ConfigError {
+            code: ErrorCode::JsonParseError { .. },
+            path,
+        } => {
+            assert_eq!(path.file_name().unwrap(), "base.json");
+        }
+        other => panic!("incorrect error: {:?}", other),
+    }
+}
+
+#[test]
+fn extends_missing_field_in_base() {
+    let dir = tempdir().unwrap();
+
+    std::fs::write(
+        dir.path().join("base.json"),
+        r#"{ "persist": { "url": "https://base" } }"#,
+    )
+    .unwrap();
+    std::fs::write(
+        dir.path().join(".foorc"),
+        r#"{ "extends": "./base.json", "name": "x" }"#,
+    )
+    .unwrap();
+
+    match search::<NestedTestConfig>("foo", dir.path()).unwrap_err() {
+        ConfigError {
+            code: ErrorCode::JsonParseError { error },
+            path,
+        } => {
+            assert_eq!(path.file_name().unwrap(), "base.json");
+            assert!(error.to_string().contains("missing field `concurrency`"));
+        }
+        other => panic!("incorrect error: {:?}", other),
+    }
+}
+
+#[test]
+fn extends_invalid_value_overridden_in_leaf() {
+    let dir = tempdir().unwrap();
+
+    std::fs::write(
+        dir.path().join("base.json"),
+        r#"{ "persist": { "url": "https://base", "concurrency": 1 } }"#,
+    )
+    .unwrap();
+    std::fs::write(
+        dir.path().join(".foorc"),
+        r#"{ "extends": "./base.json", "name": "x", "persist": { "concurrency": -1 } }"#,
+    )
+    .unwrap();
+
+    match search::<NestedTestConfig>("foo", dir.path()).unwrap_err() {
+        ConfigError {
+            code: ErrorCode::JsonParseError { .. },
+            path,
+        } => {
+            assert_eq!(path.file_name().unwrap(), ".foorc");
+        }
+        other => panic!("incorrect error: {:?}", other),
+    }
+}
+
+#[test]
+fn env_var_interpolation_not_enabled() {
+    let dir = tempdir().unwrap();
+
+    std::fs::write(
+        dir.path().join(".foorc"),
+        r#"{ "name": "${JS_CONFIG_LOADER_TEST_UNDEFINED}" }"#,
+    )
+    .unwrap();
+
+    let config = search::<Value>("foo", dir.path()).unwrap().unwrap();
+    assert_eq!(
+        config.value,
+        json!({ "name": "${JS_CONFIG_LOADER_TEST_UNDEFINED}" })
+    );
+}
@@ -7,7 +7,6 @@
 
 use std::env;
 use std::env::current_dir;
-#[cfg(unix)]
 use std::path::Path;
 use std::path::PathBuf;
 use std::process::Command;
@@ -15,7 +14,9 @@ use std::sync::Arc;
 
 use clap::Parser;
 use clap::ValueEnum;
+use common::ChromeTraceLogger;
 use common::ConsoleLogger;
+use dependency_analyzer::DefinitionGraph;
 use intern::string_key::Intern;
 use log::error;
 use log::info;
@@ -35,11 +36,19 @@ use relay_compiler::ProjectName;
 use relay_compiler::RemotePersister;
 use relay_compiler::build_project::artifact_writer::ArtifactValidationWriter;
 use relay_compiler::build_project::generate_extra_artifacts::default_generate_extra_artifacts_fn;
+use relay_compiler::compile_programs;
 use relay_compiler::compiler::Compiler;
+use relay_compiler::compiler_state::CompilerState;
 use relay_compiler::config::Config;
 use relay_compiler::config::ConfigFile;
+use relay_compiler::effective_schema::EffectiveSchemaOptions;
+use relay_compiler::effective_schema::build_and_print_effective_schema;
 use relay_compiler::errors::Error as CompilerError;
+use relay_compiler::explain::BuildExplainer;
 use relay_compiler::get_programs;
+use relay_compiler::get_single_enabled_project;
+use relay_compiler::program_stats::compute_program_stats;
+use relay_compiler::schema_coverage::compute_schema_coverage;
 #[cfg(unix)]
 use relay_compiler::server_daemon;
 #[cfg(unix)]
@@ -53,6 +62,8 @@ use relay_compiler::status_reporter::BuildStatus;
 #[cfg(unix)]
 use relay_compiler::status_reporter::NoopStatusReporter;
 use relay_compiler::subschema_extraction::compile_and_extract_subschema;
+use relay_compiler::verify_artifacts::fix_artifacts;
+use relay_compiler::verify_artifacts::verify_artifacts;
 use relay_lsp::DummyExtraDataProvider;
 use relay_lsp::FieldDefinitionSourceInfo;
 use relay_lsp::FieldSchemaInfo;
@@ -102,6 +113,11 @@ struct CodemodCommand {
     /// from the current working directory.
     config: Option<PathBuf>,
 
+    /// Verbosity level. Use `verbose` to see the changes made and the
+    /// places a codemod could not fix.
+    #[clap(long, value_enum, default_value = "quiet")]
+    output: OutputKind,
+
     /// The name of the codemod to run
     #[clap(subcommand)]
     codemod: AvailableCodemod,
@@ -148,13 +164,31 @@ struct CompileCommand {
     #[clap(long)]
     validate: bool,
 
+    /// Record the duration of each compiler phase and transform, and write
+    /// them to this file in the Chrome Trace Event format. The trace can be
+    /// opened in Perfetto (https://ui.perfetto.dev) or `chrome://tracing`.
+    /// Not supported in watch mode.
+    #[clap(long, conflicts_with = "watch")]
+    trace: Option<PathBuf>,
+
+    /// After each build, print why each artifact was regenerated or deleted:
+    /// the changed definition or schema type, and the dependency chain from it
+    /// to the artifact.
+    #[clap(long)]
+    explain: bool,
+
+    /// Same as `--explain`, and also write the explanations of each build as
+    /// JSON to this file.
+    #[clap(long)]
+    explain_output: Option<PathBuf>,
+
     /// Send this build through the compiler daemon instead of compiling
     /// in-process. The daemon is started in the background on first use and
     /// reused across invocations, eliminating per-build startup cost. Pass
     /// `--daemon=false` to force an in-process build. When the working
     /// directory is mid-rebase / mid-merge, the build falls back to
     /// in-process automatically. Not compatible with `--watch`,
-    /// `--validate`, `--repersist`, `--no-watchman`, or inline-config
+    /// `--validate`, `--repersist`, `--no-watchman`, `--trace`, `--explain`, or inline-config
     /// flags (`--src`, `--schema`, `--artifactDirectory`) — these all
     /// imply per-build behavior the daemon's in-memory state can't honor.
     /// Unix-only.
@@ -166,6 +200,9 @@ struct CompileCommand {
             "validate",
             "repersist",
             "no_watchman",
+            "trace",
+            "explain",
+            "explain_output",
             "src",
             "schema",
             "artifact_directory",
@@ -239,6 +276,187 @@ struct CompareDocumentIRCommand {
     schema_paths: Vec<String>,
 }
 
+#[derive(Parser)]
+#[clap(
+    rename_all = "camel_case",
+    about = "Report how often each schema type and field is selected by reader and normalization ASTs, and list the fields that are never selected."
+)]
+struct SchemaCoverageCommand {
+    /// Report only on this project. You can pass this argument multiple times.
+    /// If excluded, all projects will be included.
+    #[clap(name = "project", long, short)]
+    projects: Vec<String>,
+
+    /// Compile using this config file. If not provided, searches for a config in
+    /// package.json under the `relay` key or `relay.config.json` files among other up
+    /// from the current working directory.
+    config: Option<PathBuf>,
+
+    /// Write the JSON report to this file instead of stdout.
+    #[clap(long)]
+    output_file: Option<PathBuf>,
+
+    /// Disable watchman and use directory traversal to find source files.
+    #[clap(long)]
+    no_watchman: bool,
+}
+
+#[derive(Parser)]
+#[clap(
+    rename_all = "camel_case",
+    about = "Print metrics about the fragments and operations of each project: operation counts, fragment fan-in/fan-out and depth, normalization AST sizes, the largest operations and @defer/@stream/@module usage."
+)]
+struct StatsCommand {
+    /// Report only on this project. You can pass this argument multiple times.
+    /// If excluded, all projects will be included.
+    #[clap(name = "project", long, short)]
+    projects: Vec<String>,
+
+    /// Compile using this config file. If not provided, searches for a config in
+    /// package.json under the `relay` key or `relay.config.json` files among other up
+    /// from the current working directory.
+    config: Option<PathBuf>,
+
+    /// Print the metrics as JSON instead of a text report.
+    #[clap(long)]
+    json: bool,
+
+    /// Number of entries in each ranked list.
+    #[clap(long, default_value = "10")]
+    limit: usize,
+
+    /// Write the report to this file instead of stdout.
+    #[clap(long)]
+    output_file: Option<PathBuf>,
+
+    /// Disable watchman and use directory traversal to find source files.
+    #[clap(long)]
+    no_watchman: bool,
+}
+
+#[derive(Parser)]
+#[clap(
+    rename_all = "camel_case",
+    about = "Export the graph of fragment spreads of a project's operations and fragments, annotated with their file, type condition, @relay(plural) and @inline, and with @module spreads marked."
+)]
+struct GraphCommand {
+    /// Export the graph of this project. Required when the config has
+    /// several projects.
+    #[clap(long, short)]
+    project: Option<String>,
+
+    /// Compile using this config file. If not provided, searches for a config in
+    /// package.json under the `relay` key or `relay.config.json` files among other up
+    /// from the current working directory.
+    config: Option<PathBuf>,
+
+    /// Only export the operations and fragments reachable from this operation
+    /// or fragment. You can pass this argument multiple times.
+    #[clap(name = "from", long)]
+    roots: Vec<String>,
+
+    #[clap(long, value_enum, default_value = "dot")]
+    format: GraphFormat,
+
+    /// Write the graph to this file instead of stdout.
+    #[clap(long)]
+    output_file: Option<PathBuf>,
+
+    /// Disable watchman and use directory traversal to find source files.
+    #[clap(long)]
+    no_watchman: bool,
+}
+
+#[derive(Parser)]
+#[clap(
+    rename_all = "camel_case",
+    about = "Check the files in the artifact directories without compiling: artifacts whose signature doesn't match their content, and, with a saved state, missing artifacts and orphaned artifacts that no definition produced."
+)]
+struct VerifyCommand {
+    /// Verify only this project. You can pass this argument multiple times.
+    /// If excluded, all projects will be verified.
+    #[clap(name = "project", long, short)]
+    projects: Vec<String>,
+
+    /// Compile using this config file. If not provided, searches for a config in
+    /// package.json under the `relay` key or `relay.config.json` files among other up
+    /// from the current working directory.
+    config: Option<PathBuf>,
+
+    /// Saved state of a previous build, whose artifact map lists the
+    /// artifacts produced by each definition. Orphaned and missing artifacts
+    /// are only reported with a saved state, which is rejected when the
+    /// sources changed since it was written.
+    #[clap(long)]
+    saved_state: Option<PathBuf>,
+
+    /// Repair the reported artifacts: delete the orphaned ones, and compile
+    /// the projects to write the edited and missing ones.
+    #[clap(long)]
+    fix: bool,
+
+    /// Disable watchman and use directory traversal to find source files.
+    #[clap(long)]
+    no_watchman: bool,
+
+    /// Verbosity level
+    #[clap(long, value_enum, default_value = "verbose")]
+    output: OutputKind,
+}
+
+#[derive(Parser)]
+#[clap(
+    rename_all = "camel_case",
+    about = "Print the effective schema of a project in SDL: its schema and schema extensions, the schema of its Relay Resolvers and the ones of its base project, with the files excluded by `excludesExtensions` left out."
+)]
+struct PrintSchemaCommand {
+    /// Print the schema of this project. Required when the config has
+    /// several projects.
+    #[clap(long, short)]
+    project: Option<String>,
+
+    /// Compile using this config file. If not provided, searches for a config in
+    /// package.json under the `relay` key or `relay.config.json` files among other up
+    /// from the current working directory.
+    config: Option<PathBuf>,
+
+    /// Leave out the client schema extensions and the Relay Resolvers, to
+    /// print the server schema.
+    #[clap(long)]
+    exclude_client_extensions: bool,
+
+    /// Precede each type and directive with a comment naming the file
+    /// defining it, and the files adding fields to it.
+    #[clap(long)]
+    annotate_sources: bool,
+
+    /// Write the schema to this file instead of stdout.
+    #[clap(long, conflicts_with = "shard_count")]
+    output_file: Option<PathBuf>,
+
+    /// Split the types into this many shards, written to `shard_<index>.graphql`
+    /// files in `--outputDir`. The directives are printed to the first shard.
+    #[clap(long, requires = "output_dir")]
+    shard_count: Option<usize>,
+
+    /// Directory to write the shards to.
+    #[clap(long, requires = "shard_count")]
+    output_dir: Option<PathBuf>,
+
+    /// Disable watchman and use directory traversal to find source files.
+    #[clap(long)]
+    no_watchman: bool,
+}
+
+#[derive(ValueEnum, Clone, Copy)]
+enum GraphFormat {
+    /// Graphviz DOT language
+    Dot,
+    /// Mermaid flowchart
+    Mermaid,
+    Json,
+}
+
 #[derive(clap::Subcommand)]
 enum Commands {
     Compiler(CompileCommand),
@@ -247,6 +465,11 @@ enum Commands {
     Codemod(CodemodCommand),
     ExperimentalRegenerateSubSchema(UpdateSchemaCommand),
     ExperimentalCompareDocumentIR(CompareDocumentIRCommand),
+    SchemaCoverage(SchemaCoverageCommand),
+    Stats(StatsCommand),
+    Graph(GraphCommand),
+    Verify(VerifyCommand),
+    PrintSchema(PrintSchemaCommand),
     /// Manage the compiler daemon server.
     ///
     /// The daemon keeps an in-memory compiler state for fast incremental
@@ -383,6 +606,11 @@ async fn main() {
         Commands::ExperimentalCompareDocumentIR(command) => {
             handle_compare_document_ir_command(command)
         }
+        Commands::SchemaCoverage(command) => handle_schema_coverage_command(command).await,
+        Commands::Stats(command) => handle_stats_command(command).await,
+        Commands::Graph(command) => handle_graph_command(command).await,
+        Commands::Verify(command) => handle_verify_command(command).await,
+        Commands::PrintSchema(command) => handle_print_schema_command(command).await,
         #[cfg(unix)]
         Commands::Server(opt) => handle_server_command(opt).await,
     };
@@ -401,6 +629,42 @@ fn get_config(config_path: Option<PathBuf>) -> Result<Config, Error> {
     }
 }
 
+/// Load the config of a command reading the sources of the selected projects,
+/// with only those projects enabled.
+fn load_config_for_command(
+    config_path: Option<PathBuf>,
+    projects: &Vec<String>,
+    no_watchman: bool,
+) -> Result<Config, Error> {
+    let mut config = get_config(config_path)?;
+    set_project_flag(&mut config, projects)?;
+    config.file_source_config = if should_use_watchman(no_watchman) {
+        FileSourceKind::Watchman
+    } else {
+        FileSourceKind::WalkDir
+    };
+    Ok(config)
+}
+
+/// Write the output of a command to `output_file`, or to stdout.
+fn write_command_output(output_file: Option<&Path>, output: &str) -> Result<(), Error> {
+    match output_file {
+        Some(output_file) => {
+            std::fs::write(output_file, output).map_err(|e| output_write_error(output_file, e))
+        }
+        None => {
+            print!("{}", output);
+            Ok(())
+        }
+    }
+}
+
+fn output_write_error(path: &Path, error: std::io::Error) -> Error {
+    Error::ConfigError(CompilerError::ConfigError {
+        details: format!("Failed to write to {}: {}", path.to_string_lossy(), error),
+    })
+}
+
 /// Wire up the OSS CLI's default config extensions: the standard operation
 /// persister (Remote/Local from `project_config.persist`) and the default
 /// extra-artifacts generator. Used by every entry point that drives a real
@@ -475,6 +739,7 @@ fn set_project_flag(config: &mut Config, projects: &Vec<String>) -> Result<(), E
 }
 
 async fn handle_codemod_command(command: CodemodCommand) -> Result<(), Error> {
+    configure_logger(command.output, TerminalMode::Mixed);
     let mut config = get_config(command.config)?;
     let root_dir = config.root_dir.clone();
     set_project_flag(&mut config, &command.projects)?;
@@ -526,6 +791,165 @@ fn handle_compare_document_ir_command(command: CompareDocumentIRCommand) -> Resu
     Ok(())
 }
 
+async fn handle_schema_coverage_command(command: SchemaCoverageCommand) -> Result<(), Error> {
+    configure_logger(OutputKind::QuietWithErrors, TerminalMode::Stderr);
+    let config = load_config_for_command(command.config, &command.projects, command.no_watchman)?;
+
+    let (programs, _, _) = compile_programs(config)
+        .await
+        .map_err(|e| Error::CompilerError {
+            details: format!("{}", e),
+        })?;
+    let coverage = compute_schema_coverage(programs.values().map(AsRef::as_ref));
+    let report =
+        serde_json::to_string_pretty(&coverage).expect("Failed to serialize schema coverage");
+
+    write_command_output(command.output_file.as_deref(), &(report + "\n"))
+}
+
+async fn handle_stats_command(command: StatsCommand) -> Result<(), Error> {
+    configure_logger(OutputKind::QuietWithErrors, TerminalMode::Stderr);
+    let config = load_config_for_command(command.config, &command.projects, command.no_watchman)?;
+
+    let (programs, _, config) =
+        compile_programs(config)
+            .await
+            .map_err(|e| Error::CompilerError {
+                details: format!("{}", e),
+            })?;
+    let stats = compute_program_stats(&config, &programs, command.limit);
+    let report = if command.json {
+        serde_json::to_string_pretty(&stats).expect("Failed to serialize stats")
+    } else {
+        stats
+            .iter()
+            .map(|project_stats| project_stats.format_report(command.limit))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    write_command_output(command.output_file.as_deref(), &(report + "\n"))
+}
+
+async fn handle_graph_command(command: GraphCommand) -> Result<(), Error> {
+    configure_logger(OutputKind::QuietWithErrors, TerminalMode::Stderr);
+    let config = load_config_for_command(
+        command.config,
+        &command.project.into_iter().collect(),
+        command.no_watchman,
+    )?;
+
+    let project_name = get_single_enabled_project(&config).map_err(|e| Error::CompilerError {
+        details: format!("{}", e),
+    })?;
+    let (programs, _, _) = compile_programs(config)
+        .await
+        .map_err(|e| Error::CompilerError {
+            details: format!("{}", e),
+        })?;
+    let mut graph = DefinitionGraph::from_program(&programs[&project_name].source);
+    if !command.roots.is_empty() {
+        graph = graph
+            .subgraph(&command.roots)
+            .map_err(|name| Error::CompilerError {
+                details: format!("No operation or fragment named `{}` in the project", name),
+            })?;
+    }
+    let output = match command.format {
+        GraphFormat::Dot => graph.to_dot(),
+        GraphFormat::Mermaid => graph.to_mermaid(),
+        GraphFormat::Json => {
+            serde_json::to_string_pretty(&graph).expect("Failed to serialize graph") + "\n"
+        }
+    };
+
+    write_command_output(command.output_file.as_deref(), &output)
+}
+
+async fn handle_print_schema_command(command: PrintSchemaCommand) -> Result<(), Error> {
+    configure_logger(OutputKind::QuietWithErrors, TerminalMode::Stderr);
+    let config = load_config_for_command(
+        command.config,
+        &command.project.into_iter().collect(),
+        command.no_watchman,
+    )?;
+
+    let options = EffectiveSchemaOptions {
+        include_client_extensions: !command.exclude_client_extensions,
+        annotate_sources: command.annotate_sources,
+        shard_count: command.shard_count.unwrap_or(1),
+    };
+    let shards = build_and_print_effective_schema(config, &options)
+        .await
+        .map_err(|e| Error::CompilerError {
+            details: format!("{}", e),
+        })?;
+
+    match command.output_dir {
+        Some(output_dir) => {
+            std::fs::create_dir_all(&output_dir).map_err(|e| output_write_error(&output_dir, e))?;
+            for (index, shard) in shards.iter().enumerate() {
+                write_command_output(
+                    Some(&output_dir.join(format!("shard_{index}.graphql"))),
+                    shard,
+                )?;
+            }
+            Ok(())
+        }
+        None => write_command_output(command.output_file.as_deref(), &shards.concat()),
+    }
+}
+
+async fn handle_verify_command(command: VerifyCommand) -> Result<(), Error> {
+    configure_logger(command.output, TerminalMode::Mixed);
+    let mut config =
+        load_config_for_command(command.config, &command.projects, command.no_watchman)?;
+
+    let saved_state = match &command.saved_state {
+        Some(saved_state_path) => Some(
+            CompilerState::deserialize_from_file(saved_state_path).map_err(|err| {
+                Error::CompilerError {
+                    details: format!("{}", err),
+                }
+            })?,
+        ),
+        None => {
+            info!("No saved state given, orphaned and missing artifacts are not checked.");
+            None
+        }
+    };
+    apply_default_cli_extensions(&mut config);
+    let config = Arc::new(config);
+    let perf_logger = Arc::new(ConsoleLogger);
+    let verification = verify_artifacts(&config, perf_logger.as_ref(), saved_state.as_ref())
+        .await
+        .map_err(|err| Error::CompilerError {
+            details: format!("{}", err),
+        })?;
+
+    if !verification.is_empty() {
+        if !command.fix {
+            return Err(Error::CompilerError {
+                details: format!(
+                    "{}",
+                    CompilerError::ArtifactsVerificationError {
+                        error: verification.print(),
+                    }
+                ),
+            });
+        }
+        fix_artifacts(config, perf_logger, &verification)
+            .await
+            .map_err(|err| Error::CompilerError {
+                details: format!("{}", err),
+            })?;
+        info!("Fixed artifacts:{}", verification.print());
+    }
+
+    info!("Done.");
+    Ok(())
+}
+
 async fn handle_compiler_command(command: CompileCommand) -> Result<(), Error> {
     configure_logger(command.output, TerminalMode::Mixed);
 
@@ -565,6 +989,9 @@ async fn handle_compiler_command(command: CompileCommand) -> Result<(), Error> {
         FileSourceKind::WalkDir
     };
     config.repersist_operations = command.repersist;
+    if command.explain || command.explain_output.is_some() {
+        config.build_explainer = Some(BuildExplainer::new(command.explain_output));
+    }
 
     if command.watch && !matches!(&config.file_source_config, FileSourceKind::Watchman) {
         return Err(Error::CompilerError {
@@ -572,6 +999,28 @@ async fn handle_compiler_command(command: CompileCommand) -> Result<(), Error> {
         });
     }
 
+    if let Some(trace_file) = command.trace {
+        let perf_logger = Arc::new(ChromeTraceLogger::new());
+        let result = Compiler::new(Arc::new(config), Arc::clone(&perf_logger))
+            .compile()
+            .await;
+        perf_logger.write_to_file(&trace_file).map_err(|e| {
+            Error::ConfigError(CompilerError::ConfigError {
+                details: format!(
+                    "Failed to write trace to {}: {}",
+                    trace_file.to_string_lossy(),
+                    e
+                ),
+            })
+        })?;
+        info!("Wrote trace to {}", trace_file.to_string_lossy());
+        result.map_err(|err| Error::CompilerError {
+            details: format!("{}", err),
+        })?;
+        info!("Done.");
+        return Ok(());
+    }
+
     let compiler = Compiler::new(Arc::new(config), Arc::new(ConsoleLogger));
 
     if command.watch {
@@ -61,6 +61,7 @@ use relay_transforms::INTERNAL_METADATA_DIRECTIVE;
 use relay_transforms::InlineDirectiveMetadata;
 use relay_transforms::ModuleMetadata;
 use relay_transforms::NoInlineFragmentSpreadMetadata;
+use relay_transforms::PaginatedListMetadata;
 use relay_transforms::RESOLVER_BELONGS_TO_BASE_SCHEMA_DIRECTIVE;
 use relay_transforms::RefetchableMetadata;
 use relay_transforms::RelayDirective;
@@ -71,9 +72,9 @@ use relay_transforms::StreamDirective;
 use relay_transforms::TYPE_DISCRIMINATOR_DIRECTIVE_NAME;
 use relay_transforms::extract_connection_metadata_from_directive;
 use relay_transforms::extract_handle_field_directives;
+use relay_transforms::extract_paginated_list_metadata_from_directive;
 use relay_transforms::extract_values_from_handle_field_directive;
 use relay_transforms::generate_abstract_type_refinement_key;
-use relay_transforms::get_normalization_fragment_filename;
 use relay_transforms::get_normalization_operation_name;
 use relay_transforms::get_resolver_fragment_dependency_name;
 use relay_transforms::raw_text::get_raw_text_value;
@@ -180,6 +181,45 @@ pub fn build_request(
     }))
 }
 
+/// Builds a `LazyRequest`: a request without its normalization AST, which is
+/// loaded through the `operationModuleProvider` from the `$normalization`
+/// artifact of the operation.
+#[allow(clippy::too_many_arguments)]
+pub fn build_lazy_request(
+    schema: &SDLSchema,
+    ast_builder: &mut AstBuilder,
+    operation: &OperationDefinition,
+    fragment: &FragmentDefinition,
+    request_parameters: AstKey,
+    definition_source_location: WithLocation<StringKey>,
+    project_config: &ProjectConfig,
+    provider: ModuleProvider,
+) -> AstKey {
+    let mut fragment_builder = CodegenBuilder::new(
+        schema,
+        CodegenVariant::Reader,
+        ast_builder,
+        project_config,
+        definition_source_location,
+    );
+    let fragment = Primitive::Key(fragment_builder.build_fragment(fragment, true));
+    let operation_module = fragment_builder.resolve_normalization_import(
+        operation.name.item.0,
+        operation.name.location.source_location(),
+        provider,
+    );
+
+    ast_builder.intern(Ast::Object(object! {
+        fragment: fragment,
+        kind: Primitive::String(CODEGEN_CONSTANTS.lazy_request),
+        operation_module_provider: Primitive::DynamicImport {
+            provider,
+            module: operation_module,
+        },
+        params: Primitive::Key(request_parameters),
+    }))
+}
+
 pub fn build_preloadable_request(
     ast_builder: &mut AstBuilder,
     request_parameters: AstKey,
@@ -216,6 +256,24 @@ pub fn build_operation(
     builder.build_operation(operation)
 }
 
+/// Builds only the normalization selections of the operation, as an array.
+pub fn build_normalization_selections(
+    schema: &SDLSchema,
+    ast_builder: &mut AstBuilder,
+    operation: &OperationDefinition,
+    definition_source_location: WithLocation<StringKey>,
+    project_config: &ProjectConfig,
+) -> AstKey {
+    let mut builder = CodegenBuilder::new(
+        schema,
+        CodegenVariant::Normalization,
+        ast_builder,
+        project_config,
+        definition_source_location,
+    );
+    builder.build_operation_selections(operation)
+}
+
 pub fn build_fragment(
     schema: &SDLSchema,
     ast_builder: &mut AstBuilder,
@@ -422,6 +480,21 @@ impl<'schema, 'builder, 'config> CodegenBuilder<'schema, 'builder, 'config> {
             && context.has_exec_time_resolvers_enabled_provider
     }
 
+    fn build_operation_selections(&mut self, operation: &OperationDefinition) -> AstKey {
+        let mut context = ContextualMetadata {
+            has_client_edges: false,
+            has_client_to_server_resolvers: false,
+            has_exec_time_resolvers_directive: false,
+            has_exec_time_resolvers_enabled_provider: false,
+            has_server_to_client_resolvers: false,
+            use_experimental_provider: None,
+        };
+        match self.build_selections(&mut context, operation.selections.iter()) {
+            Primitive::Key(key) => key,
+            _ => panic!("Expected selections to be built as an array."),
+        }
+    }
+
     fn build_operation(&mut self, operation: &OperationDefinition) -> AstKey {
         let has_exec_time_resolvers_directive =
             operation.directives.named(*EXEC_TIME_RESOLVERS).is_some();
@@ -652,6 +725,8 @@ impl<'schema, 'builder, 'config> CodegenBuilder<'schema, 'builder, 'config> {
         skip_connection_metadata: bool,
     ) -> Primitive {
         let connection_metadata = extract_connection_metadata_from_directive(&fragment.directives);
+        let paginated_list_metadata =
+            extract_paginated_list_metadata_from_directive(&fragment.directives);
 
         let mut plural = false;
         let mut unmask = false;
@@ -665,6 +740,10 @@ impl<'schema, 'builder, 'config> CodegenBuilder<'schema, 'builder, 'config> {
         if !skip_connection_metadata && let Some(connection_metadata) = &connection_metadata {
             metadata.push(self.build_connection_metadata(connection_metadata))
         }
+        if !skip_connection_metadata && let Some(paginated_list_metadata) = paginated_list_metadata
+        {
+            metadata.push(self.build_paginated_list_metadata(paginated_list_metadata))
+        }
         if unmask {
             metadata.push(ObjectEntry {
                 key: CODEGEN_CONSTANTS.mask,
@@ -750,6 +829,29 @@ impl<'schema, 'builder, 'config> CodegenBuilder<'schema, 'builder, 'config> {
                     ),
                 operation: Primitive::GraphQLModuleDependency(GraphQLModuleDependency::Name(refetch_metadata.operation_name.into())),
             };
+            if let Some(paginated_list_metadata) = paginated_list_metadata {
+                let metadata = &paginated_list_metadata[0]; // Validated in `transform_refetchable`
+                let paginated_list_object = object! {
+                    limit: Primitive::string_or_null(metadata.limit),
+                    offset: Primitive::string_or_null(metadata.offset),
+                    path: Primitive::Key(
+                        self.array(
+                            metadata
+                                .path
+                                .as_ref()
+                                .expect("Expected path to exist")
+                                .iter()
+                                .cloned()
+                                .map(Primitive::String)
+                                .collect(),
+                        ),
+                    ),
+                };
+                refetch_object.push(ObjectEntry {
+                    key: CODEGEN_CONSTANTS.paginated_list,
+                    value: Primitive::Key(self.object(paginated_list_object)),
+                });
+            }
             if let Some(identifier_info) = &refetch_metadata.identifier_info {
                 refetch_object.push(ObjectEntry {
                     key: CODEGEN_CONSTANTS.identifier_info,
@@ -833,6 +935,33 @@ impl<'schema, 'builder, 'config> CodegenBuilder<'schema, 'builder, 'config> {
         }
     }
 
+    fn build_paginated_list_metadata(
+        &mut self,
+        paginated_list_metadata: &[PaginatedListMetadata],
+    ) -> ObjectEntry {
+        let array = paginated_list_metadata
+            .iter()
+            .map(|metadata| {
+                let path = match &metadata.path {
+                    None => Primitive::SkippableNull,
+                    Some(path) => Primitive::Key(
+                        self.array(path.iter().cloned().map(Primitive::String).collect()),
+                    ),
+                };
+                let object = object! {
+                    limit: Primitive::string_or_null(metadata.limit),
+                    offset: Primitive::string_or_null(metadata.offset),
+                    path: path,
+                };
+                Primitive::Key(self.object(object))
+            })
+            .collect::<Vec<_>>();
+        ObjectEntry {
+            key: CODEGEN_CONSTANTS.paginated_list,
+            value: Primitive::Key(self.array(array)),
+        }
+    }
+
     fn build_inline_data_fragment(&mut self, fragment: &FragmentDefinition) -> AstKey {
         let object = object! {
             kind: Primitive::String(CODEGEN_CONSTANTS.inline_data_fragment),
@@ -2633,32 +2762,31 @@ impl<'schema, 'builder, 'config> CodegenBuilder<'schema, 'builder, 'config> {
         }
     }
 
+    /// Resolves the module of the `$normalization` artifact of `name`, an
+    /// `@module` fragment or a lazily normalized operation.
     fn resolve_normalization_import(
         &self,
-        fragment_name: FragmentDefinitionName,
-        fragment_source_location: common::SourceLocationKey,
+        name: StringKey,
+        source_location: common::SourceLocationKey,
         provider: ModuleProvider,
     ) -> StringKey {
+        let normalization_filename = format!("{}.graphql", get_normalization_operation_name(name));
         if !matches!(provider, ModuleProvider::Custom { .. })
             || matches!(self.project_config.js_module_format, JsModuleFormat::Haste)
         {
-            return get_normalization_fragment_filename(fragment_name);
+            return normalization_filename.intern();
         }
         assert!(
-            !fragment_source_location.is_generated(),
-            "Cannot resolve normalization import path for fragment '{}' from a generated source location",
-            fragment_name
-        );
-        let normalization_filename = format!(
-            "{}.graphql",
-            get_normalization_operation_name(fragment_name.0)
+            !source_location.is_generated(),
+            "Cannot resolve normalization import path for '{}' from a generated source location",
+            name
         );
         let artifact_path = self
             .project_config
             .artifact_path_for_definition(self.definition_source_location);
         let norm_artifact_path = self
             .project_config
-            .path_for_language_specific_artifact(fragment_source_location, normalization_filename);
+            .path_for_language_specific_artifact(source_location, normalization_filename);
         self.project_config
             .js_module_import_identifier(&artifact_path, &norm_artifact_path)
     }
@@ -2743,7 +2871,7 @@ impl<'schema, 'builder, 'config> CodegenBuilder<'schema, 'builder, 'config> {
                         dynamic_module_provider,
                     );
                     let resolved_operation_module = self.resolve_normalization_import(
-                        fragment_name,
+                        fragment_name.0,
                         module_metadata.fragment_source_location.source_location(),
                         operation_module_provider,
                     );
@@ -73,6 +73,8 @@ pub struct CodegenConstants {
     pub key: StringKey,
     pub kind: StringKey,
     pub label: StringKey,
+    pub lazy_request: StringKey,
+    pub limit: StringKey,
     pub linked_field_property: StringKey,
     pub linked_field: StringKey,
     pub linked_handle: StringKey,
@@ -86,11 +88,13 @@ pub struct CodegenConstants {
     pub name: StringKey,
     pub normalization_node: StringKey,
     pub object_value: StringKey,
+    pub offset: StringKey,
     pub operation_kind: StringKey,
     pub operation_module_provider: StringKey,
     pub operation_value: StringKey,
     pub operation: StringKey,
     pub output_type: StringKey,
+    pub paginated_list: StringKey,
     pub params: StringKey,
     pub passing_value: StringKey,
     pub path: StringKey,
@@ -193,6 +197,8 @@ pub static CODEGEN_CONSTANTS: LazyLock<CodegenConstants> = LazyLock::new(|| Code
     key: "key".intern(),
     kind: "kind".intern(),
     label: "label".intern(),
+    lazy_request: "LazyRequest".intern(),
+    limit: "limit".intern(),
     linked_field_property: "linkedField".intern(),
     linked_field: "LinkedField".intern(),
     linked_handle: "LinkedHandle".intern(),
@@ -206,11 +212,13 @@ pub static CODEGEN_CONSTANTS: LazyLock<CodegenConstants> = LazyLock::new(|| Code
     name: "name".intern(),
     normalization_node: "normalizationNode".intern(),
     object_value: "ObjectValue".intern(),
+    offset: "offset".intern(),
     operation_kind: "operationKind".intern(),
     operation_module_provider: "operationModuleProvider".intern(),
     operation_value: "Operation".intern(),
     operation: "operation".intern(),
     output_type: "OutputType".intern(),
+    paginated_list: "paginatedList".intern(),
     params: "params".intern(),
     passing_value: "passingValue".intern(),
     path: "path".intern(),
@@ -10,11 +10,14 @@ use std::borrow::Cow;
 use std::fmt::Result as FmtResult;
 use std::fmt::Write;
 use std::path::Path;
+use std::sync::Arc;
 
+use common::WithLocation;
 use fnv::FnvBuildHasher;
 use fnv::FnvHashSet;
 use graphql_ir::ExecutableDefinitionName;
 use graphql_ir::FragmentDefinition;
+use graphql_ir::FragmentDefinitionName;
 use graphql_ir::OperationDefinition;
 use graphql_ir::reexport::Intern;
 use indexmap::IndexMap;
@@ -39,6 +42,8 @@ use crate::ast::QueryID;
 use crate::ast::RequestParameters;
 use crate::ast::ResolverModuleReference;
 use crate::build_ast::build_fragment;
+use crate::build_ast::build_lazy_request;
+use crate::build_ast::build_normalization_selections;
 use crate::build_ast::build_operation;
 use crate::build_ast::build_preloadable_request;
 use crate::build_ast::build_provided_variables;
@@ -217,6 +222,33 @@ impl<'p> Printer<'p> {
         request_parameters: RequestParameters<'_>,
         top_level_statements: &mut TopLevelStatements,
     ) -> String {
+        self.print_request_with_shared_normalization_selections(
+            schema,
+            operation,
+            fragment,
+            request_parameters,
+            &[],
+            top_level_statements,
+        )
+    }
+
+    /// Prints the request, importing the normalization selections of the
+    /// `shared_normalization_selections` operations wherever the same
+    /// selections appear in it.
+    pub fn print_request_with_shared_normalization_selections(
+        &mut self,
+        schema: &SDLSchema,
+        operation: &OperationDefinition,
+        fragment: &FragmentDefinition,
+        request_parameters: RequestParameters<'_>,
+        shared_normalization_selections: &[Arc<OperationDefinition>],
+        top_level_statements: &mut TopLevelStatements,
+    ) -> String {
+        let shared_selections = self.build_shared_selections(
+            schema,
+            fragment.name.map(|x| x.0),
+            shared_normalization_selections,
+        );
         let request_parameters = build_request_params_ast_key(
             schema,
             request_parameters,
@@ -234,6 +266,70 @@ impl<'p> Printer<'p> {
             fragment.name.map(|x| x.0),
             self.project_config,
         );
+        let printer = JSONPrinter::new(
+            &self.builder,
+            self.project_config,
+            top_level_statements,
+            Some(operation.name.item.0),
+        )
+        .with_shared_selections(shared_selections);
+        printer.print(key, self.should_dedupe(operation.name.item.0))
+    }
+
+    /// Prints the normalization selections shared by the artifacts of the
+    /// operations spreading `fragment_name`.
+    pub fn print_shared_normalization_selections(
+        &mut self,
+        schema: &SDLSchema,
+        operation: &OperationDefinition,
+        fragment_name: FragmentDefinitionName,
+        top_level_statements: &mut TopLevelStatements,
+    ) -> String {
+        let key = build_normalization_selections(
+            schema,
+            &mut self.builder,
+            operation,
+            operation.name.map(|x| x.0),
+            self.project_config,
+        );
+        let printer = JSONPrinter::new(
+            &self.builder,
+            self.project_config,
+            top_level_statements,
+            Some(operation.name.item.0),
+        );
+        printer.print(key, self.should_dedupe(fragment_name.0))
+    }
+
+    /// Prints the request without its normalization AST, which is printed
+    /// into a separate module by `print_operation_with_shared_normalization_selections`.
+    pub fn print_lazy_request(
+        &mut self,
+        schema: &SDLSchema,
+        operation: &OperationDefinition,
+        fragment: &FragmentDefinition,
+        request_parameters: RequestParameters<'_>,
+        provider: ModuleProvider,
+        top_level_statements: &mut TopLevelStatements,
+    ) -> String {
+        let request_parameters = build_request_params_ast_key(
+            schema,
+            request_parameters,
+            &mut self.builder,
+            operation,
+            operation.name.map(|x| x.0),
+            self.project_config,
+        );
+        let key = build_lazy_request(
+            schema,
+            &mut self.builder,
+            operation,
+            fragment,
+            request_parameters,
+            fragment.name.map(|x| x.0),
+            self.project_config,
+            provider,
+        );
         let printer = JSONPrinter::new(
             &self.builder,
             self.project_config,
@@ -290,6 +386,38 @@ impl<'p> Printer<'p> {
         printer.print(key, self.should_dedupe(operation.name.item.0))
     }
 
+    /// Prints the operation, importing the normalization selections of the
+    /// `shared_normalization_selections` operations wherever the same
+    /// selections appear in it.
+    pub fn print_operation_with_shared_normalization_selections(
+        &mut self,
+        schema: &SDLSchema,
+        operation: &OperationDefinition,
+        shared_normalization_selections: &[Arc<OperationDefinition>],
+        top_level_statements: &mut TopLevelStatements,
+    ) -> String {
+        let shared_selections = self.build_shared_selections(
+            schema,
+            operation.name.map(|x| x.0),
+            shared_normalization_selections,
+        );
+        let key = build_operation(
+            schema,
+            &mut self.builder,
+            operation,
+            operation.name.map(|x| x.0),
+            self.project_config,
+        );
+        let printer = JSONPrinter::new(
+            &self.builder,
+            self.project_config,
+            top_level_statements,
+            Some(operation.name.item.0),
+        )
+        .with_shared_selections(shared_selections);
+        printer.print(key, self.should_dedupe(operation.name.item.0))
+    }
+
     pub fn print_fragment(
         &mut self,
         schema: &SDLSchema,
@@ -351,6 +479,46 @@ impl<'p> Printer<'p> {
         printer.print(key, self.dedupe)
     }
 
+    /// Builds the normalization selections of the shared operations for the
+    /// artifact of `definition_source_location`, so that their keys match the
+    /// selections built for that artifact, with the import of their module.
+    fn build_shared_selections(
+        &mut self,
+        schema: &SDLSchema,
+        definition_source_location: WithLocation<StringKey>,
+        shared_normalization_selections: &[Arc<OperationDefinition>],
+    ) -> Vec<(AstKey, GraphQLModuleDependency)> {
+        let artifact_path = self
+            .project_config
+            .artifact_path_for_definition(definition_source_location);
+        shared_normalization_selections
+            .iter()
+            .map(|shared_operation| {
+                let key = build_normalization_selections(
+                    schema,
+                    &mut self.builder,
+                    shared_operation,
+                    definition_source_location,
+                    self.project_config,
+                );
+                let path = self.project_config.js_module_import_identifier(
+                    &artifact_path,
+                    &self.project_config.create_path_for_artifact(
+                        shared_operation.name.location.source_location(),
+                        shared_operation.name.item.0.to_string(),
+                    ),
+                );
+                (
+                    key,
+                    GraphQLModuleDependency::Path {
+                        name: shared_operation.name.item.into(),
+                        path,
+                    },
+                )
+            })
+            .collect()
+    }
+
     fn should_dedupe(&self, name: StringKey) -> bool {
         self.dedupe
             && !self
@@ -372,6 +540,8 @@ pub struct JSONPrinter<'b> {
     top_level_statements: &'b mut TopLevelStatements,
     skip_printing_nulls: bool,
     relativize_js_module_paths: bool,
+    /// Arrays printed as imports of shared modules, longest first.
+    shared_selections: Vec<(AstKey, GraphQLModuleDependency)>,
 }
 
 impl<'b> JSONPrinter<'b> {
@@ -393,9 +563,71 @@ impl<'b> JSONPrinter<'b> {
                 .feature_flags
                 .skip_printing_nulls
                 .is_fully_enabled(),
+            shared_selections: Default::default(),
+        }
+    }
+
+    /// Prints the given arrays as imports of their module, and runs of their
+    /// elements within other arrays as spreads of their module.
+    pub fn with_shared_selections(
+        mut self,
+        mut shared_selections: Vec<(AstKey, GraphQLModuleDependency)>,
+    ) -> Self {
+        // A single selection without nested values is not worth an import
+        shared_selections.retain(|(key, _)| match self.builder.lookup(*key) {
+            Ast::Array(array) => match array.as_slice() {
+                [] => false,
+                [Primitive::Key(key)] => self.has_nested_values(*key),
+                _ => true,
+            },
+            Ast::Object(_) => false,
+        });
+        shared_selections.sort_by_key(|(key, _)| std::cmp::Reverse(self.array_len(*key)));
+        self.shared_selections = shared_selections;
+        self
+    }
+
+    fn array_len(&self, key: AstKey) -> usize {
+        match self.builder.lookup(key) {
+            Ast::Array(array) => array.len(),
+            Ast::Object(_) => 0,
+        }
+    }
+
+    fn has_nested_values(&self, key: AstKey) -> bool {
+        match self.builder.lookup(key) {
+            Ast::Array(array) => array.iter().any(|value| matches!(value, Primitive::Key(_))),
+            Ast::Object(object) => object
+                .iter()
+                .any(|entry| matches!(entry.value, Primitive::Key(_))),
         }
     }
 
+    fn find_shared_selections(&self, key: AstKey) -> Option<&GraphQLModuleDependency> {
+        self.shared_selections
+            .iter()
+            .find(|(shared_key, _)| *shared_key == key)
+            .map(|(_, dependency)| dependency)
+    }
+
+    /// Returns the shared selections that `array` contains at `index`.
+    fn find_shared_selections_at(
+        &self,
+        array: &[Primitive],
+        index: usize,
+    ) -> Option<(usize, GraphQLModuleDependency)> {
+        self.shared_selections
+            .iter()
+            .find_map(
+                |(shared_key, dependency)| match self.builder.lookup(*shared_key) {
+                    Ast::Array(shared_array) if array[index..].starts_with(shared_array) => {
+                        Some((shared_array.len(), dependency.clone()))
+                    }
+                    _ => None,
+                },
+            )
+    }
+
     pub fn print(mut self, root_key: AstKey, dedupe: bool) -> String {
         if dedupe {
             let mut visited = Default::default();
@@ -436,6 +668,9 @@ impl<'b> JSONPrinter<'b> {
     /// v0 = {b: 2};
     /// v1 = [{a: 1}, v0];
     fn collect_value_duplicates(&mut self, visited: &mut FnvHashSet<AstKey>, key: AstKey) {
+        if self.find_shared_selections(key).is_some() {
+            return;
+        }
         match self.builder.lookup(key) {
             Ast::Array(array) => {
                 if array.is_empty() {
@@ -469,6 +704,10 @@ impl<'b> JSONPrinter<'b> {
     }
 
     fn print_ast(&mut self, f: &mut String, key: AstKey, indent: usize, is_dedupe_var: bool) {
+        if let Some(dependency) = self.find_shared_selections(key) {
+            let dependency = dependency.clone();
+            return self.write_shared_selections(f, dependency, indent);
+        }
         // Only use variable references at depth beyond the top level.
         if indent > 0 && self.duplicates.contains(&key) {
             let v = if self.variable_definitions.contains_key(&key) {
@@ -522,15 +761,26 @@ impl<'b> JSONPrinter<'b> {
                 } else {
                     f.push('[');
                     let next_indent = indent + 1;
-                    for value in array {
+                    let mut index = 0;
+                    while index < array.len() {
+                        let value = &array[index];
+                        index += 1;
                         match value {
                             Primitive::SkippableNull if self.skip_printing_nulls => continue,
                             _ => {}
                         }
                         f.push('\n');
                         print_indentation(f, next_indent);
-                        self.print_primitive(f, value, next_indent, is_dedupe_var)
-                            .unwrap();
+                        if let Some((len, dependency)) =
+                            self.find_shared_selections_at(array, index - 1)
+                        {
+                            f.push_str("...");
+                            self.write_shared_selections(f, dependency, next_indent);
+                            index += len - 1;
+                        } else {
+                            self.print_primitive(f, value, next_indent, is_dedupe_var)
+                                .unwrap();
+                        }
                         f.push(',');
                     }
                     f.pop();
@@ -542,6 +792,23 @@ impl<'b> JSONPrinter<'b> {
         }
     }
 
+    fn write_shared_selections(
+        &mut self,
+        f: &mut String,
+        dependency: GraphQLModuleDependency,
+        indent: usize,
+    ) {
+        f.push('(');
+        self.print_primitive(
+            f,
+            &Primitive::GraphQLModuleDependency(dependency),
+            indent,
+            false,
+        )
+        .unwrap();
+        f.push_str("/*:: as any*/)");
+    }
+
     fn print_primitive(
         &mut self,
         f: &mut String,
@@ -5,6 +5,9 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::collections::HashSet;
 use std::fs;
 use std::path::Path;
 use std::path::PathBuf;
@@ -18,16 +21,23 @@ use common::DiagnosticsResult;
 use common::FeatureFlag;
 use common::Rollout;
 use common::RolloutRange;
+use intern::string_key::Intern;
 use log::info;
+use log::warn;
 use lsp_types::CodeActionOrCommand;
 use lsp_types::TextEdit;
 use lsp_types::Uri;
 use relay_compiler::errors::BuildProjectError;
 use relay_compiler::errors::Error as CompilerError;
 use relay_compiler::errors::Result as CompilerResult;
+use relay_compiler::print_compiler_error;
+use relay_transforms::DeprecatedFieldReplacements;
 use relay_transforms::Programs;
 use relay_transforms::disallow_required_on_non_null_field;
 use relay_transforms::fragment_alias_directive;
+use relay_transforms::migrate_deprecated_fields;
+use schema_coordinates::SchemaCoordinate;
+use schema_coordinates::parse_schema_coordinate;
 
 #[derive(Subcommand, Debug, Clone)]
 pub enum AvailableCodemod {
@@ -39,6 +49,15 @@ pub enum AvailableCodemod {
 
     /// Runs all Relay compiler transforms and fixes all fixable diagnostics
     FixAll,
+
+    /// Moves a file (or directory) and renames the fragments and operations it
+    /// defines, along with all their usages, to start with the new module name.
+    RenameFile(RenameFileArgs),
+
+    /// Replaces selections of deprecated fields with the field named in their
+    /// deprecation reason ("Use `newField`") or in a mapping file, keeping the
+    /// response key. Selections that can't be migrated are reported.
+    MigrateDeprecatedFields(MigrateDeprecatedFieldsArgs),
 }
 
 #[derive(Args, Debug, Clone)]
@@ -50,6 +69,24 @@ pub struct MarkDangerousConditionalFragmentSpreadsArgs {
     pub rollout_percentage: FeatureFlag,
 }
 
+#[derive(Args, Debug, Clone)]
+pub struct RenameFileArgs {
+    /// The file or directory to move
+    pub from: PathBuf,
+
+    /// The new path of the file or directory
+    pub to: PathBuf,
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct MigrateDeprecatedFieldsArgs {
+    /// JSON file mapping schema coordinates of fields to the name of their
+    /// replacement, e.g. `{"User.oldName": "name"}`. These take precedence
+    /// over deprecation reasons.
+    #[clap(long)]
+    pub mapping: Option<PathBuf>,
+}
+
 pub async fn run_codemod(
     programs: CompilerResult<Vec<Arc<Programs>>>,
     root_dir: PathBuf,
@@ -88,9 +125,124 @@ pub async fn run_codemod(
                 }
             }
         }
+        AvailableCodemod::MigrateDeprecatedFields(opts) => {
+            let replacements = match &opts.mapping {
+                Some(mapping) => load_deprecated_field_replacements(mapping)?,
+                None => Default::default(),
+            };
+            // Projects sharing source files report the same selections
+            let mut seen_locations = HashSet::new();
+            let diagnostics = programs
+                .expect("Failed to build programs")
+                .iter()
+                .flat_map(|programs| {
+                    migrate_deprecated_fields(&programs.source, &replacements)
+                        .err()
+                        .unwrap_or_default()
+                })
+                .filter(|diagnostic| seen_locations.insert(diagnostic.location()))
+                .collect::<Vec<_>>();
+            let (fixable, unfixable): (Vec<_>, Vec<_>) = diagnostics
+                .into_iter()
+                .partition(|diagnostic| !diagnostic.get_data().is_empty());
+            if !unfixable.is_empty() {
+                warn!(
+                    "Codemod MigrateDeprecatedFields could not migrate {} selections:\n{}",
+                    unfixable.len(),
+                    print_compiler_error(
+                        &root_dir,
+                        CompilerError::DiagnosticsError { errors: unfixable }
+                    )
+                );
+            }
+            fix_diagnostics("MigrateDeprecatedFields", &root_dir, &fixable)
+        }
+        AvailableCodemod::RenameFile(opts) => rename_file(
+            programs.expect("Failed to build programs"),
+            &root_dir,
+            &opts.from,
+            &opts.to,
+        ),
     }
 }
 
+fn rename_file(
+    programs: Vec<Arc<Programs>>,
+    root_dir: &Path,
+    from: &Path,
+    to: &Path,
+) -> Result<(), std::io::Error> {
+    let current_dir = std::env::current_dir()?;
+    let from = current_dir.join(from);
+    let to = current_dir.join(to);
+    let relative_path = |path: &Path| {
+        path.strip_prefix(root_dir)
+            .map(Path::to_path_buf)
+            .map_err(|_| {
+                std::io::Error::other(format!(
+                    "Expected {} to be within the root directory {}",
+                    path.display(),
+                    root_dir.display()
+                ))
+            })
+    };
+    let relative_from = relative_path(&from)?;
+    let relative_to = relative_path(&to)?;
+
+    let mut collected_changes: HashMap<Uri, Vec<TextEdit>> = HashMap::new();
+    for programs in &programs {
+        for (file, changes) in relay_lsp::rename::get_text_edits_for_file_rename(
+            &programs.source,
+            &relative_from,
+            &relative_to,
+            root_dir,
+        ) {
+            let file_changes = collected_changes.entry(file).or_default();
+            // Projects sharing source files produce the same changes
+            for change in changes {
+                if !file_changes.contains(&change) {
+                    file_changes.push(change);
+                }
+            }
+        }
+    }
+
+    info!(
+        "Codemod RenameFile found changes to make in {} files.",
+        collected_changes.len()
+    );
+    apply_changes(collected_changes)?;
+
+    if let Some(parent) = to.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::rename(&from, &to)?;
+    info!("Moved {} to {}", from.display(), to.display());
+    Ok(())
+}
+
+fn load_deprecated_field_replacements(
+    mapping: &Path,
+) -> Result<DeprecatedFieldReplacements, std::io::Error> {
+    let contents = fs::read_to_string(mapping)?;
+    let mapping: HashMap<String, String> =
+        serde_json::from_str(&contents).map_err(std::io::Error::other)?;
+    mapping
+        .into_iter()
+        .map(|(coordinate, replacement)| {
+            match parse_schema_coordinate(&coordinate).map_err(std::io::Error::other)? {
+                SchemaCoordinate::Member {
+                    parent_name,
+                    member_name,
+                } => Ok(((parent_name, member_name), replacement.intern())),
+                _ => Err(std::io::Error::other(format!(
+                    "Expected a field coordinate like `Type.field`, got `{coordinate}`"
+                ))),
+            }
+        })
+        .collect()
+}
+
 fn as_diagnostics(error: CompilerError) -> Vec<Diagnostic> {
     match error {
         CompilerError::DiagnosticsError { errors } => errors,
@@ -142,7 +294,7 @@ pub fn fix_diagnostics(
 }
 
 fn apply_actions(actions: Vec<CodeActionOrCommand>) -> Result<(), std::io::Error> {
-    let mut collected_changes = std::collections::HashMap::new();
+    let mut collected_changes = HashMap::new();
 
     // Collect all the changes into a map of file-to-list-of-changes
     for action in actions {
@@ -158,6 +310,10 @@ fn apply_actions(actions: Vec<CodeActionOrCommand>) -> Result<(), std::io::Error
         }
     }
 
+    apply_changes(collected_changes)
+}
+
+fn apply_changes(collected_changes: HashMap<Uri, Vec<TextEdit>>) -> Result<(), std::io::Error> {
     for (file, mut changes) in collected_changes {
         sort_changes(&file, &mut changes)?;
 
@@ -174,7 +330,10 @@ fn apply_actions(actions: Vec<CodeActionOrCommand>) -> Result<(), std::io::Error
         }
 
         // Write file back out
-        let new_file_contents = lines.join("\n");
+        let mut new_file_contents = lines.join("\n");
+        if file_contents.ends_with('\n') {
+            new_file_contents.push('\n');
+        }
         fs::write(file.path().as_str(), new_file_contents)?;
 
         info!("Applied {} changes to {}", changes.len(), file.path());
@@ -185,7 +344,7 @@ fn apply_actions(actions: Vec<CodeActionOrCommand>) -> Result<(), std::io::Error
 fn sort_changes(uri: &Uri, changes: &mut Vec<TextEdit>) -> Result<(), std::io::Error> {
     // Now we have all the changes for this file. Sort them by position within the file, end of file first
     // This way the changes are applied in reverse order, so we don't have to worry about altering the positions of the remaining changes
-    changes.sort_by_key(|change| change.range.start);
+    changes.sort_by_key(|change| Reverse(change.range.start));
 
     // Verify none of the changes overlap
     let mut prev_change: Option<&TextEdit> = None;
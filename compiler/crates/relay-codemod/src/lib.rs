@@ -13,6 +13,8 @@
 mod codemod;
 
 pub use crate::codemod::AvailableCodemod;
+pub use crate::codemod::MigrateDeprecatedFieldsArgs;
+pub use crate::codemod::RenameFileArgs;
 pub use crate::codemod::fix_diagnostics;
 pub use crate::codemod::run_codemod;
 pub use crate::codemod::run_codemod_impl;
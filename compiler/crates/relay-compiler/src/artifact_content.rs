@@ -16,16 +16,26 @@ pub mod content_section;
 use std::sync::Arc;
 
 use common::SourceLocationKey;
+use content::generate_enum_module_content;
 use content::generate_fragment;
+use content::generate_fragment_rescript_types;
+use content::generate_fragment_zod_schema;
+use content::generate_lazy_normalization_operation;
 use content::generate_operation;
+use content::generate_operation_rescript_types;
+use content::generate_operation_zod_schema;
 use content::generate_resolvers_schema_module_content;
+use content::generate_shared_normalization_selections;
 use content::generate_split_operation;
 use content::generate_updatable_query;
 use graphql_ir::FragmentDefinition;
+use graphql_ir::FragmentDefinitionName;
 use graphql_ir::OperationDefinition;
 use relay_codegen::Printer;
 use relay_codegen::QueryID;
+use relay_config::ModuleProvider;
 use relay_typegen::FragmentLocations;
+use schema::EnumID;
 use schema::SDLSchema;
 
 use self::content::generate_preloadable_query_parameters;
@@ -41,6 +51,17 @@ pub enum ArtifactContent {
         source_hash: String,
         text: Option<String>,
         id_and_text_hash: Option<QueryID>,
+        /// Operations whose normalization selections are shared with other
+        /// artifacts, to be imported wherever the same selections appear.
+        shared_normalization_selections: Vec<Arc<OperationDefinition>>,
+        /// Provider of the `$normalization` artifact the normalization AST
+        /// is loaded from, when it is not inlined in the request.
+        lazy_normalization_provider: Option<ModuleProvider>,
+    },
+    LazyNormalizationOperation {
+        normalization_operation: Arc<OperationDefinition>,
+        shared_normalization_selections: Vec<Arc<OperationDefinition>>,
+        source_hash: String,
     },
     UpdatableQuery {
         reader_operation: Arc<OperationDefinition>,
@@ -62,7 +83,29 @@ pub enum ArtifactContent {
         source_hash: Option<String>,
         no_optional_fields_in_raw_response_type: bool,
     },
+    SharedNormalizationSelections {
+        normalization_operation: Arc<OperationDefinition>,
+        fragment_name: FragmentDefinitionName,
+        source_hash: Option<String>,
+    },
     ResolversSchema,
+    OperationZodSchema {
+        normalization_operation: Arc<OperationDefinition>,
+        typegen_operation: Arc<OperationDefinition>,
+    },
+    FragmentZodSchema {
+        typegen_fragment: Arc<FragmentDefinition>,
+    },
+    OperationReScriptTypes {
+        normalization_operation: Arc<OperationDefinition>,
+        typegen_operation: Arc<OperationDefinition>,
+    },
+    FragmentReScriptTypes {
+        typegen_fragment: Arc<FragmentDefinition>,
+    },
+    EnumModule {
+        enum_id: EnumID,
+    },
     Generic {
         content: Vec<u8>,
     },
@@ -92,6 +135,8 @@ impl ArtifactContent {
                 source_hash,
                 text,
                 id_and_text_hash,
+                shared_normalization_selections,
+                lazy_normalization_provider,
             } => generate_operation(
                 config,
                 project_config,
@@ -103,10 +148,26 @@ impl ArtifactContent {
                 source_hash.into(),
                 text,
                 id_and_text_hash,
+                shared_normalization_selections,
+                *lazy_normalization_provider,
                 skip_types,
                 fragment_locations,
             )
             .unwrap(),
+            ArtifactContent::LazyNormalizationOperation {
+                normalization_operation,
+                shared_normalization_selections,
+                source_hash,
+            } => generate_lazy_normalization_operation(
+                config,
+                project_config,
+                printer,
+                schema,
+                normalization_operation,
+                shared_normalization_selections,
+                source_hash,
+            )
+            .unwrap(),
             ArtifactContent::UpdatableQuery {
                 reader_operation,
                 typegen_operation,
@@ -168,10 +229,71 @@ impl ArtifactContent {
                 fragment_locations,
             )
             .unwrap(),
+            ArtifactContent::SharedNormalizationSelections {
+                normalization_operation,
+                fragment_name,
+                source_hash,
+            } => generate_shared_normalization_selections(
+                config,
+                project_config,
+                printer,
+                schema,
+                normalization_operation,
+                *fragment_name,
+                source_hash.as_ref(),
+            )
+            .unwrap(),
             ArtifactContent::ResolversSchema => {
                 generate_resolvers_schema_module_content(config, project_config, printer, schema)
                     .unwrap()
             }
+            ArtifactContent::OperationZodSchema {
+                normalization_operation,
+                typegen_operation,
+            } => generate_operation_zod_schema(
+                config,
+                project_config,
+                schema,
+                normalization_operation,
+                typegen_operation,
+                fragment_locations,
+            )
+            .unwrap(),
+            ArtifactContent::FragmentZodSchema { typegen_fragment } => {
+                generate_fragment_zod_schema(
+                    config,
+                    project_config,
+                    schema,
+                    typegen_fragment,
+                    fragment_locations,
+                )
+                .unwrap()
+            }
+            ArtifactContent::OperationReScriptTypes {
+                normalization_operation,
+                typegen_operation,
+            } => generate_operation_rescript_types(
+                config,
+                project_config,
+                schema,
+                normalization_operation,
+                typegen_operation,
+                fragment_locations,
+            )
+            .unwrap(),
+            ArtifactContent::FragmentReScriptTypes { typegen_fragment } => {
+                generate_fragment_rescript_types(
+                    config,
+                    project_config,
+                    schema,
+                    typegen_fragment,
+                    fragment_locations,
+                )
+                .unwrap()
+            }
+            ArtifactContent::EnumModule { enum_id } => {
+                generate_enum_module_content(config, project_config, schema, *enum_id).unwrap()
+            }
             ArtifactContent::Generic { content } => content.clone(),
         }
     }
@@ -8,16 +8,20 @@
 use std::fmt::Error as FmtError;
 use std::fmt::Result as FmtResult;
 use std::fmt::Write;
+use std::path::Path;
 use std::sync::Arc;
 
 use common::NamedItem;
 use graphql_ir::FragmentDefinition;
 use graphql_ir::FragmentDefinitionName;
 use graphql_ir::OperationDefinition;
+use intern::Lookup;
 use intern::string_key::StringKey;
 use relay_codegen::Printer;
 use relay_codegen::QueryID;
 use relay_codegen::build_request_params;
+use relay_config::JsModuleFormat;
+use relay_config::ModuleProvider;
 use relay_transforms::ASSIGNABLE_DIRECTIVE;
 use relay_transforms::RelayDataDrivenDependencyMetadata;
 use relay_transforms::is_operation_preloadable;
@@ -28,7 +32,9 @@ use relay_typegen::generate_fragment_type_exports_section;
 use relay_typegen::generate_named_validator_export;
 use relay_typegen::generate_operation_type_exports_section;
 use relay_typegen::generate_split_operation_type_exports_section;
+use schema::EnumID;
 use schema::SDLSchema;
+use schema::Schema;
 use signedsource::SIGNING_TOKEN;
 
 use super::super::ArtifactGeneratedTypes;
@@ -37,6 +43,7 @@ use super::content_section::ContentSection;
 use super::content_section::ContentSections;
 use super::content_section::DocblockSection;
 use super::content_section::GenericSection;
+use crate::build_project::OperationRegistryEntry;
 use crate::config::Config;
 use crate::config::ProjectConfig;
 
@@ -285,6 +292,8 @@ pub fn generate_operation(
     source_hash: String,
     text: &Option<String>,
     id_and_text_hash: &Option<QueryID>,
+    shared_normalization_selections: &[Arc<OperationDefinition>],
+    lazy_normalization_provider: Option<ModuleProvider>,
     skip_types: bool,
     fragment_locations: &FragmentLocations,
 ) -> Result<Vec<u8>, FmtError> {
@@ -371,6 +380,7 @@ pub fn generate_operation(
         typegen_operation,
         skip_types,
         request_parameters.is_client_request(),
+        lazy_normalization_provider.is_some(),
         project_config.typegen_config.language,
     );
 
@@ -410,13 +420,24 @@ pub fn generate_operation(
 
     let mut top_level_statements = Default::default();
     // -- Begin Query Node Section --
-    let request = printer.print_request(
-        schema,
-        normalization_operation,
-        &operation_fragment,
-        request_parameters,
-        &mut top_level_statements,
-    );
+    let request = match lazy_normalization_provider {
+        Some(provider) => printer.print_lazy_request(
+            schema,
+            normalization_operation,
+            &operation_fragment,
+            request_parameters,
+            provider,
+            &mut top_level_statements,
+        ),
+        None => printer.print_request_with_shared_normalization_selections(
+            schema,
+            normalization_operation,
+            &operation_fragment,
+            request_parameters,
+            shared_normalization_selections,
+            &mut top_level_statements,
+        ),
+    };
 
     // -- Begin Top Level Statements Section --
     let mut section: GenericSection = GenericSection::default();
@@ -467,7 +488,9 @@ pub fn generate_operation(
                     )?;
                 }
             }
-            TypegenLanguage::JavaScript | TypegenLanguage::TypeScript => {
+            TypegenLanguage::JavaScript
+            | TypegenLanguage::ReScript
+            | TypegenLanguage::TypeScript => {
                 if project_config.typegen_config.eager_es_modules {
                     writeln!(
                         section,
@@ -503,6 +526,202 @@ pub fn generate_operation(
     content_sections.into_signed_bytes()
 }
 
+pub fn generate_lazy_normalization_operation(
+    config: &Config,
+    project_config: &ProjectConfig,
+    printer: &mut Printer<'_>,
+    schema: &SDLSchema,
+    normalization_operation: &OperationDefinition,
+    shared_normalization_selections: &[Arc<OperationDefinition>],
+    source_hash: &str,
+) -> Result<Vec<u8>, FmtError> {
+    let mut content_sections = ContentSections::default();
+
+    // -- Begin Docblock Section --
+    content_sections.push(ContentSection::Docblock(generate_docblock_section(
+        config,
+        project_config,
+        vec![],
+        normalization_operation.name.item.0,
+    )?));
+    // -- End Docblock Section --
+
+    // -- Begin Disable Lint Section --
+    content_sections.push(ContentSection::Generic(generate_disable_lint_section(
+        &project_config.typegen_config.language,
+    )?));
+    // -- End Disable Lint Section --
+
+    // -- Begin Use Strict Section --
+    content_sections.push(ContentSection::Generic(generate_use_strict_section(
+        &project_config.typegen_config.language,
+    )?));
+    // -- End Use Strict Section --
+
+    // -- Begin Types Section --
+    let mut section = GenericSection::default();
+    if project_config.typegen_config.language == TypegenLanguage::Flow {
+        writeln!(section, "/*::")?;
+    }
+    write_import_type_from(
+        project_config,
+        &mut section,
+        "NormalizationOperation",
+        "relay-runtime",
+    )?;
+    if project_config.typegen_config.language == TypegenLanguage::Flow {
+        writeln!(section, "*/")?;
+    }
+    content_sections.push(ContentSection::Generic(section));
+    // -- End Types Section --
+
+    // -- Begin Top Level Statements Section --
+    let mut section = GenericSection::default();
+    let mut top_level_statements = Default::default();
+    let operation = printer.print_operation_with_shared_normalization_selections(
+        schema,
+        normalization_operation,
+        shared_normalization_selections,
+        &mut top_level_statements,
+    );
+
+    write!(section, "{}", &top_level_statements)?;
+    content_sections.push(ContentSection::Generic(section));
+    // -- End Top Level Statements Section --
+
+    // -- Begin Operation Node Section --
+    let mut section = GenericSection::default();
+    write_variable_value_with_type(
+        &project_config.typegen_config.language,
+        &mut section,
+        "node",
+        "NormalizationOperation",
+        &operation,
+    )?;
+    content_sections.push(ContentSection::Generic(section));
+    // -- End Operation Node Section --
+
+    // -- Begin Operation Node Hash Section --
+    let mut section = GenericSection::default();
+    write_source_hash(
+        config,
+        &project_config.typegen_config.language,
+        &mut section,
+        source_hash,
+    )?;
+    content_sections.push(ContentSection::Generic(section));
+    // -- End Operation Node Hash Section --
+
+    // -- Begin Export Section --
+    let mut section = GenericSection::default();
+    write_export_generated_node(&project_config.typegen_config, &mut section, "node", None)?;
+    content_sections.push(ContentSection::Generic(section));
+    // -- End Export Section --
+
+    content_sections.into_signed_bytes()
+}
+
+pub fn generate_shared_normalization_selections(
+    config: &Config,
+    project_config: &ProjectConfig,
+    printer: &mut Printer<'_>,
+    schema: &SDLSchema,
+    normalization_operation: &OperationDefinition,
+    fragment_name: FragmentDefinitionName,
+    source_hash: Option<&String>,
+) -> Result<Vec<u8>, FmtError> {
+    let mut content_sections = ContentSections::default();
+
+    // -- Begin Docblock Section --
+    content_sections.push(ContentSection::Docblock(generate_docblock_section(
+        config,
+        project_config,
+        vec![],
+        normalization_operation.name.item.0,
+    )?));
+    // -- End Docblock Section --
+
+    // -- Begin Disable Lint Section --
+    content_sections.push(ContentSection::Generic(generate_disable_lint_section(
+        &project_config.typegen_config.language,
+    )?));
+    // -- End Disable Lint Section --
+
+    // -- Begin Use Strict Section --
+    content_sections.push(ContentSection::Generic(generate_use_strict_section(
+        &project_config.typegen_config.language,
+    )?));
+    // -- End Use Strict Section --
+
+    // -- Begin Types Section --
+    let mut section = GenericSection::default();
+    if project_config.typegen_config.language == TypegenLanguage::Flow {
+        writeln!(section, "/*::")?;
+    }
+    write_import_type_from(
+        project_config,
+        &mut section,
+        "NormalizationSelection",
+        "relay-runtime",
+    )?;
+    if project_config.typegen_config.language == TypegenLanguage::Flow {
+        writeln!(section, "*/")?;
+    }
+    content_sections.push(ContentSection::Generic(section));
+    // -- End Types Section --
+
+    // -- Begin Top Level Statements Section --
+    let mut section = GenericSection::default();
+    let mut top_level_statements = Default::default();
+    let selections = printer.print_shared_normalization_selections(
+        schema,
+        normalization_operation,
+        fragment_name,
+        &mut top_level_statements,
+    );
+
+    write!(section, "{}", &top_level_statements)?;
+    content_sections.push(ContentSection::Generic(section));
+    // -- End Top Level Statements Section --
+
+    // -- Begin Selections Node Section --
+    let mut section = GenericSection::default();
+    let selections_type = match project_config.typegen_config.language {
+        TypegenLanguage::Flow => "$ReadOnlyArray<NormalizationSelection>",
+        _ => "ReadonlyArray<NormalizationSelection>",
+    };
+    write_variable_value_with_type(
+        &project_config.typegen_config.language,
+        &mut section,
+        "node",
+        selections_type,
+        &selections,
+    )?;
+    content_sections.push(ContentSection::Generic(section));
+    // -- End Selections Node Section --
+
+    // -- Begin Selections Node Hash Section --
+    let mut section = GenericSection::default();
+    if let Some(source_hash) = source_hash {
+        write_source_hash(
+            config,
+            &project_config.typegen_config.language,
+            &mut section,
+            source_hash,
+        )?;
+    }
+    content_sections.push(ContentSection::Generic(section));
+    // -- End Selections Node Hash Section --
+
+    // -- Begin Export Section --
+    let mut section = GenericSection::default();
+    write_export_generated_node(&project_config.typegen_config, &mut section, "node", None)?;
+    content_sections.push(ContentSection::Generic(section));
+    // -- End Export Section --
+
+    content_sections.into_signed_bytes()
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn generate_split_operation(
     config: &Config,
@@ -899,7 +1118,9 @@ fn write_variable_value_with_type(
     value: &str,
 ) -> FmtResult {
     match language {
-        TypegenLanguage::JavaScript => writeln!(section, "var {variable_name} = {value};"),
+        TypegenLanguage::JavaScript | TypegenLanguage::ReScript => {
+            writeln!(section, "var {variable_name} = {value};")
+        }
         TypegenLanguage::Flow => {
             writeln!(section, "var {variable_name}/*: {type_}*/ = {value};")
         }
@@ -917,7 +1138,7 @@ fn generate_disable_lint_section(language: &TypegenLanguage) -> Result<GenericSe
             writeln!(section, "/* eslint-disable */")?;
             writeln!(section, "// @ts-nocheck")?;
         }
-        TypegenLanguage::Flow | TypegenLanguage::JavaScript => {
+        TypegenLanguage::Flow | TypegenLanguage::JavaScript | TypegenLanguage::ReScript => {
             writeln!(section, "/* eslint-disable */")?;
         }
     }
@@ -928,7 +1149,7 @@ fn generate_use_strict_section(language: &TypegenLanguage) -> Result<GenericSect
     let mut section = GenericSection::default();
     match language {
         TypegenLanguage::TypeScript => {}
-        TypegenLanguage::Flow | TypegenLanguage::JavaScript => {
+        TypegenLanguage::Flow | TypegenLanguage::JavaScript | TypegenLanguage::ReScript => {
             writeln!(section, "'use strict';")?;
         }
     }
@@ -943,7 +1164,7 @@ fn write_import_type_from(
 ) -> FmtResult {
     let language = &project_config.typegen_config.language;
     match language {
-        TypegenLanguage::JavaScript => Ok(()),
+        TypegenLanguage::JavaScript | TypegenLanguage::ReScript => Ok(()),
         TypegenLanguage::Flow => writeln!(section, "import type {{ {type_} }} from '{from}';"),
         TypegenLanguage::TypeScript => writeln!(
             section,
@@ -966,9 +1187,9 @@ pub fn write_export_generated_node(
     forced_type: Option<String>,
 ) -> FmtResult {
     let export_value = match (typegen_config.language, forced_type) {
-        (TypegenLanguage::Flow, None) | (TypegenLanguage::JavaScript, _) => {
-            variable_node.to_string()
-        }
+        (TypegenLanguage::Flow, None)
+        | (TypegenLanguage::JavaScript, _)
+        | (TypegenLanguage::ReScript, _) => variable_node.to_string(),
         (TypegenLanguage::TypeScript, _) => {
             // TODO: Support force_type for TypeScript
             variable_node.to_string()
@@ -1035,7 +1256,9 @@ fn write_source_hash(
             TypegenLanguage::Flow => {
                 writeln!(section, "  (node/*:: as any*/).hash = \"{source_hash}\";")?
             }
-            TypegenLanguage::JavaScript => writeln!(section, "  node.hash = \"{source_hash}\";")?,
+            TypegenLanguage::JavaScript | TypegenLanguage::ReScript => {
+                writeln!(section, "  node.hash = \"{source_hash}\";")?
+            }
             TypegenLanguage::TypeScript => {
                 writeln!(section, "  (node as any).hash = \"{source_hash}\";")?
             }
@@ -1046,7 +1269,9 @@ fn write_source_hash(
             TypegenLanguage::Flow => {
                 writeln!(section, "(node/*:: as any*/).hash = \"{source_hash}\";")?
             }
-            TypegenLanguage::JavaScript => writeln!(section, "node.hash = \"{source_hash}\";")?,
+            TypegenLanguage::JavaScript | TypegenLanguage::ReScript => {
+                writeln!(section, "node.hash = \"{source_hash}\";")?
+            }
             TypegenLanguage::TypeScript => {
                 writeln!(section, "(node as any).hash = \"{source_hash}\";")?
             }
@@ -1163,3 +1388,358 @@ pub fn generate_resolvers_schema_module_content(
 
     content_sections.into_signed_bytes()
 }
+
+pub fn generate_operation_zod_schema(
+    config: &Config,
+    project_config: &ProjectConfig,
+    schema: &SDLSchema,
+    normalization_operation: &OperationDefinition,
+    typegen_operation: &OperationDefinition,
+    fragment_locations: &FragmentLocations,
+) -> Result<Vec<u8>, FmtError> {
+    generate_zod_schema_content(
+        config,
+        project_config,
+        normalization_operation.name.item.0,
+        &relay_typegen::generate_operation_zod_schema(
+            typegen_operation,
+            normalization_operation,
+            schema,
+            project_config,
+            fragment_locations,
+        ),
+    )
+}
+
+pub fn generate_fragment_zod_schema(
+    config: &Config,
+    project_config: &ProjectConfig,
+    schema: &SDLSchema,
+    typegen_fragment: &FragmentDefinition,
+    fragment_locations: &FragmentLocations,
+) -> Result<Vec<u8>, FmtError> {
+    generate_zod_schema_content(
+        config,
+        project_config,
+        typegen_fragment.name.item.0,
+        &relay_typegen::generate_fragment_zod_schema(
+            typegen_fragment,
+            schema,
+            project_config,
+            fragment_locations,
+        ),
+    )
+}
+
+fn generate_zod_schema_content(
+    config: &Config,
+    project_config: &ProjectConfig,
+    artifact_source_key: StringKey,
+    zod_schema: &str,
+) -> Result<Vec<u8>, FmtError> {
+    let mut content_sections = ContentSections::default();
+
+    // -- Begin Docblock Section --
+    content_sections.push(ContentSection::Docblock(generate_docblock_section(
+        config,
+        project_config,
+        vec![],
+        artifact_source_key,
+    )?));
+    // -- End Docblock Section --
+
+    // -- Begin Disable Lint Section --
+    content_sections.push(ContentSection::Generic(generate_disable_lint_section(
+        &project_config.typegen_config.language,
+    )?));
+    // -- End Disable Lint Section --
+
+    // -- Begin Use Strict Section --
+    content_sections.push(ContentSection::Generic(generate_use_strict_section(
+        &project_config.typegen_config.language,
+    )?));
+    // -- End Use Strict Section --
+
+    // -- Begin Zod Schema Section --
+    let mut section = GenericSection::default();
+    write!(section, "{zod_schema}")?;
+    content_sections.push(ContentSection::Generic(section));
+    // -- End Zod Schema Section --
+
+    content_sections.into_signed_bytes()
+}
+
+pub fn generate_operation_rescript_types(
+    config: &Config,
+    project_config: &ProjectConfig,
+    schema: &SDLSchema,
+    normalization_operation: &OperationDefinition,
+    typegen_operation: &OperationDefinition,
+    fragment_locations: &FragmentLocations,
+) -> Result<Vec<u8>, FmtError> {
+    generate_rescript_types_content(
+        config,
+        project_config,
+        normalization_operation.name.item.0,
+        &relay_typegen::generate_operation_rescript_types(
+            typegen_operation,
+            normalization_operation,
+            schema,
+            project_config,
+            fragment_locations,
+        ),
+    )
+}
+
+pub fn generate_fragment_rescript_types(
+    config: &Config,
+    project_config: &ProjectConfig,
+    schema: &SDLSchema,
+    typegen_fragment: &FragmentDefinition,
+    fragment_locations: &FragmentLocations,
+) -> Result<Vec<u8>, FmtError> {
+    generate_rescript_types_content(
+        config,
+        project_config,
+        typegen_fragment.name.item.0,
+        &relay_typegen::generate_fragment_rescript_types(
+            typegen_fragment,
+            schema,
+            project_config,
+            fragment_locations,
+        ),
+    )
+}
+
+fn generate_rescript_types_content(
+    config: &Config,
+    project_config: &ProjectConfig,
+    artifact_source_key: StringKey,
+    rescript_types: &str,
+) -> Result<Vec<u8>, FmtError> {
+    let mut content_sections = ContentSections::default();
+
+    // -- Begin Comment Annotations Section --
+    // ReScript has no docblocks, so the header and signature go into line comments.
+    let mut section = CommentAnnotationsSection::default();
+    if !config.header.is_empty() {
+        for header_line in &config.header {
+            writeln!(section, "{header_line}")?;
+        }
+        writeln!(section)?;
+    }
+    writeln!(section, "{SIGNING_TOKEN}")?;
+    content_sections.push(ContentSection::CommentAnnotations(section));
+    // -- End Comment Annotations Section --
+
+    // -- Begin Types Section --
+    let mut section = GenericSection::default();
+    write!(section, "{rescript_types}")?;
+    content_sections.push(ContentSection::Generic(section));
+    // -- End Types Section --
+
+    // -- Begin Node Section --
+    let mut section = GenericSection::default();
+    if project_config.typegen_config.eager_es_modules {
+        writeln!(
+            section,
+            "@module(\"./{artifact_source_key}.graphql\") external node: Js.Json.t = \"default\""
+        )?;
+    } else {
+        writeln!(
+            section,
+            "@module external node: Js.Json.t = \"./{artifact_source_key}.graphql\""
+        )?;
+    }
+    content_sections.push(ContentSection::Generic(section));
+    // -- End Node Section --
+
+    content_sections.into_signed_bytes()
+}
+
+pub fn generate_enum_module_content(
+    config: &Config,
+    project_config: &ProjectConfig,
+    schema: &SDLSchema,
+    enum_id: EnumID,
+) -> Result<Vec<u8>, FmtError> {
+    let enum_type = schema.enum_(enum_id);
+    let enum_name = enum_type.name.item;
+    let language = &project_config.typegen_config.language;
+    let mut content_sections = ContentSections::default();
+
+    // -- Begin Docblock Section --
+    content_sections.push(ContentSection::Docblock(generate_docblock_section(
+        config,
+        project_config,
+        vec![],
+        enum_name.0,
+    )?));
+    // -- End Docblock Section --
+
+    // -- Begin Disable Lint Section --
+    content_sections.push(ContentSection::Generic(generate_disable_lint_section(
+        language,
+    )?));
+    // -- End Disable Lint Section --
+
+    // -- Begin Use Strict Section --
+    content_sections.push(ContentSection::Generic(generate_use_strict_section(
+        language,
+    )?));
+    // -- End Use Strict Section --
+
+    // -- Begin Types Section --
+    let mut section = GenericSection::default();
+    if *language == TypegenLanguage::Flow {
+        writeln!(section, "/*::")?;
+    }
+    write!(
+        section,
+        "{}",
+        relay_typegen::generate_enum_module_type_export(enum_id, schema, project_config)
+    )?;
+    if *language == TypegenLanguage::Flow {
+        writeln!(section, "*/")?;
+    }
+    content_sections.push(ContentSection::Generic(section));
+    // -- End Types Section --
+
+    // -- Begin Values Section --
+    let mut section = GenericSection::default();
+    let mut values = String::from("Object.freeze([");
+    for (index, enum_value) in enum_type.values.iter().enumerate() {
+        let separator = if index == 0 { "" } else { "," };
+        write!(values, "{separator}\n  \"{}\"", enum_value.value)?;
+    }
+    values.push_str("\n])");
+    let values_type = match language {
+        TypegenLanguage::TypeScript => format!("ReadonlyArray<{enum_name}>"),
+        _ => format!("$ReadOnlyArray<{enum_name}>"),
+    };
+    write_variable_value_with_type(language, &mut section, "values", &values_type, &values)?;
+    content_sections.push(ContentSection::Generic(section));
+    // -- End Values Section --
+
+    // -- Begin Exports Section --
+    let mut section = GenericSection::default();
+    write_export_generated_node(&project_config.typegen_config, &mut section, "values", None)?;
+    content_sections.push(ContentSection::Generic(section));
+    // -- End Exports Section --
+
+    content_sections.into_signed_bytes()
+}
+
+pub fn generate_operation_registry_content(
+    config: &Config,
+    project_config: &ProjectConfig,
+    registry_path: &Path,
+    entries: &[OperationRegistryEntry],
+) -> Result<Vec<u8>, FmtError> {
+    let language = &project_config.typegen_config.language;
+    let mut content_sections = ContentSections::default();
+
+    // -- Begin Docblock Section --
+    content_sections.push(ContentSection::Docblock(generate_docblock_section(
+        config,
+        project_config,
+        vec![],
+        StringKey::from(project_config.name),
+    )?));
+    // -- End Docblock Section --
+
+    // -- Begin Disable Lint Section --
+    content_sections.push(ContentSection::Generic(generate_disable_lint_section(
+        language,
+    )?));
+    // -- End Disable Lint Section --
+
+    // -- Begin Use Strict Section --
+    content_sections.push(ContentSection::Generic(generate_use_strict_section(
+        language,
+    )?));
+    // -- End Use Strict Section --
+
+    // -- Begin Registry Section --
+    let mut section = GenericSection::default();
+    let import = |artifact_path: &Path| {
+        let import_path = project_config.js_module_import_identifier(
+            &config.root_dir.join(registry_path),
+            &config.root_dir.join(artifact_path),
+        );
+        // Like other imports of generated files, CommonJS imports are
+        // relative paths without the file extension.
+        let import_path = match project_config.js_module_format {
+            JsModuleFormat::CommonJS => {
+                let path = Path::new(import_path.lookup()).with_extension("");
+                let path = path.to_string_lossy();
+                if path.starts_with("../") {
+                    path.into_owned()
+                } else {
+                    format!("./{path}")
+                }
+            }
+            JsModuleFormat::Haste => import_path.to_string(),
+        };
+        format!("() => import('{import_path}')")
+    };
+    let mut by_id = entries.iter().collect::<Vec<_>>();
+    by_id.sort_by(|a, b| a.id.cmp(&b.id));
+    let declaration = match language {
+        TypegenLanguage::TypeScript => "const",
+        TypegenLanguage::Flow | TypegenLanguage::JavaScript | TypegenLanguage::ReScript => "var",
+    };
+    writeln!(section, "{declaration} registry = {{")?;
+    writeln!(section, "  \"byId\": {{")?;
+    for (index, entry) in by_id.iter().enumerate() {
+        let separator = if index + 1 == by_id.len() { "" } else { "," };
+        writeln!(section, "    \"{}\": {{", entry.id)?;
+        writeln!(section, "      \"name\": \"{}\",", entry.name)?;
+        writeln!(
+            section,
+            "      \"parameters\": {},",
+            import(&entry.parameters_path)
+        )?;
+        writeln!(
+            section,
+            "      \"operation\": {}",
+            import(&entry.operation_path)
+        )?;
+        writeln!(section, "    }}{separator}")?;
+    }
+    writeln!(section, "  }},")?;
+    writeln!(section, "  \"byName\": {{")?;
+    for (index, entry) in entries.iter().enumerate() {
+        let separator = if index + 1 == entries.len() { "" } else { "," };
+        writeln!(section, "    \"{}\": {{", entry.name)?;
+        writeln!(section, "      \"id\": \"{}\",", entry.id)?;
+        writeln!(
+            section,
+            "      \"parameters\": {},",
+            import(&entry.parameters_path)
+        )?;
+        writeln!(
+            section,
+            "      \"operation\": {}",
+            import(&entry.operation_path)
+        )?;
+        writeln!(section, "    }}{separator}")?;
+    }
+    writeln!(section, "  }}")?;
+    writeln!(section, "}};")?;
+    content_sections.push(ContentSection::Generic(section));
+    // -- End Registry Section --
+
+    // -- Begin Exports Section --
+    let mut section = GenericSection::default();
+    write_export_generated_node(
+        &project_config.typegen_config,
+        &mut section,
+        "registry",
+        None,
+    )?;
+    content_sections.push(ContentSection::Generic(section));
+    // -- End Exports Section --
+
+    content_sections.into_signed_bytes()
+}
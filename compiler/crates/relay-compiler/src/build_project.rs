@@ -19,7 +19,10 @@ mod build_ir;
 mod build_resolvers_schema;
 pub mod build_schema;
 mod generate_artifacts;
+mod generate_enum_modules;
 pub mod generate_extra_artifacts;
+mod generate_mock_payloads;
+mod generate_operation_registry;
 pub mod get_artifacts_file_hash_map;
 mod log_program_stats;
 mod persist_operations;
@@ -45,6 +48,7 @@ use common::PerfLogEvent;
 use common::PerfLogger;
 use common::WithDiagnostics;
 use common::sync::*;
+use dashmap::DashMap;
 use dashmap::DashSet;
 use dashmap::mapref::entry::Entry;
 use dependency_analyzer::get_ir_definition_references;
@@ -56,6 +60,9 @@ pub use generate_artifacts::Artifact;
 pub use generate_artifacts::ArtifactContent;
 pub use generate_artifacts::generate_artifacts;
 pub use generate_artifacts::generate_preloadable_query_parameters_artifact;
+pub use generate_mock_payloads::generate_mock_payloads;
+pub use generate_operation_registry::OperationRegistryEntry;
+use generate_operation_registry::write_operation_registry;
 use graphql_ir::ExecutableDefinition;
 use graphql_ir::ExecutableDefinitionName;
 use graphql_ir::FragmentDefinitionNameSet;
@@ -84,6 +91,7 @@ pub use validate::AdditionalValidations;
 pub use validate::validate;
 pub use validate::validate_reader;
 
+pub(crate) use self::log_program_stats::count_selections;
 use self::log_program_stats::print_stats;
 pub use self::project_asts::ProjectAstData;
 pub use self::project_asts::ProjectAsts;
@@ -97,6 +105,9 @@ use crate::compiler_state::CompilerState;
 use crate::config::Config;
 use crate::config::ProjectConfig;
 use crate::errors::BuildProjectError;
+use crate::explain::ArtifactCause;
+use crate::explain::BuildExplainer;
+use crate::explain::describe_source_key;
 use crate::file_source::SourceControlUpdateStatus;
 use crate::graphql_asts::GraphQLAsts;
 
@@ -143,12 +154,18 @@ pub fn build_raw_program(
 ) -> Result<(Program, SourceHashes), BuildProjectError> {
     // Build a type aware IR.
     let BuildIRResult { ir, source_hashes } = log_event.time("build_ir_time", || {
-        build_ir::build_ir(project_config, project_asts, &schema, build_mode, log_event).map_err(
-            |errors| BuildProjectError::ValidationErrors {
-                errors,
-                project_name: project_config.name,
-            },
+        build_ir::build_ir(
+            project_config,
+            project_asts,
+            &schema,
+            build_mode,
+            log_event,
+            None,
         )
+        .map_err(|errors| BuildProjectError::ValidationErrors {
+            errors,
+            project_name: project_config.name,
+        })
     })?;
 
     // Turn the IR into a base Program.
@@ -168,15 +185,22 @@ fn build_raw_program_chunks(
     schema: Arc<SDLSchema>,
     log_event: &impl PerfLogEvent,
     build_mode: BuildMode,
+    explainer: Option<&BuildExplainer>,
 ) -> Result<(Vec<Program>, SourceHashes), BuildProjectError> {
     // Build a type aware IR.
     let BuildIRResult { ir, source_hashes } = log_event.time("build_ir_time", || {
-        build_ir::build_ir(project_config, project_asts, &schema, build_mode, log_event).map_err(
-            |errors| BuildProjectError::ValidationErrors {
-                errors,
-                project_name: project_config.name,
-            },
+        build_ir::build_ir(
+            project_config,
+            project_asts,
+            &schema,
+            build_mode,
+            log_event,
+            explainer,
         )
+        .map_err(|errors| BuildProjectError::ValidationErrors {
+            errors,
+            project_name: project_config.name,
+        })
     })?;
 
     let chunks = if ir.len() < MIN_CHUNK_SIZE {
@@ -319,6 +343,7 @@ pub fn build_programs(
 ) -> Result<BuildProgramsOutput, BuildProjectFailure> {
     let schema_change_safety_timer = log_event.start("schema_change_safety_time");
     let project_name = project_config.name;
+    let mut full_build_reason = "initial build";
     let mut build_mode = if !compiler_state.has_processed_changes() {
         BuildMode::Full
     } else {
@@ -329,7 +354,10 @@ pub fn build_programs(
             Some(&schema),
         );
         match project_schema_change {
-            SchemaChangeSafety::Unsafe => BuildMode::Full,
+            SchemaChangeSafety::Unsafe => {
+                full_build_reason = "unsafe schema change";
+                BuildMode::Full
+            }
             SchemaChangeSafety::Safe | SchemaChangeSafety::SafeWithIncrementalBuild(_) => {
                 let base_schema_change = if let Some(base) = project_config.base {
                     // When the base project shares the same pending schema
@@ -353,8 +381,14 @@ pub fn build_programs(
                     SchemaChangeSafety::Safe
                 };
                 match (project_schema_change, base_schema_change) {
-                    (SchemaChangeSafety::Unsafe, _) => BuildMode::Full,
-                    (_, SchemaChangeSafety::Unsafe) => BuildMode::Full,
+                    (SchemaChangeSafety::Unsafe, _) => {
+                        full_build_reason = "unsafe schema change";
+                        BuildMode::Full
+                    }
+                    (_, SchemaChangeSafety::Unsafe) => {
+                        full_build_reason = "unsafe schema change in the base project";
+                        BuildMode::Full
+                    }
                     (SchemaChangeSafety::Safe, SchemaChangeSafety::Safe) => BuildMode::Incremental,
                     (SchemaChangeSafety::SafeWithIncrementalBuild(c), SchemaChangeSafety::Safe) => {
                         BuildMode::IncrementalWithSchemaChanges(c)
@@ -376,6 +410,7 @@ pub fn build_programs(
     if !config.has_schema_change_incremental_build {
         // Killswitch here to bail out of schema based incremental builds
         build_mode = if let BuildMode::IncrementalWithSchemaChanges(_) = build_mode {
+            full_build_reason = "schema change, with incremental schema builds disabled";
             BuildMode::Full
         } else {
             build_mode
@@ -398,8 +433,18 @@ pub fn build_programs(
             }
         },
     );
-    let (programs, source_hashes) =
-        build_raw_program_chunks(project_config, project_asts, schema, log_event, build_mode)?;
+    let explainer = config.build_explainer.as_ref();
+    if let (Some(explainer), BuildMode::Full) = (explainer, &build_mode) {
+        explainer.record_full_build(project_name, full_build_reason);
+    }
+    let (programs, source_hashes) = build_raw_program_chunks(
+        project_config,
+        project_asts,
+        schema,
+        log_event,
+        build_mode,
+        explainer,
+    )?;
 
     if compiler_state.should_cancel_current_build() {
         debug!("Build is cancelled: updates in source code/or new file changes are pending.");
@@ -696,6 +741,11 @@ pub async fn commit_project(
         None => None,
     };
 
+    let explainer = config.build_explainer.as_ref();
+    if let Some(explainer) = explainer {
+        explainer.record_regenerated(project_config.name, &artifacts);
+    }
+
     // Write the generated artifacts to disk. This step is separate from
     // generating artifacts or persisting to avoid partial writes in case of
     // errors as much as possible.
@@ -727,6 +777,13 @@ pub async fn commit_project(
                 if should_stop_updating_artifacts() {
                     break;
                 }
+                if let Some(explainer) = explainer {
+                    explainer.record_deleted(
+                        project_config.name,
+                        remaining_artifact,
+                        ArtifactCause::Stale,
+                    );
+                }
                 let path = config.root_dir.join(remaining_artifact);
                 config.artifact_writer.remove(path)?;
             }
@@ -755,10 +812,23 @@ pub async fn commit_project(
             });
             log_event.stop(write_artifacts_incremental_time);
 
+            // Why each artifact is removed, recorded when explaining the build
+            let removal_causes: DashMap<PathBuf, ArtifactCause, FnvBuildHasher> =
+                Default::default();
             log_event.time("update_artifact_map_time", || {
                 // All generated paths for removed definitions should be removed
                 for name in &removed_artifact_sources {
                     if let Some((_, artifacts)) = artifact_map.0.remove(name) {
+                        if explainer.is_some() {
+                            for artifact in &artifacts {
+                                removal_causes.insert(
+                                    artifact.path.clone(),
+                                    ArtifactCause::RemovedSource {
+                                        source: describe_source_key(name),
+                                    },
+                                );
+                            }
+                        }
                         artifacts_to_remove.extend(artifacts.into_iter().map(|a| a.path));
                     }
                 }
@@ -769,12 +839,21 @@ pub async fn commit_project(
                         .entry(definition_name)
                     {
                         Entry::Occupied(mut entry) => {
+                            let definition = explainer.map(|_| describe_source_key(entry.key()));
                             let prev_records = entry.get_mut();
                             let current_records_paths =
                                 FnvHashSet::from_iter(artifact_records.iter().map(|r| &r.path));
 
                             for prev_record in prev_records.drain(..) {
                                 if !current_records_paths.contains(&prev_record.path) {
+                                    if let Some(definition) = &definition {
+                                        removal_causes.insert(
+                                            prev_record.path.clone(),
+                                            ArtifactCause::NoLongerGenerated {
+                                                definition: definition.clone(),
+                                            },
+                                        );
+                                    }
                                     artifacts_to_remove.insert(prev_record.path);
                                 }
                             }
@@ -801,6 +880,12 @@ pub async fn commit_project(
                 if should_stop_updating_artifacts() {
                     break;
                 }
+                if let Some(explainer) = explainer {
+                    let cause = removal_causes
+                        .remove(&path)
+                        .map_or(ArtifactCause::Stale, |(_, cause)| cause);
+                    explainer.record_deleted(project_config.name, &path, cause);
+                }
                 config.artifact_writer.remove(config.root_dir.join(path))?;
             }
             log_event.stop(delete_artifacts_incremental_time);
@@ -809,6 +894,10 @@ pub async fn commit_project(
         }
     };
 
+    log_event.time("write_operation_registry_time", || {
+        write_operation_registry(config, project_config, &next_artifact_map)
+    })?;
+
     if source_control_update_status.is_started() {
         log_event.number("update_artifacts_after_source_control_update", 1);
         debug!(
@@ -24,20 +24,35 @@ pub struct ArtifactGeneratedTypes {
 }
 
 impl ArtifactGeneratedTypes {
+    /// `is_lazy` is set for the queries whose normalization AST is loaded
+    /// lazily, which are `LazyConcreteRequest`s until it is loaded.
     pub fn from_operation(
         operation: &OperationDefinition,
         skip_types: bool,
         is_client_only: bool,
+        is_lazy: bool,
         language: TypegenLanguage,
     ) -> Self {
         if skip_types || language == TypegenLanguage::TypeScript {
+            let ast_type = if is_lazy {
+                "LazyConcreteRequest"
+            } else {
+                "ConcreteRequest"
+            };
             Self {
-                imported_types: "ConcreteRequest",
-                ast_type: "ConcreteRequest",
+                imported_types: ast_type,
+                ast_type,
                 exported_type: None,
             }
         } else {
             let (kind, imported_types) = match operation.kind {
+                OperationKind::Query if is_lazy => {
+                    if is_client_only {
+                        ("ClientQuery", "LazyConcreteRequest, ClientQuery")
+                    } else {
+                        ("Query", "LazyConcreteRequest, Query")
+                    }
+                }
                 OperationKind::Query => {
                     if is_client_only {
                         ("ClientQuery", "ClientRequest, ClientQuery")
@@ -72,7 +87,9 @@ impl ArtifactGeneratedTypes {
             };
             Self {
                 imported_types,
-                ast_type: if is_client_only {
+                ast_type: if is_lazy {
+                    "LazyConcreteRequest"
+                } else if is_client_only {
                     "ClientRequest"
                 } else {
                     "ConcreteRequest"
@@ -11,6 +11,7 @@ use common::Diagnostic;
 use common::PerfLogEvent;
 use common::sync::ParallelIterator;
 use dependency_analyzer::get_reachable_ir;
+use dependency_analyzer::get_reachable_ir_with_reasons;
 use fnv::FnvHashMap;
 use fnv::FnvHashSet;
 use graphql_ir::ExecutableDefinitionName;
@@ -28,6 +29,7 @@ use schema::SDLSchema;
 use super::BuildMode;
 use super::ProjectAsts;
 use crate::config::ProjectConfig;
+use crate::explain::BuildExplainer;
 
 pub struct BuildIRResult {
     pub ir: Vec<graphql_ir::ExecutableDefinition>,
@@ -77,6 +79,7 @@ pub fn build_ir(
     schema: &SDLSchema,
     build_mode: BuildMode,
     log_event: &impl PerfLogEvent,
+    explainer: Option<&BuildExplainer>,
 ) -> Result<BuildIRResult, Vec<Diagnostic>> {
     let asts = project_asts.definitions;
     let is_full_build = matches!(build_mode, BuildMode::Full);
@@ -85,24 +88,48 @@ pub fn build_ir(
     if project_config.resolvers_schema_module.is_some() {
         ir = annotate_resolver_root_fragments(schema, ir);
     }
-    let affected_ir: Vec<graphql_ir::ExecutableDefinition> = match build_mode {
-        BuildMode::Incremental => get_reachable_ir(
-            ir,
-            project_asts.base_definition_names,
-            project_asts.changed_names,
-            schema,
-            HashSet::default(),
-            log_event,
-        ),
-        BuildMode::IncrementalWithSchemaChanges(changes) => get_reachable_ir(
+    let schema_changes = match build_mode {
+        BuildMode::Incremental => Some(HashSet::default()),
+        BuildMode::IncrementalWithSchemaChanges(changes) => Some(changes),
+        BuildMode::Full => None,
+    };
+    let affected_ir: Vec<graphql_ir::ExecutableDefinition> = match (schema_changes, explainer) {
+        (None, _) => ir,
+        (Some(schema_changes), None) => get_reachable_ir(
             ir,
             project_asts.base_definition_names,
             project_asts.changed_names,
             schema,
-            changes,
+            schema_changes,
             log_event,
         ),
-        BuildMode::Full => ir,
+        (Some(schema_changes), Some(explainer)) => {
+            let (affected_ir, reasons) = get_reachable_ir_with_reasons(
+                ir,
+                project_asts.base_definition_names,
+                project_asts.changed_names,
+                schema,
+                schema_changes,
+                log_event,
+            );
+            let origins: FnvHashSet<StringKey> = reasons
+                .values()
+                .map(|reason| reason.origin.into())
+                .collect();
+            let files = asts
+                .iter()
+                .filter_map(|ast| {
+                    let name = ast.name().filter(|name| origins.contains(name))?;
+                    let key = match ast {
+                        ExecutableDefinition::Operation(_) => OperationDefinitionName(name).into(),
+                        ExecutableDefinition::Fragment(_) => FragmentDefinitionName(name).into(),
+                    };
+                    Some((key, ast.location().source_location().path().to_string()))
+                })
+                .collect();
+            explainer.record_incremental_build(project_config.name, reasons, &files);
+            affected_ir
+        }
     };
 
     let source_hashes = if is_full_build {
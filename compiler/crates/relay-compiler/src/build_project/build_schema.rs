@@ -311,10 +311,17 @@ fn maybe_validate_schema(
         return Ok(());
     }
 
+    let strict = project_config.feature_flags.enable_strict_schema_validation;
     validate(
         schema,
         SchemaValidationOptions {
             allow_introspection_names: true,
+            validate_directive_applications: strict,
+            // Directives that Relay interprets without defining them.
+            implicit_directives: [project_config.schema_config.unselectable_directive_name]
+                .into_iter()
+                .collect(),
+            validate_input_values: strict,
         },
     )
 }
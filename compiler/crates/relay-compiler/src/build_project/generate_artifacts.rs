@@ -10,10 +10,16 @@ use std::sync::Arc;
 
 use common::NamedItem;
 use common::SourceLocationKey;
+use dependency_analyzer::ExecutableDefinitionNameMap;
+use dependency_analyzer::ExecutableDefinitionNameSet;
+use dependency_analyzer::get_program_definition_references;
 use fnv::FnvHashMap;
+use graphql_ir::ExecutableDefinitionName;
 use graphql_ir::FragmentDefinition;
+use graphql_ir::FragmentDefinitionNameSet;
 use graphql_ir::OperationDefinition;
 use graphql_ir::OperationDefinitionName;
+use graphql_syntax::OperationKind;
 use graphql_text_printer::PrinterOptions;
 use graphql_text_printer::compute_operation_text;
 use graphql_text_printer::precompute_fragment_texts;
@@ -22,17 +28,24 @@ use intern::string_key::StringKey;
 use rayon::prelude::*;
 use relay_codegen::QueryID;
 use relay_config::ResolversSchemaModuleConfig;
+use relay_config::TypegenLanguage;
+use relay_transforms::ASSIGNABLE_DIRECTIVE;
 use relay_transforms::ArtifactSourceKeyData;
 use relay_transforms::ClientEdgeGeneratedQueryMetadataDirective;
 use relay_transforms::PrefetchablePaginationEdgesFragmentMetadata;
 use relay_transforms::Programs;
 use relay_transforms::RawResponseGenerationMode;
 use relay_transforms::RefetchableDerivedFromMetadata;
+use relay_transforms::SharedNormalizationSelectionsMetadata;
 use relay_transforms::SplitOperationMetadata;
 use relay_transforms::UPDATABLE_DIRECTIVE;
+use relay_transforms::get_normalization_operation_name;
+use relay_transforms::is_operation_preloadable;
 
 pub use super::artifact_content::ArtifactContent;
 use super::build_ir::SourceHashes;
+use super::generate_enum_modules::generate_enum_module_artifacts;
+use super::generate_mock_payloads::generate_mock_payload_artifacts;
 use crate::artifact_map::ArtifactSourceKey;
 use crate::config::ProjectConfig;
 
@@ -81,7 +94,14 @@ pub fn generate_artifacts(
             )
         })
         .collect();
-    group_operations(programs).into_values().map(|operations| {
+    // The fragment references are only needed to find the shared normalization
+    // selections of the operations
+    let definition_references = if programs.shared_normalization_selections.is_empty() {
+        Default::default()
+    } else {
+        get_program_definition_references(&programs.operation_text)
+    };
+    let mut artifacts: Vec<Artifact> = group_operations(programs).into_values().map(|operations| {
             if let Some(normalization) = operations.normalization {
                 // We have a normalization AST... so we'll move forward with that
                 if let Some(metadata) = SplitOperationMetadata::find(&normalization.directives)
@@ -124,6 +144,8 @@ pub fn generate_artifacts(
 
                     return generate_normalization_artifact(
                         &mut op_texts,
+                        programs,
+                        &definition_references,
                         ArtifactSourceKey::ExecutableDefinition(source_name.into()),
                         project_config,
                         &operations,
@@ -136,6 +158,8 @@ pub fn generate_artifacts(
                     let source_hash = source_hashes.get(&source_name).cloned().unwrap();
                     return generate_normalization_artifact(
                         &mut op_texts,
+                        programs,
+                        &definition_references,
                         ArtifactSourceKey::ExecutableDefinition(source_name),
                         project_config,
                         &operations,
@@ -148,6 +172,8 @@ pub fn generate_artifacts(
                         .unwrap();
                     return generate_normalization_artifact(
                         &mut op_texts,
+                        programs,
+                        &definition_references,
                         ArtifactSourceKey::ExecutableDefinition(normalization.name.item.into()),
                         project_config,
                         &operations,
@@ -226,11 +252,246 @@ pub fn generate_artifacts(
                 _ => vec![],
             }
         )
+        .chain(generate_mock_payload_artifacts(project_config, programs))
+        .collect();
+    let shared_normalization_selections_artifacts =
+        generate_shared_normalization_selections_artifacts(
+            project_config,
+            programs,
+            &source_hashes,
+            &artifacts,
+        );
+    artifacts.extend(shared_normalization_selections_artifacts);
+
+    if project_config.lazy_normalization.is_some() {
+        let lazy_normalization_artifacts =
+            generate_lazy_normalization_artifacts(project_config, &artifacts);
+        artifacts.extend(lazy_normalization_artifacts);
+    }
+    if project_config.typegen_config.zod.is_some() {
+        let zod_schema_artifacts = generate_zod_schema_artifacts(&artifacts);
+        artifacts.extend(zod_schema_artifacts);
+    }
+    if project_config.typegen_config.language == TypegenLanguage::ReScript {
+        let rescript_types_artifacts = generate_rescript_types_artifacts(&artifacts);
+        artifacts.extend(rescript_types_artifacts);
+    }
+    let enum_module_artifacts =
+        generate_enum_module_artifacts(project_config, &programs.source.schema, &artifacts);
+    artifacts.extend(enum_module_artifacts);
+    artifacts
+}
+
+/// Generates a `<Fragment>$normalizationSelections.graphql` artifact for each
+/// fragment whose shared normalization selections are imported by an operation
+/// artifact.
+fn generate_shared_normalization_selections_artifacts(
+    project_config: &ProjectConfig,
+    programs: &Programs,
+    source_hashes: &SourceHashes,
+    artifacts: &[Artifact],
+) -> Vec<Artifact> {
+    let mut fragment_names = FragmentDefinitionNameSet::default();
+    for artifact in artifacts {
+        if let ArtifactContent::Operation {
+            shared_normalization_selections,
+            ..
+        } = &artifact.content
+        {
+            fragment_names.extend(
+                shared_normalization_selections
+                    .iter()
+                    .filter_map(|operation| {
+                        SharedNormalizationSelectionsMetadata::find(&operation.directives)
+                            .map(|metadata| metadata.fragment_name)
+                    }),
+            );
+        }
+    }
+    fragment_names
+        .into_iter()
+        .map(|fragment_name| {
+            let operation = &programs.shared_normalization_selections[&fragment_name];
+            Artifact {
+                artifact_source_keys: vec![ArtifactSourceKey::ExecutableDefinition(
+                    fragment_name.into(),
+                )],
+                path: project_config.artifact_path_for_definition(operation.name),
+                content: ArtifactContent::SharedNormalizationSelections {
+                    normalization_operation: Arc::clone(operation),
+                    fragment_name,
+                    source_hash: source_hashes.get(&fragment_name.into()).cloned(),
+                },
+                source_file: operation.name.location.source_location(),
+            }
+        })
+        .collect()
+}
+
+/// Returns the shared normalization selections of the fragments the operation
+/// spreads, directly or transitively, if the feature is enabled for it.
+fn get_shared_normalization_selections(
+    project_config: &ProjectConfig,
+    programs: &Programs,
+    definition_references: &ExecutableDefinitionNameMap<ExecutableDefinitionNameSet>,
+    operation_name: OperationDefinitionName,
+) -> Vec<Arc<OperationDefinition>> {
+    if !project_config
+        .feature_flags
+        .share_normalization_selections
+        .is_enabled_for(operation_name.0)
+    {
+        return vec![];
+    }
+    let mut reachable = ExecutableDefinitionNameSet::default();
+    let mut stack: Vec<_> = definition_references
+        .get(&operation_name.into())
+        .into_iter()
+        .flatten()
+        .copied()
+        .collect();
+    while let Some(name) = stack.pop() {
+        if reachable.insert(name) {
+            stack.extend(definition_references.get(&name).into_iter().flatten());
+        }
+    }
+    let mut shared_normalization_selections: Vec<_> = reachable
+        .into_iter()
+        .filter_map(|name| match name {
+            ExecutableDefinitionName::FragmentDefinitionName(fragment_name) => programs
+                .shared_normalization_selections
+                .get(&fragment_name)
+                .map(Arc::clone),
+            ExecutableDefinitionName::OperationDefinitionName(_) => None,
+        })
+        .collect();
+    shared_normalization_selections.sort_by_key(|operation| operation.name.item);
+    shared_normalization_selections
+}
+
+/// Generates a `<Name>$normalization.graphql` artifact with the normalization
+/// AST of each operation artifact that loads it lazily.
+fn generate_lazy_normalization_artifacts(
+    project_config: &ProjectConfig,
+    artifacts: &[Artifact],
+) -> Vec<Artifact> {
+    artifacts
+        .iter()
+        .filter_map(|artifact| match &artifact.content {
+            ArtifactContent::Operation {
+                normalization_operation,
+                source_hash,
+                shared_normalization_selections,
+                lazy_normalization_provider: Some(_),
+                ..
+            } => Some(Artifact {
+                artifact_source_keys: artifact.artifact_source_keys.clone(),
+                path: project_config.path_for_language_specific_artifact(
+                    artifact.source_file,
+                    format!(
+                        "{}.graphql",
+                        get_normalization_operation_name(normalization_operation.name.item.0)
+                    ),
+                ),
+                content: ArtifactContent::LazyNormalizationOperation {
+                    normalization_operation: Arc::clone(normalization_operation),
+                    shared_normalization_selections: shared_normalization_selections.clone(),
+                    source_hash: source_hash.clone(),
+                },
+                source_file: artifact.source_file,
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Generates a `<Name>.graphql.zod.{ts,js}` artifact next to each operation and
+/// (non-assignable) fragment artifact.
+fn generate_zod_schema_artifacts(artifacts: &[Artifact]) -> Vec<Artifact> {
+    artifacts
+        .iter()
+        .filter_map(|artifact| {
+            let content = match &artifact.content {
+                ArtifactContent::Operation {
+                    normalization_operation,
+                    typegen_operation,
+                    ..
+                } => ArtifactContent::OperationZodSchema {
+                    normalization_operation: Arc::clone(normalization_operation),
+                    typegen_operation: Arc::clone(typegen_operation),
+                },
+                ArtifactContent::Fragment {
+                    typegen_fragment, ..
+                } if typegen_fragment
+                    .directives
+                    .named(*ASSIGNABLE_DIRECTIVE)
+                    .is_none() =>
+                {
+                    ArtifactContent::FragmentZodSchema {
+                        typegen_fragment: Arc::clone(typegen_fragment),
+                    }
+                }
+                _ => return None,
+            };
+            let extension = artifact.path.extension()?.to_str()?;
+            Some(Artifact {
+                artifact_source_keys: artifact.artifact_source_keys.clone(),
+                path: artifact.path.with_extension(format!("zod.{extension}")),
+                content,
+                source_file: artifact.source_file,
+            })
+        })
+        .collect()
+}
+
+/// Generates a `<Name>_graphql.res` module with the ReScript types next to each
+/// operation and (non-assignable) fragment artifact.
+fn generate_rescript_types_artifacts(artifacts: &[Artifact]) -> Vec<Artifact> {
+    artifacts
+        .iter()
+        .filter_map(|artifact| {
+            let (name, content) = match &artifact.content {
+                ArtifactContent::Operation {
+                    normalization_operation,
+                    typegen_operation,
+                    ..
+                } => (
+                    normalization_operation.name.item.0,
+                    ArtifactContent::OperationReScriptTypes {
+                        normalization_operation: Arc::clone(normalization_operation),
+                        typegen_operation: Arc::clone(typegen_operation),
+                    },
+                ),
+                ArtifactContent::Fragment {
+                    typegen_fragment, ..
+                } if typegen_fragment
+                    .directives
+                    .named(*ASSIGNABLE_DIRECTIVE)
+                    .is_none() =>
+                {
+                    (
+                        typegen_fragment.name.item.0,
+                        ArtifactContent::FragmentReScriptTypes {
+                            typegen_fragment: Arc::clone(typegen_fragment),
+                        },
+                    )
+                }
+                _ => return None,
+            };
+            Some(Artifact {
+                artifact_source_keys: artifact.artifact_source_keys.clone(),
+                path: artifact.path.with_file_name(format!("{name}_graphql.res")),
+                content,
+                source_file: artifact.source_file,
+            })
+        })
         .collect()
 }
 
 fn generate_normalization_artifact(
     op_texts: &mut FnvHashMap<OperationDefinitionName, String>,
+    programs: &Programs,
+    definition_references: &ExecutableDefinitionNameMap<ExecutableDefinitionNameSet>,
     artifact_source: ArtifactSourceKey,
     project_config: &ProjectConfig,
     operations: &OperationGroup<'_>,
@@ -243,6 +504,24 @@ fn generate_normalization_artifact(
     let normalization = operations
         .normalization
         .expect("Operations must have a normalization entry.");
+    let shared_normalization_selections = get_shared_normalization_selections(
+        project_config,
+        programs,
+        definition_references,
+        normalization.name.item,
+    );
+    // Refetch and client edge queries are read synchronously by the fragment
+    // hooks, and `@preloadable` queries are loaded through the
+    // `PreloadableQueryRegistry`
+    let lazy_normalization_provider = if normalization.kind == OperationKind::Query
+        && RefetchableDerivedFromMetadata::find(&normalization.directives).is_none()
+        && ClientEdgeGeneratedQueryMetadataDirective::find(&normalization.directives).is_none()
+        && !is_operation_preloadable(normalization)
+    {
+        project_config.lazy_normalization_provider()
+    } else {
+        None
+    };
 
     Artifact {
         artifact_source_keys: vec![artifact_source],
@@ -254,6 +533,8 @@ fn generate_normalization_artifact(
             source_hash,
             text,
             id_and_text_hash: None,
+            shared_normalization_selections,
+            lazy_normalization_provider,
         },
         source_file: normalization.name.location.source_location(),
     }
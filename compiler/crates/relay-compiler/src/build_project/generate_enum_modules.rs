@@ -0,0 +1,174 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Shared modules for the schema enums used by the generated types.
+//!
+//! When `typegenConfig.generateEnumModules` is set, the types of artifacts
+//! import the enums from `<Enum><enumModuleSuffix>` modules instead of
+//! defining them inline, and the compiler writes one such module per enum
+//! into the artifact directory. Each module is attributed to the definitions
+//! whose types use the enum, so it is removed together with the last of them.
+
+use common::SourceLocationKey;
+use fnv::FnvHashSet;
+use graphql_ir::FragmentDefinition;
+use graphql_ir::OperationDefinition;
+use graphql_ir::ScalarField;
+use graphql_ir::VariableDefinition;
+use graphql_ir::Visitor;
+use indexmap::IndexMap;
+use relay_config::TypegenLanguage;
+use relay_typegen::has_raw_response_type_directive;
+use schema::EnumID;
+use schema::InputObjectID;
+use schema::SDLSchema;
+use schema::Schema;
+use schema::Type;
+
+use super::Artifact;
+use super::ArtifactContent;
+use crate::artifact_map::ArtifactSourceKey;
+use crate::config::ProjectConfig;
+
+/// Generate a `<Enum><suffix>` module for each server enum used by the types
+/// of `artifacts`, if the project generates its enum modules.
+pub(crate) fn generate_enum_module_artifacts(
+    project_config: &ProjectConfig,
+    schema: &SDLSchema,
+    artifacts: &[Artifact],
+) -> Vec<Artifact> {
+    let typegen_config = &project_config.typegen_config;
+    let (Some(suffix), Some(output)) = (&typegen_config.enum_module_suffix, &project_config.output)
+    else {
+        return vec![];
+    };
+    if !typegen_config.generate_enum_modules {
+        return vec![];
+    }
+    let extension = match typegen_config.language {
+        TypegenLanguage::TypeScript => "ts",
+        TypegenLanguage::Flow | TypegenLanguage::JavaScript | TypegenLanguage::ReScript => "js",
+    };
+
+    let mut enum_source_keys: IndexMap<EnumID, Vec<ArtifactSourceKey>> = IndexMap::new();
+    for artifact in artifacts {
+        let mut collector = EnumCollector::new(schema);
+        match &artifact.content {
+            ArtifactContent::Operation {
+                normalization_operation,
+                typegen_operation,
+                ..
+            } => {
+                collector.visit_operation(typegen_operation);
+                if has_raw_response_type_directive(normalization_operation) {
+                    collector.visit_operation(normalization_operation);
+                }
+            }
+            ArtifactContent::UpdatableQuery {
+                typegen_operation, ..
+            }
+            | ArtifactContent::SplitOperation {
+                typegen_operation: Some(typegen_operation),
+                ..
+            } => {
+                collector.visit_operation(typegen_operation);
+            }
+            ArtifactContent::Fragment {
+                typegen_fragment, ..
+            } => {
+                collector.visit_fragment(typegen_fragment);
+            }
+            _ => continue,
+        }
+        for enum_id in collector.enums {
+            let source_keys = enum_source_keys.entry(enum_id).or_default();
+            for source_key in &artifact.artifact_source_keys {
+                if !source_keys.contains(source_key) {
+                    source_keys.push(source_key.clone());
+                }
+            }
+        }
+    }
+
+    enum_source_keys.sort_by_cached_key(|enum_id, _| schema.enum_(*enum_id).name.item);
+    enum_source_keys
+        .into_iter()
+        .map(|(enum_id, artifact_source_keys)| Artifact {
+            artifact_source_keys,
+            path: output.join(format!(
+                "{}{}.{}",
+                schema.enum_(enum_id).name.item,
+                suffix,
+                extension
+            )),
+            content: ArtifactContent::EnumModule { enum_id },
+            source_file: SourceLocationKey::generated(),
+        })
+        .collect()
+}
+
+/// Collects the server enums that the types of a definition import: the
+/// types of its scalar fields and of its variables, including the fields of
+/// input objects.
+struct EnumCollector<'a> {
+    schema: &'a SDLSchema,
+    enums: FnvHashSet<EnumID>,
+    visited_input_objects: FnvHashSet<InputObjectID>,
+}
+
+impl<'a> EnumCollector<'a> {
+    fn new(schema: &'a SDLSchema) -> Self {
+        Self {
+            schema,
+            enums: Default::default(),
+            visited_input_objects: Default::default(),
+        }
+    }
+
+    fn collect_type(&mut self, type_: Type) {
+        match type_ {
+            Type::Enum(enum_id) if !self.schema.enum_(enum_id).is_extension => {
+                self.enums.insert(enum_id);
+            }
+            Type::InputObject(input_object_id)
+                if self.visited_input_objects.insert(input_object_id) =>
+            {
+                for field in self.schema.input_object(input_object_id).fields.iter() {
+                    self.collect_type(field.type_.inner());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn collect_variables(&mut self, variable_definitions: &[VariableDefinition]) {
+        for variable_definition in variable_definitions {
+            self.collect_type(variable_definition.type_.inner());
+        }
+    }
+}
+
+impl Visitor for EnumCollector<'_> {
+    const NAME: &'static str = "EnumCollector";
+    const VISIT_ARGUMENTS: bool = false;
+    const VISIT_DIRECTIVES: bool = false;
+
+    fn visit_operation(&mut self, operation: &OperationDefinition) {
+        self.collect_variables(&operation.variable_definitions);
+        self.default_visit_operation(operation);
+    }
+
+    fn visit_fragment(&mut self, fragment: &FragmentDefinition) {
+        self.collect_variables(&fragment.variable_definitions);
+        self.collect_variables(&fragment.used_global_variables);
+        self.default_visit_fragment(fragment);
+    }
+
+    fn visit_scalar_field(&mut self, field: &ScalarField) {
+        self.collect_type(self.schema.field(field.definition.item).type_.inner());
+    }
+}
@@ -0,0 +1,548 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Deterministic mock responses of operations, built from their
+//! normalization AST.
+//!
+//! The mock of an operation is a JSON array of payloads: the initial
+//! response, followed by one payload per `@defer` fragment and per item
+//! streamed with `@stream`. Values are picked deterministically:
+//! * `ID` fields are `<Type-mock-id-N>`, numbered in selection order;
+//! * other strings are `<mock-value-for-field-"alias">`;
+//! * `Int` is 42, `Float` is 4.2 and `Boolean` is false;
+//! * enums use their first value;
+//! * custom scalars use the value configured in `customScalarValues`, or
+//!   `null` when the field is nullable;
+//! * lists have a single item, or `initialCount` items followed by a
+//!   streamed item for `@stream`;
+//! * an abstract type is mocked as the first concrete type selected by a
+//!   type condition, or else as its first possible type by name.
+//!
+//! Client extensions and Relay Resolvers are not part of the server response
+//! and are not mocked.
+
+use std::collections::VecDeque;
+
+use common::ArgumentName;
+use common::NamedItem;
+use graphql_ir::ConditionValue;
+use graphql_ir::ConstantValue;
+use graphql_ir::Directive;
+use graphql_ir::Field as _;
+use graphql_ir::LinkedField;
+use graphql_ir::OperationDefinition;
+use graphql_ir::OperationDefinitionName;
+use graphql_ir::Program;
+use graphql_ir::ScalarField;
+use graphql_ir::Selection;
+use graphql_ir::Value as IrValue;
+use graphql_ir::VariableName;
+use intern::Lookup;
+use relay_transforms::CLIENT_EXTENSION_DIRECTIVE_NAME;
+use relay_transforms::ClientEdgeGeneratedQueryMetadataDirective;
+use relay_transforms::MATCH_CONSTANTS;
+use relay_transforms::Programs;
+use relay_transforms::RefetchableDerivedFromMetadata;
+use relay_transforms::RelayResolverMetadata;
+use relay_transforms::SplitOperationMetadata;
+use schema::FieldID;
+use schema::SDLSchema;
+use schema::Schema;
+use schema::Type;
+use schema::TypeReference;
+use serde_json::Map;
+use serde_json::Value;
+use serde_json::json;
+
+use super::Artifact;
+use super::ArtifactContent;
+use crate::artifact_map::ArtifactSourceKey;
+use crate::config::ProjectConfig;
+
+/// Generate a `<OperationName>.mock.json` artifact for each operation, if
+/// mock payloads are enabled for the project.
+pub(crate) fn generate_mock_payload_artifacts(
+    project_config: &ProjectConfig,
+    programs: &Programs,
+) -> Vec<Artifact> {
+    if project_config.mock_payloads.is_none() {
+        return vec![];
+    }
+    let program = &programs.normalization;
+    program
+        .operations()
+        .filter(|operation| SplitOperationMetadata::find(&operation.directives).is_none())
+        .map(|operation| {
+            let source_name = if let Some(derived_from_metadata) =
+                RefetchableDerivedFromMetadata::find(&operation.directives)
+            {
+                derived_from_metadata.0.into()
+            } else if let Some(client_edges_directive) =
+                ClientEdgeGeneratedQueryMetadataDirective::find(&operation.directives)
+            {
+                client_edges_directive.source_name.item
+            } else {
+                operation.name.item.into()
+            };
+            let source_file = operation.name.location.source_location();
+            let payloads = generate_mock_payloads(project_config, program, operation);
+            let mut content = serde_json::to_string_pretty(&payloads).unwrap();
+            content.push('\n');
+            Artifact {
+                artifact_source_keys: vec![ArtifactSourceKey::ExecutableDefinition(source_name)],
+                path: project_config.create_path_for_artifact(
+                    source_file,
+                    format!("{}.mock.json", operation.name.item.0),
+                ),
+                content: ArtifactContent::Generic {
+                    content: content.into_bytes(),
+                },
+                source_file,
+            }
+        })
+        .collect()
+}
+
+/// Build the mock payloads of an operation of the normalization program.
+pub fn generate_mock_payloads(
+    project_config: &ProjectConfig,
+    program: &Program,
+    operation: &OperationDefinition,
+) -> Value {
+    MockPayloadGenerator {
+        project_config,
+        program,
+        schema: &program.schema,
+        operation,
+        next_id: 0,
+        incremental: VecDeque::new(),
+    }
+    .generate()
+}
+
+struct MockPayloadGenerator<'a> {
+    project_config: &'a ProjectConfig,
+    program: &'a Program,
+    schema: &'a SDLSchema,
+    operation: &'a OperationDefinition,
+    next_id: usize,
+    /// Payloads delivered after the initial payload, in order.
+    incremental: VecDeque<IncrementalPayload<'a>>,
+}
+
+enum IncrementalPayload<'a> {
+    /// The selections of a `@defer` fragment on the object at `path`.
+    Defer {
+        label: String,
+        path: Vec<Value>,
+        type_: Type,
+        selections: &'a [Selection],
+    },
+    /// The item at `path` of a `@stream` field.
+    Stream {
+        label: String,
+        path: Vec<Value>,
+        field: &'a LinkedField,
+        item_type: &'a TypeReference<Type>,
+    },
+}
+
+impl<'a> MockPayloadGenerator<'a> {
+    fn generate(mut self) -> Value {
+        let operation = self.operation;
+        let mut data = Map::new();
+        self.mock_selections(&operation.selections, operation.type_, &[], &mut data);
+        let mut payloads = vec![json!({ "data": data })];
+
+        while let Some(payload) = self.incremental.pop_front() {
+            payloads.push(match payload {
+                IncrementalPayload::Defer {
+                    label,
+                    path,
+                    type_,
+                    selections,
+                } => {
+                    let mut data = Map::new();
+                    self.mock_selections(selections, type_, &path, &mut data);
+                    json!({ "data": data, "label": label, "path": path })
+                }
+                IncrementalPayload::Stream {
+                    label,
+                    path,
+                    field,
+                    item_type,
+                } => {
+                    let data = self.mock_linked_value(field, item_type, &path);
+                    json!({ "data": data, "label": label, "path": path })
+                }
+            });
+        }
+        Value::Array(payloads)
+    }
+
+    /// Mock the selections on an object of the concrete type `type_` into
+    /// `data`.
+    fn mock_selections(
+        &mut self,
+        selections: &'a [Selection],
+        type_: Type,
+        path: &[Value],
+        data: &mut Map<String, Value>,
+    ) {
+        let defer_stream_interface = &self.project_config.schema_config.defer_stream_interface;
+        for selection in selections {
+            match selection {
+                Selection::ScalarField(field) => {
+                    if self.is_client_field(field.definition.item, &field.directives) {
+                        continue;
+                    }
+                    let value = self.mock_scalar_field(field, type_);
+                    insert_value(data, field.alias_or_name(self.schema).to_string(), value);
+                }
+                Selection::LinkedField(field) => {
+                    if self.is_client_field(field.definition.item, &field.directives) {
+                        continue;
+                    }
+                    let key = field.alias_or_name(self.schema).to_string();
+                    let mut field_path = path.to_vec();
+                    field_path.push(Value::String(key.clone()));
+                    let field_type = &self.schema.field(field.definition.item).type_;
+                    let value = match field.directives.named(defer_stream_interface.stream_name) {
+                        Some(stream) if self.is_enabled(stream) => {
+                            self.mock_stream(field, field_type, stream, field_path)
+                        }
+                        _ => self.mock_linked_value(field, field_type, &field_path),
+                    };
+                    insert_value(data, key, value);
+                }
+                Selection::InlineFragment(fragment) => {
+                    if fragment.type_condition.is_some_and(|type_condition| {
+                        !self.schema.is_named_type_subtype_of(type_, type_condition)
+                    }) || fragment
+                        .directives
+                        .named(*CLIENT_EXTENSION_DIRECTIVE_NAME)
+                        .is_some()
+                        || RelayResolverMetadata::find(&fragment.directives).is_some()
+                    {
+                        continue;
+                    }
+                    match fragment.directives.named(defer_stream_interface.defer_name) {
+                        Some(defer) if self.is_enabled(defer) => {
+                            self.incremental.push_back(IncrementalPayload::Defer {
+                                label: self.label(defer),
+                                path: path.to_vec(),
+                                type_,
+                                selections: &fragment.selections,
+                            });
+                        }
+                        _ => self.mock_selections(&fragment.selections, type_, path, data),
+                    }
+                }
+                Selection::FragmentSpread(spread) => {
+                    // Spreads of the normalization AST reference split
+                    // operations, which are absent for base project fragments.
+                    if let Some(split_operation) = self
+                        .program
+                        .operation(OperationDefinitionName(spread.fragment.item.0))
+                    {
+                        self.mock_selections(&split_operation.selections, type_, path, data);
+                    }
+                }
+                Selection::Condition(condition) => {
+                    let value = match &condition.value {
+                        ConditionValue::Constant(value) => Some(*value),
+                        ConditionValue::Variable(variable) => self
+                            .variable_default_value(variable.name.item)
+                            .and_then(|value| match value {
+                                ConstantValue::Boolean(value) => Some(*value),
+                                _ => None,
+                            }),
+                    };
+                    // Conditions on variables without a default value are
+                    // assumed to pass, to mock as many fields as possible.
+                    if value.is_none_or(|value| value == condition.passing_value) {
+                        self.mock_selections(&condition.selections, type_, path, data);
+                    }
+                }
+            }
+        }
+    }
+
+    fn mock_scalar_field(&mut self, field: &ScalarField, parent_type: Type) -> Value {
+        if field.definition.item == self.schema.typename_field() {
+            return Value::String(self.schema.get_type_name(parent_type).to_string());
+        }
+        let definition = self.schema.field(field.definition.item);
+        if definition.name.item == MATCH_CONSTANTS.js_field_name
+            && let Some(module) = field
+                .arguments
+                .iter()
+                .find(|argument| argument.name.item == MATCH_CONSTANTS.js_field_module_arg)
+                .and_then(|argument| argument.value.item.get_string_literal())
+        {
+            return Value::String(module.to_string());
+        }
+        let key = field.alias_or_name(self.schema);
+        self.mock_scalar_value(&definition.type_, key.lookup(), parent_type, true)
+    }
+
+    fn mock_scalar_value(
+        &mut self,
+        type_: &TypeReference<Type>,
+        key: &str,
+        parent_type: Type,
+        nullable: bool,
+    ) -> Value {
+        match type_ {
+            TypeReference::NonNull(of) => self.mock_scalar_value(of, key, parent_type, false),
+            TypeReference::List(of) => {
+                Value::Array(vec![self.mock_scalar_value(of, key, parent_type, true)])
+            }
+            TypeReference::Named(Type::Enum(id)) => self
+                .schema
+                .enum_(*id)
+                .values
+                .first()
+                .map_or(Value::Null, |value| Value::String(value.value.to_string())),
+            TypeReference::Named(Type::Scalar(id)) => {
+                let name = self.schema.scalar(*id).name.item;
+                match name.0.lookup() {
+                    "ID" => {
+                        self.next_id += 1;
+                        Value::String(format!(
+                            "<{}-mock-id-{}>",
+                            self.schema.get_type_name(parent_type),
+                            self.next_id
+                        ))
+                    }
+                    "String" => mock_string(key),
+                    "Int" => json!(42),
+                    "Float" => json!(4.2),
+                    "Boolean" => Value::Bool(false),
+                    _ => match self
+                        .project_config
+                        .mock_payloads
+                        .as_ref()
+                        .and_then(|config| config.custom_scalar_values.get(&name))
+                    {
+                        Some(value) => value.clone(),
+                        None if nullable => Value::Null,
+                        None => mock_string(key),
+                    },
+                }
+            }
+            TypeReference::Named(_) => Value::Null,
+        }
+    }
+
+    fn mock_linked_value(
+        &mut self,
+        field: &'a LinkedField,
+        type_: &'a TypeReference<Type>,
+        path: &[Value],
+    ) -> Value {
+        match type_ {
+            TypeReference::NonNull(of) => self.mock_linked_value(field, of, path),
+            TypeReference::List(of) => {
+                let mut item_path = path.to_vec();
+                item_path.push(json!(0));
+                Value::Array(vec![self.mock_linked_value(field, of, &item_path)])
+            }
+            TypeReference::Named(type_) => {
+                let concrete_type = self.concrete_type(*type_, &field.selections);
+                let mut data = Map::new();
+                self.mock_selections(&field.selections, concrete_type, path, &mut data);
+                Value::Object(data)
+            }
+        }
+    }
+
+    /// Mock the initial items of a `@stream` field, and queue a payload for
+    /// the next item.
+    fn mock_stream(
+        &mut self,
+        field: &'a LinkedField,
+        type_: &'a TypeReference<Type>,
+        stream: &'a Directive,
+        path: Vec<Value>,
+    ) -> Value {
+        let Some(item_type) = type_.nullable_type().list_item_type() else {
+            return self.mock_linked_value(field, type_, &path);
+        };
+        let initial_count = match self.argument_value(
+            stream,
+            self.project_config
+                .schema_config
+                .defer_stream_interface
+                .initial_count_arg,
+        ) {
+            Some(ConstantValue::Int(count)) => (*count).max(0) as usize,
+            _ => 0,
+        };
+        let items = (0..initial_count)
+            .map(|index| {
+                let mut item_path = path.clone();
+                item_path.push(json!(index));
+                self.mock_linked_value(field, item_type, &item_path)
+            })
+            .collect();
+
+        let mut item_path = path;
+        item_path.push(json!(initial_count));
+        self.incremental.push_back(IncrementalPayload::Stream {
+            label: self.label(stream),
+            path: item_path,
+            field,
+            item_type,
+        });
+        Value::Array(items)
+    }
+
+    /// The concrete type to mock an object of `type_` as.
+    fn concrete_type(&self, type_: Type, selections: &[Selection]) -> Type {
+        let mut possible_types: Vec<Type> = match type_ {
+            Type::Interface(id) => self
+                .schema
+                .interface(id)
+                .implementing_objects
+                .iter()
+                .map(|id| Type::Object(*id))
+                .collect(),
+            Type::Union(id) => self
+                .schema
+                .union(id)
+                .members
+                .iter()
+                .map(|id| Type::Object(*id))
+                .collect(),
+            _ => return type_,
+        };
+        possible_types.retain(|possible_type| !self.schema.is_extension_type(*possible_type));
+        possible_types.sort_by_key(|possible_type| self.schema.get_type_name(*possible_type));
+
+        let mut type_conditions = vec![];
+        collect_type_conditions(selections, &mut type_conditions);
+        // Prefer concrete type conditions over abstract ones.
+        type_conditions.sort_by_key(|type_condition| !type_condition.is_object());
+        type_conditions
+            .iter()
+            .find_map(|type_condition| {
+                possible_types.iter().find(|possible_type| {
+                    self.schema
+                        .is_named_type_subtype_of(**possible_type, *type_condition)
+                })
+            })
+            .or(possible_types.first())
+            .copied()
+            .unwrap_or(type_)
+    }
+
+    fn is_client_field(&self, field_id: FieldID, directives: &[Directive]) -> bool {
+        field_id == self.schema.clientid_field()
+            || self.schema.field(field_id).is_extension
+            || RelayResolverMetadata::find(directives).is_some()
+    }
+
+    /// Whether a `@defer` or `@stream` directive applies, according to its
+    /// `if` argument.
+    fn is_enabled(&self, directive: &'a Directive) -> bool {
+        !matches!(
+            self.argument_value(
+                directive,
+                self.project_config
+                    .schema_config
+                    .defer_stream_interface
+                    .if_arg
+            ),
+            Some(ConstantValue::Boolean(false))
+        )
+    }
+
+    fn label(&self, directive: &'a Directive) -> String {
+        match self.argument_value(
+            directive,
+            self.project_config
+                .schema_config
+                .defer_stream_interface
+                .label_arg,
+        ) {
+            Some(ConstantValue::String(label)) => label.to_string(),
+            _ => String::new(),
+        }
+    }
+
+    /// The constant value of an argument of a directive, or the default value
+    /// of the variable it is set to.
+    fn argument_value(
+        &self,
+        directive: &'a Directive,
+        name: ArgumentName,
+    ) -> Option<&'a ConstantValue> {
+        match &directive.arguments.named(name)?.value.item {
+            IrValue::Constant(value) => Some(value),
+            IrValue::Variable(variable) => self.variable_default_value(variable.name.item),
+            _ => None,
+        }
+    }
+
+    fn variable_default_value(&self, name: VariableName) -> Option<&'a ConstantValue> {
+        self.operation
+            .variable_definitions
+            .iter()
+            .find(|definition| definition.name.item == name)
+            .and_then(|definition| definition.default_value.as_ref())
+            .map(|value| &value.item)
+    }
+}
+
+fn collect_type_conditions(selections: &[Selection], type_conditions: &mut Vec<Type>) {
+    for selection in selections {
+        match selection {
+            Selection::InlineFragment(fragment) => match fragment.type_condition {
+                Some(type_condition) => type_conditions.push(type_condition),
+                None => collect_type_conditions(&fragment.selections, type_conditions),
+            },
+            Selection::Condition(condition) => {
+                collect_type_conditions(&condition.selections, type_conditions)
+            }
+            Selection::ScalarField(_)
+            | Selection::LinkedField(_)
+            | Selection::FragmentSpread(_) => {}
+        }
+    }
+}
+
+fn mock_string(key: &str) -> Value {
+    Value::String(format!("<mock-value-for-field-\"{key}\">"))
+}
+
+/// Insert a field value, merging it with the value of a previous selection
+/// of the same field.
+fn insert_value(data: &mut Map<String, Value>, key: String, value: Value) {
+    match data.get_mut(&key) {
+        Some(existing) => merge_values(existing, value),
+        None => {
+            data.insert(key, value);
+        }
+    }
+}
+
+fn merge_values(existing: &mut Value, value: Value) {
+    match (existing, value) {
+        (Value::Object(existing), Value::Object(value)) => {
+            for (key, value) in value {
+                insert_value(existing, key, value);
+            }
+        }
+        (Value::Array(existing), Value::Array(value)) => {
+            for (existing, value) in existing.iter_mut().zip(value) {
+                merge_values(existing, value);
+            }
+        }
+        _ => {}
+    }
+}
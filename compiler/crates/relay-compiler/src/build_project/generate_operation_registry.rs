@@ -0,0 +1,161 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Registry of the `@preloadable` operations of a project, for servers that
+//! receive a persisted id and need to load the matching `$parameters` and
+//! operation artifacts.
+//!
+//! The registry is derived from the artifact map after every commit, so it
+//! covers all the operations of the project in incremental builds as well.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::path::PathBuf;
+
+use serde::Serialize;
+
+use super::Config;
+use super::ProjectConfig;
+use crate::artifact_content::content::generate_operation_registry_content;
+use crate::artifact_map::ArtifactMap;
+use crate::artifact_map::ArtifactSourceKey;
+use crate::errors::BuildProjectError;
+
+/// A `@preloadable` operation with its persisted id and the paths of its
+/// artifacts, relative to the root directory.
+pub struct OperationRegistryEntry {
+    pub name: String,
+    pub id: String,
+    pub parameters_path: PathBuf,
+    pub operation_path: PathBuf,
+}
+
+#[derive(Serialize)]
+struct OperationRegistryJson<'a> {
+    #[serde(rename = "byId")]
+    by_id: BTreeMap<&'a str, OperationRegistryJsonEntry<'a>>,
+    #[serde(rename = "byName")]
+    by_name: BTreeMap<&'a str, OperationRegistryJsonEntry<'a>>,
+}
+
+#[derive(Serialize)]
+struct OperationRegistryJsonEntry<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<&'a str>,
+    parameters: String,
+    operation: String,
+}
+
+/// Writes the registry module and its JSON equivalent, if the project has
+/// an `operationRegistry` configured. Files are only written when their
+/// content changed.
+pub fn write_operation_registry(
+    config: &Config,
+    project_config: &ProjectConfig,
+    artifact_map: &ArtifactMap,
+) -> Result<(), BuildProjectError> {
+    let Some(registry_config) = &project_config.operation_registry else {
+        return Ok(());
+    };
+    let entries = collect_operation_registry_entries(artifact_map);
+
+    let content = generate_operation_registry_content(
+        config,
+        project_config,
+        &registry_config.path,
+        &entries,
+    )
+    .unwrap();
+    write_if_changed(config, &registry_config.path, content)?;
+
+    let mut json = serde_json::to_string_pretty(&operation_registry_json(&entries)).unwrap();
+    json.push('\n');
+    write_if_changed(
+        config,
+        &registry_config.path.with_extension("json"),
+        json.into_bytes(),
+    )
+}
+
+/// Pairs the `$parameters` artifact of every operation with the operation
+/// artifact carrying its persisted id. Entries are sorted by name.
+fn collect_operation_registry_entries(artifact_map: &ArtifactMap) -> Vec<OperationRegistryEntry> {
+    let mut entries: Vec<_> = artifact_map
+        .0
+        .iter()
+        .filter_map(|entry| {
+            let ArtifactSourceKey::ExecutableDefinition(definition_name) = entry.key() else {
+                return None;
+            };
+            let name = definition_name.to_string();
+            let parameters_prefix = format!("{name}$parameters.");
+            let parameters = entry.value().iter().find(|record| {
+                record.path.file_name().is_some_and(|file_name| {
+                    file_name.to_string_lossy().starts_with(&parameters_prefix)
+                })
+            })?;
+            let operation = entry.value().iter().find(|record| {
+                record.path != parameters.path && record.persisted_operation_id.is_some()
+            })?;
+            Some(OperationRegistryEntry {
+                id: operation.persisted_operation_id.clone().unwrap(),
+                parameters_path: parameters.path.clone(),
+                operation_path: operation.path.clone(),
+                name,
+            })
+        })
+        .collect();
+    entries.sort_by(|a, b| a.name.cmp(&b.name));
+    entries
+}
+
+fn operation_registry_json(entries: &[OperationRegistryEntry]) -> OperationRegistryJson<'_> {
+    let mut registry = OperationRegistryJson {
+        by_id: Default::default(),
+        by_name: Default::default(),
+    };
+    for entry in entries {
+        registry.by_id.insert(
+            &entry.id,
+            OperationRegistryJsonEntry {
+                id: None,
+                name: Some(&entry.name),
+                parameters: format_normalized_path(&entry.parameters_path),
+                operation: format_normalized_path(&entry.operation_path),
+            },
+        );
+        registry.by_name.insert(
+            &entry.name,
+            OperationRegistryJsonEntry {
+                id: Some(&entry.id),
+                name: None,
+                parameters: format_normalized_path(&entry.parameters_path),
+                operation: format_normalized_path(&entry.operation_path),
+            },
+        );
+    }
+    registry
+}
+
+fn write_if_changed(
+    config: &Config,
+    path: &Path,
+    content: Vec<u8>,
+) -> Result<(), BuildProjectError> {
+    let path = config.root_dir.join(path);
+    if config.artifact_writer.should_write(&path, &content, None)? {
+        config.artifact_writer.write(path, content)?;
+    }
+    Ok(())
+}
+
+fn format_normalized_path(path: &Path) -> String {
+    path.to_string_lossy()
+        .replace(std::path::MAIN_SEPARATOR, "/")
+}
@@ -31,6 +31,16 @@ pub fn print_stats(extra_info: &str, program: &Program) {
     }
 }
 
+/// Number of selections (fields, inline fragments and conditions) in the
+/// operation, counting shared subtrees every time they appear.
+pub fn count_selections(operation: &OperationDefinition) -> usize {
+    let stats = IRStatsVisitor::default().visit_operation(operation);
+    stats.linked_field.total
+        + stats.scalar_field.total
+        + stats.inline_fragment.total
+        + stats.condition.total
+}
+
 #[derive(Default)]
 struct IRStatsVisitor {
     visited: FnvHashMap<PointerAddress, StatsCollection>,
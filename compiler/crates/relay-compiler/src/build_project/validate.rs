@@ -30,6 +30,7 @@ use relay_transforms::validate_no_double_underscore_alias;
 use relay_transforms::validate_no_inline_fragments_with_raw_response_type;
 use relay_transforms::validate_no_unselectable_selections;
 use relay_transforms::validate_operation_variables;
+use relay_transforms::validate_paginated_lists;
 use relay_transforms::validate_relay_directives;
 use relay_transforms::validate_resolver_fragments;
 use relay_transforms::validate_static_args;
@@ -75,6 +76,10 @@ pub fn validate(
         validate_unused_variables(program),
         validate_unused_fragment_variables(program),
         validate_connections(program, &project_config.schema_config.connection_interface),
+        validate_paginated_lists(
+            program,
+            &project_config.schema_config.paginated_list_interface,
+        ),
         validate_relay_directives(program),
         validate_global_variable_names(program),
         if matches!(project_config.js_module_format, JsModuleFormat::Haste)
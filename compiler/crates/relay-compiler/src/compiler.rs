@@ -618,6 +618,9 @@ impl<TPerfLogger: PerfLogger> Compiler<TPerfLogger> {
         setup_event: &impl PerfLogEvent,
     ) -> Result<Vec<Diagnostic>> {
         let build_projects_time = setup_event.start("build_projects_time");
+        if let Some(explainer) = &self.config.build_explainer {
+            explainer.reset();
+        }
         let result = build_projects(
             Arc::clone(&self.config),
             Arc::clone(&self.perf_logger),
@@ -634,6 +637,9 @@ impl<TPerfLogger: PerfLogger> Compiler<TPerfLogger> {
                     post_artifacts_write(&self.config)
                         .map_err(|error| Error::PostArtifactsError { error })?;
                 }
+                if let Some(explainer) = &self.config.build_explainer {
+                    explainer.report().map_err(Error::IOError)?;
+                }
 
                 Ok(diagnostics)
             })
@@ -41,8 +41,11 @@ use relay_config::CustomType;
 use relay_config::DiagnosticReportConfig;
 pub use relay_config::ExtraArtifactsConfig;
 use relay_config::JsModuleFormat;
+use relay_config::LazyNormalizationConfig;
 pub use relay_config::LocalPersistConfig;
+use relay_config::MockPayloadsConfig;
 use relay_config::ModuleImportConfig;
+use relay_config::OperationRegistryConfig;
 pub use relay_config::PersistConfig;
 pub use relay_config::ProjectConfig;
 use relay_config::ProjectName;
@@ -81,6 +84,7 @@ use crate::compiler_state::ProjectSet;
 use crate::errors::ConfigValidationError;
 use crate::errors::Error;
 use crate::errors::Result;
+use crate::explain::BuildExplainer;
 use crate::path_validator::PathValidator;
 use crate::source_control_for_root;
 use crate::status_reporter::BuildStatus;
@@ -405,6 +409,10 @@ pub struct Config {
     /// Names of directives that will be automatically copied from the parent fragment to refetchable queries
     pub transferrable_refetchable_query_directives: Vec<DirectiveName>,
 
+    /// Records why each artifact is regenerated or deleted, and reports it
+    /// after each build.
+    pub build_explainer: Option<BuildExplainer>,
+
     /// Inputs the daemon polls each iteration to decide whether to exit so
     /// a fresh process can pick up a new binary/config. `None` when the
     /// config came from in-memory data (tests, `SingleProjectConfigFile`
@@ -773,6 +781,10 @@ impl Config {
                     resolvers_schema_module: config_file_project.resolvers_schema_module,
                     codegen_command: config_file_project.codegen_command,
                     get_custom_path_for_artifact: None,
+                    mock_payloads: config_file_project.mock_payloads,
+                    operation_registry: config_file_project.operation_registry,
+                    lazy_normalization: config_file_project.lazy_normalization,
+                    report_unused_fields: config_file_project.report_unused_fields,
                 };
                 Ok((project_name, project_config))
             })
@@ -824,6 +836,7 @@ impl Config {
             custom_extract_relay_resolvers: None,
             should_extract_full_source: None,
             transferrable_refetchable_query_directives: vec![],
+            build_explainer: None,
             restart_signals: DaemonRestartSignals::try_capture(
                 &config_path,
                 std::env::current_exe().ok(),
@@ -907,6 +920,30 @@ impl Config {
                     base_project_name: base_name,
                 })
             }
+
+            // Enum modules are generated into the artifact directory
+            let typegen_config = &project_config.typegen_config;
+            if typegen_config.generate_enum_modules
+                && (project_config.output.is_none() || typegen_config.enum_module_suffix.is_none())
+            {
+                errors
+                    .push(ConfigValidationError::GenerateEnumModulesMissingOptions { project_name })
+            }
+
+            // The registry is keyed by persisted id
+            if project_config.operation_registry.is_some() && project_config.persist.is_none() {
+                errors.push(
+                    ConfigValidationError::OperationRegistryMissingPersistConfig { project_name },
+                )
+            }
+
+            // Haste projects have no default dynamic import
+            if project_config.lazy_normalization.is_some()
+                && project_config.lazy_normalization_provider().is_none()
+            {
+                errors
+                    .push(ConfigValidationError::LazyNormalizationMissingProvider { project_name })
+            }
         }
     }
 
@@ -1486,6 +1523,30 @@ pub struct SingleProjectConfigFile {
     #[serde(default)]
     pub resolvers_schema_module: Option<ResolversSchemaModuleConfig>,
 
+    /// Generate a deterministic mock response for each operation, as
+    /// `<OperationName>.mock.json` next to the operation artifact.
+    #[serde(default)]
+    pub mock_payloads: Option<MockPayloadsConfig>,
+
+    /// Generate a registry mapping the persisted id and name of each
+    /// `@preloadable` operation to lazy imports of its artifacts, as a module
+    /// at `path` and a JSON file next to it.
+    #[serde(default)]
+    pub operation_registry: Option<OperationRegistryConfig>,
+
+    /// Generate the normalization AST of each query into a sibling
+    /// `<OperationName>$normalization.graphql` artifact, loaded through an
+    /// `operationModuleProvider` of the request instead of being inlined.
+    /// The request artifact is then a `LazyRequest`, which is turned into a
+    /// request with `loadLazyRequest` from `relay-runtime`.
+    #[serde(default)]
+    pub lazy_normalization: Option<LazyNormalizationConfig>,
+
+    /// Report fields selected by a fragment passed inline to `useFragment`
+    /// that the module never reads as warnings in the language server.
+    #[serde(default)]
+    pub report_unused_fields: bool,
+
     /// Opt out of source control checks/integration.
     #[serde(default)]
     pub no_source_control: Option<bool>,
@@ -1515,6 +1576,10 @@ impl Default for SingleProjectConfigFile {
             feature_flags: None,
             module_import_config: Default::default(),
             resolvers_schema_module: Default::default(),
+            mock_payloads: None,
+            operation_registry: None,
+            lazy_normalization: None,
+            report_unused_fields: false,
             no_source_control: Some(false),
             extra: Default::default(),
         }
@@ -1600,6 +1665,10 @@ impl SingleProjectConfigFile {
             module_import_config: self.module_import_config,
             relativize_js_module_paths: self.relativize_js_module_paths,
             resolvers_schema_module: self.resolvers_schema_module,
+            mock_payloads: self.mock_payloads,
+            operation_registry: self.operation_registry,
+            lazy_normalization: self.lazy_normalization,
+            report_unused_fields: self.report_unused_fields,
             extra: self.extra,
             ..Default::default()
         };
@@ -1786,6 +1855,30 @@ pub struct ConfigFileProject {
     /// the top of generated code to let readers know how to regenerate the file.
     #[serde(default)]
     pub codegen_command: Option<String>,
+
+    /// Generate a deterministic mock response for each operation, as
+    /// `<OperationName>.mock.json` next to the operation artifact.
+    #[serde(default)]
+    pub mock_payloads: Option<MockPayloadsConfig>,
+
+    /// Generate a registry mapping the persisted id and name of each
+    /// `@preloadable` operation to lazy imports of its artifacts, as a module
+    /// at `path` and a JSON file next to it.
+    #[serde(default)]
+    pub operation_registry: Option<OperationRegistryConfig>,
+
+    /// Generate the normalization AST of each query into a sibling
+    /// `<OperationName>$normalization.graphql` artifact, loaded through an
+    /// `operationModuleProvider` of the request instead of being inlined.
+    /// The request artifact is then a `LazyRequest`, which is turned into a
+    /// request with `loadLazyRequest` from `relay-runtime`.
+    #[serde(default)]
+    pub lazy_normalization: Option<LazyNormalizationConfig>,
+
+    /// Report fields selected by a fragment passed inline to `useFragment`
+    /// that the module never reads as warnings in the language server.
+    #[serde(default)]
+    pub report_unused_fields: bool,
 }
 
 pub type PersistId = String;
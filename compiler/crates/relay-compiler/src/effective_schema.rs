@@ -0,0 +1,195 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Utilities for printing the effective schema of a Relay project: its
+//! schema, schema extensions and Relay Resolvers, as the compiler builds them.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::Arc;
+
+use common::ConsoleLogger;
+use common::Location;
+use common::PerfLogger;
+use common::SourceLocationKey;
+use intern::Lookup;
+use relay_config::ProjectName;
+use schema::SDLSchema;
+use schema::Schema;
+use schema::Type;
+use schema_print::generate_shard_map;
+use schema_print::print_directive;
+use schema_print::print_schema_definition;
+use schema_print::print_type;
+
+use crate::GraphQLAsts;
+use crate::ProgramsError;
+use crate::build_project::build_schema::build_schema;
+use crate::config::Config;
+use crate::errors::BuildProjectError;
+use crate::errors::Error;
+use crate::errors::print_compiler_error;
+use crate::file_source::FileSource;
+use crate::get_single_enabled_project;
+
+/// How to print the effective schema.
+pub struct EffectiveSchemaOptions {
+    /// Include the client schema extensions and the Relay Resolvers of the
+    /// project and of its base project.
+    pub include_client_extensions: bool,
+    /// Precede each definition with a comment naming the file defining it,
+    /// and the files extending it with fields.
+    pub annotate_sources: bool,
+    /// Number of shards to split the types into. The schema definition and
+    /// the directives are printed to the first shard.
+    pub shard_count: usize,
+}
+
+impl Default for EffectiveSchemaOptions {
+    fn default() -> Self {
+        Self {
+            include_client_extensions: true,
+            annotate_sources: false,
+            shard_count: 1,
+        }
+    }
+}
+
+/// Build the schema of the only enabled project, without compiling its
+/// documents, and print it in SDL. Returns `options.shard_count` shards.
+pub async fn build_and_print_effective_schema(
+    config: Config,
+    options: &EffectiveSchemaOptions,
+) -> Result<Vec<String>, ProgramsError> {
+    let project_name = get_single_enabled_project(&config)?;
+    let root_dir = config.root_dir.clone();
+    let schema = build_effective_schema(Arc::new(config), project_name, options)
+        .await
+        .map_err(|e| ProgramsError::CompilationFailed(print_compiler_error(&root_dir, e)))?;
+
+    Ok(print_effective_schema(&schema, options))
+}
+
+async fn build_effective_schema(
+    config: Arc<Config>,
+    project_name: ProjectName,
+    options: &EffectiveSchemaOptions,
+) -> Result<Arc<SDLSchema>, Error> {
+    let perf_logger = ConsoleLogger;
+    let log_event = perf_logger.create_event("build_effective_schema");
+    let file_source = FileSource::connect(&config, &log_event).await?;
+    let mut compiler_state = file_source.query(&log_event, &perf_logger).await?;
+
+    // Projects without any source files have no entries in the compiler
+    // state, which building the schema of Relay Resolvers expects.
+    let project_config = &config.projects[&project_name];
+    for name in std::iter::once(project_name).chain(project_config.base) {
+        compiler_state.graphql_sources.entry(name).or_default();
+        if options.include_client_extensions {
+            compiler_state.docblocks.entry(name).or_default();
+        } else {
+            compiler_state.extensions.remove(&name);
+            compiler_state.docblocks.insert(name, Default::default());
+        }
+    }
+    let graphql_asts = GraphQLAsts::from_graphql_sources_map(
+        &compiler_state.graphql_sources,
+        &Default::default(),
+        &config,
+    )?;
+
+    build_schema(
+        &compiler_state,
+        &config,
+        project_config,
+        &graphql_asts,
+        &log_event,
+    )
+    .map_err(|errors| Error::BuildProjectsErrors {
+        errors: vec![BuildProjectError::ValidationErrors {
+            errors,
+            project_name,
+        }],
+    })
+}
+
+fn print_effective_schema(schema: &SDLSchema, options: &EffectiveSchemaOptions) -> Vec<String> {
+    let shard_count = options.shard_count.max(1);
+    let mut shards = vec![String::new(); shard_count];
+
+    let first_shard = &mut shards[0];
+    first_shard.push_str(&print_schema_definition(schema));
+    let mut directives = schema.get_directives().collect::<Vec<_>>();
+    directives.sort_by_key(|directive| directive.name.item);
+    for directive in directives {
+        if options.annotate_sources {
+            write_source_annotation(first_shard, directive.name.location);
+        }
+        first_shard.push_str(&print_directive(schema, directive));
+    }
+
+    for (shard_index, types) in generate_shard_map(schema, shard_count, Default::default()) {
+        let types = types
+            .into_iter()
+            .map(|(type_name, type_)| (type_name.lookup(), type_))
+            .collect::<BTreeMap<_, _>>();
+        let shard = &mut shards[shard_index];
+        for type_ in types.into_values() {
+            if options.annotate_sources {
+                write_source_annotation(shard, type_location(schema, type_));
+                write_extension_annotations(shard, schema, type_);
+            }
+            shard.push_str(&print_type(schema, type_));
+        }
+    }
+    shards
+}
+
+/// Names the file defining a type or directive. Built-in definitions, such
+/// as the ones of Relay's directives, have no file.
+fn write_source_annotation(output: &mut String, location: Location) {
+    match location.source_location() {
+        SourceLocationKey::Generated => writeln!(output, "# Built in").unwrap(),
+        source_location => writeln!(output, "# Defined in {}", source_location.path()).unwrap(),
+    }
+}
+
+/// Names the files, other than the one defining the type, that add fields
+/// to an object or interface.
+fn write_extension_annotations(output: &mut String, schema: &SDLSchema, type_: Type) {
+    let fields = match type_ {
+        Type::Object(id) => &schema.object(id).fields,
+        Type::Interface(id) => &schema.interface(id).fields,
+        _ => return,
+    };
+    let type_path = type_location(schema, type_).source_location().path();
+    let mut fields_by_path: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for field_id in fields {
+        let field = schema.field(*field_id);
+        let path = field.name.location.source_location().path();
+        if path != type_path {
+            fields_by_path
+                .entry(path)
+                .or_default()
+                .push(field.name.item.lookup());
+        }
+    }
+    for (path, field_names) in fields_by_path {
+        writeln!(output, "# Extended in {}: {}", path, field_names.join(", ")).unwrap();
+    }
+}
+
+fn type_location(schema: &SDLSchema, type_: Type) -> Location {
+    match type_ {
+        Type::Enum(id) => schema.enum_(id).name.location,
+        Type::InputObject(id) => schema.input_object(id).name.location,
+        Type::Interface(id) => schema.interface(id).name.location,
+        Type::Object(id) => schema.object(id).name.location,
+        Type::Scalar(id) => schema.scalar(id).name.location,
+        Type::Union(id) => schema.union(id).name.location,
+    }
+}
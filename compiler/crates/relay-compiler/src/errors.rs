@@ -128,6 +128,9 @@ pub enum Error {
     #[error("Artifacts validation failed: {error}")]
     ArtifactsValidationError { error: String },
 
+    #[error("Artifacts verification failed: {error}")]
+    ArtifactsVerificationError { error: String },
+
     #[error("Error in post artifact writer: {error}")]
     PostArtifactsError {
         #[serde(skip_serializing)]
@@ -162,6 +165,9 @@ pub enum Error {
         saved_state_version: String,
         config_version: String,
     },
+
+    #[error("The saved state is out of date, these sources changed since it was written:{sources}")]
+    SavedStateOutOfDate { sources: String },
 }
 
 #[derive(Debug, Error)]
@@ -196,6 +202,21 @@ pub enum ConfigValidationError {
         base_project_name: ProjectName,
     },
 
+    #[error(
+        "The project `{project_name}` sets `generateEnumModules`, which requires both `artifactDirectory` and `enumModuleSuffix` to be set."
+    )]
+    GenerateEnumModulesMissingOptions { project_name: ProjectName },
+
+    #[error(
+        "The project `{project_name}` sets `operationRegistry`, which requires `persistConfig` to be set."
+    )]
+    OperationRegistryMissingPersistConfig { project_name: ProjectName },
+
+    #[error(
+        "The project `{project_name}` sets `lazyNormalization`, which requires a `provider`, or a `dynamicModuleProvider` in `moduleImportConfig`."
+    )]
+    LazyNormalizationMissingProvider { project_name: ProjectName },
+
     #[error("Project `{project_name}` needs to define exactly one of `schema` or `schema_dir`.")]
     ProjectNeedsSchemaXorSchemaDir { project_name: ProjectName },
 
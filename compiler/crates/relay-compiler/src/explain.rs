@@ -0,0 +1,337 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Records why a build regenerated or deleted each artifact, for
+//! `relay compiler --explain`.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use dependency_analyzer::ReachableIrReason;
+use graphql_ir::ExecutableDefinitionName;
+use log::info;
+use relay_config::ProjectName;
+use rustc_hash::FxHashMap;
+use schema_diff::check::IncrementalBuildSchemaChange;
+use serde::Serialize;
+
+use crate::artifact_map::ArtifactSourceKey;
+use crate::build_project::Artifact;
+
+/// Collects the causes of the artifact changes of a build. Set on
+/// `Config::build_explainer`, and reported after each successful build.
+pub struct BuildExplainer {
+    /// Write the report of each build as JSON to this file.
+    output_file: Option<PathBuf>,
+    rebuilds: Mutex<FxHashMap<ProjectName, ProjectRebuild>>,
+    explanations: Mutex<Vec<ArtifactExplanation>>,
+}
+
+enum ProjectRebuild {
+    Full(&'static str),
+    Incremental(FxHashMap<ExecutableDefinitionName, ArtifactCause>),
+}
+
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArtifactExplanation {
+    pub project: String,
+    /// Path of the artifact, relative to the root directory.
+    pub path: String,
+    pub action: ArtifactAction,
+    pub cause: ArtifactCause,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ArtifactAction {
+    Regenerated,
+    Deleted,
+}
+
+#[derive(Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum ArtifactCause {
+    /// Every artifact of the project is regenerated.
+    FullBuild { reason: String },
+    /// A definition changed. `path` is the dependency chain from it to the
+    /// definition generating the artifact.
+    ChangedDefinition {
+        definition: String,
+        file: Option<String>,
+        path: Vec<String>,
+    },
+    /// A schema type used by the first definition of `path` changed.
+    SchemaChange {
+        coordinate: String,
+        change: String,
+        path: Vec<String>,
+    },
+    /// The definition or resolver generating the artifact was removed.
+    RemovedSource { source: String },
+    /// The definition previously generating the artifact no longer does.
+    NoLongerGenerated { definition: String },
+    /// The artifact is not generated by any definition.
+    Stale,
+    /// The artifact is regenerated with changed sources that are not
+    /// executable definitions, such as resolvers.
+    Unattributed { sources: Vec<String> },
+}
+
+impl fmt::Display for ArtifactCause {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArtifactCause::FullBuild { reason } => write!(f, "full build ({reason})"),
+            ArtifactCause::ChangedDefinition {
+                definition,
+                file,
+                path,
+            } => {
+                write!(f, "{definition} changed")?;
+                if let Some(file) = file {
+                    write!(f, " in {file}")?;
+                }
+                write_path(f, path)
+            }
+            ArtifactCause::SchemaChange {
+                coordinate,
+                change,
+                path,
+            } => {
+                write!(f, "schema {change} {coordinate} changed")?;
+                if let Some(definition) = path.first() {
+                    write!(f, ", used by {definition}")?;
+                }
+                write_path(f, path)
+            }
+            ArtifactCause::RemovedSource { source } => write!(f, "{source} was removed"),
+            ArtifactCause::NoLongerGenerated { definition } => {
+                write!(f, "{definition} no longer generates it")
+            }
+            ArtifactCause::Stale => write!(f, "no definition generates it"),
+            ArtifactCause::Unattributed { sources } => {
+                write!(f, "sources changed: {}", sources.join(", "))
+            }
+        }
+    }
+}
+
+fn write_path(f: &mut fmt::Formatter<'_>, path: &[String]) -> fmt::Result {
+    if path.len() > 1 {
+        write!(f, ": {}", path.join(" -> "))?;
+    }
+    Ok(())
+}
+
+impl BuildExplainer {
+    pub fn new(output_file: Option<PathBuf>) -> Self {
+        Self {
+            output_file,
+            rebuilds: Default::default(),
+            explanations: Default::default(),
+        }
+    }
+
+    pub(crate) fn record_full_build(&self, project_name: ProjectName, reason: &'static str) {
+        self.rebuilds
+            .lock()
+            .unwrap()
+            .insert(project_name, ProjectRebuild::Full(reason));
+    }
+
+    /// Record the reasons of an incremental build. `files` maps the origins
+    /// of the reasons to their source file.
+    pub(crate) fn record_incremental_build(
+        &self,
+        project_name: ProjectName,
+        reasons: impl IntoIterator<Item = (ExecutableDefinitionName, ReachableIrReason)>,
+        files: &FxHashMap<ExecutableDefinitionName, String>,
+    ) {
+        let causes = reasons
+            .into_iter()
+            .map(|(name, reason)| {
+                let path = reason.path.iter().map(|name| name.to_string()).collect();
+                let cause = match reason.schema_change {
+                    Some(change) => {
+                        let (change, coordinate) = describe_schema_change(change);
+                        ArtifactCause::SchemaChange {
+                            coordinate,
+                            change: change.to_string(),
+                            path,
+                        }
+                    }
+                    None => ArtifactCause::ChangedDefinition {
+                        definition: reason.origin.to_string(),
+                        file: files.get(&reason.origin).cloned(),
+                        path,
+                    },
+                };
+                (name, cause)
+            })
+            .collect();
+        self.rebuilds
+            .lock()
+            .unwrap()
+            .insert(project_name, ProjectRebuild::Incremental(causes));
+    }
+
+    pub(crate) fn record_regenerated(&self, project_name: ProjectName, artifacts: &[Artifact]) {
+        let rebuilds = self.rebuilds.lock().unwrap();
+        let rebuild = rebuilds.get(&project_name);
+        let mut explanations = self.explanations.lock().unwrap();
+        for artifact in artifacts {
+            let cause = match rebuild {
+                Some(ProjectRebuild::Full(reason)) => ArtifactCause::FullBuild {
+                    reason: reason.to_string(),
+                },
+                Some(ProjectRebuild::Incremental(causes)) => artifact
+                    .artifact_source_keys
+                    .iter()
+                    .find_map(|key| match key {
+                        ArtifactSourceKey::ExecutableDefinition(name) => causes.get(name).cloned(),
+                        _ => None,
+                    })
+                    .unwrap_or_else(|| ArtifactCause::Unattributed {
+                        sources: artifact
+                            .artifact_source_keys
+                            .iter()
+                            .map(describe_source_key)
+                            .collect(),
+                    }),
+                None => ArtifactCause::Unattributed { sources: vec![] },
+            };
+            explanations.push(ArtifactExplanation {
+                project: project_name.to_string(),
+                path: artifact.path.display().to_string(),
+                action: ArtifactAction::Regenerated,
+                cause,
+            });
+        }
+    }
+
+    pub(crate) fn record_deleted(
+        &self,
+        project_name: ProjectName,
+        path: &Path,
+        cause: ArtifactCause,
+    ) {
+        self.explanations.lock().unwrap().push(ArtifactExplanation {
+            project: project_name.to_string(),
+            path: path.display().to_string(),
+            action: ArtifactAction::Deleted,
+            cause,
+        });
+    }
+
+    /// Forget everything recorded by a previous build.
+    pub fn reset(&self) {
+        self.rebuilds.lock().unwrap().clear();
+        self.explanations.lock().unwrap().clear();
+    }
+
+    /// The explanations recorded since the last reset, sorted by project,
+    /// action and path.
+    pub fn explanations(&self) -> Vec<ArtifactExplanation> {
+        let mut explanations = self.explanations.lock().unwrap().clone();
+        explanations
+            .sort_by(|a, b| (&a.project, a.action, &a.path).cmp(&(&b.project, b.action, &b.path)));
+        explanations
+    }
+
+    /// Print the explanations of the build, and write them to the output
+    /// file if any.
+    pub fn report(&self) -> io::Result<()> {
+        let explanations = self.explanations();
+        for line in format_explanations(&explanations).lines() {
+            info!("{line}");
+        }
+        if let Some(output_file) = &self.output_file {
+            fs::write(
+                output_file,
+                serde_json::to_string_pretty(&explanations)? + "\n",
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Format the explanations as text, one section per project. Artifacts
+/// regenerated by a full build are summarized.
+pub fn format_explanations(explanations: &[ArtifactExplanation]) -> String {
+    let mut out = String::new();
+    let mut start = 0;
+    while start < explanations.len() {
+        let project = &explanations[start].project;
+        let end = start
+            + explanations[start..]
+                .iter()
+                .take_while(|explanation| &explanation.project == project)
+                .count();
+        let project_explanations = &explanations[start..end];
+        start = end;
+
+        let (regenerated, deleted): (Vec<_>, Vec<_>) = project_explanations
+            .iter()
+            .partition(|explanation| explanation.action == ArtifactAction::Regenerated);
+        out.push_str(&format!(
+            "[{}] {} regenerated, {} deleted\n",
+            project,
+            pluralize(regenerated.len(), "artifact"),
+            pluralize(deleted.len(), "artifact")
+        ));
+        match regenerated.first().map(|explanation| &explanation.cause) {
+            Some(cause @ ArtifactCause::FullBuild { .. }) => {
+                out.push_str(&format!("  all regenerated: {cause}\n"));
+            }
+            _ => {
+                for explanation in &regenerated {
+                    out.push_str(&format!(
+                        "  regenerated {}\n    because {}\n",
+                        explanation.path, explanation.cause
+                    ));
+                }
+            }
+        }
+        for explanation in &deleted {
+            out.push_str(&format!(
+                "  deleted {}\n    because {}\n",
+                explanation.path, explanation.cause
+            ));
+        }
+    }
+    out
+}
+
+fn pluralize(count: usize, noun: &str) -> String {
+    if count == 1 {
+        format!("{count} {noun}")
+    } else {
+        format!("{count} {noun}s")
+    }
+}
+
+fn describe_schema_change(change: IncrementalBuildSchemaChange) -> (&'static str, String) {
+    match change {
+        IncrementalBuildSchemaChange::Enum(name) => ("enum", name.to_string()),
+        IncrementalBuildSchemaChange::InputObject(name) => ("input object", name.to_string()),
+        IncrementalBuildSchemaChange::Object(name) => ("object", name.to_string()),
+        IncrementalBuildSchemaChange::Union(name) => ("union", name.to_string()),
+        IncrementalBuildSchemaChange::Interface(name) => ("interface", name.to_string()),
+    }
+}
+
+pub(crate) fn describe_source_key(key: &ArtifactSourceKey) -> String {
+    match key {
+        ArtifactSourceKey::ExecutableDefinition(name) => name.to_string(),
+        ArtifactSourceKey::ResolverHash(_) => "resolver".to_string(),
+        ArtifactSourceKey::Schema() => "schema".to_string(),
+    }
+}
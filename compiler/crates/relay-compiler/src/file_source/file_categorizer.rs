@@ -405,7 +405,11 @@ fn in_relative_generated_dir(default_generated_dir: &OsStr, path: &Path) -> bool
 }
 
 fn is_source_code_extension(extension: &OsStr) -> bool {
-    extension == "js" || extension == "jsx" || extension == "ts" || extension == "tsx"
+    extension == "js"
+        || extension == "jsx"
+        || extension == "ts"
+        || extension == "tsx"
+        || extension == "res"
 }
 
 fn is_schema_extension(extension: &OsStr) -> bool {
@@ -418,10 +422,13 @@ fn is_extra_extensions(extension: &OsStr) -> bool {
 
 fn is_valid_source_code_extension(typegen_language: &TypegenLanguage, extension: &OsStr) -> bool {
     match typegen_language {
-        TypegenLanguage::TypeScript => is_source_code_extension(extension),
+        TypegenLanguage::TypeScript => {
+            extension == "js" || extension == "jsx" || extension == "ts" || extension == "tsx"
+        }
         TypegenLanguage::Flow | TypegenLanguage::JavaScript => {
             extension == "js" || extension == "jsx"
         }
+        TypegenLanguage::ReScript => extension == "res",
     }
 }
 
@@ -441,6 +448,7 @@ mod tests {
                         "src/vendor": "public",
                         "src/custom": "with_custom_generated_dir",
                         "src/typescript": "typescript",
+                        "src/rescript": "rescript",
                         "src/custom_overlapping": ["with_custom_generated_dir", "overlapping_generated_dir"],
                         "src/react_native.native.js": ["public"],
                         "src/component.react.native.js": ["public"],
@@ -470,6 +478,10 @@ mod tests {
                             "schema": "graphql/ts_schema.graphql",
                             "language": "typescript"
                         },
+                        "rescript": {
+                            "schema": "graphql/res_schema.graphql",
+                            "language": "rescript"
+                        },
                         "overlapping_generated_dir": {
                             "schema": "graphql/__generated__/custom.graphql",
                             "language": "flow"
@@ -591,6 +603,25 @@ mod tests {
                 project_set: ProjectSet::of("typescript".intern().into()),
             },
         );
+        assert_eq!(
+            categorizer
+                .categorize(&PathBuf::from("src/rescript/A.res"), &config)
+                .unwrap(),
+            FileGroup::Source {
+                project_set: ProjectSet::of("rescript".intern().into()),
+            },
+        );
+        assert_eq!(
+            categorizer
+                .categorize(
+                    &PathBuf::from("src/rescript/__generated__/A_graphql.res"),
+                    &config
+                )
+                .unwrap(),
+            FileGroup::Generated {
+                project_name: "rescript".intern().into(),
+            },
+        );
         assert_eq!(
             categorizer
                 .categorize(&PathBuf::from("schema/fb_schema.bin"), &config)
@@ -44,6 +44,9 @@ fn get_expected_file_extensions(config: &Config) -> HashSet<&'static str> {
                 file_extensions.insert("js");
                 file_extensions.insert("jsx");
             }
+            TypegenLanguage::ReScript => {
+                file_extensions.insert("res");
+            }
             TypegenLanguage::TypeScript => {
                 file_extensions.insert("js");
                 file_extensions.insert("jsx");
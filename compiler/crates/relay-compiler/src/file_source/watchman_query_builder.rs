@@ -121,11 +121,12 @@ fn get_sources_dir_exprs(
 }
 
 fn get_project_file_ext_expr(typegen_language: TypegenLanguage) -> Expr {
-    // Ending in *.js(x) or *.ts(x) depending on the project language.
+    // Ending in *.js(x), *.ts(x) or *.res depending on the project language.
     Expr::Suffix(match &typegen_language {
         TypegenLanguage::Flow | TypegenLanguage::JavaScript => {
             vec![PathBuf::from("js"), PathBuf::from("jsx")]
         }
+        TypegenLanguage::ReScript => vec![PathBuf::from("res")],
         TypegenLanguage::TypeScript => {
             vec![
                 PathBuf::from("js"),
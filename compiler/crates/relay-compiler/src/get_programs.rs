@@ -9,15 +9,18 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use std::sync::Mutex;
 
+use common::ConsoleLogger;
 use common::PerfLogger;
 use relay_config::ProjectName;
 use relay_transforms::Programs;
+use thiserror::Error;
 
 use crate::NoopArtifactWriter;
 use crate::compiler::Compiler;
 use crate::compiler_state::CompilerState;
 use crate::config::Config;
 use crate::errors::Result;
+use crate::errors::print_compiler_error;
 
 type RelayPrograms = (
     HashMap<ProjectName, Arc<Programs>>,
@@ -27,6 +30,16 @@ type RelayPrograms = (
 
 type ProgramsResult = Result<RelayPrograms>;
 
+/// Errors of the CLI tools compiling the programs or the schema of projects.
+#[derive(Debug, Error)]
+pub enum ProgramsError {
+    #[error("Expected exactly one project, but found {0}. Select a project with --project.")]
+    MultipleProjects(usize),
+
+    #[error("Compilation failed: {0}")]
+    CompilationFailed(String),
+}
+
 /// Many CLI tools use `get_programs` to compile Relay programs as a prerequisite to other operations.
 /// In those cases it's often not practical to to continue if the programs
 /// cannot be created. In those cases, it makes sense to exit the process with
@@ -80,3 +93,27 @@ pub async fn get_programs<TPerfLogger: PerfLogger + 'static>(
     };
     Ok((programs, compiler_state, Arc::clone(&config)))
 }
+
+/// Like `get_programs`, but with the compiler errors printed with their
+/// source context, for CLI tools reporting them.
+pub async fn compile_programs(config: Config) -> std::result::Result<RelayPrograms, ProgramsError> {
+    let root_dir = config.root_dir.clone();
+    get_programs(config, Arc::new(ConsoleLogger))
+        .await
+        .map_err(|e| ProgramsError::CompilationFailed(print_compiler_error(&root_dir, e)))
+}
+
+/// The name of the only enabled project, for CLI tools working on a single
+/// project.
+pub fn get_single_enabled_project(
+    config: &Config,
+) -> std::result::Result<ProjectName, ProgramsError> {
+    let enabled_projects = config
+        .enabled_projects()
+        .map(|project_config| project_config.name)
+        .collect::<Vec<_>>();
+    match enabled_projects[..] {
+        [project_name] => Ok(project_name),
+        _ => Err(ProgramsError::MultipleProjects(enabled_projects.len())),
+    }
+}
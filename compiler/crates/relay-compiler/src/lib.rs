@@ -16,18 +16,23 @@ pub mod compiler;
 pub mod compiler_state;
 pub mod config;
 mod docblocks;
+pub mod effective_schema;
 pub mod errors;
+pub mod explain;
 pub mod file_source;
 mod get_programs;
 mod graphql_asts;
 mod operation_persister;
 mod path_validator;
+pub mod program_stats;
 mod red_to_green;
+pub mod schema_coverage;
 #[cfg(unix)]
 pub mod server_daemon;
 pub mod status_reporter;
 pub mod subschema_extraction;
 mod utils;
+pub mod verify_artifacts;
 
 pub use artifact_map::ArtifactSourceKey;
 pub use build_project::AdditionalValidations;
@@ -81,8 +86,11 @@ pub use file_source::SourceControlUpdateStatus;
 pub use file_source::SourceReader;
 pub use file_source::WalkDirFileSourceResult;
 pub use file_source::source_for_location;
+pub use get_programs::ProgramsError;
 pub use get_programs::assert_programs;
+pub use get_programs::compile_programs;
 pub use get_programs::get_programs;
+pub use get_programs::get_single_enabled_project;
 pub use graphql_asts::GraphQLAsts;
 pub use operation_persister::LocalPersister;
 pub use operation_persister::RemotePersister;
@@ -0,0 +1,353 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Utilities for computing metrics about the fragments and operations of
+//! Relay projects.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::sync::Arc;
+
+use common::NamedItem;
+use dependency_analyzer::Distribution;
+use dependency_analyzer::QueryStatsReport;
+use dependency_analyzer::compute_program_query_stats;
+use graphql_ir::FragmentSpread;
+use graphql_ir::InlineFragment;
+use graphql_ir::LinkedField;
+use graphql_ir::Visitor;
+use graphql_text_printer::PrinterOptions;
+use graphql_text_printer::print_full_operation;
+use relay_config::DeferStreamInterface;
+use relay_config::ProjectName;
+use relay_transforms::MATCH_CONSTANTS;
+use relay_transforms::Programs;
+use rustc_hash::FxHashMap;
+use serde::Serialize;
+
+use crate::build_project::count_selections;
+use crate::config::Config;
+use crate::config::ProjectConfig;
+
+/// Metrics of the fragments and operations of a single project.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectStats {
+    pub project: String,
+    pub operations: OperationCounts,
+    pub fragment_count: usize,
+    /// Transitive fragment count of each operation.
+    pub transitive_fragments: Option<Distribution>,
+    /// Longest chain of fragment spreads of each operation.
+    pub fragment_depth: Option<Distribution>,
+    /// Number of definitions spreading each fragment.
+    pub fragment_fan_in: Option<Distribution>,
+    /// Number of fragments spread by each fragment.
+    pub fragment_fan_out: Option<Distribution>,
+    /// Number of selections in each normalization AST.
+    pub normalization_selections: Option<Distribution>,
+    /// Operations with the most transitive fragments.
+    pub operations_by_transitive_fragments: Vec<OperationFragments>,
+    /// Fragments with the highest fan-in.
+    pub most_spread_fragments: Vec<FragmentSpreads>,
+    /// Operations with the largest printed query text.
+    pub largest_operations: Vec<OperationSize>,
+    pub directive_usages: DirectiveUsages,
+    #[serde(skip)]
+    query_stats: QueryStatsReport,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OperationCounts {
+    pub query: usize,
+    pub mutation: usize,
+    pub subscription: usize,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OperationFragments {
+    pub name: String,
+    pub kind: String,
+    pub direct_fragments: usize,
+    pub transitive_fragments: usize,
+    pub max_fragment_depth: usize,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FragmentSpreads {
+    pub name: String,
+    pub fan_in: usize,
+    pub fan_out: usize,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OperationSize {
+    pub name: String,
+    pub kind: String,
+    pub text_size: usize,
+    pub normalization_selections: usize,
+}
+
+/// Number of occurrences of incremental delivery and data-driven dependency
+/// directives in the source documents.
+#[derive(Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DirectiveUsages {
+    pub defer: usize,
+    pub stream: usize,
+    pub module: usize,
+}
+
+/// Compute the stats of each compiled project, sorted by project name.
+/// `limit` is the number of entries kept in each ranked list.
+pub fn compute_program_stats(
+    config: &Config,
+    programs: &HashMap<ProjectName, Arc<Programs>>,
+    limit: usize,
+) -> Vec<ProjectStats> {
+    let mut stats = programs
+        .iter()
+        .map(|(project_name, programs)| {
+            compute_project_stats(&config.projects[project_name], programs, limit)
+        })
+        .collect::<Vec<_>>();
+    stats.sort_by(|a, b| a.project.cmp(&b.project));
+    stats
+}
+
+fn compute_project_stats(
+    project_config: &ProjectConfig,
+    programs: &Programs,
+    limit: usize,
+) -> ProjectStats {
+    let query_stats = compute_program_query_stats(&programs.source);
+
+    let printer_options = PrinterOptions {
+        compact: project_config
+            .feature_flags
+            .compact_query_text
+            .is_fully_enabled(),
+        ..Default::default()
+    };
+    let normalization_selections = programs
+        .normalization
+        .operations()
+        .map(|operation| (operation.name.item, count_selections(operation)))
+        .collect::<FxHashMap<_, _>>();
+    let mut largest_operations = programs
+        .operation_text
+        .operations()
+        .map(|operation| OperationSize {
+            name: operation.name.item.to_string(),
+            kind: operation.kind.to_string(),
+            text_size: print_full_operation(&programs.operation_text, operation, printer_options)
+                .len(),
+            normalization_selections: normalization_selections
+                .get(&operation.name.item)
+                .copied()
+                .unwrap_or(0),
+        })
+        .collect::<Vec<_>>();
+    largest_operations.sort_by(|a, b| b.text_size.cmp(&a.text_size).then(a.name.cmp(&b.name)));
+
+    let mut directive_usages = DirectiveUsagesCollector {
+        defer_stream_interface: &project_config.schema_config.defer_stream_interface,
+        usages: Default::default(),
+    };
+    directive_usages.visit_program(&programs.source);
+
+    ProjectStats {
+        project: project_config.name.to_string(),
+        operations: OperationCounts {
+            query: query_stats.query_count,
+            mutation: query_stats.mutation_count,
+            subscription: query_stats.subscription_count,
+        },
+        fragment_count: query_stats.fragments.len(),
+        transitive_fragments: Distribution::from_values(
+            &query_stats
+                .operations
+                .iter()
+                .map(|operation| operation.transitive_fragment_count)
+                .collect::<Vec<_>>(),
+        ),
+        fragment_depth: Distribution::from_values(
+            &query_stats
+                .operations
+                .iter()
+                .map(|operation| operation.max_fragment_depth)
+                .collect::<Vec<_>>(),
+        ),
+        fragment_fan_in: Distribution::from_values(
+            &query_stats
+                .fragments
+                .iter()
+                .map(|fragment| fragment.fan_in)
+                .collect::<Vec<_>>(),
+        ),
+        fragment_fan_out: Distribution::from_values(
+            &query_stats
+                .fragments
+                .iter()
+                .map(|fragment| fragment.fan_out)
+                .collect::<Vec<_>>(),
+        ),
+        normalization_selections: Distribution::from_values(
+            &normalization_selections
+                .values()
+                .copied()
+                .collect::<Vec<_>>(),
+        ),
+        operations_by_transitive_fragments: query_stats
+            .operations
+            .iter()
+            .take(limit)
+            .map(|operation| OperationFragments {
+                name: operation.name.clone(),
+                kind: operation.kind.to_string(),
+                direct_fragments: operation.direct_fragment_count,
+                transitive_fragments: operation.transitive_fragment_count,
+                max_fragment_depth: operation.max_fragment_depth,
+            })
+            .collect(),
+        most_spread_fragments: query_stats
+            .fragments
+            .iter()
+            .take(limit)
+            .map(|fragment| FragmentSpreads {
+                name: fragment.name.clone(),
+                fan_in: fragment.fan_in,
+                fan_out: fragment.fan_out,
+            })
+            .collect(),
+        largest_operations: largest_operations.into_iter().take(limit).collect(),
+        directive_usages: directive_usages.usages,
+        query_stats,
+    }
+}
+
+impl ProjectStats {
+    /// Format the stats as a deterministic text report. `limit` controls how
+    /// many operations appear in the ranked operation list.
+    pub fn format_report(&self, limit: usize) -> String {
+        let mut out = String::new();
+        writeln!(out, "==== Project: {} ====", self.project).unwrap();
+        out.push_str(&self.query_stats.format_report_with_limit(limit));
+
+        writeln!(out).unwrap();
+        writeln!(out, "== Fragments (by fan-in, descending) ==").unwrap();
+        for fragment in &self.most_spread_fragments {
+            writeln!(
+                out,
+                "{:>3} {} [fan-out: {}]",
+                fragment.fan_in, fragment.name, fragment.fan_out
+            )
+            .unwrap();
+        }
+        writeln!(out, "Total fragments: {}", self.fragment_count).unwrap();
+        write_distribution(&mut out, "Fan-in", &self.fragment_fan_in);
+        write_distribution(&mut out, "Fan-out", &self.fragment_fan_out);
+
+        writeln!(out).unwrap();
+        writeln!(out, "== Fragment depth ==").unwrap();
+        write_distribution(&mut out, "Depth", &self.fragment_depth);
+
+        writeln!(out).unwrap();
+        writeln!(out, "== Normalization selections ==").unwrap();
+        write_distribution(&mut out, "Selections", &self.normalization_selections);
+
+        writeln!(out).unwrap();
+        writeln!(out, "== Largest operations (by query text size) ==").unwrap();
+        for operation in &self.largest_operations {
+            writeln!(
+                out,
+                "{:>6} {} ({}) [selections: {}]",
+                operation.text_size,
+                operation.name,
+                operation.kind,
+                operation.normalization_selections
+            )
+            .unwrap();
+        }
+
+        writeln!(out).unwrap();
+        writeln!(out, "== Directives ==").unwrap();
+        writeln!(
+            out,
+            "@defer: {}, @stream: {}, @module: {}",
+            self.directive_usages.defer, self.directive_usages.stream, self.directive_usages.module
+        )
+        .unwrap();
+        out
+    }
+}
+
+fn write_distribution(out: &mut String, label: &str, distribution: &Option<Distribution>) {
+    match distribution {
+        Some(dist) => writeln!(
+            out,
+            "{}: Min: {}  Max: {}  Mean: {:.2}  Median: {}  P90: {}  P99: {}",
+            label, dist.min, dist.max, dist.mean, dist.median, dist.p90, dist.p99
+        )
+        .unwrap(),
+        None => writeln!(out, "{label}: -").unwrap(),
+    }
+}
+
+struct DirectiveUsagesCollector<'a> {
+    defer_stream_interface: &'a DeferStreamInterface,
+    usages: DirectiveUsages,
+}
+
+impl Visitor for DirectiveUsagesCollector<'_> {
+    const NAME: &'static str = "DirectiveUsagesCollector";
+    const VISIT_ARGUMENTS: bool = false;
+    const VISIT_DIRECTIVES: bool = false;
+
+    fn visit_fragment_spread(&mut self, spread: &FragmentSpread) {
+        if spread
+            .directives
+            .named(self.defer_stream_interface.defer_name)
+            .is_some()
+        {
+            self.usages.defer += 1;
+        }
+        if spread
+            .directives
+            .named(MATCH_CONSTANTS.module_directive_name)
+            .is_some()
+        {
+            self.usages.module += 1;
+        }
+    }
+
+    fn visit_inline_fragment(&mut self, fragment: &InlineFragment) {
+        if fragment
+            .directives
+            .named(self.defer_stream_interface.defer_name)
+            .is_some()
+        {
+            self.usages.defer += 1;
+        }
+        self.default_visit_inline_fragment(fragment)
+    }
+
+    fn visit_linked_field(&mut self, field: &LinkedField) {
+        if field
+            .directives
+            .named(self.defer_stream_interface.stream_name)
+            .is_some()
+        {
+            self.usages.stream += 1;
+        }
+        self.default_visit_linked_field(field)
+    }
+}
@@ -0,0 +1,166 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Utilities for reporting which schema fields are selected by a Relay
+//! project, and from where.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use common::WithLocation;
+use graphql_ir::LinkedField;
+use graphql_ir::Program;
+use graphql_ir::ScalarField;
+use graphql_ir::Visitor;
+use relay_transforms::Programs;
+use rustc_hash::FxHashMap;
+use schema::FieldID;
+use schema::Schema;
+use schema::Type;
+use schema_coordinates::SchemaCoordinate;
+use serde::Serialize;
+
+/// Usage counts of a schema type or field.
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CoverageEntry {
+    /// Number of selections in reader ASTs (fragments and operation roots).
+    pub reader_usages: usize,
+    /// Number of selections in normalization ASTs (operations with all
+    /// fragments inlined).
+    pub normalization_usages: usize,
+    /// Source files, relative to the root directory, that select it.
+    pub files: BTreeSet<&'static str>,
+}
+
+impl CoverageEntry {
+    fn is_used(&self) -> bool {
+        self.reader_usages > 0 || self.normalization_usages > 0
+    }
+
+    fn merge(&mut self, other: &CoverageEntry) {
+        self.reader_usages += other.reader_usages;
+        self.normalization_usages += other.normalization_usages;
+        self.files.extend(other.files.iter().copied());
+    }
+}
+
+/// Schema-wide usage report, keyed by schema coordinates.
+///
+/// Only fields of server object and interface types are reported, client
+/// schema extensions are skipped.
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaCoverage {
+    /// Usages of all fields of each type, keyed by type coordinate (`User`).
+    pub types: BTreeMap<String, CoverageEntry>,
+    /// Usages of each field, keyed by field coordinate (`User.name`).
+    pub fields: BTreeMap<String, CoverageEntry>,
+    /// Coordinates of the fields that are never selected.
+    pub unused_fields: Vec<String>,
+}
+
+/// Compute the schema coverage of the given programs. Projects sharing a
+/// schema type have their usages merged.
+pub fn compute_schema_coverage<'a>(
+    all_programs: impl IntoIterator<Item = &'a Programs>,
+) -> SchemaCoverage {
+    let mut coverage = SchemaCoverage::default();
+    for programs in all_programs {
+        let schema = &programs.source.schema;
+        let mut field_usages = FieldUsagesCollector::default();
+        visit_program(&mut field_usages, &programs.reader, true);
+        field_usages.is_normalization = true;
+        visit_program(&mut field_usages, &programs.normalization, false);
+
+        for (_, type_) in schema.get_type_map() {
+            let fields = match type_ {
+                Type::Object(id) if !schema.object(*id).is_extension => &schema.object(*id).fields,
+                Type::Interface(id) if !schema.interface(*id).is_extension => {
+                    &schema.interface(*id).fields
+                }
+                _ => continue,
+            };
+            let type_coordinate = SchemaCoordinate::Type {
+                name: schema.get_type_name(*type_),
+            }
+            .to_string();
+            let type_entry = coverage.types.entry(type_coordinate).or_default();
+            for field_id in fields {
+                let field = schema.field(*field_id);
+                if field.is_extension {
+                    continue;
+                }
+                let field_coordinate = SchemaCoordinate::Member {
+                    parent_name: schema.get_type_name(*type_),
+                    member_name: field.name.item,
+                }
+                .to_string();
+                let field_entry = coverage.fields.entry(field_coordinate).or_default();
+                if let Some(usage) = field_usages.fields.get(field_id) {
+                    field_entry.merge(usage);
+                    type_entry.merge(usage);
+                }
+            }
+        }
+    }
+
+    coverage.unused_fields = coverage
+        .fields
+        .iter()
+        .filter(|(_, entry)| !entry.is_used())
+        .map(|(coordinate, _)| coordinate.clone())
+        .collect();
+    coverage
+}
+
+fn visit_program(collector: &mut FieldUsagesCollector, program: &Program, include_fragments: bool) {
+    for operation in program.operations() {
+        collector.visit_operation(operation);
+    }
+    if include_fragments {
+        for fragment in program.fragments() {
+            collector.visit_fragment(fragment);
+        }
+    }
+}
+
+#[derive(Default)]
+struct FieldUsagesCollector {
+    is_normalization: bool,
+    fields: FxHashMap<FieldID, CoverageEntry>,
+}
+
+impl FieldUsagesCollector {
+    fn record(&mut self, definition: &WithLocation<FieldID>) {
+        let entry = self.fields.entry(definition.item).or_default();
+        if self.is_normalization {
+            entry.normalization_usages += 1;
+        } else {
+            entry.reader_usages += 1;
+        }
+        let source_location = definition.location.source_location();
+        if !source_location.is_generated() {
+            entry.files.insert(source_location.path());
+        }
+    }
+}
+
+impl Visitor for FieldUsagesCollector {
+    const NAME: &'static str = "FieldUsagesCollector";
+    const VISIT_ARGUMENTS: bool = false;
+    const VISIT_DIRECTIVES: bool = false;
+
+    fn visit_scalar_field(&mut self, field: &ScalarField) {
+        self.record(&field.definition);
+    }
+
+    fn visit_linked_field(&mut self, field: &LinkedField) {
+        self.record(&field.definition);
+        self.default_visit_linked_field(field);
+    }
+}
@@ -0,0 +1,324 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Verification of the files in the artifact directories, without compiling
+//! the projects: signatures are checked against the content of every signed
+//! artifact, and the artifacts on disk are compared with the artifact map of
+//! a saved state to find the orphaned and missing ones. The saved state must
+//! have been written from the current sources.
+
+use std::fmt::Write;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use common::PerfLogEvent;
+use common::PerfLogger;
+use fnv::FnvHashMap;
+use fnv::FnvHashSet;
+use relay_config::ProjectName;
+use walkdir::WalkDir;
+
+use crate::FileCategorizer;
+use crate::FileGroup;
+use crate::FileSource;
+use crate::compiler::Compiler;
+use crate::compiler_state::ArtifactMapKind;
+use crate::compiler_state::CompilerState;
+use crate::compiler_state::IncrementalSources;
+use crate::compiler_state::Source;
+use crate::config::Config;
+use crate::errors::Error;
+use crate::errors::Result;
+
+/// Artifacts reported by `verify_artifacts`, as paths relative to the root
+/// directory sorted by path.
+#[derive(Debug, Default)]
+pub struct ArtifactsVerification {
+    /// Signed artifacts whose signature doesn't match their content, such as
+    /// hand edits or merge conflicts.
+    pub edited: Vec<PathBuf>,
+    /// Artifacts of the saved state that are not on disk.
+    pub missing: Vec<PathBuf>,
+    /// Files of the artifact directories that are not in the saved state.
+    pub orphaned: Vec<PathBuf>,
+}
+
+impl ArtifactsVerification {
+    pub fn is_empty(&self) -> bool {
+        self.edited.is_empty() && self.missing.is_empty() && self.orphaned.is_empty()
+    }
+
+    pub fn print(&self) -> String {
+        let mut output = String::new();
+        write_verified_artifacts(
+            &mut output,
+            "\nEdited (the signature doesn't match the content):",
+            &self.edited,
+        );
+        write_verified_artifacts(&mut output, "\nMissing:", &self.missing);
+        write_verified_artifacts(
+            &mut output,
+            "\nOrphaned (not produced by any definition):",
+            &self.orphaned,
+        );
+        output
+    }
+}
+
+/// Checks the files of the artifact directories of the enabled projects.
+///
+/// Orphaned and missing artifacts are only reported with a `saved_state`,
+/// which records the artifacts produced by each definition. A saved state
+/// written before the last changes of the sources is rejected, as its
+/// artifact map doesn't list the artifacts of the current definitions. Files
+/// without a signature, like the JSON artifacts, are not checked for edits.
+pub async fn verify_artifacts(
+    config: &Arc<Config>,
+    perf_logger: &impl PerfLogger,
+    saved_state: Option<&CompilerState>,
+) -> Result<ArtifactsVerification> {
+    let existing_artifacts = find_existing_artifacts(config);
+
+    let mut verification = ArtifactsVerification::default();
+    for path in existing_artifacts.keys() {
+        let content =
+            std::fs::read(config.root_dir.join(path)).map_err(|err| Error::ReadFileError {
+                file: path.clone(),
+                source: err,
+            })?;
+        let content = String::from_utf8_lossy(&content);
+        if signedsource::is_signed(&content) && !signedsource::is_valid_signature(&content) {
+            verification.edited.push(path.clone());
+        }
+    }
+
+    if let Some(saved_state) = saved_state {
+        if saved_state.saved_state_version != config.saved_state_version {
+            return Err(Error::SavedStateVersionMismatch {
+                saved_state_version: saved_state.saved_state_version.clone(),
+                config_version: config.saved_state_version.clone(),
+            });
+        }
+        let changed_sources = find_changed_sources(config, perf_logger, saved_state).await?;
+        if !changed_sources.is_empty() {
+            let mut sources = String::new();
+            write_verified_artifacts(&mut sources, "", &changed_sources);
+            return Err(Error::SavedStateOutOfDate { sources });
+        }
+        let (mapped_projects, expected_artifacts) = get_expected_artifacts(config, saved_state);
+        verification.missing = expected_artifacts
+            .iter()
+            .filter(|path| !existing_artifacts.contains_key(*path))
+            .cloned()
+            .collect();
+        verification.orphaned = existing_artifacts
+            .iter()
+            .filter(|(path, project_name)| {
+                mapped_projects.contains(*project_name) && !expected_artifacts.contains(*path)
+            })
+            .map(|(path, _)| path.clone())
+            .collect();
+    }
+
+    verification.missing.sort();
+    verification.orphaned.sort();
+    // Orphaned artifacts are deleted, whatever their content
+    verification
+        .edited
+        .retain(|path| verification.orphaned.binary_search(path).is_err());
+    verification.edited.sort();
+    Ok(verification)
+}
+
+/// Deletes the orphaned artifacts, then compiles the projects to restore the
+/// edited and missing ones. The projects are compiled even when only orphans
+/// were deleted, so that the artifacts of every current definition are on
+/// disk afterwards.
+pub async fn fix_artifacts(
+    config: Arc<Config>,
+    perf_logger: Arc<impl PerfLogger + 'static>,
+    verification: &ArtifactsVerification,
+) -> Result<()> {
+    for path in &verification.orphaned {
+        config
+            .artifact_writer
+            .remove(config.root_dir.join(path))
+            .map_err(|error| Error::BuildProjectsErrors {
+                errors: vec![error],
+            })?;
+    }
+    Compiler::new(config, perf_logger).compile().await?;
+    Ok(())
+}
+
+/// The source files of the enabled projects whose GraphQL, docblocks, schema
+/// or extensions differ from the ones recorded in the saved state, sorted by
+/// path.
+async fn find_changed_sources(
+    config: &Arc<Config>,
+    perf_logger: &impl PerfLogger,
+    saved_state: &CompilerState,
+) -> Result<Vec<PathBuf>> {
+    let log_event = perf_logger.create_event("verify_artifacts_sources");
+    let file_source = FileSource::connect(config, &log_event).await?;
+    let compiler_state = file_source.query(&log_event, perf_logger).await?;
+    log_event.complete();
+
+    let mut changed_sources = FnvHashSet::default();
+    for project_config in config.enabled_projects() {
+        let project_name = project_config.name;
+        find_changed_paths(
+            &mut changed_sources,
+            compiler_state.graphql_sources.get(&project_name),
+            saved_state.graphql_sources.get(&project_name),
+            |sources| {
+                sources
+                    .iter()
+                    .map(|source| source.graphql_source.text_source().text.as_str())
+                    .collect::<Vec<_>>()
+            },
+        );
+        find_changed_paths(
+            &mut changed_sources,
+            compiler_state.docblocks.get(&project_name),
+            saved_state.docblocks.get(&project_name),
+            |sources| {
+                sources
+                    .iter()
+                    .map(|source| source.docblock_source.text_source().text.as_str())
+                    .collect::<Vec<_>>()
+            },
+        );
+        find_changed_paths(
+            &mut changed_sources,
+            compiler_state.schemas.get(&project_name),
+            saved_state.schemas.get(&project_name),
+            |source| source.as_str(),
+        );
+        find_changed_paths(
+            &mut changed_sources,
+            compiler_state.extensions.get(&project_name),
+            saved_state.extensions.get(&project_name),
+            |source| source.as_str(),
+        );
+    }
+    let mut changed_sources: Vec<_> = changed_sources.into_iter().collect();
+    changed_sources.sort();
+    Ok(changed_sources)
+}
+
+/// Adds the paths whose content differs between the current and the saved
+/// sources, including the files that were added or removed.
+fn find_changed_paths<'a, V: Source + Clone + 'a, T: PartialEq>(
+    changed_paths: &mut FnvHashSet<PathBuf>,
+    current_sources: Option<&'a IncrementalSources<V>>,
+    saved_sources: Option<&'a IncrementalSources<V>>,
+    get_content: impl Fn(&'a V) -> T,
+) {
+    let get_contents = |sources: Option<&'a IncrementalSources<V>>| -> FnvHashMap<&'a PathBuf, T> {
+        sources
+            .map(|sources| {
+                sources
+                    .get_all_non_empty()
+                    .into_iter()
+                    .map(|(path, source)| (path, get_content(source)))
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+    let current_contents = get_contents(current_sources);
+    let saved_contents = get_contents(saved_sources);
+    for (path, content) in &current_contents {
+        if saved_contents.get(path) != Some(content) {
+            changed_paths.insert((*path).clone());
+        }
+    }
+    for path in saved_contents.keys() {
+        if !current_contents.contains_key(path) {
+            changed_paths.insert((*path).clone());
+        }
+    }
+}
+
+/// The files categorized as generated for an enabled project, in all the
+/// roots of the config, with their project. Unlike the file sources,
+/// `excludes` are ignored.
+fn find_existing_artifacts(config: &Config) -> FnvHashMap<PathBuf, ProjectName> {
+    let categorizer = FileCategorizer::from_config(config);
+    config
+        .get_all_roots()
+        .iter()
+        .flat_map(|root| WalkDir::new(config.root_dir.join(root)))
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            if !entry.file_type().is_file() {
+                return None;
+            }
+            let relative_path = entry.path().strip_prefix(&config.root_dir).ok()?;
+            match categorizer.categorize(relative_path, config) {
+                Ok(FileGroup::Generated { project_name })
+                    if config
+                        .projects
+                        .get(&project_name)
+                        .is_some_and(|project_config| project_config.enabled) =>
+                {
+                    Some((relative_path.to_path_buf(), project_name))
+                }
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// The enabled projects with an artifact map in the saved state, and the
+/// artifacts recorded in their artifact maps with the operation registries
+/// written from them.
+///
+/// Only the files of the artifact directories are compared with the saved
+/// state, as the other artifacts are not listed on disk.
+fn get_expected_artifacts(
+    config: &Config,
+    saved_state: &CompilerState,
+) -> (FnvHashSet<ProjectName>, FnvHashSet<PathBuf>) {
+    let categorizer = FileCategorizer::from_config(config);
+    let mut mapped_projects = FnvHashSet::default();
+    let mut expected_artifacts = FnvHashSet::default();
+    for project_config in config.enabled_projects() {
+        let Some(ArtifactMapKind::Mapping(artifact_map)) = saved_state
+            .artifacts
+            .0
+            .get(&project_config.name)
+            .map(|artifact_map| artifact_map.as_ref())
+        else {
+            continue;
+        };
+        mapped_projects.insert(project_config.name);
+        for entry in artifact_map.0.iter() {
+            expected_artifacts.extend(entry.value().iter().map(|record| record.path.clone()));
+        }
+        if let Some(registry_config) = &project_config.operation_registry {
+            expected_artifacts.insert(registry_config.path.clone());
+            expected_artifacts.insert(registry_config.path.with_extension("json"));
+        }
+    }
+    expected_artifacts.retain(|path| {
+        matches!(
+            categorizer.categorize(path, config),
+            Ok(FileGroup::Generated { .. })
+        )
+    });
+    (mapped_projects, expected_artifacts)
+}
+
+fn write_verified_artifacts(output: &mut String, title: &str, artifacts: &[PathBuf]) {
+    if !artifacts.is_empty() {
+        writeln!(output, "{title}").unwrap();
+        for artifact_path in artifacts {
+            writeln!(output, " - {}", artifact_path.display()).unwrap();
+        }
+    }
+}
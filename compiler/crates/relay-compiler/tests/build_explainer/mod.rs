@@ -0,0 +1,107 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use common::ConsoleLogger;
+use fixture_tests::Fixture;
+use graphql_test_helpers::FileChange;
+use graphql_test_helpers::ProjectFixture;
+use graphql_test_helpers::TestDir;
+use relay_compiler::File;
+use relay_compiler::FileSourceKind;
+use relay_compiler::FileSourceResult;
+use relay_compiler::WalkDirFileSourceResult;
+use relay_compiler::compiler::Compiler;
+use relay_compiler::config::Config;
+use relay_compiler::explain::BuildExplainer;
+use relay_compiler::explain::format_explanations;
+
+/// The fixture format for build explainer tests is the project fixture
+/// format of the integration tests, with the file changes of an incremental
+/// build:
+///
+/// ```text
+/// //- src/query.js
+/// graphql`
+///   query FooQuery {
+///     me { name }
+///   }
+/// `;
+///
+/// //- relay.config.json
+/// {
+///   "sources": { "src": "default" },
+///   "projects": {
+///     "default": { "language": "typescript", "schema": "./schema.graphql" }
+///   }
+/// }
+///
+/// //- schema.graphql
+/// type Query { me: User }
+/// type User { name: String }
+///
+/// //-++ src/query.js
+/// graphql`
+///   query FooQuery {
+///     me { name, age }
+///   }
+/// `;
+/// ```
+///
+/// The output is the text explanation of the initial build and of the
+/// incremental build, followed by the JSON explanation of the incremental
+/// build.
+pub async fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let project_fixture = ProjectFixture::deserialize(fixture.content);
+
+    let test_dir = TestDir::new();
+    project_fixture.write_to_dir(test_dir.path());
+
+    let mut config =
+        Config::search(&PathBuf::from(test_dir.path())).map_err(|e| format!("{:#}", e))?;
+    config.file_source_config = FileSourceKind::WalkDir;
+    config.has_schema_change_incremental_build = true;
+    config.build_explainer = Some(BuildExplainer::new(None));
+    let config = Arc::new(config);
+    let explainer = config.build_explainer.as_ref().unwrap();
+
+    let compiler = Compiler::new(Arc::clone(&config), Arc::new(ConsoleLogger));
+    let mut state = compiler.compile().await.map_err(|e| format!("{:#}", e))?;
+    let initial_explanations = explainer.explanations();
+
+    project_fixture.flush_file_changes_to_dir(test_dir.path());
+    let changed_files = project_fixture
+        .file_changes()
+        .iter()
+        .map(|(path, change)| File {
+            name: path.clone(),
+            exists: matches!(change, FileChange::Change(_)),
+        })
+        .collect();
+    state
+        .pending_file_source_changes
+        .write()
+        .unwrap()
+        .push(FileSourceResult::WalkDir(WalkDirFileSourceResult {
+            files: changed_files,
+            resolved_root: config.root_dir.clone(),
+        }));
+    compiler
+        .build_with_changed_files(&mut state)
+        .await
+        .map_err(|e| format!("{:#}", e))?;
+    let incremental_explanations = explainer.explanations();
+
+    Ok(format!(
+        "Initial build:\n{}\nIncremental build:\n{}\n{}",
+        format_explanations(&initial_explanations),
+        format_explanations(&incremental_explanations),
+        serde_json::to_string_pretty(&incremental_explanations).unwrap()
+    ))
+}
@@ -0,0 +1,27 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<e80ad59c0de10a19df3d37dd6ca56b05>>
+ */
+
+mod build_explainer;
+
+use build_explainer::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[tokio::test]
+async fn changed_fragment() {
+    let input = include_str!("build_explainer/fixtures/changed_fragment.input");
+    let expected = include_str!("build_explainer/fixtures/changed_fragment.expected");
+    test_fixture(transform_fixture, file!(), "changed_fragment.input", "build_explainer/fixtures/changed_fragment.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn changed_schema_enum() {
+    let input = include_str!("build_explainer/fixtures/changed_schema_enum.input");
+    let expected = include_str!("build_explainer/fixtures/changed_schema_enum.expected");
+    test_fixture(transform_fixture, file!(), "changed_schema_enum.input", "build_explainer/fixtures/changed_schema_enum.expected", input, expected).await;
+}
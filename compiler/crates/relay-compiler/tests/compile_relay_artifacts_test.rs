@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<a0afe2b86cc3e80c53e8ac9f7e5bb66f>>
+ * @generated SignedSource<<af202632b349b3336339dee46cd3a642>>
  */
 
 mod compile_relay_artifacts;
@@ -166,6 +166,13 @@ async fn append_node_literal_edge_type_name_variable() {
     test_fixture(transform_fixture, file!(), "append-node-literal-edge-type-name-variable.graphql", "compile_relay_artifacts/fixtures/append-node-literal-edge-type-name-variable.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn append_to_list() {
+    let input = include_str!("compile_relay_artifacts/fixtures/append-to-list.graphql");
+    let expected = include_str!("compile_relay_artifacts/fixtures/append-to-list.expected");
+    test_fixture(transform_fixture, file!(), "append-to-list.graphql", "compile_relay_artifacts/fixtures/append-to-list.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn auto_filled_argument_on_defer() {
     let input = include_str!("compile_relay_artifacts/fixtures/auto-filled-argument-on-defer.graphql");
@@ -1321,6 +1328,13 @@ async fn refetchable_fragment_with_connection_with_stream() {
     test_fixture(transform_fixture, file!(), "refetchable-fragment-with-connection-with-stream.graphql", "compile_relay_artifacts/fixtures/refetchable-fragment-with-connection-with-stream.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn refetchable_paginated_list() {
+    let input = include_str!("compile_relay_artifacts/fixtures/refetchable-paginated-list.graphql");
+    let expected = include_str!("compile_relay_artifacts/fixtures/refetchable-paginated-list.expected");
+    test_fixture(transform_fixture, file!(), "refetchable-paginated-list.graphql", "compile_relay_artifacts/fixtures/refetchable-paginated-list.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn refetchable_with_arguments_conflicting_invalid() {
     let input = include_str!("compile_relay_artifacts/fixtures/refetchable-with-arguments-conflicting.invalid.graphql");
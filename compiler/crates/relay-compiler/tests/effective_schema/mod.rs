@@ -0,0 +1,67 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::path::PathBuf;
+
+use fixture_tests::Fixture;
+use graphql_test_helpers::ProjectFixture;
+use graphql_test_helpers::TestDir;
+use relay_compiler::FileSourceKind;
+use relay_compiler::config::Config;
+use relay_compiler::effective_schema::EffectiveSchemaOptions;
+use relay_compiler::effective_schema::build_and_print_effective_schema;
+
+/// The fixture format for effective schema tests is the project fixture
+/// format of the integration tests. The options are set with comments:
+///
+/// ```text
+/// # exclude-client-extensions
+/// # annotate-sources
+/// # shard-count: 2
+///
+/// //- relay.config.json
+/// {
+///   "language": "typescript",
+///   "schema": "./schema.graphql"
+/// }
+///
+/// //- schema.graphql
+/// type Query { me: User }
+/// type User { name: String }
+/// ```
+///
+/// The output is the printed schema, shard by shard.
+pub async fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let project_fixture = ProjectFixture::deserialize(fixture.content);
+
+    let test_dir = TestDir::new();
+    project_fixture.write_to_dir(test_dir.path());
+
+    let mut config =
+        Config::search(&PathBuf::from(test_dir.path())).map_err(|e| format!("{:#}", e))?;
+    config.file_source_config = FileSourceKind::WalkDir;
+
+    let options = EffectiveSchemaOptions {
+        include_client_extensions: !fixture.content.contains("# exclude-client-extensions"),
+        annotate_sources: fixture.content.contains("# annotate-sources"),
+        shard_count: fixture
+            .content
+            .lines()
+            .find_map(|line| line.strip_prefix("# shard-count: "))
+            .map_or(1, |shard_count| shard_count.parse().unwrap()),
+    };
+    let shards = build_and_print_effective_schema(config, &options)
+        .await
+        .map_err(|e| format!("{:#}", e))?;
+
+    Ok(shards
+        .iter()
+        .enumerate()
+        .map(|(index, shard)| format!("# Shard {index}\n{shard}"))
+        .collect::<Vec<_>>()
+        .join("\n"))
+}
@@ -0,0 +1,59 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<40935c2184de6c5e4a8cf50ba90b18f1>>
+ */
+
+mod effective_schema;
+
+use effective_schema::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[tokio::test]
+async fn client_extensions_and_resolvers() {
+    let input = include_str!("effective_schema/fixtures/client_extensions_and_resolvers.input");
+    let expected =
+        include_str!("effective_schema/fixtures/client_extensions_and_resolvers.expected");
+    test_fixture(
+        transform_fixture,
+        file!(),
+        "client_extensions_and_resolvers.input",
+        "effective_schema/fixtures/client_extensions_and_resolvers.expected",
+        input,
+        expected,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn sharded() {
+    let input = include_str!("effective_schema/fixtures/sharded.input");
+    let expected = include_str!("effective_schema/fixtures/sharded.expected");
+    test_fixture(
+        transform_fixture,
+        file!(),
+        "sharded.input",
+        "effective_schema/fixtures/sharded.expected",
+        input,
+        expected,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn without_client_extensions() {
+    let input = include_str!("effective_schema/fixtures/without_client_extensions.input");
+    let expected = include_str!("effective_schema/fixtures/without_client_extensions.expected");
+    test_fixture(
+        transform_fixture,
+        file!(),
+        "without_client_extensions.input",
+        "effective_schema/fixtures/without_client_extensions.expected",
+        input,
+        expected,
+    )
+    .await;
+}
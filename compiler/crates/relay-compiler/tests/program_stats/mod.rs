@@ -0,0 +1,64 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::path::PathBuf;
+
+use fixture_tests::Fixture;
+use graphql_test_helpers::ProjectFixture;
+use graphql_test_helpers::TestDir;
+use relay_compiler::FileSourceKind;
+use relay_compiler::compile_programs;
+use relay_compiler::config::Config;
+use relay_compiler::program_stats::compute_program_stats;
+
+/// The fixture format for program stats tests:
+///
+/// ```text
+/// //- src/query.js
+/// graphql`
+///   query FooQuery {
+///     me { name }
+///   }
+/// `;
+///
+/// //- relay.config.json
+/// {
+///   "language": "typescript",
+///   "schema": "./schema.graphql"
+/// }
+///
+/// //- schema.graphql
+/// type Query { me: User }
+/// type User { name: String, age: Int }
+/// ```
+///
+/// The output is the text report of each project, followed by the JSON
+/// report of all projects.
+pub async fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let project_fixture = ProjectFixture::deserialize(fixture.content);
+
+    let test_dir = TestDir::new();
+    project_fixture.write_to_dir(test_dir.path());
+
+    let mut config =
+        Config::search(&PathBuf::from(test_dir.path())).map_err(|e| format!("{:#}", e))?;
+    config.file_source_config = FileSourceKind::WalkDir;
+
+    let (programs, _, config) = compile_programs(config)
+        .await
+        .map_err(|e| format!("{:#}", e))?;
+    let stats = compute_program_stats(&config, &programs, 3);
+
+    let mut output = stats
+        .iter()
+        .map(|project_stats| project_stats.format_report(3))
+        .collect::<Vec<_>>()
+        .join("\n");
+    output.push_str("\n\n");
+    output.push_str(&serde_json::to_string_pretty(&stats).unwrap());
+    Ok(output)
+}
@@ -0,0 +1,27 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<c70ac593d2e22d60bd1f906097b820ef>>
+ */
+
+mod program_stats;
+
+use program_stats::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[tokio::test]
+async fn empty_project() {
+    let input = include_str!("program_stats/fixtures/empty_project.input");
+    let expected = include_str!("program_stats/fixtures/empty_project.expected");
+    test_fixture(transform_fixture, file!(), "empty_project.input", "program_stats/fixtures/empty_project.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn fragments_and_operations() {
+    let input = include_str!("program_stats/fixtures/fragments_and_operations.input");
+    let expected = include_str!("program_stats/fixtures/fragments_and_operations.expected");
+    test_fixture(transform_fixture, file!(), "fragments_and_operations.input", "program_stats/fixtures/fragments_and_operations.expected", input, expected).await;
+}
@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<baa09f0d4a4f275268990ab3c4f1420e>>
+ * @generated SignedSource<<1b3252057a90c042e12b44ca28813831>>
  */
 
 mod relay_compiler_integration;
@@ -89,6 +89,13 @@ async fn config_validation_excluded_source_directory() {
     test_fixture(transform_fixture, file!(), "config_validation_excluded_source_directory.input", "relay_compiler_integration/fixtures/config_validation_excluded_source_directory.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn config_validation_generate_enum_modules_missing_options() {
+    let input = include_str!("relay_compiler_integration/fixtures/config_validation_generate_enum_modules_missing_options.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/config_validation_generate_enum_modules_missing_options.expected");
+    test_fixture(transform_fixture, file!(), "config_validation_generate_enum_modules_missing_options.input", "relay_compiler_integration/fixtures/config_validation_generate_enum_modules_missing_options.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn config_validation_invalid_exclude_glob() {
     let input = include_str!("relay_compiler_integration/fixtures/config_validation_invalid_exclude_glob.input");
@@ -103,6 +110,13 @@ async fn config_validation_invalid_excludes_extensions_glob() {
     test_fixture(transform_fixture, file!(), "config_validation_invalid_excludes_extensions_glob.input", "relay_compiler_integration/fixtures/config_validation_invalid_excludes_extensions_glob.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn config_validation_lazy_normalization_missing_provider() {
+    let input = include_str!("relay_compiler_integration/fixtures/config_validation_lazy_normalization_missing_provider.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/config_validation_lazy_normalization_missing_provider.expected");
+    test_fixture(transform_fixture, file!(), "config_validation_lazy_normalization_missing_provider.input", "relay_compiler_integration/fixtures/config_validation_lazy_normalization_missing_provider.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn config_validation_missing_schema_extension_directory() {
     let input = include_str!("relay_compiler_integration/fixtures/config_validation_missing_schema_extension_directory.input");
@@ -124,6 +138,13 @@ async fn config_validation_missing_source_directory() {
     test_fixture(transform_fixture, file!(), "config_validation_missing_source_directory.input", "relay_compiler_integration/fixtures/config_validation_missing_source_directory.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn config_validation_operation_registry_missing_persist_config() {
+    let input = include_str!("relay_compiler_integration/fixtures/config_validation_operation_registry_missing_persist_config.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/config_validation_operation_registry_missing_persist_config.expected");
+    test_fixture(transform_fixture, file!(), "config_validation_operation_registry_missing_persist_config.input", "relay_compiler_integration/fixtures/config_validation_operation_registry_missing_persist_config.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn config_validation_project_missing() {
     let input = include_str!("relay_compiler_integration/fixtures/config_validation_project_missing.input");
@@ -166,6 +187,13 @@ async fn delete_match_removes_normalization() {
     test_fixture(transform_fixture, file!(), "delete_match_removes_normalization.input", "relay_compiler_integration/fixtures/delete_match_removes_normalization.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn enum_modules() {
+    let input = include_str!("relay_compiler_integration/fixtures/enum_modules.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/enum_modules.expected");
+    test_fixture(transform_fixture, file!(), "enum_modules.input", "relay_compiler_integration/fixtures/enum_modules.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn error_handling_fragment() {
     let input = include_str!("relay_compiler_integration/fixtures/error_handling_fragment.input");
@@ -257,6 +285,13 @@ async fn incremental_delete_module_fragment() {
     test_fixture(transform_fixture, file!(), "incremental_delete_module_fragment.input", "relay_compiler_integration/fixtures/incremental_delete_module_fragment.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn incremental_enum_modules_removed() {
+    let input = include_str!("relay_compiler_integration/fixtures/incremental_enum_modules_removed.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/incremental_enum_modules_removed.expected");
+    test_fixture(transform_fixture, file!(), "incremental_enum_modules_removed.input", "relay_compiler_integration/fixtures/incremental_enum_modules_removed.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn incremental_file_deletion() {
     let input = include_str!("relay_compiler_integration/fixtures/incremental_file_deletion.input");
@@ -313,6 +348,13 @@ async fn incremental_object_removed_with_interface() {
     test_fixture(transform_fixture, file!(), "incremental_object_removed_with_interface.input", "relay_compiler_integration/fixtures/incremental_object_removed_with_interface.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn incremental_operation_registry() {
+    let input = include_str!("relay_compiler_integration/fixtures/incremental_operation_registry.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/incremental_operation_registry.expected");
+    test_fixture(transform_fixture, file!(), "incremental_operation_registry.input", "relay_compiler_integration/fixtures/incremental_operation_registry.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn incremental_schema_change_with_base_project() {
     let input = include_str!("relay_compiler_integration/fixtures/incremental_schema_change_with_base_project.input");
@@ -327,6 +369,27 @@ async fn incremental_schema_field_nullability_change() {
     test_fixture(transform_fixture, file!(), "incremental_schema_field_nullability_change.input", "relay_compiler_integration/fixtures/incremental_schema_field_nullability_change.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn incremental_share_normalization_selections() {
+    let input = include_str!("relay_compiler_integration/fixtures/incremental_share_normalization_selections.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/incremental_share_normalization_selections.expected");
+    test_fixture(transform_fixture, file!(), "incremental_share_normalization_selections.input", "relay_compiler_integration/fixtures/incremental_share_normalization_selections.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn lazy_normalization() {
+    let input = include_str!("relay_compiler_integration/fixtures/lazy_normalization.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/lazy_normalization.expected");
+    test_fixture(transform_fixture, file!(), "lazy_normalization.input", "relay_compiler_integration/fixtures/lazy_normalization.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn lazy_normalization_haste() {
+    let input = include_str!("relay_compiler_integration/fixtures/lazy_normalization_haste.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/lazy_normalization_haste.expected");
+    test_fixture(transform_fixture, file!(), "lazy_normalization_haste.input", "relay_compiler_integration/fixtures/lazy_normalization_haste.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn live_resolver_implements_interface_field() {
     let input = include_str!("relay_compiler_integration/fixtures/live_resolver_implements_interface_field.input");
@@ -383,6 +446,20 @@ async fn mixed_interface_server_type_does_not_implement_node_invalid() {
     test_fixture(transform_fixture, file!(), "mixed_interface_server_type_does_not_implement_node.invalid.input", "relay_compiler_integration/fixtures/mixed_interface_server_type_does_not_implement_node.invalid.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn mock_payloads() {
+    let input = include_str!("relay_compiler_integration/fixtures/mock_payloads.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/mock_payloads.expected");
+    test_fixture(transform_fixture, file!(), "mock_payloads.input", "relay_compiler_integration/fixtures/mock_payloads.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn mock_payloads_defer_stream() {
+    let input = include_str!("relay_compiler_integration/fixtures/mock_payloads_defer_stream.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/mock_payloads_defer_stream.expected");
+    test_fixture(transform_fixture, file!(), "mock_payloads_defer_stream.input", "relay_compiler_integration/fixtures/mock_payloads_defer_stream.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn module_directive_no_config() {
     let input = include_str!("relay_compiler_integration/fixtures/module_directive_no_config.input");
@@ -446,6 +523,13 @@ async fn non_relay_file_in_generated_dir_with_custom_output() {
     test_fixture(transform_fixture, file!(), "non_relay_file_in_generated_dir_with_custom_output.input", "relay_compiler_integration/fixtures/non_relay_file_in_generated_dir_with_custom_output.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn operation_registry() {
+    let input = include_str!("relay_compiler_integration/fixtures/operation_registry.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/operation_registry.expected");
+    test_fixture(transform_fixture, file!(), "operation_registry.input", "relay_compiler_integration/fixtures/operation_registry.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn prefetchable_pagination_rename_fragment_removes_edges() {
     let input = include_str!("relay_compiler_integration/fixtures/prefetchable_pagination_rename_fragment_removes_edges.input");
@@ -516,6 +600,13 @@ async fn required_conditional_field() {
     test_fixture(transform_fixture, file!(), "required_conditional_field.input", "relay_compiler_integration/fixtures/required_conditional_field.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn rescript() {
+    let input = include_str!("relay_compiler_integration/fixtures/rescript.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/rescript.expected");
+    test_fixture(transform_fixture, file!(), "rescript.input", "relay_compiler_integration/fixtures/rescript.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn resolver_on_interface() {
     let input = include_str!("relay_compiler_integration/fixtures/resolver_on_interface.input");
@@ -957,6 +1048,13 @@ async fn shadow_return_directive_in_user_source_invalid() {
     test_fixture(transform_fixture, file!(), "shadow_return_directive_in_user_source.invalid.input", "relay_compiler_integration/fixtures/shadow_return_directive_in_user_source.invalid.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn share_normalization_selections() {
+    let input = include_str!("relay_compiler_integration/fixtures/share_normalization_selections.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/share_normalization_selections.expected");
+    test_fixture(transform_fixture, file!(), "share_normalization_selections.input", "relay_compiler_integration/fixtures/share_normalization_selections.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn simple_fragment() {
     let input = include_str!("relay_compiler_integration/fixtures/simple_fragment.input");
@@ -992,6 +1090,20 @@ async fn spread_multiple_interface_fragments_on_concrete_type() {
     test_fixture(transform_fixture, file!(), "spread_multiple_interface_fragments_on_concrete_type.input", "relay_compiler_integration/fixtures/spread_multiple_interface_fragments_on_concrete_type.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn strict_schema_validation() {
+    let input = include_str!("relay_compiler_integration/fixtures/strict_schema_validation.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/strict_schema_validation.expected");
+    test_fixture(transform_fixture, file!(), "strict_schema_validation.input", "relay_compiler_integration/fixtures/strict_schema_validation.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn strict_schema_validation_disabled() {
+    let input = include_str!("relay_compiler_integration/fixtures/strict_schema_validation_disabled.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/strict_schema_validation_disabled.expected");
+    test_fixture(transform_fixture, file!(), "strict_schema_validation_disabled.input", "relay_compiler_integration/fixtures/strict_schema_validation_disabled.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn typescript_resolver_type_import() {
     let input = include_str!("relay_compiler_integration/fixtures/typescript_resolver_type_import.input");
@@ -1005,3 +1117,10 @@ async fn typescript_resolver_with_context() {
     let expected = include_str!("relay_compiler_integration/fixtures/typescript_resolver_with_context.expected");
     test_fixture(transform_fixture, file!(), "typescript_resolver_with_context.input", "relay_compiler_integration/fixtures/typescript_resolver_with_context.expected", input, expected).await;
 }
+
+#[tokio::test]
+async fn zod_schemas() {
+    let input = include_str!("relay_compiler_integration/fixtures/zod_schemas.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/zod_schemas.expected");
+    test_fixture(transform_fixture, file!(), "zod_schemas.input", "relay_compiler_integration/fixtures/zod_schemas.expected", input, expected).await;
+}
@@ -0,0 +1,27 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<3766749b7f65cad63eef2da508685951>>
+ */
+
+mod schema_coverage;
+
+use schema_coverage::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[tokio::test]
+async fn fragments_and_operations() {
+    let input = include_str!("schema_coverage/fixtures/fragments_and_operations.input");
+    let expected = include_str!("schema_coverage/fixtures/fragments_and_operations.expected");
+    test_fixture(transform_fixture, file!(), "fragments_and_operations.input", "schema_coverage/fixtures/fragments_and_operations.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn multiple_projects() {
+    let input = include_str!("schema_coverage/fixtures/multiple_projects.input");
+    let expected = include_str!("schema_coverage/fixtures/multiple_projects.expected");
+    test_fixture(transform_fixture, file!(), "multiple_projects.input", "schema_coverage/fixtures/multiple_projects.expected", input, expected).await;
+}
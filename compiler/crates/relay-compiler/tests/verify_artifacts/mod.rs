@@ -0,0 +1,118 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use common::ConsoleLogger;
+use fixture_tests::Fixture;
+use graphql_test_helpers::ProjectFixture;
+use graphql_test_helpers::TestDir;
+use relay_compiler::FileSourceKind;
+use relay_compiler::compiler::Compiler;
+use relay_compiler::compiler_state::CompilerState;
+use relay_compiler::config::Config;
+use relay_compiler::errors::Error;
+use relay_compiler::verify_artifacts::fix_artifacts;
+use relay_compiler::verify_artifacts::verify_artifacts;
+
+/// The fixture format for artifact verification tests is the project fixture
+/// format of the integration tests. The project is compiled, then the file
+/// changes are applied, to edit, add or delete artifacts or sources:
+///
+/// ```text
+/// //- src/query.js
+/// graphql`
+///   query FooQuery {
+///     me { name }
+///   }
+/// `;
+///
+/// //- relay.config.json
+/// {
+///   "sources": { "src": "default" },
+///   "projects": {
+///     "default": { "language": "typescript", "schema": "./schema.graphql" }
+///   }
+/// }
+///
+/// //- schema.graphql
+/// type Query { me: User }
+/// type User { name: String }
+///
+/// //-++ src/__generated__/FooQuery.graphql.ts
+/// <<<<<<< HEAD
+/// ```
+///
+/// The saved state of the build is given to the verification. The output is
+/// the result of verifying the artifacts, of verifying them with `fix`, and
+/// of verifying them again after the fix.
+pub async fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let project_fixture = ProjectFixture::deserialize(fixture.content);
+
+    let test_dir = TestDir::new();
+    project_fixture.write_to_dir(test_dir.path());
+
+    let config = load_config(test_dir.path().into())?;
+    let compiler_state = Compiler::new(Arc::new(config), Arc::new(ConsoleLogger))
+        .compile()
+        .await
+        .map_err(|e| format!("{:#}", e))?;
+    let saved_state_dir = TestDir::new();
+    let saved_state_path = saved_state_dir.path().join("saved_state");
+    compiler_state
+        .serialize_to_file(&saved_state_path)
+        .map_err(|e| format!("{:#}", e))?;
+
+    project_fixture.flush_file_changes_to_dir(test_dir.path());
+
+    let verify = verify_test_dir(test_dir.path().into(), &saved_state_path, false).await;
+    let fix = verify_test_dir(test_dir.path().into(), &saved_state_path, true).await;
+    let verify_after_fix = verify_test_dir(test_dir.path().into(), &saved_state_path, false).await;
+
+    Ok(format!(
+        "Verify:\n{}\n\nVerify with fix:\n{}\n\nVerify after fix:\n{}",
+        verify, fix, verify_after_fix
+    ))
+}
+
+async fn verify_test_dir(root_dir: PathBuf, saved_state_path: &PathBuf, fix: bool) -> String {
+    let config = match load_config(root_dir) {
+        Ok(config) => config,
+        Err(error) => return error,
+    };
+    let saved_state = match CompilerState::deserialize_from_file(saved_state_path) {
+        Ok(saved_state) => saved_state,
+        Err(error) => return format!("{:#}", error),
+    };
+    let config = Arc::new(config);
+    let verification = match verify_artifacts(&config, &ConsoleLogger, Some(&saved_state)).await {
+        Ok(verification) => verification,
+        Err(error) => return format!("{:#}", error),
+    };
+    if verification.is_empty() {
+        "OK".to_string()
+    } else if fix {
+        match fix_artifacts(config, Arc::new(ConsoleLogger), &verification).await {
+            Ok(_) => format!("Fixed artifacts:{}", verification.print()),
+            Err(error) => format!("{:#}", error),
+        }
+    } else {
+        format!(
+            "{}",
+            Error::ArtifactsVerificationError {
+                error: verification.print(),
+            }
+        )
+    }
+}
+
+fn load_config(root_dir: PathBuf) -> Result<Config, String> {
+    let mut config = Config::search(&root_dir).map_err(|e| format!("{:#}", e))?;
+    config.file_source_config = FileSourceKind::WalkDir;
+    Ok(config)
+}
@@ -0,0 +1,41 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<2bada3fba7e927a153b399fdecfff008>>
+ */
+
+mod verify_artifacts;
+
+use verify_artifacts::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[tokio::test]
+async fn corrupted_artifacts() {
+    let input = include_str!("verify_artifacts/fixtures/corrupted_artifacts.input");
+    let expected = include_str!("verify_artifacts/fixtures/corrupted_artifacts.expected");
+    test_fixture(transform_fixture, file!(), "corrupted_artifacts.input", "verify_artifacts/fixtures/corrupted_artifacts.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn out_of_date_artifacts() {
+    let input = include_str!("verify_artifacts/fixtures/out_of_date_artifacts.input");
+    let expected = include_str!("verify_artifacts/fixtures/out_of_date_artifacts.expected");
+    test_fixture(transform_fixture, file!(), "out_of_date_artifacts.input", "verify_artifacts/fixtures/out_of_date_artifacts.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn stale_saved_state() {
+    let input = include_str!("verify_artifacts/fixtures/stale_saved_state.input");
+    let expected = include_str!("verify_artifacts/fixtures/stale_saved_state.expected");
+    test_fixture(transform_fixture, file!(), "stale_saved_state.input", "verify_artifacts/fixtures/stale_saved_state.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn up_to_date_artifacts() {
+    let input = include_str!("verify_artifacts/fixtures/up_to_date_artifacts.input");
+    let expected = include_str!("verify_artifacts/fixtures/up_to_date_artifacts.expected");
+    test_fixture(transform_fixture, file!(), "up_to_date_artifacts.input", "verify_artifacts/fixtures/up_to_date_artifacts.expected", input, expected).await;
+}
@@ -0,0 +1,29 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::ModuleProvider;
+
+/// Configuration for lazily loaded normalization ASTs.
+///
+/// The normalization AST of each query is generated into a sibling
+/// `<OperationName>$normalization.graphql` artifact, and the request artifact
+/// references it through an `operationModuleProvider` instead of inlining it.
+/// Refetch, client edge and `@preloadable` queries keep their normalization
+/// AST inlined. The runtime loads the AST in `fetchQuery`, `loadQuery` and the
+/// query hooks before executing the query.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, JsonSchema)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct LazyNormalizationConfig {
+    /// Defines the import statement generated for the `operationModuleProvider`
+    /// of the request. Defaults to the `operationModuleProvider` or the
+    /// `dynamicModuleProvider` of `moduleImportConfig`.
+    pub provider: Option<ModuleProvider>,
+}
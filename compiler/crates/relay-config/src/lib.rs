@@ -13,8 +13,12 @@ mod connection_interface;
 mod defer_stream_interface;
 mod diagnostic_report_config;
 mod js_module_format;
+mod lazy_normalization_config;
+mod mock_payloads_config;
 mod module_import_config;
 mod non_node_id_fields_config;
+mod operation_registry_config;
+mod paginated_list_interface;
 mod project_config;
 mod project_name;
 mod resolvers_schema_module_config;
@@ -25,10 +29,14 @@ pub use defer_stream_interface::DeferStreamInterface;
 pub use diagnostic_report_config::DiagnosticLevel;
 pub use diagnostic_report_config::DiagnosticReportConfig;
 pub use js_module_format::JsModuleFormat;
+pub use lazy_normalization_config::LazyNormalizationConfig;
+pub use mock_payloads_config::MockPayloadsConfig;
 pub use module_import_config::ModuleImportConfig;
 pub use module_import_config::ModuleProvider;
 pub use module_import_config::Surface;
 pub use non_node_id_fields_config::NonNodeIdFieldsConfig;
+pub use operation_registry_config::OperationRegistryConfig;
+pub use paginated_list_interface::PaginatedListInterface;
 pub use project_config::ExtraArtifactsConfig;
 pub use project_config::LocalPersistAlgorithm;
 pub use project_config::LocalPersistConfig;
@@ -45,3 +53,4 @@ pub use typegen_config::OneOfGeneration;
 pub use typegen_config::ResolverContextTypeInput;
 pub use typegen_config::TypegenConfig;
 pub use typegen_config::TypegenLanguage;
+pub use typegen_config::ZodConfig;
@@ -0,0 +1,29 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::ScalarName;
+use fnv::FnvBuildHasher;
+use indexmap::IndexMap;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+type FnvIndexMap<K, V> = IndexMap<K, V, FnvBuildHasher>;
+
+/// Configuration for the mock payloads generated next to each operation
+/// artifact, as `<OperationName>.mock.json`.
+#[derive(Default, Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct MockPayloadsConfig {
+    /// A map from custom GraphQL scalar types to the value used in mock
+    /// payloads, example:
+    /// { "DateTime": "2020-01-01T00:00:00Z" }
+    /// Nullable fields of other custom scalars are mocked as `null`.
+    #[serde(default)]
+    pub custom_scalar_values: FnvIndexMap<ScalarName, Value>,
+}
@@ -0,0 +1,25 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::path::PathBuf;
+
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Configuration for the operation registry of a project.
+///
+/// The registry maps the persisted id and the name of every `@preloadable`
+/// operation to lazy imports of its `$parameters` and operation artifacts.
+/// It is written as a module at `path`, and as JSON next to it.
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct OperationRegistryConfig {
+    /// Path of the generated registry module, relative to the root directory.
+    /// The JSON registry is written to the same path with a `.json` extension.
+    pub path: PathBuf,
+}
@@ -0,0 +1,34 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use intern::string_key::Intern;
+use intern::string_key::StringKey;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Configuration where Relay should expect the fields and arguments of
+/// offset based lists annotated with `@paginatedList` in the schema.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct PaginatedListInterface {
+    pub items: StringKey,
+    pub total_count: StringKey,
+    pub offset: StringKey,
+    pub limit: StringKey,
+}
+
+impl Default for PaginatedListInterface {
+    fn default() -> Self {
+        PaginatedListInterface {
+            items: "items".intern(),
+            total_count: "totalCount".intern(),
+            offset: "offset".intern(),
+            limit: "limit".intern(),
+        }
+    }
+}
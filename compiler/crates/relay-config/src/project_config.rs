@@ -37,9 +37,13 @@ use crate::TypegenLanguage;
 use crate::connection_interface::ConnectionInterface;
 use crate::defer_stream_interface::DeferStreamInterface;
 use crate::diagnostic_report_config::DiagnosticReportConfig;
+use crate::lazy_normalization_config::LazyNormalizationConfig;
+use crate::mock_payloads_config::MockPayloadsConfig;
 use crate::module_import_config::ModuleImportConfig;
 use crate::module_import_config::ModuleProvider;
 use crate::non_node_id_fields_config::NonNodeIdFieldsConfig;
+use crate::operation_registry_config::OperationRegistryConfig;
+use crate::paginated_list_interface::PaginatedListInterface;
 use crate::resolvers_schema_module_config::ResolversSchemaModuleConfig;
 
 type FnvIndexMap<K, V> = IndexMap<K, V, FnvBuildHasher>;
@@ -204,6 +208,11 @@ pub struct SchemaConfig {
     #[serde(default)]
     pub connection_interface: ConnectionInterface,
 
+    /// Configuration for the field and argument names of offset based lists
+    /// annotated with `@paginatedList`.
+    #[serde(default)]
+    pub paginated_list_interface: PaginatedListInterface,
+
     /// The name of the `id` field that exists on the `Node` interface.
     #[serde(default = "default_node_interface_id_field")]
     pub node_interface_id_field: StringKey,
@@ -247,6 +256,7 @@ impl Default for SchemaConfig {
     fn default() -> Self {
         Self {
             connection_interface: ConnectionInterface::default(),
+            paginated_list_interface: PaginatedListInterface::default(),
             defer_stream_interface: DeferStreamInterface::default(),
             node_interface_id_field: default_node_interface_id_field(),
             node_interface_id_variable_name: default_node_interface_id_variable_name(),
@@ -318,6 +328,18 @@ pub struct ProjectConfig {
     /// Treats JS module paths as relative to './' when true, and leaves JS
     /// module paths unmodified when false.
     pub relativize_js_module_paths: bool,
+    /// Configuration for the mock payloads generated for each operation.
+    /// No mock payloads are generated when absent.
+    pub mock_payloads: Option<MockPayloadsConfig>,
+    /// Configuration for the registry of preloadable operations.
+    /// No registry is generated when absent.
+    pub operation_registry: Option<OperationRegistryConfig>,
+    /// Configuration for lazily loaded normalization ASTs. The normalization
+    /// AST is inlined in request artifacts when absent.
+    pub lazy_normalization: Option<LazyNormalizationConfig>,
+    /// Whether the language server reports fields selected by fragments that
+    /// the module owning them never reads.
+    pub report_unused_fields: bool,
 }
 
 impl Default for ProjectConfig {
@@ -350,6 +372,10 @@ impl Default for ProjectConfig {
             codegen_command: Default::default(),
             get_custom_path_for_artifact: None,
             relativize_js_module_paths: true,
+            mock_payloads: None,
+            operation_registry: None,
+            lazy_normalization: None,
+            report_unused_fields: false,
         }
     }
 }
@@ -384,6 +410,10 @@ impl Debug for ProjectConfig {
             codegen_command,
             get_custom_path_for_artifact: _,
             relativize_js_module_paths,
+            mock_payloads,
+            operation_registry,
+            lazy_normalization,
+            report_unused_fields,
         } = self;
         f.debug_struct("ProjectConfig")
             .field("name", name)
@@ -412,6 +442,10 @@ impl Debug for ProjectConfig {
             .field("resolvers_schema_module", resolvers_schema_module)
             .field("codegen_command", codegen_command)
             .field("relativize_js_module_paths", relativize_js_module_paths)
+            .field("mock_payloads", mock_payloads)
+            .field("operation_registry", operation_registry)
+            .field("lazy_normalization", lazy_normalization)
+            .field("report_unused_fields", report_unused_fields)
             .finish()
     }
 }
@@ -532,7 +566,7 @@ impl ProjectConfig {
         artifact_file_name: String,
     ) -> PathBuf {
         let filename = match &self.typegen_config.language {
-            TypegenLanguage::Flow | TypegenLanguage::JavaScript => {
+            TypegenLanguage::Flow | TypegenLanguage::JavaScript | TypegenLanguage::ReScript => {
                 format!("{artifact_file_name}.js")
             }
             TypegenLanguage::TypeScript => format!("{artifact_file_name}.ts"),
@@ -559,6 +593,17 @@ impl ProjectConfig {
         }
     }
 
+    /// Returns the provider of the lazily loaded normalization ASTs, if
+    /// `lazy_normalization` is set and a provider can be resolved for it.
+    pub fn lazy_normalization_provider(&self) -> Option<ModuleProvider> {
+        let lazy_normalization = self.lazy_normalization.as_ref()?;
+        let module_import_config = self.effective_module_import_config();
+        lazy_normalization
+            .provider
+            .or(module_import_config.operation_module_provider)
+            .or(module_import_config.dynamic_module_provider)
+    }
+
     /// Generates identifier for importing module at `target_module_path` from module at `importing_artifact_path`.
     /// Import Identifier is a relative path in CommonJS projects and a module name in Haste projects.
     pub fn js_module_import_identifier(
@@ -35,6 +35,7 @@ pub enum TypegenLanguage {
     JavaScript,
     TypeScript,
     Flow,
+    ReScript,
 }
 
 impl TypegenLanguage {
@@ -120,6 +121,19 @@ pub struct ResolverContextTypeInputPackage {
     pub package: StringKey,
 }
 
+/// Configures the Zod schemas generated next to each operation and fragment
+/// artifact, as `<Name>.graphql.zod.{ts,js}`.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct ZodConfig {
+    /// A map from custom GraphQL scalar types to a Zod expression validating
+    /// them, example:
+    /// { "DateTime": "z.string().datetime()" }
+    /// Custom scalars without an entry are accepted without validation.
+    #[serde(default)]
+    pub custom_scalar_schemas: FnvIndexMap<ScalarName, String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct TypegenConfig {
@@ -134,6 +148,13 @@ pub struct TypegenConfig {
     /// value, in the example above it would just import from "Foo".
     pub enum_module_suffix: Option<String>,
 
+    /// When set together with `enumModuleSuffix`, the compiler generates the
+    /// enum modules into the artifact directory instead of expecting them to
+    /// be written by hand. Each module exports the type of the enum and a
+    /// frozen array of its values, and is removed once no artifact uses it.
+    #[serde(default)]
+    pub generate_enum_modules: bool,
+
     /// # For Flow type generation
     /// When set, generated input types will have the listed fields optional
     /// even if the schema defines them as required.
@@ -189,6 +210,23 @@ pub struct TypegenConfig {
     /// generates the type as if the @oneOf annotation does not exist.
     #[serde(default)]
     pub one_of_type: OneOfGeneration,
+
+    /// When set, a Zod schema mirroring the generated variables and response
+    /// types is emitted for each operation and fragment.
+    #[serde(default)]
+    pub zod: Option<ZodConfig>,
+
+    /// When set, values of type `ID` are typed with this generic brand
+    /// helper, keyed by the object type they are the id of, e.g. `ID<"User">`,
+    /// or `ID<string>` when the type is unknown. Example:
+    /// {"name": "ID", "path": "../src/BrandedID"}
+    #[serde(default)]
+    pub branded_id_type: Option<CustomTypeImport>,
+
+    /// Copy the descriptions and deprecation reasons of schema fields and
+    /// input object fields onto the generated properties as doc comments.
+    #[serde(default)]
+    pub emit_doc_comments: bool,
 }
 
 fn get_true() -> bool {
@@ -200,6 +238,7 @@ impl Default for TypegenConfig {
         TypegenConfig {
             language: TypegenLanguage::JavaScript,
             enum_module_suffix: Default::default(),
+            generate_enum_modules: Default::default(),
             optional_input_fields: Default::default(),
             use_import_type_syntax: Default::default(),
             custom_scalar_types: Default::default(),
@@ -210,6 +249,9 @@ impl Default for TypegenConfig {
             custom_error_type: None,
             resolver_context_type: Default::default(),
             one_of_type: Default::default(),
+            zod: None,
+            branded_id_type: None,
+            emit_doc_comments: false,
         }
     }
 }
@@ -418,9 +418,13 @@ fn directive() {
             "catch",
             "required",
             "stream_connection",
+            "paginatedList",
             "match",
             "customDirective",
             "prependNode",
+            "appendToList",
+            "prependToList",
+            "deleteFromList",
             "fixme_fat_interface",
             "stream",
             "include",
@@ -457,9 +461,13 @@ fn directive_on_scalar_field() {
             "catch",
             "required",
             "stream_connection",
+            "paginatedList",
             "match",
             "customDirective",
             "prependNode",
+            "appendToList",
+            "prependToList",
+            "deleteFromList",
             "fixme_fat_interface",
             "stream",
             "include",
@@ -745,9 +753,13 @@ fn empty_directive() {
             "catch",
             "required",
             "stream_connection",
+            "paginatedList",
             "match",
             "customDirective",
             "prependNode",
+            "appendToList",
+            "prependToList",
+            "deleteFromList",
             "fixme_fat_interface",
             "stream",
             "include",
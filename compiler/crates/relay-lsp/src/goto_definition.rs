@@ -14,14 +14,20 @@ use std::sync::Arc;
 
 use common::ArgumentName;
 use common::DirectiveName;
+use common::Span;
 use graphql_ir::FragmentDefinitionName;
+use graphql_syntax::SchemaDocument;
 use intern::string_key::Intern;
 use intern::string_key::StringKey;
 use log::error;
 use log::info;
 use lsp_types::GotoDefinitionResponse;
+use lsp_types::TextDocumentPositionParams;
 use lsp_types::request::GotoDefinition;
+use lsp_types::request::GotoImplementation;
+use lsp_types::request::GotoTypeDefinition;
 use lsp_types::request::Request;
+use schema::ObjectID;
 use schema::SDLSchema;
 use schema::Schema;
 use schema::Type;
@@ -29,8 +35,9 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use self::goto_docblock_definition::get_docblock_definition_description;
-use self::goto_graphql_definition::get_graphql_definition_description;
+pub use self::goto_graphql_definition::get_graphql_definition_description;
 use self::goto_graphql_definition::get_graphql_schema_definition_description;
+pub use self::goto_graphql_definition::get_graphql_schema_implementation_description;
 use crate::FieldDefinitionSourceInfo;
 use crate::FieldSchemaInfo;
 use crate::LSPExtraDataProvider;
@@ -65,31 +72,48 @@ pub enum DefinitionDescription {
     },
 }
 
+/// Resolve the GraphQL definition the user would like to goto from a text document position
+fn get_definition_description(
+    state: &impl GlobalState,
+    text_document_position_params: &TextDocumentPositionParams,
+    schema: &Arc<SDLSchema>,
+    get_schema_definition_description: fn(
+        SchemaDocument,
+        Span,
+    ) -> LSPRuntimeResult<DefinitionDescription>,
+) -> LSPRuntimeResult<DefinitionDescription> {
+    let (feature, location) = state.extract_feature_from_text(text_document_position_params, 1)?;
+    let position_span = location.span();
+
+    match feature {
+        crate::Feature::ExecutableDocument(document) => {
+            get_graphql_definition_description(document, position_span, schema)
+        }
+        crate::Feature::DocblockIr(docblock_ir) => {
+            get_docblock_definition_description(&docblock_ir, position_span)
+        }
+        crate::Feature::SchemaDocument(document) => {
+            get_schema_definition_description(document, position_span)
+        }
+    }
+}
+
 /// Resolve a GotoDefinitionRequest to a GotoDefinitionResponse
 pub fn on_goto_definition(
     state: &impl GlobalState,
     params: <GotoDefinition as Request>::Params,
 ) -> LSPRuntimeResult<<GotoDefinition as Request>::Result> {
-    let (feature, location) =
-        state.extract_feature_from_text(&params.text_document_position_params, 1)?;
-
     let project_name = state
         .extract_project_name_from_uri(&params.text_document_position_params.text_document.uri)?;
     let schema = state.get_schema(&project_name)?;
     let program = state.get_program(&project_name)?;
-    let position_span = location.span();
 
-    let definition_description = match feature {
-        crate::Feature::ExecutableDocument(document) => {
-            get_graphql_definition_description(document, position_span, &schema)?
-        }
-        crate::Feature::DocblockIr(docblock_ir) => {
-            get_docblock_definition_description(&docblock_ir, position_span)?
-        }
-        crate::Feature::SchemaDocument(document) => {
-            get_graphql_schema_definition_description(document, position_span)?
-        }
-    };
+    let definition_description = get_definition_description(
+        state,
+        &params.text_document_position_params,
+        &schema,
+        get_graphql_schema_definition_description,
+    )?;
 
     let extra_data_provider = state.get_extra_data_provider();
     let root_dir = state.root_dir();
@@ -149,6 +173,155 @@ pub fn on_goto_definition(
     Ok(Some(goto_definition_response))
 }
 
+/// Resolve a GotoImplementation request on an abstract type to the locations of
+/// all the concrete object types implementing it
+pub fn on_goto_implementation(
+    state: &impl GlobalState,
+    params: <GotoImplementation as Request>::Params,
+) -> LSPRuntimeResult<<GotoImplementation as Request>::Result> {
+    let project_name = state
+        .extract_project_name_from_uri(&params.text_document_position_params.text_document.uri)?;
+    let schema = state.get_schema(&project_name)?;
+
+    let type_name = match get_definition_description(
+        state,
+        &params.text_document_position_params,
+        &schema,
+        get_graphql_schema_implementation_description,
+    )? {
+        DefinitionDescription::Type { type_name } => type_name,
+        _ => return Err(LSPRuntimeError::ExpectedError),
+    };
+
+    let extra_data_provider = state.get_extra_data_provider();
+    let root_dir = state.root_dir();
+    let locations = get_implementing_objects(&schema, type_name)?
+        .into_iter()
+        .filter_map(|object_id| {
+            locate_type_definition_location(
+                extra_data_provider,
+                project_name,
+                schema.object(object_id).name.item.0,
+                &schema,
+                &root_dir,
+            )
+            .ok()
+        })
+        .collect::<Vec<_>>();
+
+    if locations.is_empty() {
+        return Err(LSPRuntimeError::ExpectedError);
+    }
+
+    Ok(Some(GotoDefinitionResponse::Array(locations)))
+}
+
+/// The concrete object types implementing an interface, recursively, or
+/// members of a union, sorted by name. An object type implements itself.
+pub fn get_implementing_objects(
+    schema: &SDLSchema,
+    type_name: StringKey,
+) -> LSPRuntimeResult<Vec<ObjectID>> {
+    let mut implementing_objects: Vec<ObjectID> = match schema
+        .get_type(type_name)
+        .ok_or(LSPRuntimeError::ExpectedError)?
+    {
+        Type::Interface(interface_id) => schema
+            .interface(interface_id)
+            .recursively_implementing_objects(schema)
+            .into_iter()
+            .collect(),
+        Type::Union(union_id) => schema.union(union_id).members.clone(),
+        Type::Object(object_id) => vec![object_id],
+        _ => return Err(LSPRuntimeError::ExpectedError),
+    };
+    implementing_objects.sort_by_key(|object_id| schema.object(*object_id).name.item);
+    Ok(implementing_objects)
+}
+
+/// Resolve a GotoTypeDefinition request to the definition of the type of the
+/// field, argument or fragment under the cursor
+pub fn on_goto_type_definition(
+    state: &impl GlobalState,
+    params: <GotoTypeDefinition as Request>::Params,
+) -> LSPRuntimeResult<<GotoTypeDefinition as Request>::Result> {
+    let project_name = state
+        .extract_project_name_from_uri(&params.text_document_position_params.text_document.uri)?;
+    let schema = state.get_schema(&project_name)?;
+    let program = state.get_program(&project_name)?;
+
+    let definition_description = get_definition_description(
+        state,
+        &params.text_document_position_params,
+        &schema,
+        get_graphql_schema_definition_description,
+    )?;
+    let type_ = get_definition_type(definition_description, &schema, &program)?;
+
+    let location = locate_type_definition_location(
+        state.get_extra_data_provider(),
+        project_name,
+        schema.get_type_name(type_),
+        &schema,
+        &state.root_dir(),
+    )?;
+
+    Ok(Some(GotoDefinitionResponse::Array(vec![location])))
+}
+
+/// The type of a field, of an argument, or the type condition of a fragment.
+/// Types are their own type, and directives have none.
+pub fn get_definition_type(
+    definition_description: DefinitionDescription,
+    schema: &SDLSchema,
+    program: &graphql_ir::Program,
+) -> LSPRuntimeResult<Type> {
+    match definition_description {
+        DefinitionDescription::Field {
+            parent_type,
+            field_name,
+        } => {
+            let field_id = schema
+                .named_field(parent_type, field_name)
+                .ok_or(LSPRuntimeError::ExpectedError)?;
+            Ok(schema.field(field_id).type_.inner())
+        }
+        DefinitionDescription::FieldArgument {
+            parent_type,
+            field_name,
+            argument_name,
+        } => {
+            let field_id = schema
+                .named_field(parent_type, field_name)
+                .ok_or(LSPRuntimeError::ExpectedError)?;
+            Ok(schema
+                .field(field_id)
+                .arguments
+                .named(argument_name)
+                .ok_or(LSPRuntimeError::ExpectedError)?
+                .type_
+                .inner())
+        }
+        DefinitionDescription::DirectiveArgument {
+            directive_name,
+            argument_name,
+        } => Ok(schema
+            .get_directive(directive_name)
+            .and_then(|directive| directive.arguments.named(argument_name))
+            .ok_or(LSPRuntimeError::ExpectedError)?
+            .type_
+            .inner()),
+        DefinitionDescription::Fragment { fragment_name } => Ok(program
+            .fragment(fragment_name)
+            .ok_or(LSPRuntimeError::ExpectedError)?
+            .type_condition),
+        DefinitionDescription::Type { type_name } => schema
+            .get_type(type_name)
+            .ok_or(LSPRuntimeError::ExpectedError),
+        DefinitionDescription::Directive { .. } => Err(LSPRuntimeError::ExpectedError),
+    }
+}
+
 fn locate_fragment_definition(
     program: graphql_ir::Program,
     fragment_name: FragmentDefinitionName,
@@ -187,6 +360,23 @@ fn locate_type_definition(
     schema: &Arc<SDLSchema>,
     root_dir: &std::path::Path,
 ) -> Result<GotoDefinitionResponse, LSPRuntimeError> {
+    locate_type_definition_location(
+        extra_data_provider,
+        project_name,
+        type_name,
+        schema,
+        root_dir,
+    )
+    .map(GotoDefinitionResponse::Scalar)
+}
+
+fn locate_type_definition_location(
+    extra_data_provider: &dyn LSPExtraDataProvider,
+    project_name: StringKey,
+    type_name: StringKey,
+    schema: &Arc<SDLSchema>,
+    root_dir: &std::path::Path,
+) -> LSPRuntimeResult<lsp_types::Location> {
     let provider_response = extra_data_provider.resolve_field_definition(
         project_name.to_string(),
         type_name.to_string(),
@@ -196,15 +386,17 @@ fn locate_type_definition(
     let field_definition_source_info = get_field_definition_source_info_result(provider_response);
 
     match field_definition_source_info {
-        Ok(source_info) => Ok(if source_info.is_local {
-            GotoDefinitionResponse::Scalar(get_location(
-                &source_info.file_path,
-                source_info.line_number,
-                source_info.column_number,
-            )?)
-        } else {
-            return Err(LSPRuntimeError::ExpectedError);
-        }),
+        Ok(source_info) => {
+            if source_info.is_local {
+                get_location(
+                    &source_info.file_path,
+                    source_info.line_number,
+                    source_info.column_number,
+                )
+            } else {
+                Err(LSPRuntimeError::ExpectedError)
+            }
+        }
         // If we couldn't resolve through the extra data provider, we'll fallback to
         // try to find a location in the server sdl.
         Err(err) => {
@@ -226,7 +418,6 @@ fn locate_type_definition(
                 })
                 .map(|schema_location| {
                     transform_relay_location_on_disk_to_lsp_location(root_dir, schema_location)
-                        .map(GotoDefinitionResponse::Scalar)
                 })
                 .ok_or(LSPRuntimeError::ExpectedError)?
         }
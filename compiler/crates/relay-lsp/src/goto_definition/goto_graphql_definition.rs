@@ -82,6 +82,26 @@ pub fn get_graphql_schema_definition_description(
     }
 }
 
+/// Like `get_graphql_schema_definition_description`, but also resolves the
+/// names of interface and union definitions, which are where one looks for
+/// implementations from in a schema file.
+pub fn get_graphql_schema_implementation_description(
+    document: SchemaDocument,
+    position_span: Span,
+) -> LSPRuntimeResult<DefinitionDescription> {
+    if let ResolutionPath::Ident(IdentPath {
+        inner: type_name,
+        parent:
+            IdentParent::InterfaceTypeDefinitionName(_) | IdentParent::UnionTypeDefinitionName(_),
+    }) = document.resolve((), position_span)
+    {
+        return Ok(DefinitionDescription::Type {
+            type_name: type_name.value,
+        });
+    }
+    get_graphql_schema_definition_description(document, position_span)
+}
+
 pub fn get_graphql_definition_description(
     document: ExecutableDocument,
     position_span: Span,
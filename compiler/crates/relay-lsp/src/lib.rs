@@ -32,6 +32,7 @@ mod resolved_types_at_location;
 mod search_schema_items;
 pub mod server;
 pub mod shutdown;
+pub mod signature_help;
 mod status_reporter;
 pub mod status_updater;
 pub mod text_documents;
@@ -9,6 +9,7 @@
 
 use std::collections::HashMap;
 use std::path::Path;
+use std::path::PathBuf;
 use std::sync::LazyLock;
 
 use common::DirectiveName;
@@ -22,6 +23,7 @@ use graphql_ir::Program;
 use graphql_ir::Visitor;
 use graphql_syntax::ExecutableDefinition;
 use graphql_syntax::OperationDefinition;
+use intern::Lookup;
 use intern::string_key::Intern;
 use intern::string_key::StringKey;
 use lsp_types::PrepareRenameResponse;
@@ -31,8 +33,10 @@ use lsp_types::WorkspaceEdit;
 use lsp_types::request::PrepareRenameRequest;
 use lsp_types::request::Rename;
 use lsp_types::request::Request;
+use lsp_types::request::WillRenameFiles;
 use rayon::prelude::IntoParallelRefIterator;
 use rayon::prelude::ParallelIterator;
+use relay_transforms::extract_module_name;
 use resolution_path::ArgumentParent;
 use resolution_path::ArgumentPath;
 use resolution_path::DirectiveParent;
@@ -96,6 +100,136 @@ pub fn on_prepare_rename(
     Ok(Some(PrepareRenameResponse::Range(lsp_location.range)))
 }
 
+/// Resolve a [`WillRenameFiles`] request to workspace edits that rename the
+/// definitions in the renamed files (and all of their usages), so that their
+/// names keep starting with the module name.
+pub fn on_will_rename_files(
+    state: &impl GlobalState,
+    params: <WillRenameFiles as Request>::Params,
+) -> LSPRuntimeResult<<WillRenameFiles as Request>::Result> {
+    let root_dir = &state.root_dir();
+    let mut changes: HashMap<Uri, Vec<TextEdit>> = HashMap::new();
+
+    for file_rename in params.files {
+        let (Ok(old_uri), Ok(new_uri)) = (
+            file_rename.old_uri.parse::<Uri>(),
+            file_rename.new_uri.parse::<Uri>(),
+        ) else {
+            continue;
+        };
+        // Files outside of Relay projects are not our concern
+        let Ok(project_name) = state.extract_project_name_from_uri(&old_uri) else {
+            continue;
+        };
+        let (Ok(old_path), Ok(new_path)) = (
+            Path::new(old_uri.path().as_str()).strip_prefix(root_dir),
+            Path::new(new_uri.path().as_str()).strip_prefix(root_dir),
+        ) else {
+            continue;
+        };
+        let program = state.get_program(&project_name)?;
+
+        for (uri, text_edits) in
+            get_text_edits_for_file_rename(&program, old_path, new_path, root_dir)
+        {
+            changes.entry(uri).or_default().extend(text_edits);
+        }
+    }
+
+    if changes.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(WorkspaceEdit {
+        changes: Some(changes),
+        ..Default::default()
+    }))
+}
+
+/// Text edits, grouped by file, that update the names of the definitions in
+/// `old_path` (a file or a directory, relative to the root dir) after it gets
+/// moved to `new_path`.
+pub fn get_text_edits_for_file_rename(
+    program: &Program,
+    old_path: &Path,
+    new_path: &Path,
+    root_dir: &Path,
+) -> HashMap<Uri, Vec<TextEdit>> {
+    let mut changes: HashMap<Uri, Vec<TextEdit>> = HashMap::new();
+    for (new_name, locations) in get_locations_for_file_rename(program, old_path, new_path) {
+        for (uri, text_edits) in
+            map_locations_to_text_edits(locations, new_name.to_string(), root_dir)
+        {
+            changes.entry(uri).or_default().extend(text_edits);
+        }
+    }
+    changes
+}
+
+/// For every definition in `old_path` whose name starts with the module name
+/// derived from its path, returns the new name derived from the path it is
+/// moved to, along with the locations of the definition and all its usages.
+pub fn get_locations_for_file_rename(
+    program: &Program,
+    old_path: &Path,
+    new_path: &Path,
+) -> Vec<(StringKey, Vec<IRLocation>)> {
+    let mut renames = vec![];
+
+    for fragment in program.fragments() {
+        if let Some(new_name) = get_name_for_moved_definition(
+            fragment.name.item.0,
+            fragment.name.location,
+            old_path,
+            new_path,
+        ) {
+            renames.push((
+                new_name,
+                FragmentFinder::get_fragment_usages(program, fragment.name.item.0),
+            ));
+        }
+    }
+    for operation in program.operations() {
+        if let Some(new_name) = get_name_for_moved_definition(
+            operation.name.item.0,
+            operation.name.location,
+            old_path,
+            new_path,
+        ) {
+            renames.push((new_name, vec![operation.name.location]));
+        }
+    }
+
+    renames.sort_by_key(|(new_name, _)| *new_name);
+    renames
+}
+
+fn get_name_for_moved_definition(
+    name: StringKey,
+    location: IRLocation,
+    old_path: &Path,
+    new_path: &Path,
+) -> Option<StringKey> {
+    let definition_path = PathBuf::from(location.source_location().path());
+    // `old_path` can either be the file containing the definition, or one of its parent directories
+    let relative_path = definition_path.strip_prefix(old_path).ok()?;
+    let moved_definition_path = if relative_path.as_os_str().is_empty() {
+        new_path.to_path_buf()
+    } else {
+        new_path.join(relative_path)
+    };
+
+    let old_module_name = extract_module_name(definition_path.to_str()?)?;
+    let new_module_name = extract_module_name(moved_definition_path.to_str()?)?;
+    if old_module_name == new_module_name {
+        return None;
+    }
+
+    name.lookup()
+        .strip_prefix(&old_module_name)
+        .map(|suffix| format!("{new_module_name}{suffix}").intern())
+}
+
 #[derive(Debug, Clone)]
 enum RenameKind {
     OperationDefinition,
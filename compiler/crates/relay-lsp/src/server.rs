@@ -37,11 +37,17 @@ pub use lsp_state::build_ir_for_lsp;
 use lsp_types::CodeActionOptions;
 use lsp_types::CodeActionProviderCapability;
 use lsp_types::CompletionOptions;
+use lsp_types::FileOperationFilter;
+use lsp_types::FileOperationPattern;
+use lsp_types::FileOperationRegistrationOptions;
 use lsp_types::RenameOptions;
 use lsp_types::ServerCapabilities;
+use lsp_types::SignatureHelpOptions;
 use lsp_types::TextDocumentSyncCapability;
 use lsp_types::TextDocumentSyncKind;
 use lsp_types::WorkDoneProgressOptions;
+use lsp_types::WorkspaceFileOperationsServerCapabilities;
+use lsp_types::WorkspaceServerCapabilities;
 use lsp_types::notification::Cancel;
 use lsp_types::notification::DidChangeTextDocument;
 use lsp_types::notification::DidCloseTextDocument;
@@ -51,6 +57,8 @@ use lsp_types::notification::Exit;
 use lsp_types::request::CodeActionRequest;
 use lsp_types::request::Completion;
 use lsp_types::request::GotoDefinition;
+use lsp_types::request::GotoImplementation;
+use lsp_types::request::GotoTypeDefinition;
 use lsp_types::request::HoverRequest;
 use lsp_types::request::InlayHintRequest;
 use lsp_types::request::PrepareRenameRequest;
@@ -58,6 +66,8 @@ use lsp_types::request::References;
 use lsp_types::request::Rename;
 use lsp_types::request::ResolveCompletionItem;
 use lsp_types::request::Shutdown;
+use lsp_types::request::SignatureHelpRequest;
+use lsp_types::request::WillRenameFiles;
 use relay_compiler::NoopArtifactWriter;
 use relay_compiler::config::Config;
 use schema_documentation::SchemaDocumentation;
@@ -77,6 +87,8 @@ use crate::find_field_usages::on_find_field_usages;
 use crate::goto_definition::GetSourceLocationOfTypeDefinition;
 use crate::goto_definition::on_get_source_location_of_type_definition;
 use crate::goto_definition::on_goto_definition;
+use crate::goto_definition::on_goto_implementation;
+use crate::goto_definition::on_goto_type_definition;
 use crate::graphql_tools::GraphQLExecuteQuery;
 use crate::graphql_tools::on_graphql_execute_query;
 use crate::hover::on_hover;
@@ -88,6 +100,7 @@ use crate::print_operation::on_print_operation;
 use crate::references::on_references;
 use crate::rename::on_prepare_rename;
 use crate::rename::on_rename;
+use crate::rename::on_will_rename_files;
 use crate::resolved_types_at_location::ResolvedTypesAtLocation;
 use crate::resolved_types_at_location::on_get_resolved_types_at_location;
 use crate::search_schema_items::SearchSchemaItems;
@@ -97,6 +110,7 @@ use crate::server::lsp_state_resources::LSPStateResources;
 use crate::server::task_queue::TaskQueue;
 use crate::shutdown::on_exit;
 use crate::shutdown::on_shutdown;
+use crate::signature_help::on_signature_help;
 use crate::status_reporter::LSPStatusReporter;
 use crate::text_documents::on_cancel;
 use crate::text_documents::on_did_change_text_document;
@@ -134,12 +148,38 @@ pub fn initialize(connection: &Connection) -> LSPProcessResult<()> {
         })),
         hover_provider: Some(lsp_types::HoverProviderCapability::Simple(true)),
         definition_provider: Some(lsp_types::OneOf::Left(true)),
+        implementation_provider: Some(lsp_types::ImplementationProviderCapability::Simple(true)),
+        type_definition_provider: Some(lsp_types::TypeDefinitionProviderCapability::Simple(true)),
         references_provider: Some(lsp_types::OneOf::Left(true)),
         code_action_provider: Some(CodeActionProviderCapability::Options(CodeActionOptions {
             code_action_kinds: Some(vec![lsp_types::CodeActionKind::QUICKFIX]),
             ..Default::default()
         })),
         inlay_hint_provider: Some(lsp_types::OneOf::Left(true)),
+        // Renaming a file changes the module name its definitions need to start with
+        workspace: Some(WorkspaceServerCapabilities {
+            workspace_folders: None,
+            file_operations: Some(WorkspaceFileOperationsServerCapabilities {
+                will_rename: Some(FileOperationRegistrationOptions {
+                    filters: vec![FileOperationFilter {
+                        scheme: Some("file".to_string()),
+                        pattern: FileOperationPattern {
+                            glob: "**".to_string(),
+                            matches: None,
+                            options: None,
+                        },
+                    }],
+                }),
+                ..Default::default()
+            }),
+        }),
+        signature_help_provider: Some(SignatureHelpOptions {
+            trigger_characters: Some(vec!["(".into(), ",".into()]),
+            retrigger_characters: None,
+            work_done_progress_options: WorkDoneProgressOptions {
+                work_done_progress: None,
+            },
+        }),
         ..Default::default()
     };
 
@@ -324,6 +364,8 @@ fn dispatch_request(request: lsp_server::Request, lsp_state: &impl GlobalState)
             .on_request_sync::<PrintOperation>(on_print_operation)?
             .on_request_sync::<HoverRequest>(on_hover)?
             .on_request_sync::<GotoDefinition>(on_goto_definition)?
+            .on_request_sync::<GotoImplementation>(on_goto_implementation)?
+            .on_request_sync::<GotoTypeDefinition>(on_goto_type_definition)?
             .on_request_sync::<References>(on_references)?
             .on_request_sync::<Completion>(on_completion)?
             .on_request_sync::<ResolveCompletionItem>(on_resolve_completion_item)?
@@ -333,8 +375,10 @@ fn dispatch_request(request: lsp_server::Request, lsp_state: &impl GlobalState)
             .on_request_sync::<HeartbeatRequest>(on_heartbeat)?
             .on_request_sync::<FindFieldUsages>(on_find_field_usages)?
             .on_request_sync::<InlayHintRequest>(on_inlay_hint_request)?
+            .on_request_sync::<SignatureHelpRequest>(on_signature_help)?
             .on_request_sync::<Rename>(on_rename)?
             .on_request_sync::<PrepareRenameRequest>(on_prepare_rename)?
+            .on_request_sync::<WillRenameFiles>(on_will_rename_files)?
             .request();
 
         // If we have gotten here, we have not handled the request
@@ -13,6 +13,7 @@ use common::Location;
 use common::PerfLogger;
 use common::SourceLocationKey;
 use common::Span;
+use common::TextSource;
 use crossbeam::channel::SendError;
 use crossbeam::channel::Sender;
 use dashmap::DashMap;
@@ -48,6 +49,7 @@ use relay_docblock::ParseOptions;
 use relay_docblock::parse_docblock_ast;
 use relay_transforms::apply_transforms;
 use relay_transforms::deprecated_fields_for_executable_definition;
+use relay_unused_fields::find_unused_fields;
 use schema::SDLSchema;
 use schema_documentation::CombinedSchemaDocumentation;
 use schema_documentation::SchemaDocumentation;
@@ -186,6 +188,8 @@ pub struct LSPState<
     schema_documentation_loader: Option<Box<dyn SchemaDocumentationLoader<TSchemaDocumentation>>>,
     pub(crate) source_programs: SourcePrograms,
     synced_javascript_sources: DashMap<Uri, Vec<JavaScriptSourceFeature>>,
+    /// The text of synced JavaScript files, for analyses of the whole module
+    synced_javascript_texts: DashMap<Uri, String>,
     synced_schema_sources: DashMap<Uri, GraphQLSource>,
     pub(crate) perf_logger: Arc<TPerfLogger>,
     pub(crate) diagnostic_reporter: Arc<DiagnosticReporter>,
@@ -230,6 +234,7 @@ impl<TPerfLogger: PerfLogger + 'static, TSchemaDocumentation: SchemaDocumentatio
             schema_documentation_loader,
             source_programs: Arc::new(DashMap::with_hasher(FnvBuildHasher::default())),
             synced_javascript_sources: Default::default(),
+            synced_javascript_texts: Default::default(),
             synced_schema_sources: Default::default(),
         };
 
@@ -241,8 +246,15 @@ impl<TPerfLogger: PerfLogger + 'static, TSchemaDocumentation: SchemaDocumentatio
         lsp_state
     }
 
-    fn insert_synced_js_sources(&self, uri: &Uri, sources: Vec<JavaScriptSourceFeature>) {
+    fn insert_synced_js_sources(
+        &self,
+        uri: &Uri,
+        text: &str,
+        sources: Vec<JavaScriptSourceFeature>,
+    ) {
         self.synced_javascript_sources.insert(uri.clone(), sources);
+        self.synced_javascript_texts
+            .insert(uri.clone(), text.to_string());
     }
 
     fn validate_synced_js_sources(&self, uri: &Uri) -> LSPRuntimeResult<()> {
@@ -343,6 +355,19 @@ impl<TPerfLogger: PerfLogger + 'static, TSchemaDocumentation: SchemaDocumentatio
                 }));
             }
         }
+
+        if project_config.report_unused_fields
+            && let Some(text) = self.synced_javascript_texts.get(uri)
+        {
+            let text_source = TextSource::from_whole_document(text.as_str());
+            diagnostics.extend(find_unused_fields(&text, uri.path().as_str()).iter().map(
+                |diagnostic| {
+                    self.diagnostic_reporter
+                        .convert_diagnostic(&text_source, diagnostic)
+                },
+            ));
+        }
+
         self.diagnostic_reporter
             .update_quick_diagnostics_for_uri(uri, diagnostics);
 
@@ -405,13 +430,19 @@ impl<TPerfLogger: PerfLogger + 'static, TSchemaDocumentation: SchemaDocumentatio
         self.task_scheduler.schedule(super::Task::LSPState(task));
     }
 
-    fn process_synced_js_sources(&self, uri: &Uri, sources: Vec<JavaScriptSourceFeature>) {
-        self.insert_synced_js_sources(uri, sources);
+    fn process_synced_js_sources(
+        &self,
+        uri: &Uri,
+        text: &str,
+        sources: Vec<JavaScriptSourceFeature>,
+    ) {
+        self.insert_synced_js_sources(uri, text, sources);
         self.schedule_task(Task::SyncedSource(uri.clone()));
     }
 
     fn remove_synced_js_sources(&self, uri: &Uri) {
         self.synced_javascript_sources.remove(uri);
+        self.synced_javascript_texts.remove(uri);
         self.diagnostic_reporter
             .clear_quick_diagnostics_for_uri(uri);
     }
@@ -692,7 +723,7 @@ impl<TPerfLogger: PerfLogger + 'static, TSchemaDocumentation: SchemaDocumentatio
 
                 if !embedded_sources.is_empty() {
                     self.initialize_lsp_state_resources(project_name);
-                    self.process_synced_js_sources(uri, embedded_sources);
+                    self.process_synced_js_sources(uri, text, embedded_sources);
                 }
 
                 Ok(())
@@ -721,7 +752,7 @@ impl<TPerfLogger: PerfLogger + 'static, TSchemaDocumentation: SchemaDocumentatio
                 if embedded_sources.is_empty() {
                     self.remove_synced_js_sources(uri);
                 } else {
-                    self.process_synced_js_sources(uri, embedded_sources);
+                    self.process_synced_js_sources(uri, text, embedded_sources);
                 }
 
                 Ok(())
@@ -0,0 +1,477 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Utilities for providing the signature help language feature
+
+use common::DirectiveName;
+use common::Span;
+use graphql_ir::DIRECTIVE_ARGUMENTS;
+use graphql_ir::FragmentDefinitionName;
+use graphql_ir::Program;
+use graphql_ir::Value as IrValue;
+use graphql_syntax::Argument;
+use graphql_syntax::ConstantValue;
+use graphql_syntax::Directive;
+use graphql_syntax::ExecutableDefinition;
+use graphql_syntax::ExecutableDocument;
+use graphql_syntax::List;
+use graphql_syntax::Selection;
+use graphql_syntax::TokenKind;
+use graphql_syntax::Value;
+use graphql_text_printer::PrinterOptions;
+use graphql_text_printer::print_value;
+use intern::Lookup;
+use intern::string_key::StringKey;
+use lsp_types::Documentation;
+use lsp_types::MarkupContent;
+use lsp_types::MarkupKind;
+use lsp_types::ParameterInformation;
+use lsp_types::ParameterLabel;
+use lsp_types::SignatureHelp;
+use lsp_types::SignatureInformation;
+use lsp_types::request::Request;
+use lsp_types::request::SignatureHelpRequest;
+use schema::ArgumentDefinitions;
+use schema::SDLSchema;
+use schema::Schema;
+use schema_documentation::SchemaDocumentation;
+
+use crate::lsp_runtime_error::LSPRuntimeResult;
+use crate::node_resolution_info::TypePath;
+use crate::node_resolution_info::TypePathItem;
+use crate::server::GlobalState;
+
+/// The argument list the cursor is in, along with what it belongs to.
+#[derive(Debug)]
+enum SignatureHelpKind {
+    /// Arguments of a field, resolved through the type path
+    Field(TypePath),
+    /// Arguments of a directive defined in the schema
+    Directive(DirectiveName),
+    /// `@arguments` (or spread arguments) passed to a fragment with `@argumentDefinitions`
+    FragmentArguments(FragmentDefinitionName),
+}
+
+#[derive(Debug)]
+struct SignatureHelpContext {
+    kind: SignatureHelpKind,
+    /// Names of the arguments that are already provided in the document
+    existing_names: Vec<StringKey>,
+    /// The argument the cursor is currently on, if any
+    active_argument: Option<StringKey>,
+}
+
+/// An argument definition, independent of where it was defined
+struct ParameterDescription {
+    name: StringKey,
+    label: String,
+    documentation: Option<String>,
+}
+
+pub fn on_signature_help(
+    state: &impl GlobalState,
+    params: <SignatureHelpRequest as Request>::Params,
+) -> LSPRuntimeResult<<SignatureHelpRequest as Request>::Result> {
+    let (document, position_span) =
+        state.extract_executable_document_from_text(&params.text_document_position_params, 0)?;
+    let project_name = state
+        .extract_project_name_from_uri(&params.text_document_position_params.text_document.uri)?;
+    let schema = state.get_schema(&project_name)?;
+    let program = state.get_program(&project_name)?;
+    let schema_documentation = state.get_schema_documentation(project_name.lookup());
+
+    Ok(resolve_signature_help(
+        document,
+        position_span,
+        &schema,
+        &schema_documentation,
+        &program,
+    ))
+}
+
+fn resolve_signature_help(
+    document: ExecutableDocument,
+    position_span: Span,
+    schema: &SDLSchema,
+    schema_documentation: &impl SchemaDocumentation,
+    program: &Program,
+) -> Option<SignatureHelp> {
+    let context = create_signature_help_context(&document, position_span)?;
+    let (signature_label, documentation, parameters) = match context.kind {
+        SignatureHelpKind::Field(type_path) => {
+            let (parent_type, field) = type_path.resolve_current_field(schema)?;
+            let parent_type_name = schema.get_type_name(parent_type).lookup();
+            let field_name = field.name.item.lookup();
+            let parameters = describe_schema_arguments(schema, &field.arguments, |argument| {
+                schema_documentation
+                    .get_field_argument_description(
+                        parent_type_name,
+                        field_name,
+                        argument.name.item.0.lookup(),
+                    )
+                    .map(|description| description.to_string())
+                    .or_else(|| argument.description.map(|d| d.lookup().to_string()))
+            });
+            let documentation = schema_documentation
+                .get_field_description(parent_type_name, field_name)
+                .map(|description| description.to_string())
+                .or_else(|| field.description.map(|d| d.lookup().to_string()));
+            (
+                SignatureLabel {
+                    prefix: field_name.to_string(),
+                    suffix: format!(": {}", schema.get_type_string(&field.type_)),
+                },
+                documentation,
+                parameters,
+            )
+        }
+        SignatureHelpKind::Directive(directive_name) => {
+            let directive = schema.get_directive(directive_name)?;
+            let parameters = describe_schema_arguments(schema, &directive.arguments, |argument| {
+                argument.description.map(|d| d.lookup().to_string())
+            });
+            (
+                SignatureLabel {
+                    prefix: format!("@{directive_name}"),
+                    suffix: String::new(),
+                },
+                directive.description.map(|d| d.lookup().to_string()),
+                parameters,
+            )
+        }
+        SignatureHelpKind::FragmentArguments(fragment_name) => {
+            let fragment = program.fragment(fragment_name)?;
+            let parameters = fragment
+                .variable_definitions
+                .iter()
+                .map(|variable_definition| {
+                    let mut label = format!(
+                        "{}: {}",
+                        variable_definition.name.item,
+                        schema.get_type_string(&variable_definition.type_)
+                    );
+                    if let Some(default_value) = &variable_definition.default_value {
+                        label.push_str(" = ");
+                        label.push_str(&print_value(
+                            schema,
+                            &IrValue::Constant(default_value.item.clone()),
+                            PrinterOptions::default(),
+                        ));
+                    }
+                    ParameterDescription {
+                        name: variable_definition.name.item.0,
+                        label,
+                        documentation: None,
+                    }
+                })
+                .collect::<Vec<_>>();
+            (
+                SignatureLabel {
+                    prefix: "@arguments".to_string(),
+                    suffix: String::new(),
+                },
+                Some(format!(
+                    "Arguments defined with `@argumentDefinitions` on fragment `{}` on `{}`.",
+                    fragment_name,
+                    schema.get_type_name(fragment.type_condition)
+                )),
+                parameters,
+            )
+        }
+    };
+
+    let active_parameter = context
+        .active_argument
+        .and_then(|name| parameters.iter().position(|param| param.name == name))
+        .or_else(|| {
+            // The cursor is not on an argument: suggest the first one that is still missing
+            parameters
+                .iter()
+                .position(|param| !context.existing_names.contains(&param.name))
+        })
+        .map(|index| index as u32);
+
+    Some(SignatureHelp {
+        signatures: vec![build_signature_information(
+            signature_label,
+            documentation,
+            parameters,
+        )],
+        active_signature: Some(0),
+        active_parameter,
+    })
+}
+
+/// The parts of the signature label around the parenthesized parameter list
+struct SignatureLabel {
+    prefix: String,
+    suffix: String,
+}
+
+fn build_signature_information(
+    signature_label: SignatureLabel,
+    documentation: Option<String>,
+    parameters: Vec<ParameterDescription>,
+) -> SignatureInformation {
+    let mut label = signature_label.prefix;
+    label.push('(');
+    let mut parameter_information = Vec::with_capacity(parameters.len());
+    for (index, parameter) in parameters.into_iter().enumerate() {
+        if index > 0 {
+            label.push_str(", ");
+        }
+        // Offsets are expressed in UTF-16 code units, as required by the LSP spec
+        let start = utf16_len(&label);
+        label.push_str(&parameter.label);
+        let end = utf16_len(&label);
+        parameter_information.push(ParameterInformation {
+            label: ParameterLabel::LabelOffsets([start, end]),
+            documentation: parameter.documentation.map(markdown_documentation),
+        });
+    }
+    label.push(')');
+    label.push_str(&signature_label.suffix);
+
+    SignatureInformation {
+        label,
+        documentation: documentation.map(markdown_documentation),
+        parameters: Some(parameter_information),
+        active_parameter: None,
+    }
+}
+
+fn describe_schema_arguments(
+    schema: &SDLSchema,
+    arguments: &ArgumentDefinitions,
+    get_description: impl Fn(&schema::Argument) -> Option<String>,
+) -> Vec<ParameterDescription> {
+    arguments
+        .iter()
+        .map(|argument| {
+            let mut label = format!(
+                "{}: {}",
+                argument.name.item,
+                schema.get_type_string(&argument.type_)
+            );
+            if let Some(default_value) = &argument.default_value {
+                label.push_str(&format!(" = {default_value}"));
+            }
+            let mut documentation = get_description(argument);
+            if let Some(deprecation) = argument.deprecated() {
+                let deprecation_note = match deprecation.reason {
+                    Some(reason) => format!("**Deprecated**: {reason}"),
+                    None => "**Deprecated**".to_string(),
+                };
+                documentation = Some(match documentation {
+                    Some(description) => format!("{description}\n\n{deprecation_note}"),
+                    None => deprecation_note,
+                });
+            }
+            ParameterDescription {
+                name: argument.name.item.0,
+                label,
+                documentation,
+            }
+        })
+        .collect()
+}
+
+fn markdown_documentation(value: String) -> Documentation {
+    Documentation::MarkupContent(MarkupContent {
+        kind: MarkupKind::Markdown,
+        value,
+    })
+}
+
+fn utf16_len(value: &str) -> u32 {
+    value.encode_utf16().count() as u32
+}
+
+fn create_signature_help_context(
+    document: &ExecutableDocument,
+    position_span: Span,
+) -> Option<SignatureHelpContext> {
+    for definition in &document.definitions {
+        match definition {
+            ExecutableDefinition::Operation(operation) => {
+                if operation.location.contains(position_span) {
+                    let (_, kind) = operation.operation?;
+                    return context_from_selections_or_directives(
+                        &operation.selections,
+                        &operation.directives,
+                        position_span,
+                        vec![TypePathItem::Operation(kind)],
+                    );
+                }
+            }
+            ExecutableDefinition::Fragment(fragment) => {
+                if fragment.location.contains(position_span) {
+                    let type_name = fragment.type_condition.type_.value;
+                    return context_from_selections_or_directives(
+                        &fragment.selections,
+                        &fragment.directives,
+                        position_span,
+                        vec![TypePathItem::FragmentDefinition { type_name }],
+                    );
+                }
+            }
+        }
+    }
+    None
+}
+
+fn context_from_selections_or_directives(
+    selections: &List<Selection>,
+    directives: &[Directive],
+    position_span: Span,
+    type_path: Vec<TypePathItem>,
+) -> Option<SignatureHelpContext> {
+    if selections.span.contains(position_span) {
+        context_from_selections(selections, position_span, type_path)
+    } else {
+        context_from_directives(directives, position_span, None)
+    }
+}
+
+fn context_from_selections(
+    selections: &List<Selection>,
+    position_span: Span,
+    mut type_path: Vec<TypePathItem>,
+) -> Option<SignatureHelpContext> {
+    let selection = selections
+        .items
+        .iter()
+        .find(|selection| selection.span().contains(position_span))?;
+    match selection {
+        Selection::LinkedField(field) => {
+            type_path.push(TypePathItem::LinkedField {
+                name: field.name.value,
+            });
+            if let Some(arguments) = &field.arguments
+                && is_within_arguments(arguments, position_span)
+            {
+                return Some(context_from_arguments(
+                    SignatureHelpKind::Field(type_path.into()),
+                    arguments,
+                    position_span,
+                ));
+            }
+            context_from_selections_or_directives(
+                &field.selections,
+                &field.directives,
+                position_span,
+                type_path,
+            )
+        }
+        Selection::ScalarField(field) => {
+            if let Some(arguments) = &field.arguments
+                && is_within_arguments(arguments, position_span)
+            {
+                type_path.push(TypePathItem::ScalarField {
+                    name: field.name.value,
+                });
+                return Some(context_from_arguments(
+                    SignatureHelpKind::Field(type_path.into()),
+                    arguments,
+                    position_span,
+                ));
+            }
+            context_from_directives(&field.directives, position_span, None)
+        }
+        Selection::FragmentSpread(spread) => {
+            let fragment_name = FragmentDefinitionName(spread.name.value);
+            if let Some(arguments) = &spread.arguments
+                && is_within_arguments(arguments, position_span)
+            {
+                return Some(context_from_arguments(
+                    SignatureHelpKind::FragmentArguments(fragment_name),
+                    arguments,
+                    position_span,
+                ));
+            }
+            context_from_directives(&spread.directives, position_span, Some(fragment_name))
+        }
+        Selection::InlineFragment(fragment) => {
+            if let Some(type_condition) = &fragment.type_condition {
+                type_path.push(TypePathItem::InlineFragment {
+                    type_name: type_condition.type_.value,
+                });
+            }
+            context_from_selections_or_directives(
+                &fragment.selections,
+                &fragment.directives,
+                position_span,
+                type_path,
+            )
+        }
+    }
+}
+
+fn context_from_directives(
+    directives: &[Directive],
+    position_span: Span,
+    fragment_spread_name: Option<FragmentDefinitionName>,
+) -> Option<SignatureHelpContext> {
+    let directive = directives
+        .iter()
+        .find(|directive| directive.span.contains(position_span))?;
+    let arguments = directive.arguments.as_ref()?;
+    if !is_within_arguments(arguments, position_span) {
+        return None;
+    }
+    let kind = match fragment_spread_name {
+        Some(fragment_name) if directive.name.value == *DIRECTIVE_ARGUMENTS => {
+            SignatureHelpKind::FragmentArguments(fragment_name)
+        }
+        _ => SignatureHelpKind::Directive(DirectiveName(directive.name.value)),
+    };
+    Some(context_from_arguments(kind, arguments, position_span))
+}
+
+/// Whether the cursor is after the opening parenthesis, and before the closing one (if any)
+fn is_within_arguments(arguments: &List<Argument>, position_span: Span) -> bool {
+    arguments.span.contains(position_span)
+        && (arguments.end.kind == TokenKind::Empty
+            || position_span.start < arguments.end.span.start)
+}
+
+fn context_from_arguments(
+    kind: SignatureHelpKind,
+    arguments: &List<Argument>,
+    position_span: Span,
+) -> SignatureHelpContext {
+    let active_argument = arguments
+        .items
+        .iter()
+        .rev()
+        .find(|argument| argument.span.start <= position_span.start)
+        .filter(|argument| {
+            // `(arg: |)`: the value of the argument is still being typed
+            argument.span.contains(position_span) || has_empty_value(argument)
+        })
+        .map(|argument| argument.name.value);
+
+    SignatureHelpContext {
+        kind,
+        existing_names: arguments
+            .items
+            .iter()
+            .map(|argument| argument.name.value)
+            .collect(),
+        active_argument,
+    }
+}
+
+fn has_empty_value(argument: &Argument) -> bool {
+    matches!(
+        &argument.value,
+        Value::Constant(ConstantValue::Null(token)) if token.kind == TokenKind::Empty
+    )
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,207 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::sync::Arc;
+
+use common::SourceLocationKey;
+use common::Span;
+use graphql_ir::Program;
+use graphql_ir::build;
+use graphql_syntax::parse_executable;
+use graphql_syntax::parse_executable_with_error_recovery;
+use lsp_types::ParameterLabel;
+use lsp_types::SignatureHelp;
+use relay_test_schema::get_test_schema;
+
+use super::resolve_signature_help;
+
+fn parse_and_resolve_signature_help(
+    source: &str,
+    program: Option<Program>,
+) -> Option<SignatureHelp> {
+    let pos = source.find('|').unwrap() - 1;
+    let next_source = source.replace('|', "");
+    let document = parse_executable_with_error_recovery(
+        &next_source,
+        SourceLocationKey::standalone("/test/file"),
+    )
+    .item;
+
+    let position_span = Span {
+        start: pos as u32,
+        end: pos as u32,
+    };
+    let test_schema = get_test_schema();
+
+    resolve_signature_help(
+        document,
+        position_span,
+        &Arc::clone(&test_schema),
+        &Arc::clone(&test_schema),
+        &program.unwrap_or_else(|| Program::new(Arc::clone(&test_schema))),
+    )
+}
+
+fn build_test_program(source: &str) -> Program {
+    let document = parse_executable(source, SourceLocationKey::Generated).unwrap();
+    let ir = build(&get_test_schema(), &document.definitions).unwrap();
+    Program::from_definitions(get_test_schema(), ir)
+}
+
+fn parameter_labels(signature_help: &SignatureHelp) -> Vec<String> {
+    let signature = &signature_help.signatures[0];
+    signature
+        .parameters
+        .as_ref()
+        .unwrap()
+        .iter()
+        .map(|parameter| match parameter.label {
+            ParameterLabel::LabelOffsets([start, end]) => {
+                signature.label[start as usize..end as usize].to_string()
+            }
+            ParameterLabel::Simple(ref label) => label.clone(),
+        })
+        .collect()
+}
+
+#[test]
+fn field_arguments_empty_list() {
+    let signature_help = parse_and_resolve_signature_help(
+        r#"
+            fragment Test on User {
+                profilePicture(|) {
+                    uri
+                }
+            }
+        "#,
+        None,
+    )
+    .unwrap();
+    assert_eq!(
+        signature_help.signatures[0].label,
+        "profilePicture(size: [Int], preset: PhotoSize): Image"
+    );
+    assert_eq!(
+        parameter_labels(&signature_help),
+        vec!["size: [Int]", "preset: PhotoSize"]
+    );
+    assert_eq!(signature_help.active_parameter, Some(0));
+}
+
+#[test]
+fn field_arguments_active_parameter() {
+    let signature_help = parse_and_resolve_signature_help(
+        r#"
+            fragment Test on User {
+                profilePicture(size: 1, preset: S|) {
+                    uri
+                }
+            }
+        "#,
+        None,
+    )
+    .unwrap();
+    assert_eq!(signature_help.active_parameter, Some(1));
+}
+
+#[test]
+fn field_arguments_next_missing_parameter() {
+    let signature_help = parse_and_resolve_signature_help(
+        r#"
+            fragment Test on User {
+                profilePicture(preset: SMALL, |) {
+                    uri
+                }
+            }
+        "#,
+        None,
+    )
+    .unwrap();
+    assert_eq!(signature_help.active_parameter, Some(0));
+}
+
+#[test]
+fn scalar_field_arguments_in_linked_field() {
+    let signature_help = parse_and_resolve_signature_help(
+        r#"
+            query Test {
+                me {
+                    profilePicture(size: 1) {
+                        uri
+                    }
+                    firstName(if: true, unless: |)
+                }
+            }
+        "#,
+        None,
+    )
+    .unwrap();
+    assert_eq!(
+        signature_help.signatures[0].label,
+        "firstName(if: Boolean, unless: Boolean): String"
+    );
+    assert_eq!(signature_help.active_parameter, Some(1));
+}
+
+#[test]
+fn directive_arguments() {
+    let signature_help = parse_and_resolve_signature_help(
+        r#"
+            fragment Test on User {
+                name @include(|)
+            }
+        "#,
+        None,
+    )
+    .unwrap();
+    assert_eq!(parameter_labels(&signature_help), vec!["if: Boolean!"]);
+}
+
+#[test]
+fn fragment_arguments_directive() {
+    let signature_help = parse_and_resolve_signature_help(
+        r#"
+            fragment Test on User {
+                ...PictureFragment @arguments(pictureSize: [42], |)
+            }
+        "#,
+        Some(build_test_program(
+            r#"
+            fragment PictureFragment on User
+                @argumentDefinitions(
+                    pictureSize: {type: "[Int]"},
+                    preset: {type: "PhotoSize", defaultValue: SMALL}
+                ) {
+                profilePicture(size: $pictureSize, preset: $preset) {
+                    uri
+                }
+            }
+        "#,
+        )),
+    )
+    .unwrap();
+    assert_eq!(
+        signature_help.signatures[0].label,
+        "@arguments(pictureSize: [Int], preset: PhotoSize = SMALL)"
+    );
+    assert_eq!(signature_help.active_parameter, Some(1));
+}
+
+#[test]
+fn outside_of_arguments() {
+    let signature_help = parse_and_resolve_signature_help(
+        r#"
+            fragment Test on User {
+                profilePicture(size: 1) {
+                    ur|i
+                }
+            }
+        "#,
+        None,
+    );
+    assert!(signature_help.is_none());
+}
@@ -0,0 +1,51 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::SourceLocationKey;
+use common::Span;
+use fixture_tests::Fixture;
+use graphql_syntax::parse_executable;
+use graphql_syntax::parse_schema_document;
+use graphql_test_helpers::diagnostics_to_sorted_string;
+use relay_lsp::goto_definition::DefinitionDescription;
+use relay_lsp::goto_definition::get_graphql_definition_description;
+use relay_lsp::goto_definition::get_graphql_schema_implementation_description;
+use relay_lsp::goto_definition::get_implementing_objects;
+use relay_test_schema::get_test_schema;
+use schema::Schema;
+
+/// The cursor is marked with `|`. Documents containing `# sdl` are parsed as
+/// schema documents.
+pub async fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let schema = get_test_schema();
+    let cursor_position = fixture.content.find('|').unwrap() - 1;
+    let source = fixture.content.replace('|', "");
+    let source_location = SourceLocationKey::standalone(fixture.file_name);
+    let position_span = Span::from_usize(cursor_position, cursor_position);
+
+    let definition_description = if fixture.content.contains("# sdl") {
+        let document = parse_schema_document(&source, source_location)
+            .map_err(|diagnostics| diagnostics_to_sorted_string(&source, &diagnostics))?;
+        get_graphql_schema_implementation_description(document, position_span)
+    } else {
+        let document = parse_executable(&source, source_location)
+            .map_err(|diagnostics| diagnostics_to_sorted_string(&source, &diagnostics))?;
+        get_graphql_definition_description(document, position_span, &schema)
+    };
+
+    let type_name = match definition_description {
+        Ok(DefinitionDescription::Type { type_name }) => type_name,
+        _ => return Err("<NO IMPLEMENTATIONS>".to_string()),
+    };
+    let implementing_objects = get_implementing_objects(&schema, type_name)
+        .map_err(|_| "<NO IMPLEMENTATIONS>".to_string())?;
+
+    Ok(implementing_objects
+        .into_iter()
+        .map(|object_id| format!("{}\n", schema.object(object_id).name.item))
+        .collect())
+}
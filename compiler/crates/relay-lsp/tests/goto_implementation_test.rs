@@ -0,0 +1,48 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<774ec4297545f9034c65cbd96f134808>>
+ */
+
+mod goto_implementation;
+
+use goto_implementation::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[tokio::test]
+async fn field_invalid() {
+    let input = include_str!("goto_implementation/fixtures/field.invalid.graphql");
+    let expected = include_str!("goto_implementation/fixtures/field.invalid.expected");
+    test_fixture(transform_fixture, file!(), "field.invalid.graphql", "goto_implementation/fixtures/field.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn interface_type_condition() {
+    let input = include_str!("goto_implementation/fixtures/interface_type_condition.graphql");
+    let expected = include_str!("goto_implementation/fixtures/interface_type_condition.expected");
+    test_fixture(transform_fixture, file!(), "interface_type_condition.graphql", "goto_implementation/fixtures/interface_type_condition.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn object_type_condition() {
+    let input = include_str!("goto_implementation/fixtures/object_type_condition.graphql");
+    let expected = include_str!("goto_implementation/fixtures/object_type_condition.expected");
+    test_fixture(transform_fixture, file!(), "object_type_condition.graphql", "goto_implementation/fixtures/object_type_condition.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn sdl_interface_definition() {
+    let input = include_str!("goto_implementation/fixtures/sdl_interface_definition.graphql");
+    let expected = include_str!("goto_implementation/fixtures/sdl_interface_definition.expected");
+    test_fixture(transform_fixture, file!(), "sdl_interface_definition.graphql", "goto_implementation/fixtures/sdl_interface_definition.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn union_type_condition() {
+    let input = include_str!("goto_implementation/fixtures/union_type_condition.graphql");
+    let expected = include_str!("goto_implementation/fixtures/union_type_condition.expected");
+    test_fixture(transform_fixture, file!(), "union_type_condition.graphql", "goto_implementation/fixtures/union_type_condition.expected", input, expected).await;
+}
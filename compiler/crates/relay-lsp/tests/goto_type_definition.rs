@@ -0,0 +1,45 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::sync::Arc;
+
+use common::SourceLocationKey;
+use common::Span;
+use fixture_tests::Fixture;
+use graphql_ir::Program;
+use graphql_ir::build;
+use graphql_syntax::parse_executable;
+use graphql_test_helpers::diagnostics_to_sorted_string;
+use relay_lsp::goto_definition::get_definition_type;
+use relay_lsp::goto_definition::get_graphql_definition_description;
+use relay_test_schema::get_test_schema;
+use schema::Schema;
+
+/// The cursor is marked with `|`.
+pub async fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let schema = get_test_schema();
+    let cursor_position = fixture.content.find('|').unwrap() - 1;
+    let source = fixture.content.replace('|', "");
+    let source_location = SourceLocationKey::standalone(fixture.file_name);
+    let ast = parse_executable(&source, source_location)
+        .map_err(|diagnostics| diagnostics_to_sorted_string(&source, &diagnostics))?;
+    let ir = build(&schema, &ast.definitions)
+        .map_err(|diagnostics| diagnostics_to_sorted_string(&source, &diagnostics))?;
+    let program = Program::from_definitions(Arc::clone(&schema), ir);
+
+    let type_ = get_graphql_definition_description(
+        ast,
+        Span::from_usize(cursor_position, cursor_position),
+        &schema,
+    )
+    .and_then(|definition_description| {
+        get_definition_type(definition_description, &schema, &program)
+    })
+    .map_err(|_| "<NO TYPE DEFINITION>".to_string())?;
+
+    Ok(format!("{}\n", schema.get_type_name(type_)))
+}
@@ -0,0 +1,48 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<a6c89f1125285e784aa1cb1623c18f74>>
+ */
+
+mod goto_type_definition;
+
+use goto_type_definition::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[tokio::test]
+async fn directive_invalid() {
+    let input = include_str!("goto_type_definition/fixtures/directive.invalid.graphql");
+    let expected = include_str!("goto_type_definition/fixtures/directive.invalid.expected");
+    test_fixture(transform_fixture, file!(), "directive.invalid.graphql", "goto_type_definition/fixtures/directive.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn field() {
+    let input = include_str!("goto_type_definition/fixtures/field.graphql");
+    let expected = include_str!("goto_type_definition/fixtures/field.expected");
+    test_fixture(transform_fixture, file!(), "field.graphql", "goto_type_definition/fixtures/field.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn field_argument() {
+    let input = include_str!("goto_type_definition/fixtures/field_argument.graphql");
+    let expected = include_str!("goto_type_definition/fixtures/field_argument.expected");
+    test_fixture(transform_fixture, file!(), "field_argument.graphql", "goto_type_definition/fixtures/field_argument.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn fragment_spread() {
+    let input = include_str!("goto_type_definition/fixtures/fragment_spread.graphql");
+    let expected = include_str!("goto_type_definition/fixtures/fragment_spread.expected");
+    test_fixture(transform_fixture, file!(), "fragment_spread.graphql", "goto_type_definition/fixtures/fragment_spread.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn interface_field() {
+    let input = include_str!("goto_type_definition/fixtures/interface_field.graphql");
+    let expected = include_str!("goto_type_definition/fixtures/interface_field.expected");
+    test_fixture(transform_fixture, file!(), "interface_field.graphql", "goto_type_definition/fixtures/interface_field.expected", input, expected).await;
+}
@@ -0,0 +1,90 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::path::Path;
+use std::sync::Arc;
+
+use common::SourceLocationKey;
+use common::Span;
+use fixture_tests::Fixture;
+use graphql_ir::Program;
+use graphql_ir::build;
+use graphql_syntax::parse_executable;
+use graphql_test_helpers::diagnostics_to_sorted_string;
+use intern::Lookup;
+use itertools::Itertools;
+use relay_lsp::rename::get_locations_for_file_rename;
+use relay_test_schema::get_test_schema;
+
+const RENAME_PREFIX: &str = "# rename: ";
+const FILE_PREFIX: &str = "# file: ";
+
+/// Fixtures start with a `# rename: <old path> -> <new path>` line, followed by
+/// the files of the project, each of them starting with a `# file: <path>` line.
+pub async fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let mut lines = fixture.content.lines();
+    let (old_path, new_path) = lines
+        .next()
+        .and_then(|line| line.strip_prefix(RENAME_PREFIX))
+        .and_then(|rename| rename.split_once(" -> "))
+        .ok_or("Expected the fixture to start with a `# rename: <old> -> <new>` line")?;
+
+    let mut files: Vec<(&str, String)> = vec![];
+    for line in lines {
+        if let Some(path) = line.strip_prefix(FILE_PREFIX) {
+            files.push((path, String::new()));
+        } else if let Some((_, source)) = files.last_mut() {
+            source.push_str(line);
+            source.push('\n');
+        }
+    }
+
+    let schema = get_test_schema();
+    let mut definitions = vec![];
+    for (path, source) in &files {
+        let ast = parse_executable(source, SourceLocationKey::standalone(path))
+            .map_err(|diagnostics| diagnostics_to_sorted_string(source, &diagnostics))?;
+        definitions.extend(ast.definitions);
+    }
+    let ir = build(&schema, &definitions)
+        .map_err(|diagnostics| format!("{diagnostics:?}"))?;
+    let program = Program::from_definitions(Arc::clone(&schema), ir);
+
+    let renames =
+        get_locations_for_file_rename(&program, Path::new(old_path), Path::new(new_path));
+
+    let output = files
+        .iter()
+        .map(|(path, source)| {
+            let replacements = renames
+                .iter()
+                .flat_map(|(new_name, locations)| {
+                    locations
+                        .iter()
+                        .filter(|location| location.source_location().path() == *path)
+                        .map(|location| (location.span(), new_name.lookup()))
+                })
+                .collect::<Vec<_>>();
+            format!("{}{}\n{}", FILE_PREFIX, path, apply_replacements(source, replacements))
+        })
+        .join("\n");
+
+    Ok(output)
+}
+
+fn apply_replacements(source: &str, replacements: Vec<(Span, &str)>) -> String {
+    let mut result = source.to_string();
+    // Apply from the end of the file, so that the remaining spans stay valid
+    for (span, new_text) in replacements
+        .into_iter()
+        .sorted_by_key(|(span, _)| span.start)
+        .rev()
+    {
+        result.replace_range(span.start as usize..span.end as usize, new_text);
+    }
+    result
+}
@@ -0,0 +1,34 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<8abb791c764e5232471031f798f99dfc>>
+ */
+
+mod rename_file;
+
+use rename_file::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[tokio::test]
+async fn rename_directory_index_file() {
+    let input = include_str!("rename_file/fixtures/rename_directory_index_file.graphql");
+    let expected = include_str!("rename_file/fixtures/rename_directory_index_file.expected");
+    test_fixture(transform_fixture, file!(), "rename_directory_index_file.graphql", "rename_file/fixtures/rename_directory_index_file.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn rename_file() {
+    let input = include_str!("rename_file/fixtures/rename_file.graphql");
+    let expected = include_str!("rename_file/fixtures/rename_file.expected");
+    test_fixture(transform_fixture, file!(), "rename_file.graphql", "rename_file/fixtures/rename_file.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn rename_file_keeps_unprefixed_names() {
+    let input = include_str!("rename_file/fixtures/rename_file_keeps_unprefixed_names.graphql");
+    let expected = include_str!("rename_file/fixtures/rename_file_keeps_unprefixed_names.expected");
+    test_fixture(transform_fixture, file!(), "rename_file_keeps_unprefixed_names.graphql", "rename_file/fixtures/rename_file_keeps_unprefixed_names.expected", input, expected).await;
+}
@@ -40,6 +40,7 @@ pub struct Programs {
     pub normalization: Arc<Program>,
     pub operation_text: Arc<Program>,
     pub typegen: Arc<Program>,
+    pub shared_normalization_selections: Arc<SharedNormalizationSelections>,
 }
 
 pub fn apply_transforms<TPerfLogger>(
@@ -131,6 +132,8 @@ where
             )
         },
     )?;
+    let (normalization_program, shared_normalization_selections) =
+        extract_shared_normalization_selections(normalization_program);
 
     Ok(Programs {
         source: program,
@@ -138,6 +141,7 @@ where
         normalization: normalization_program,
         operation_text: text_program,
         typegen: typegen_program,
+        shared_normalization_selections: Arc::new(shared_normalization_selections),
     })
 }
 
@@ -186,6 +190,12 @@ fn apply_common_transforms(
             false,
         )
     });
+    program = log_event.time("transform_paginated_lists", || {
+        transform_paginated_lists(
+            &program,
+            &project_config.schema_config.paginated_list_interface,
+        )
+    });
     program = log_event.time("mask", || mask(&program));
     program = log_event.time("transform_defer_stream", || {
         transform_defer_stream(
@@ -404,6 +414,7 @@ fn apply_operation_transforms(
             &project_config.feature_flags,
         )
     })?;
+    program = log_event.time("declarative_list", || transform_declarative_list(&program))?;
 
     program = log_event.time("generate_live_query_metadata", || {
         generate_live_query_metadata(&program)
@@ -458,6 +469,14 @@ fn apply_normalization_transforms(
         maybe_print_stats,
     )?;
 
+    program = log_event.time("generate_shared_normalization_selections", || {
+        generate_shared_normalization_selections(
+            &program,
+            &project_config.feature_flags.share_normalization_selections,
+            &base_fragment_names,
+        )
+    });
+
     program = log_event.time("apply_fragment_arguments", || {
         apply_fragment_arguments(
             &program,
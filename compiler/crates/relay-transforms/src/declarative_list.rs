@@ -0,0 +1,618 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::sync::Arc;
+use std::sync::LazyLock;
+
+use common::ArgumentName;
+use common::Diagnostic;
+use common::DiagnosticsResult;
+use common::DirectiveName;
+use common::NamedItem;
+use common::WithLocation;
+use graphql_ir::Argument;
+use graphql_ir::ConstantArgument;
+use graphql_ir::ConstantValue;
+use graphql_ir::Directive;
+use graphql_ir::Field;
+use graphql_ir::FragmentDefinition;
+use graphql_ir::LinkedField;
+use graphql_ir::Program;
+use graphql_ir::ScalarField;
+use graphql_ir::Selection;
+use graphql_ir::Transformed;
+use graphql_ir::Transformer;
+use graphql_ir::Value;
+use intern::Lookup;
+use intern::string_key::Intern;
+use intern::string_key::StringKey;
+use schema::SDLSchema;
+use schema::Schema;
+use schema::Type;
+use schema::TypeReference;
+use schema::suggestion_list::GraphQLSuggestions;
+use schema::suggestion_list::did_you_mean;
+use thiserror::Error;
+
+use crate::handle_fields::HandleFieldDirectiveValues;
+use crate::handle_fields::build_handle_field_directive;
+
+/// Transforms the `@appendToList`, `@prependToList` and `@deleteFromList`
+/// directives, the counterparts of the declarative connection directives for
+/// plain list fields, into handle fields.
+pub fn transform_declarative_list(program: &Program) -> DiagnosticsResult<Program> {
+    let mut transform = DeclarativeListMutationTransform::new(program);
+    let next_program = transform
+        .transform_program(program)
+        .replace_or_else(|| program.clone());
+
+    if transform.errors.is_empty() {
+        Ok(next_program)
+    } else {
+        Err(transform.errors)
+    }
+}
+
+static APPEND_TO_LIST: LazyLock<DirectiveName> =
+    LazyLock::new(|| DirectiveName("appendToList".intern()));
+static PREPEND_TO_LIST: LazyLock<DirectiveName> =
+    LazyLock::new(|| DirectiveName("prependToList".intern()));
+static DELETE_FROM_LIST: LazyLock<DirectiveName> =
+    LazyLock::new(|| DirectiveName("deleteFromList".intern()));
+static PARENT_ID_ARG_NAME: LazyLock<ArgumentName> =
+    LazyLock::new(|| ArgumentName("parentID".intern()));
+static PARENT_TYPE_NAME_ARG_NAME: LazyLock<ArgumentName> =
+    LazyLock::new(|| ArgumentName("parentTypeName".intern()));
+static FIELD_ARG_NAME: LazyLock<ArgumentName> = LazyLock::new(|| ArgumentName("field".intern()));
+static FIELD_ARGS_ARG_NAME: LazyLock<ArgumentName> =
+    LazyLock::new(|| ArgumentName("fieldArgs".intern()));
+static FIELD_NAME_HANDLE_ARG: LazyLock<ArgumentName> =
+    LazyLock::new(|| ArgumentName("fieldName".intern()));
+static FIELD_ARGS_HANDLE_ARG: LazyLock<ArgumentName> =
+    LazyLock::new(|| ArgumentName("fieldArgs".intern()));
+static EMPTY_STRING: LazyLock<StringKey> = LazyLock::new(|| "".intern());
+
+/// The list field targeted by a declarative list directive, resolved against
+/// the parent type.
+struct ListField {
+    name: StringKey,
+    arguments: Vec<ConstantArgument>,
+    item_type: Type,
+}
+
+struct DeclarativeListMutationTransform<'a> {
+    schema: &'a SDLSchema,
+    errors: Vec<Diagnostic>,
+}
+
+impl<'a> DeclarativeListMutationTransform<'a> {
+    fn new(program: &'a Program) -> Self {
+        Self {
+            schema: &program.schema,
+            errors: vec![],
+        }
+    }
+
+    fn find_list_directive<'d>(&mut self, directives: &'d [Directive]) -> Option<&'d Directive> {
+        let mut list_directives = directives.iter().filter(|directive| {
+            directive.name.item == *APPEND_TO_LIST
+                || directive.name.item == *PREPEND_TO_LIST
+                || directive.name.item == *DELETE_FROM_LIST
+        });
+        let list_directive = list_directives.next()?;
+        if let Some(other_directive) = list_directives.next() {
+            self.errors.push(
+                Diagnostic::error(
+                    ValidationMessage::ConflictingListDirectives {
+                        first_directive_name: list_directive.name.item,
+                        second_directive_name: other_directive.name.item,
+                    },
+                    other_directive.location,
+                )
+                .annotate("conflicting with", list_directive.location),
+            );
+            return None;
+        }
+        Some(list_directive)
+    }
+
+    fn get_string_argument<'d>(
+        &mut self,
+        directive: &'d Directive,
+        argument_name: ArgumentName,
+    ) -> Option<(&'d Argument, StringKey)> {
+        let Some(argument) = directive.arguments.named(argument_name) else {
+            self.errors.push(Diagnostic::error(
+                ValidationMessage::ListArgumentRequired {
+                    directive_name: directive.name.item,
+                    argument_name,
+                },
+                directive.location,
+            ));
+            return None;
+        };
+        match argument.value.item.get_string_literal() {
+            Some(value) => Some((argument, value)),
+            None => {
+                self.errors.push(Diagnostic::error(
+                    ValidationMessage::ListArgumentNotAStringLiteral {
+                        directive_name: directive.name.item,
+                        argument_name,
+                    },
+                    argument.value.location,
+                ));
+                None
+            }
+        }
+    }
+
+    /// Resolves the `parentTypeName`, `field` and `fieldArgs` arguments of the
+    /// directive to a plural linked field of the parent type, with constant
+    /// arguments.
+    fn resolve_list_field(&mut self, directive: &Directive) -> Option<ListField> {
+        let (parent_type_arg, parent_type_name) =
+            self.get_string_argument(directive, *PARENT_TYPE_NAME_ARG_NAME)?;
+        let (field_arg, field_name) = self.get_string_argument(directive, *FIELD_ARG_NAME)?;
+
+        let parent_type = match self.schema.get_type(parent_type_name) {
+            Some(type_ @ (Type::Object(_) | Type::Interface(_))) => type_,
+            _ => {
+                self.errors.push(Diagnostic::error(
+                    ValidationMessage::InvalidListParentTypeName {
+                        directive_name: directive.name.item,
+                        parent_type_name,
+                        suggestions: GraphQLSuggestions::new(self.schema)
+                            .composite_type_suggestions(parent_type_name),
+                    },
+                    parent_type_arg.value.location,
+                ));
+                return None;
+            }
+        };
+
+        let Some(field_id) = self.schema.named_field(parent_type, field_name) else {
+            self.errors.push(Diagnostic::error(
+                ValidationMessage::UnknownListField {
+                    directive_name: directive.name.item,
+                    field_name,
+                    parent_type_name,
+                    suggestions: GraphQLSuggestions::new(self.schema)
+                        .field_name_suggestion(Some(parent_type), field_name),
+                },
+                field_arg.value.location,
+            ));
+            return None;
+        };
+        let schema_field = self.schema.field(field_id);
+        if !schema_field.type_.is_list() || !schema_field.type_.inner().is_composite_type() {
+            self.errors.push(Diagnostic::error(
+                ValidationMessage::InvalidListFieldType {
+                    directive_name: directive.name.item,
+                    field_name,
+                    parent_type_name,
+                    current_type: self.schema.get_type_string(&schema_field.type_),
+                },
+                field_arg.value.location,
+            ));
+            return None;
+        }
+
+        let mut arguments = Vec::new();
+        for argument in self.get_list_field_arguments(directive)? {
+            let argument_name = argument.name.item;
+            let Some(argument_definition) = schema_field.arguments.named(argument_name) else {
+                self.errors.push(Diagnostic::error(
+                    ValidationMessage::UnknownListFieldArgument {
+                        directive_name: directive.name.item,
+                        field_name,
+                        argument_name,
+                    },
+                    argument.name.location,
+                ));
+                return None;
+            };
+            let Some(value) = coerce_list_field_argument_value(
+                self.schema,
+                &argument.value.item,
+                &argument_definition.type_,
+            ) else {
+                self.errors.push(Diagnostic::error(
+                    ValidationMessage::InvalidListFieldArgumentValue {
+                        directive_name: directive.name.item,
+                        field_name,
+                        argument_name,
+                        expected_type: self.schema.get_type_string(&argument_definition.type_),
+                    },
+                    argument.value.location,
+                ));
+                return None;
+            };
+            arguments.push(ConstantArgument {
+                name: argument.name,
+                value: WithLocation::new(argument.value.location, value),
+            });
+        }
+
+        Some(ListField {
+            name: field_name,
+            arguments,
+            item_type: schema_field.type_.inner(),
+        })
+    }
+
+    /// Returns the properties of the `fieldArgs` object of the directive, which
+    /// the `RelayListFieldArguments` scalar restricts to constant values.
+    fn get_list_field_arguments<'d>(
+        &mut self,
+        directive: &'d Directive,
+    ) -> Option<&'d [ConstantArgument]> {
+        let Some(argument) = directive.arguments.named(*FIELD_ARGS_ARG_NAME) else {
+            return Some(&[]);
+        };
+        match &argument.value.item {
+            Value::Constant(ConstantValue::Object(arguments)) => Some(arguments),
+            Value::Constant(ConstantValue::Null()) => Some(&[]),
+            _ => {
+                self.errors.push(Diagnostic::error(
+                    ValidationMessage::ListFieldArgumentsNotAnObjectLiteral {
+                        directive_name: directive.name.item,
+                    },
+                    argument.value.location,
+                ));
+                None
+            }
+        }
+    }
+
+    fn build_list_handle_directive(
+        &mut self,
+        directive: &Directive,
+        list_field: ListField,
+    ) -> Option<Directive> {
+        let Some(parent_id_arg) = directive.arguments.named(*PARENT_ID_ARG_NAME) else {
+            self.errors.push(Diagnostic::error(
+                ValidationMessage::ListArgumentRequired {
+                    directive_name: directive.name.item,
+                    argument_name: *PARENT_ID_ARG_NAME,
+                },
+                directive.location,
+            ));
+            return None;
+        };
+        let location = directive.location;
+        let mut handle_args = vec![
+            parent_id_arg.clone(),
+            Argument {
+                name: WithLocation::new(location, *FIELD_NAME_HANDLE_ARG),
+                value: WithLocation::new(
+                    location,
+                    Value::Constant(ConstantValue::String(list_field.name)),
+                ),
+            },
+        ];
+        if !list_field.arguments.is_empty() {
+            handle_args.push(Argument {
+                name: WithLocation::new(location, *FIELD_ARGS_HANDLE_ARG),
+                value: WithLocation::new(
+                    location,
+                    Value::Constant(ConstantValue::Object(list_field.arguments)),
+                ),
+            });
+        }
+        Some(build_handle_field_directive(HandleFieldDirectiveValues {
+            handle: directive.name.item.0,
+            key: *EMPTY_STRING,
+            dynamic_key: None,
+            filters: None,
+            handle_args: Some(handle_args),
+        }))
+    }
+}
+
+/// Checks a value of the `fieldArgs` object against the type of the list field
+/// argument, returning the value to use in the handle arguments. Custom scalar
+/// literals cannot contain enum values, so these are written as strings.
+fn coerce_list_field_argument_value(
+    schema: &SDLSchema,
+    value: &ConstantValue,
+    type_: &TypeReference<Type>,
+) -> Option<ConstantValue> {
+    match (type_, value) {
+        (TypeReference::NonNull(_), ConstantValue::Null()) => None,
+        (_, ConstantValue::Null()) => Some(ConstantValue::Null()),
+        (TypeReference::NonNull(of), _) => coerce_list_field_argument_value(schema, value, of),
+        (TypeReference::List(of), ConstantValue::List(items)) => items
+            .iter()
+            .map(|item| coerce_list_field_argument_value(schema, item, of))
+            .collect::<Option<Vec<_>>>()
+            .map(ConstantValue::List),
+        // As in GraphQL, a single item is accepted for a list
+        (TypeReference::List(of), _) => coerce_list_field_argument_value(schema, value, of),
+        (TypeReference::Named(Type::Enum(id)), ConstantValue::String(name))
+        | (TypeReference::Named(Type::Enum(id)), ConstantValue::Enum(name)) => schema
+            .enum_(*id)
+            .values
+            .iter()
+            .any(|enum_value| enum_value.value == *name)
+            .then_some(ConstantValue::Enum(*name)),
+        (TypeReference::Named(Type::InputObject(id)), ConstantValue::Object(fields)) => {
+            let input_object = schema.input_object(*id);
+            let mut next_fields = Vec::with_capacity(fields.len());
+            for field in fields {
+                let field_definition = input_object.fields.named(field.name.item)?;
+                next_fields.push(ConstantArgument {
+                    name: field.name,
+                    value: WithLocation::new(
+                        field.value.location,
+                        coerce_list_field_argument_value(
+                            schema,
+                            &field.value.item,
+                            &field_definition.type_,
+                        )?,
+                    ),
+                });
+            }
+            let has_missing_fields = input_object.fields.iter().any(|field_definition| {
+                field_definition.type_.is_non_null()
+                    && field_definition.default_value.is_none()
+                    && fields.named(field_definition.name.item).is_none()
+            });
+            (!has_missing_fields).then_some(ConstantValue::Object(next_fields))
+        }
+        (TypeReference::Named(Type::Scalar(id)), _) => {
+            match (schema.scalar(*id).name.item.0.lookup(), value) {
+                ("ID", ConstantValue::String(_) | ConstantValue::Int(_))
+                | ("String", ConstantValue::String(_))
+                | ("Int", ConstantValue::Int(_))
+                | ("Float", ConstantValue::Float(_))
+                | ("Boolean", ConstantValue::Boolean(_)) => Some(value.clone()),
+                ("Float", ConstantValue::Int(int)) => Some(ConstantValue::Float(From::from(*int))),
+                ("ID" | "String" | "Int" | "Float" | "Boolean", _) => None,
+                // Custom scalars accept any value
+                _ => Some(value.clone()),
+            }
+        }
+        _ => None,
+    }
+}
+
+fn replace_directive(
+    directives: &[Directive],
+    directive: &Directive,
+    handle_directive: Directive,
+) -> Vec<Directive> {
+    directives
+        .iter()
+        .map(|current| {
+            if current.name.item == directive.name.item {
+                handle_directive.clone()
+            } else {
+                current.clone()
+            }
+        })
+        .collect()
+}
+
+impl Transformer<'_> for DeclarativeListMutationTransform<'_> {
+    const NAME: &'static str = "DeclarativeListMutationTransform";
+    const VISIT_ARGUMENTS: bool = false;
+    const VISIT_DIRECTIVES: bool = false;
+
+    fn transform_fragment(&mut self, _: &FragmentDefinition) -> Transformed<FragmentDefinition> {
+        Transformed::Keep
+    }
+
+    fn transform_scalar_field(&mut self, field: &ScalarField) -> Transformed<Selection> {
+        let Some(list_directive) = self.find_list_directive(&field.directives) else {
+            return Transformed::Keep;
+        };
+        if list_directive.name.item != *DELETE_FROM_LIST {
+            self.errors.push(Diagnostic::error(
+                ValidationMessage::ListMutationDirectiveOnScalarField {
+                    directive_name: list_directive.name.item,
+                    field_name: field.alias_or_name(self.schema),
+                },
+                field.definition.location,
+            ));
+            return Transformed::Keep;
+        }
+        let field_definition = self.schema.field(field.definition.item);
+        if !self.schema.is_id(field_definition.type_.inner()) {
+            self.errors.push(Diagnostic::error(
+                ValidationMessage::DeleteFromListDirectiveOnUnsupportedType {
+                    directive_name: list_directive.name.item,
+                    field_name: field.alias_or_name(self.schema),
+                    current_type: self.schema.get_type_string(&field_definition.type_),
+                },
+                list_directive.location,
+            ));
+            return Transformed::Keep;
+        }
+        let Some(list_field) = self.resolve_list_field(list_directive) else {
+            return Transformed::Keep;
+        };
+        match self.build_list_handle_directive(list_directive, list_field) {
+            Some(handle_directive) => {
+                Transformed::Replace(Selection::ScalarField(Arc::new(ScalarField {
+                    directives: replace_directive(
+                        &field.directives,
+                        list_directive,
+                        handle_directive,
+                    ),
+                    ..field.clone()
+                })))
+            }
+            None => Transformed::Keep,
+        }
+    }
+
+    fn transform_linked_field(&mut self, field: &LinkedField) -> Transformed<Selection> {
+        let transformed_field = self.default_transform_linked_field(field);
+        let Some(list_directive) = self.find_list_directive(&field.directives) else {
+            return transformed_field;
+        };
+        if list_directive.name.item == *DELETE_FROM_LIST {
+            self.errors.push(Diagnostic::error(
+                ValidationMessage::DeleteFromListDirectiveOnLinkedField {
+                    directive_name: list_directive.name.item,
+                    field_name: field.alias_or_name(self.schema),
+                },
+                field.definition.location,
+            ));
+            return transformed_field;
+        }
+        let Some(list_field) = self.resolve_list_field(list_directive) else {
+            return transformed_field;
+        };
+
+        // The records of the payload field are added to the list, so they must
+        // be valid items of it.
+        let payload_type = self.schema.field(field.definition.item).type_.inner();
+        if payload_type != list_field.item_type
+            && !self
+                .schema
+                .is_named_type_subtype_of(payload_type, list_field.item_type)
+        {
+            self.errors.push(Diagnostic::error(
+                ValidationMessage::IncompatibleListItemType {
+                    directive_name: list_directive.name.item,
+                    field_name: field.alias_or_name(self.schema),
+                    payload_type: self.schema.get_type_name(payload_type),
+                    list_field_name: list_field.name,
+                    item_type: self.schema.get_type_name(list_field.item_type),
+                },
+                list_directive.location,
+            ));
+            return transformed_field;
+        }
+
+        let Some(handle_directive) = self.build_list_handle_directive(list_directive, list_field)
+        else {
+            return transformed_field;
+        };
+        let next_field = match transformed_field {
+            Transformed::Replace(Selection::LinkedField(linked_field)) => (*linked_field).clone(),
+            Transformed::Keep => field.clone(),
+            _ => {
+                panic!("DeclarativeList got unexpected transform result: `{transformed_field:?}`.")
+            }
+        };
+        Transformed::Replace(Selection::LinkedField(Arc::new(LinkedField {
+            directives: replace_directive(&next_field.directives, list_directive, handle_directive),
+            ..next_field
+        })))
+    }
+}
+
+#[derive(Debug, Error, serde::Serialize)]
+#[serde(tag = "type")]
+enum ValidationMessage {
+    #[error("Expected the '{argument_name}' argument to be defined on @{directive_name}.")]
+    ListArgumentRequired {
+        directive_name: DirectiveName,
+        argument_name: ArgumentName,
+    },
+
+    #[error("Expected the '{argument_name}' argument of @{directive_name} to be a string literal.")]
+    ListArgumentNotAStringLiteral {
+        directive_name: DirectiveName,
+        argument_name: ArgumentName,
+    },
+
+    #[error(
+        "Invalid use of @{first_directive_name} and @{second_directive_name} on the same field - these directives cannot be used together."
+    )]
+    ConflictingListDirectives {
+        first_directive_name: DirectiveName,
+        second_directive_name: DirectiveName,
+    },
+
+    #[error(
+        "Expected the 'parentTypeName' argument value on @{directive_name} to be the name of an object or interface type. '{parent_type_name}' does not refer to a known object or interface type.{suggestions}", suggestions = did_you_mean(suggestions)
+    )]
+    InvalidListParentTypeName {
+        directive_name: DirectiveName,
+        parent_type_name: StringKey,
+        suggestions: Vec<StringKey>,
+    },
+
+    #[error(
+        "Expected the 'fieldArgs' argument of @{directive_name} to be an object of constant values, e.g. '{{first: 10}}'."
+    )]
+    ListFieldArgumentsNotAnObjectLiteral { directive_name: DirectiveName },
+
+    #[error(
+        "Unknown field '{field_name}' on type '{parent_type_name}' in the 'field' argument of @{directive_name}.{suggestions}", suggestions = did_you_mean(suggestions)
+    )]
+    UnknownListField {
+        directive_name: DirectiveName,
+        field_name: StringKey,
+        parent_type_name: StringKey,
+        suggestions: Vec<StringKey>,
+    },
+
+    #[error(
+        "Unsupported use of @{directive_name} with field '{parent_type_name}.{field_name}'. Expected a list of objects, interfaces or unions, but got '{current_type}'."
+    )]
+    InvalidListFieldType {
+        directive_name: DirectiveName,
+        field_name: StringKey,
+        parent_type_name: StringKey,
+        current_type: String,
+    },
+
+    #[error(
+        "Unknown argument '{argument_name}' on field '{field_name}' in the 'fieldArgs' argument of @{directive_name}."
+    )]
+    UnknownListFieldArgument {
+        directive_name: DirectiveName,
+        field_name: StringKey,
+        argument_name: ArgumentName,
+    },
+
+    #[error(
+        "Expected the argument '{argument_name}' of field '{field_name}' in the 'fieldArgs' argument of @{directive_name} to be a constant value of type '{expected_type}'."
+    )]
+    InvalidListFieldArgumentValue {
+        directive_name: DirectiveName,
+        field_name: StringKey,
+        argument_name: ArgumentName,
+        expected_type: String,
+    },
+
+    #[error("Invalid use of @{directive_name} on scalar field '{field_name}'.")]
+    ListMutationDirectiveOnScalarField {
+        directive_name: DirectiveName,
+        field_name: StringKey,
+    },
+
+    #[error(
+        "Invalid use of @{directive_name} on field '{field_name}'. Expected field type 'ID', got '{current_type}'."
+    )]
+    DeleteFromListDirectiveOnUnsupportedType {
+        directive_name: DirectiveName,
+        field_name: StringKey,
+        current_type: String,
+    },
+
+    #[error("Invalid use of @{directive_name} on linked field '{field_name}'.")]
+    DeleteFromListDirectiveOnLinkedField {
+        directive_name: DirectiveName,
+        field_name: StringKey,
+    },
+
+    #[error(
+        "Invalid use of @{directive_name} on field '{field_name}'. Records of type '{payload_type}' cannot be added to the list field '{list_field_name}' of '{item_type}' items."
+    )]
+    IncompatibleListItemType {
+        directive_name: DirectiveName,
+        field_name: StringKey,
+        payload_type: StringKey,
+        list_field_name: StringKey,
+        item_type: StringKey,
+    },
+}
@@ -311,6 +311,98 @@ pub enum ValidationMessage {
         "The `@__relay_shadow_return` directive is internal to the Relay compiler and cannot be used in source. Shadow resolver return data is marked by spreading the resolver's `@returnFragment` placeholder inside its `@rootFragment`; the compiler generates this directive automatically."
     )]
     InternalShadowReturnDirectiveNotAllowed,
+
+    #[error("Unable to migrate the field `{parent_name}.{field_name}`: {reason}.")]
+    UnableToMigrateDeprecatedField {
+        parent_name: StringKey,
+        field_name: StringKey,
+        reason: String,
+    },
+
+    #[error(
+        "@paginatedList used on invalid field '{field_name}'. Expected the return type to be a non-plural interface or object, got '{type_string}'."
+    )]
+    InvalidPaginatedListFieldType {
+        field_name: StringKey,
+        type_string: String,
+    },
+
+    #[error(
+        "Invalid use of @paginatedList on field '{field_name}', it cannot be combined with @{connection_directive_name}."
+    )]
+    PaginatedListWithConnection {
+        field_name: StringKey,
+        connection_directive_name: DirectiveName,
+    },
+
+    #[error("Expected field '{field_name}' to be passed a '{limit_arg}' argument.")]
+    ExpectedPaginatedListToHaveLimitArg {
+        field_name: StringKey,
+        limit_arg: ArgumentName,
+    },
+
+    #[error("Expected '{field_name}' to be passed a '{items_selection_name}' selection.")]
+    ExpectedPaginatedListToHaveItemsSelection {
+        field_name: StringKey,
+        items_selection_name: StringKey,
+    },
+
+    #[error(
+        "@paginatedList used on invalid field '{field_name}'. Expected the field type '{type_name}' to expose a '{items_field_name}' field that returns a list."
+    )]
+    ExpectedPaginatedListToExposeValidItemsField {
+        field_name: StringKey,
+        type_name: StringKey,
+        items_field_name: StringKey,
+    },
+
+    #[error(
+        "@paginatedList used on invalid field '{field_name}'. Expected the field type '{type_name}' to expose a '{total_count_field_name}' field that returns a scalar."
+    )]
+    ExpectedPaginatedListToExposeValidTotalCountField {
+        field_name: StringKey,
+        type_name: StringKey,
+        total_count_field_name: StringKey,
+    },
+
+    #[error(
+        "@paginatedList used on invalid field '{field_name}'. Expected the field to accept an '{offset_arg}' argument."
+    )]
+    ExpectedPaginatedListToAcceptOffsetArg {
+        field_name: StringKey,
+        offset_arg: ArgumentName,
+    },
+
+    #[error(
+        "Expected the handler argument to @paginatedList to be a string literal for field '{field_name}'."
+    )]
+    InvalidPaginatedListHandlerArg { field_name: StringKey },
+
+    #[error(
+        "Expected the key argument to @paginatedList to be a string literal for field '{field_name}'."
+    )]
+    InvalidPaginatedListKeyArg { field_name: StringKey },
+
+    #[error(
+        "Expected the key argument to @paginatedList to be of form '<SomeName>{postfix}', got '{key_arg_value}'."
+    )]
+    InvalidPaginatedListKeyArgPostfix {
+        key_arg_value: StringKey,
+        postfix: String,
+    },
+
+    #[error(
+        "Expected the filters argument to @paginatedList to be a list of string literals for field '{field_name}'."
+    )]
+    InvalidPaginatedListFiltersArg { field_name: StringKey },
+
+    #[error(
+        "Expected the `filters` argument to `@paginatedList` to be a list of argument names to the field '{field_name}' to use to identify the list, got `{invalid_name}`. Not specifying `filters` is often recommended and will use all arguments except the offset and limit."
+    )]
+    InvalidPaginatedListFiltersArgNotAnArgument {
+        field_name: StringKey,
+        invalid_name: StringKey,
+    },
 }
 
 #[derive(
@@ -372,6 +464,13 @@ pub enum ValidationMessageWithData {
         condition_name: String,
     },
 
+    #[error("The field `{parent_name}.{field_name}` can be replaced with `{replacement}`.")]
+    ReplaceDeprecatedField {
+        parent_name: StringKey,
+        field_name: StringKey,
+        replacement: String,
+    },
+
     #[error("The Codemod '{codemod_name}' wants to update the query at this location to '{fix}.")]
     CodemodCustomErrorWithFix {
         codemod_name: StringKey,
@@ -421,6 +520,9 @@ impl WithDiagnosticData for ValidationMessageWithData {
                     Box::new(format!("{fragment_name} @alias")),
                 ]
             }
+            ValidationMessageWithData::ReplaceDeprecatedField { replacement, .. } => {
+                vec![Box::new(replacement.to_owned())]
+            }
             ValidationMessageWithData::CodemodCustomErrorWithFix { fix, .. } => {
                 vec![Box::new(fix.to_owned())]
             }
@@ -23,6 +23,7 @@ mod client_extensions_abstract_types;
 mod connections;
 mod debug_transform;
 mod declarative_connection;
+mod declarative_list;
 mod defer_stream;
 mod directive_finder;
 mod errors;
@@ -44,6 +45,7 @@ mod match_;
 mod metadata_directive;
 mod murmurhash;
 mod no_inline;
+mod paginated_lists;
 mod preloadable_directive;
 mod provided_variable_fragment_transform;
 pub mod raw_text;
@@ -55,6 +57,7 @@ mod relay_resolvers_abstract_types;
 mod remove_base_fragments;
 mod required_directive;
 mod root_variables;
+mod shared_normalization_selections;
 mod skip_client_directives;
 mod skip_client_extensions;
 mod skip_null_arguments_transform;
@@ -65,6 +68,7 @@ mod skip_updatable_queries;
 mod sort_selections;
 mod test_operation_metadata;
 mod transform_connections;
+mod transform_paginated_lists;
 mod unwrap_custom_directive_selection;
 mod util;
 mod validate_operation_variables;
@@ -129,6 +133,7 @@ pub use connections::ConnectionMetadata;
 pub use connections::extract_connection_metadata_from_directive;
 pub use debug_transform::debug_transform;
 pub use declarative_connection::transform_declarative_connection;
+pub use declarative_list::transform_declarative_list;
 pub use defer_stream::DeferDirective;
 pub use defer_stream::StreamDirective;
 pub use defer_stream::transform_defer_stream;
@@ -166,6 +171,10 @@ pub use match_::split_module_import;
 pub use match_::transform_match;
 pub use match_::transform_subscriptions;
 pub use no_inline::NO_INLINE_DIRECTIVE_NAME;
+pub use paginated_lists::PAGINATED_LIST_DIRECTIVE_NAME;
+pub use paginated_lists::PaginatedListInterface;
+pub use paginated_lists::PaginatedListMetadata;
+pub use paginated_lists::extract_paginated_list_metadata_from_directive;
 pub use preloadable_directive::is_operation_preloadable;
 pub use preloadable_directive::should_generate_hack_preloader;
 pub use provided_variable_fragment_transform::provided_variable_fragment_transform;
@@ -197,6 +206,11 @@ pub use required_directive::RequiredMetadataDirective;
 pub use required_directive::THROW_ACTION;
 pub use required_directive::required_directive;
 pub use root_variables::VariableMapEntry;
+pub use shared_normalization_selections::SharedNormalizationSelections;
+pub use shared_normalization_selections::SharedNormalizationSelectionsMetadata;
+pub use shared_normalization_selections::extract_shared_normalization_selections;
+pub use shared_normalization_selections::generate_shared_normalization_selections;
+pub use shared_normalization_selections::get_shared_normalization_selections_name;
 pub use skip_client_directives::skip_client_directives;
 pub use skip_client_extensions::skip_client_extensions;
 pub use skip_null_arguments_transform::skip_null_arguments_transform;
@@ -209,6 +223,7 @@ pub use sort_selections::sort_selections;
 pub use test_operation_metadata::generate_test_operation_metadata;
 pub use transform_connections::PrefetchablePaginationEdgesFragmentMetadata;
 pub use transform_connections::transform_connections;
+pub use transform_paginated_lists::transform_paginated_lists;
 pub use unwrap_custom_directive_selection::unwrap_custom_directive_selection;
 pub use util::extract_variable_name;
 pub use util::generate_abstract_type_refinement_key;
@@ -0,0 +1,101 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::sync::LazyLock;
+
+use common::ArgumentName;
+use common::DirectiveName;
+use common::NamedItem;
+use graphql_ir::Directive;
+use graphql_ir::LinkedField;
+use graphql_ir::associated_data_impl;
+use intern::string_key::Intern;
+use intern::string_key::StringKey;
+pub use relay_config::PaginatedListInterface;
+
+use crate::util::extract_variable_name;
+
+pub static PAGINATED_LIST_DIRECTIVE_NAME: LazyLock<DirectiveName> =
+    LazyLock::new(|| DirectiveName("paginatedList".intern()));
+/// The handler used for `@paginatedList` fields that don't specify one.
+pub static PAGINATED_LIST_HANDLER: LazyLock<StringKey> = LazyLock::new(|| "paginatedList".intern());
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PaginatedListMetadataDirective(pub Vec<PaginatedListMetadata>);
+
+associated_data_impl!(PaginatedListMetadataDirective);
+
+/// Describes an offset based list annotated with `@paginatedList`, attached
+/// to the document root (fragment or operation).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PaginatedListMetadata {
+    /// Path to the list field, or `None` if it is nested in a plural field.
+    pub path: Option<Vec<StringKey>>,
+    /// Name of the variable passed to the offset argument.
+    pub offset: Option<StringKey>,
+    /// Name of the variable passed to the limit argument.
+    pub limit: Option<StringKey>,
+}
+
+/// Builds the metadata for a `@paginatedList` field that has already been
+/// validated.
+pub fn build_paginated_list_metadata(
+    list_field: &LinkedField,
+    paginated_list_interface: &PaginatedListInterface,
+    path: &Option<Vec<StringKey>>,
+) -> PaginatedListMetadata {
+    PaginatedListMetadata {
+        path: path.clone(),
+        offset: extract_variable_name(
+            list_field
+                .arguments
+                .named(ArgumentName(paginated_list_interface.offset)),
+        ),
+        limit: extract_variable_name(
+            list_field
+                .arguments
+                .named(ArgumentName(paginated_list_interface.limit)),
+        ),
+    }
+}
+
+pub fn extract_paginated_list_metadata_from_directive(
+    directives: &[Directive],
+) -> Option<&[PaginatedListMetadata]> {
+    PaginatedListMetadataDirective::find(directives).map(|metadatas| metadatas.0.as_slice())
+}
+
+/// Helper to extract the `@paginatedList` directive if present in the given
+/// list of directives
+pub fn extract_paginated_list_directive(directives: &[Directive]) -> Option<&Directive> {
+    directives.named(*PAGINATED_LIST_DIRECTIVE_NAME)
+}
+
+/// Helper to get the default set of filters to be used for a `@paginatedList`
+/// handle field when no filters are explicitly specified: all arguments except
+/// the offset and limit.
+pub fn get_default_paginated_list_filters(
+    list_field: &LinkedField,
+    paginated_list_interface: &PaginatedListInterface,
+) -> Option<Vec<StringKey>> {
+    let filtered_args = list_field
+        .arguments
+        .iter()
+        .filter_map(|arg| {
+            let name = arg.name.item.0;
+            if name == paginated_list_interface.offset || name == paginated_list_interface.limit {
+                None
+            } else {
+                Some(name)
+            }
+        })
+        .collect::<Vec<_>>();
+    if filtered_args.is_empty() {
+        return None;
+    }
+    Some(filtered_args)
+}
@@ -54,6 +54,8 @@ use self::refetchable_directive::RefetchableDirective;
 use self::validation_message::ValidationMessage;
 use crate::connections::ConnectionConstants;
 use crate::connections::extract_connection_metadata_from_directive;
+use crate::paginated_lists::PaginatedListInterface;
+use crate::paginated_lists::extract_paginated_list_metadata_from_directive;
 use crate::relay_directive::PLURAL_ARG_NAME;
 use crate::relay_directive::RELAY_DIRECTIVE_NAME;
 use crate::root_variables::InferVariablesVisitor;
@@ -240,6 +242,7 @@ impl<'program, 'pc> RefetchableFragment<'program, 'pc> {
             )? {
                 if !self.for_typegen {
                     self.validate_connection_metadata(refetch_root.fragment.as_ref())?;
+                    self.validate_paginated_list_metadata(refetch_root.fragment.as_ref())?;
                 }
                 return Ok((refetchable_directive, refetch_root));
             }
@@ -403,6 +406,53 @@ impl<'program, 'pc> RefetchableFragment<'program, 'pc> {
         }
         Ok(())
     }
+
+    /// Validate that any @paginatedList usage is valid for refetching:
+    /// - Variables are used for both the offset and limit arguments
+    /// - Exactly one paginated list, and no connection
+    /// - Has a stable path to the list data
+    ///
+    /// Paginated list metadata is extracted in `transform_paginated_lists`
+    fn validate_paginated_list_metadata(
+        &self,
+        fragment: &FragmentDefinition,
+    ) -> DiagnosticsResult<()> {
+        let Some(metadatas) = extract_paginated_list_metadata_from_directive(&fragment.directives)
+        else {
+            return Ok(());
+        };
+        let error = |message| Err(vec![Diagnostic::error(message, fragment.name.location)]);
+        if extract_connection_metadata_from_directive(&fragment.directives).is_some() {
+            return error(
+                ValidationMessage::RefetchableWithConnectionAndPaginatedList {
+                    fragment_name: fragment.name.item,
+                },
+            );
+        }
+        match metadatas {
+            [metadata] => {
+                if metadata.path.is_none() {
+                    return error(ValidationMessage::RefetchableWithPaginatedListInPlural {
+                        fragment_name: fragment.name.item,
+                    });
+                }
+                if metadata.offset.is_none() || metadata.limit.is_none() {
+                    let PaginatedListInterface { offset, limit, .. } =
+                        &self.project_config.schema_config.paginated_list_interface;
+                    return error(
+                        ValidationMessage::RefetchableWithConstPaginatedListArguments {
+                            fragment_name: fragment.name.item,
+                            arguments: format!("{offset} and {limit}"),
+                        },
+                    );
+                }
+                Ok(())
+            }
+            _ => error(ValidationMessage::RefetchableWithMultiplePaginatedLists {
+                fragment_name: fragment.name.item,
+            }),
+        }
+    }
 }
 
 type BuildRefetchOperationFn = fn(
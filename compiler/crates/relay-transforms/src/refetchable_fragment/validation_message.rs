@@ -65,6 +65,35 @@ pub(super) enum ValidationMessage {
         arguments: &'static str,
     },
 
+    #[error(
+        "Invalid use of @refetchable with @paginatedList in fragment '{fragment_name}', at most one @paginatedList can appear in a refetchable fragment."
+    )]
+    RefetchableWithMultiplePaginatedLists {
+        fragment_name: FragmentDefinitionName,
+    },
+
+    #[error(
+        "Invalid use of @refetchable with @paginatedList in fragment '{fragment_name}', refetchable paginated lists cannot appear inside plural fields."
+    )]
+    RefetchableWithPaginatedListInPlural {
+        fragment_name: FragmentDefinitionName,
+    },
+
+    #[error(
+        "Invalid use of @refetchable with @paginatedList in fragment '{fragment_name}', refetchable paginated lists must use variables for the {arguments} arguments."
+    )]
+    RefetchableWithConstPaginatedListArguments {
+        fragment_name: FragmentDefinitionName,
+        arguments: String,
+    },
+
+    #[error(
+        "Invalid use of @refetchable in fragment '{fragment_name}', a refetchable fragment can paginate either a @connection or a @paginatedList, but not both."
+    )]
+    RefetchableWithConnectionAndPaginatedList {
+        fragment_name: FragmentDefinitionName,
+    },
+
     #[error(
         r#"When provided, the `directives` argument to `@refetchable` needs to be a list of literal strings. Each string should be a server directive valid on queries. Example: `@refetchable(queryName: "ExampleQuery", directives: ["@owner(name: \"an owner\")"])"#
     )]
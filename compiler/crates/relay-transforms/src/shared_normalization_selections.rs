@@ -0,0 +1,135 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::sync::Arc;
+
+use ::intern::string_key::Intern;
+use common::FeatureFlag;
+use common::NamedItem;
+use graphql_ir::FragmentDefinition;
+use graphql_ir::FragmentDefinitionName;
+use graphql_ir::FragmentDefinitionNameMap;
+use graphql_ir::FragmentDefinitionNameSet;
+use graphql_ir::FragmentSignature;
+use graphql_ir::FragmentSpread;
+use graphql_ir::OperationDefinition;
+use graphql_ir::OperationDefinitionName;
+use graphql_ir::Program;
+use graphql_ir::Selection;
+use graphql_ir::associated_data_impl;
+use graphql_syntax::OperationKind;
+
+use crate::ArtifactSourceKeyData;
+use crate::NO_INLINE_DIRECTIVE_NAME;
+
+/// Marks the operation generated for a fragment by
+/// `generate_shared_normalization_selections`. Its normalization selections
+/// are the ones of the fragment spread on a field of the fragment's type.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SharedNormalizationSelectionsMetadata {
+    pub fragment_name: FragmentDefinitionName,
+}
+associated_data_impl!(SharedNormalizationSelectionsMetadata);
+
+/// The operations generated by `generate_shared_normalization_selections`, by
+/// the name of their fragment. They are not operations of the project, so they
+/// are kept out of the normalization program.
+pub type SharedNormalizationSelections = FragmentDefinitionNameMap<Arc<OperationDefinition>>;
+
+pub fn get_shared_normalization_selections_name(name: FragmentDefinitionName) -> String {
+    format!("{name}$normalizationSelections")
+}
+
+/// For every fragment enabled by `feature`, adds an operation that only spreads
+/// that fragment. After the normalization transforms, its selections are the
+/// ones any query inlining the fragment in the same context would contain,
+/// and codegen can replace such selections with an import of the shared module.
+///
+/// Fragments with arguments, `@no_inline` fragments, fragments of the base
+/// project and fragments generated for Relay Resolvers are not shared.
+pub fn generate_shared_normalization_selections(
+    program: &Program,
+    feature: &FeatureFlag,
+    base_fragment_names: &FragmentDefinitionNameSet,
+) -> Program {
+    if matches!(feature, FeatureFlag::Disabled) {
+        return program.clone();
+    }
+    let mut next_program = program.clone();
+    for fragment in program.fragments() {
+        if !is_shareable_fragment(fragment, feature, base_fragment_names) {
+            continue;
+        }
+        next_program.insert_operation(Arc::new(OperationDefinition {
+            name: fragment.name.map(|name| {
+                OperationDefinitionName(get_shared_normalization_selections_name(name).intern())
+            }),
+            type_: fragment.type_condition,
+            variable_definitions: vec![],
+            directives: vec![
+                SharedNormalizationSelectionsMetadata {
+                    fragment_name: fragment.name.item,
+                }
+                .into(),
+            ],
+            selections: vec![Selection::FragmentSpread(Arc::new(FragmentSpread {
+                fragment: fragment.name,
+                arguments: vec![],
+                signature: Some(FragmentSignature {
+                    name: fragment.name,
+                    variable_definitions: vec![],
+                    type_condition: fragment.type_condition,
+                    directives: fragment.directives.clone(),
+                }),
+                directives: vec![],
+            }))],
+            kind: OperationKind::Query,
+        }));
+    }
+    next_program
+}
+
+/// Moves the operations added by `generate_shared_normalization_selections`
+/// out of the normalization program.
+pub fn extract_shared_normalization_selections(
+    program: Arc<Program>,
+) -> (Arc<Program>, SharedNormalizationSelections) {
+    let mut shared_normalization_selections = SharedNormalizationSelections::default();
+    for operation in program.operations() {
+        if let Some(metadata) = SharedNormalizationSelectionsMetadata::find(&operation.directives) {
+            shared_normalization_selections.insert(metadata.fragment_name, Arc::clone(operation));
+        }
+    }
+    if shared_normalization_selections.is_empty() {
+        return (program, shared_normalization_selections);
+    }
+    let mut next_program = Program::new(Arc::clone(&program.schema));
+    for fragment in program.fragments() {
+        next_program.insert_fragment(Arc::clone(fragment));
+    }
+    for operation in program.operations() {
+        if SharedNormalizationSelectionsMetadata::find(&operation.directives).is_none() {
+            next_program.insert_operation(Arc::clone(operation));
+        }
+    }
+    (Arc::new(next_program), shared_normalization_selections)
+}
+
+fn is_shareable_fragment(
+    fragment: &FragmentDefinition,
+    feature: &FeatureFlag,
+    base_fragment_names: &FragmentDefinitionNameSet,
+) -> bool {
+    feature.is_enabled_for(fragment.name.item.0)
+        && fragment.variable_definitions.is_empty()
+        && fragment
+            .directives
+            .named(*NO_INLINE_DIRECTIVE_NAME)
+            .is_none()
+        && !base_fragment_names.contains(&fragment.name.item)
+        && ArtifactSourceKeyData::find(&fragment.directives).is_none()
+}
@@ -0,0 +1,249 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::sync::Arc;
+
+use common::WithLocation;
+use graphql_ir::Directive;
+use graphql_ir::Field;
+use graphql_ir::FragmentDefinition;
+use graphql_ir::LinkedField;
+use graphql_ir::OperationDefinition;
+use graphql_ir::Program;
+use graphql_ir::ScalarField;
+use graphql_ir::Selection;
+use graphql_ir::Transformed;
+use graphql_ir::Transformer;
+use intern::string_key::StringKey;
+use schema::Schema;
+
+use crate::handle_fields::build_handle_field_directive_from_connection_directive;
+use crate::paginated_lists::PAGINATED_LIST_HANDLER;
+use crate::paginated_lists::PaginatedListInterface;
+use crate::paginated_lists::PaginatedListMetadata;
+use crate::paginated_lists::PaginatedListMetadataDirective;
+use crate::paginated_lists::build_paginated_list_metadata;
+use crate::paginated_lists::extract_paginated_list_directive;
+use crate::paginated_lists::get_default_paginated_list_filters;
+
+/// Transforms offset based list fields annotated with `@paginatedList`:
+/// - The directive is replaced with a handle field directive, so the list is
+///   stored under a stable key that the runtime handler merges pages into.
+/// - The `items` and `totalCount` fields are selected under their own names,
+///   as the runtime relies on them to merge pages and to know when the end of
+///   the list was reached.
+/// - The metadata of each list is attached to the document root.
+pub fn transform_paginated_lists(
+    program: &Program,
+    paginated_list_interface: &PaginatedListInterface,
+) -> Program {
+    let mut transform = PaginatedListTransform::new(program, paginated_list_interface);
+    transform
+        .transform_program(program)
+        .replace_or_else(|| program.clone())
+}
+
+struct PaginatedListTransform<'s> {
+    paginated_list_interface: &'s PaginatedListInterface,
+    current_path: Option<Vec<StringKey>>,
+    current_metadata: Vec<PaginatedListMetadata>,
+    program: &'s Program,
+}
+
+impl<'s> PaginatedListTransform<'s> {
+    fn new(program: &'s Program, paginated_list_interface: &'s PaginatedListInterface) -> Self {
+        Self {
+            paginated_list_interface,
+            current_path: None,
+            current_metadata: Vec::new(),
+            program,
+        }
+    }
+
+    fn transform_list_selections(&mut self, list_field: &LinkedField) -> Vec<Selection> {
+        let schema = &self.program.schema;
+        let mut next_selections = self
+            .transform_selections(&list_field.selections)
+            .replace_or_else(|| list_field.selections.clone());
+        let list_type = schema.field(list_field.definition.item).type_.inner();
+
+        // Relay runtime relies on the items and totalCount fields being present
+        // under their own names, regardless of any alias used by the product code.
+        let has_unaliased_selection = |selections: &[Selection], name: StringKey| {
+            selections.iter().any(|selection| match selection {
+                Selection::LinkedField(field) => {
+                    field.alias_or_name(schema) == name
+                        && schema.field(field.definition.item).name.item == name
+                }
+                Selection::ScalarField(field) => {
+                    field.alias_or_name(schema) == name
+                        && schema.field(field.definition.item).name.item == name
+                }
+                _ => false,
+            })
+        };
+
+        let items_name = self.paginated_list_interface.items;
+        if !has_unaliased_selection(&next_selections, items_name) {
+            let aliased_items = next_selections
+                .iter()
+                .find_map(|selection| match selection {
+                    Selection::LinkedField(field)
+                        if schema.field(field.definition.item).name.item == items_name =>
+                    {
+                        Some(Selection::LinkedField(Arc::new(LinkedField {
+                            alias: None,
+                            ..field.as_ref().clone()
+                        })))
+                    }
+                    Selection::ScalarField(field)
+                        if schema.field(field.definition.item).name.item == items_name =>
+                    {
+                        Some(Selection::ScalarField(Arc::new(ScalarField {
+                            alias: None,
+                            ..field.as_ref().clone()
+                        })))
+                    }
+                    _ => None,
+                });
+            next_selections.extend(aliased_items);
+        }
+
+        let total_count_name = self.paginated_list_interface.total_count;
+        if !has_unaliased_selection(&next_selections, total_count_name) {
+            let total_count_field_id = schema.named_field(list_type, total_count_name).expect(
+                "Expected presence of total count field to have been previously validated.",
+            );
+            next_selections.push(Selection::ScalarField(Arc::new(ScalarField {
+                alias: None,
+                definition: WithLocation::generated(total_count_field_id),
+                arguments: Vec::new(),
+                directives: Vec::new(),
+            })));
+        }
+        next_selections
+    }
+
+    fn transform_list_directives(
+        &self,
+        list_field: &LinkedField,
+        list_directive: &Directive,
+    ) -> Vec<Directive> {
+        let handle_directive = build_handle_field_directive_from_connection_directive(
+            list_directive,
+            Some(*PAGINATED_LIST_HANDLER),
+            get_default_paginated_list_filters(list_field, self.paginated_list_interface),
+        );
+        let mut next_directives = list_field
+            .directives
+            .iter()
+            .filter(|&directive| directive != list_directive)
+            .cloned()
+            .collect::<Vec<_>>();
+        next_directives.push(handle_directive);
+        next_directives
+    }
+
+    fn transform_list_field(
+        &mut self,
+        list_field: &LinkedField,
+        list_directive: &Directive,
+    ) -> Transformed<Selection> {
+        let metadata = build_paginated_list_metadata(
+            list_field,
+            self.paginated_list_interface,
+            &self.current_path,
+        );
+        let next_selections = self.transform_list_selections(list_field);
+        let next_directives = self.transform_list_directives(list_field, list_directive);
+        self.current_metadata.push(metadata);
+
+        Transformed::Replace(Selection::LinkedField(Arc::new(LinkedField {
+            selections: next_selections,
+            directives: next_directives,
+            ..list_field.clone()
+        })))
+    }
+
+    fn get_metadata_directive(&mut self) -> Directive {
+        PaginatedListMetadataDirective(std::mem::take(&mut self.current_metadata)).into()
+    }
+}
+
+impl Transformer<'_> for PaginatedListTransform<'_> {
+    const NAME: &'static str = "PaginatedListTransform";
+    const VISIT_ARGUMENTS: bool = false;
+    const VISIT_DIRECTIVES: bool = false;
+
+    fn transform_operation(
+        &mut self,
+        operation: &OperationDefinition,
+    ) -> Transformed<OperationDefinition> {
+        self.current_path = Some(Vec::new());
+        self.current_metadata = Vec::new();
+
+        let transformed = self.default_transform_operation(operation);
+        if self.current_metadata.is_empty() {
+            return transformed;
+        }
+
+        let mut transformed_operation = match transformed {
+            Transformed::Delete => return Transformed::Delete,
+            Transformed::Keep => operation.clone(),
+            Transformed::Replace(replaced) => replaced,
+        };
+        transformed_operation
+            .directives
+            .push(self.get_metadata_directive());
+        Transformed::Replace(transformed_operation)
+    }
+
+    fn transform_fragment(
+        &mut self,
+        fragment: &FragmentDefinition,
+    ) -> Transformed<FragmentDefinition> {
+        self.current_path = Some(Vec::new());
+        self.current_metadata = Vec::new();
+
+        let transformed = self.default_transform_fragment(fragment);
+        if self.current_metadata.is_empty() {
+            return transformed;
+        }
+
+        let mut transformed_fragment = match transformed {
+            Transformed::Delete => return Transformed::Delete,
+            Transformed::Keep => fragment.clone(),
+            Transformed::Replace(replaced) => replaced,
+        };
+        transformed_fragment
+            .directives
+            .push(self.get_metadata_directive());
+        Transformed::Replace(transformed_fragment)
+    }
+
+    fn transform_linked_field(&mut self, field: &LinkedField) -> Transformed<Selection> {
+        let schema = &self.program.schema;
+        let schema_field = schema.field(field.definition.item);
+        let current_path_at_field = self.current_path.clone();
+
+        // Keep track of the current path as long as we don't encounter plural field.
+        if schema_field.type_.is_list() {
+            self.current_path = None
+        } else if let Some(path) = self.current_path.as_mut() {
+            path.push(field.alias_or_name(schema))
+        }
+
+        let res = if let Some(list_directive) = extract_paginated_list_directive(&field.directives)
+        {
+            self.transform_list_field(field, list_directive)
+        } else {
+            self.default_transform_linked_field(field)
+        };
+        self.current_path = current_path_at_field;
+        res
+    }
+}
@@ -41,6 +41,7 @@ use crate::connections::ConnectionMetadataDirective;
 use crate::fragment_alias_directive::FRAGMENT_DANGEROUSLY_UNALIAS_DIRECTIVE_NAME;
 use crate::handle_fields::HANDLE_FIELD_DIRECTIVE_NAME;
 use crate::inline_data_fragment::InlineDirectiveMetadata;
+use crate::paginated_lists::PaginatedListMetadataDirective;
 use crate::raw_text::RAW_TEXT_DIRECTIVE_NAME;
 use crate::refetchable_fragment::RefetchableMetadata;
 use crate::required_directive::CHILDREN_CAN_BUBBLE_METADATA_KEY;
@@ -88,13 +89,14 @@ pub fn extract_variable_name(argument: Option<&Argument>) -> Option<StringKey> {
     }
 }
 
-static CUSTOM_METADATA_DIRECTIVES: LazyLock<[DirectiveName; 22]> = LazyLock::new(|| {
+static CUSTOM_METADATA_DIRECTIVES: LazyLock<[DirectiveName; 23]> = LazyLock::new(|| {
     [
         *CATCH_DIRECTIVE_NAME,
         *CLIENT_EXTENSION_DIRECTIVE_NAME,
         ConnectionMetadataDirective::directive_name(),
         *HANDLE_FIELD_DIRECTIVE_NAME,
         ModuleMetadata::directive_name(),
+        PaginatedListMetadataDirective::directive_name(),
         PrefetchablePaginationEdgesFragmentMetadata::directive_name(),
         *DIRECTIVE_SPLIT_OPERATION,
         RefetchableMetadata::directive_name(),
@@ -115,12 +117,13 @@ static CUSTOM_METADATA_DIRECTIVES: LazyLock<[DirectiveName; 22]> = LazyLock::new
     ]
 });
 
-static DIRECTIVES_SKIPPED_IN_NODE_IDENTIFIER: LazyLock<[DirectiveName; 11]> = LazyLock::new(|| {
+static DIRECTIVES_SKIPPED_IN_NODE_IDENTIFIER: LazyLock<[DirectiveName; 12]> = LazyLock::new(|| {
     [
         *CATCH_DIRECTIVE_NAME,
         *CLIENT_EXTENSION_DIRECTIVE_NAME,
         ConnectionMetadataDirective::directive_name(),
         *HANDLE_FIELD_DIRECTIVE_NAME,
+        PaginatedListMetadataDirective::directive_name(),
         RefetchableMetadata::directive_name(),
         RefetchableDerivedFromMetadata::directive_name(),
         *INTERNAL_METADATA_DIRECTIVE,
@@ -12,6 +12,7 @@ mod disallow_readtime_features_in_mutations;
 mod disallow_required_on_non_null_field;
 mod disallow_reserved_aliases;
 mod disallow_typename_on_root;
+mod migrate_deprecated_fields;
 mod validate_client_schema_extensions_use_catch;
 mod validate_connections;
 mod validate_fragment_alias_conflict;
@@ -21,6 +22,7 @@ mod validate_module_names;
 mod validate_no_double_underscore_alias;
 mod validate_no_inline_with_raw_response_type;
 mod validate_no_unselectable_selections;
+mod validate_paginated_lists;
 mod validate_relay_directives;
 mod validate_required_arguments;
 mod validate_resolver_fragments;
@@ -37,6 +39,8 @@ pub use disallow_readtime_features_in_mutations::disallow_readtime_features_in_m
 pub use disallow_required_on_non_null_field::disallow_required_on_non_null_field;
 pub use disallow_reserved_aliases::disallow_reserved_aliases;
 pub use disallow_typename_on_root::disallow_typename_on_root;
+pub use migrate_deprecated_fields::DeprecatedFieldReplacements;
+pub use migrate_deprecated_fields::migrate_deprecated_fields;
 pub use validate_client_schema_extensions_use_catch::validate_client_schema_extensions_use_catch;
 pub use validate_connections::validate_connections;
 pub use validate_fragment_alias_conflict::validate_fragment_alias_conflict;
@@ -47,6 +51,7 @@ pub use validate_module_names::validate_module_names;
 pub use validate_no_double_underscore_alias::validate_no_double_underscore_alias;
 pub use validate_no_inline_with_raw_response_type::validate_no_inline_fragments_with_raw_response_type;
 pub use validate_no_unselectable_selections::validate_no_unselectable_selections;
+pub use validate_paginated_lists::validate_paginated_lists;
 pub use validate_relay_directives::validate_relay_directives;
 pub use validate_required_arguments::validate_required_arguments;
 pub use validate_resolver_fragments::validate_resolver_fragments;
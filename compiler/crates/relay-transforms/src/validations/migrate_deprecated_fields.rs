@@ -0,0 +1,202 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::sync::Arc;
+use std::sync::LazyLock;
+
+use common::Diagnostic;
+use common::DiagnosticsResult;
+use common::NamedItem;
+use common::WithLocation;
+use graphql_ir::Argument;
+use graphql_ir::LinkedField;
+use graphql_ir::Program;
+use graphql_ir::ScalarField;
+use graphql_ir::Validator;
+use intern::Lookup;
+use intern::string_key::Intern;
+use intern::string_key::StringKey;
+use regex::Regex;
+use rustc_hash::FxHashMap;
+use schema::FieldID;
+use schema::SDLSchema;
+use schema::Schema;
+
+use crate::ValidationMessage;
+use crate::ValidationMessageWithData;
+
+/// Explicit replacements, keyed by the parent type name and the name of the
+/// field to replace, taking precedence over the deprecation reason.
+pub type DeprecatedFieldReplacements = FxHashMap<(StringKey, StringKey), StringKey>;
+
+/// Matches deprecation reasons following the "Use `newField`" convention. The
+/// replacement may be qualified by its type, as in "Use `User.newField`".
+static REPLACEMENT_IN_REASON: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\buse\s+`(?:[_A-Za-z][_0-9A-Za-z]*\.)?([_A-Za-z][_0-9A-Za-z]*)`").unwrap()
+});
+
+/// Finds selections of deprecated fields, and fields with an explicit
+/// replacement, that can be replaced by another field of the same type.
+/// Fixable selections are reported with their replacement text, keeping the
+/// response key of the old field so generated types don't change. All other
+/// selections of deprecated fields are reported without a fix.
+pub fn migrate_deprecated_fields(
+    program: &Program,
+    replacements: &DeprecatedFieldReplacements,
+) -> DiagnosticsResult<()> {
+    let mut validator = MigrateDeprecatedFields::new(&program.schema, replacements);
+    validator.validate_program(program)?;
+
+    if validator.warnings.is_empty() {
+        Ok(())
+    } else {
+        Err(validator.warnings)
+    }
+}
+
+struct MigrateDeprecatedFields<'a> {
+    schema: &'a Arc<SDLSchema>,
+    replacements: &'a DeprecatedFieldReplacements,
+    warnings: Vec<Diagnostic>,
+}
+
+impl<'a> MigrateDeprecatedFields<'a> {
+    fn new(schema: &'a Arc<SDLSchema>, replacements: &'a DeprecatedFieldReplacements) -> Self {
+        Self {
+            schema,
+            replacements,
+            warnings: vec![],
+        }
+    }
+
+    fn migrate_field(
+        &mut self,
+        definition: &WithLocation<FieldID>,
+        alias: Option<WithLocation<StringKey>>,
+        arguments: &[Argument],
+    ) {
+        let field = self.schema.field(definition.item);
+        let Some(parent_type) = field.parent_type else {
+            return;
+        };
+        let parent_name = self.schema.get_type_name(parent_type);
+        let field_name = field.name.item;
+
+        let replacement_name = match self.replacements.get(&(parent_name, field_name)) {
+            Some(replacement_name) => *replacement_name,
+            None => {
+                let Some(deprecation) = field.deprecated() else {
+                    return;
+                };
+                match deprecation.reason.and_then(|reason| {
+                    REPLACEMENT_IN_REASON
+                        .captures(reason.lookup())
+                        .map(|captures| captures[1].intern())
+                }) {
+                    Some(replacement_name) => replacement_name,
+                    None => {
+                        return self.report_unfixable(
+                            definition,
+                            "the deprecation reason does not name a replacement field".to_string(),
+                        );
+                    }
+                }
+            }
+        };
+
+        let Some(replacement_id) = self.schema.named_field(parent_type, replacement_name) else {
+            return self.report_unfixable(
+                definition,
+                format!("`{parent_name}` has no field `{replacement_name}`"),
+            );
+        };
+        let replacement = self.schema.field(replacement_id);
+
+        if replacement.type_ != field.type_ {
+            return self.report_unfixable(
+                definition,
+                format!(
+                    "`{}` has type `{}`, but `{}` has type `{}`",
+                    replacement_name,
+                    self.schema.get_type_string(&replacement.type_),
+                    field_name,
+                    self.schema.get_type_string(&field.type_),
+                ),
+            );
+        }
+        if let Some(argument) = arguments
+            .iter()
+            .find(|argument| replacement.arguments.named(argument.name.item).is_none())
+        {
+            return self.report_unfixable(
+                definition,
+                format!(
+                    "`{}` does not accept the argument `{}`",
+                    replacement_name, argument.name.item
+                ),
+            );
+        }
+        if let Some(argument) = replacement.arguments.iter().find(|argument| {
+            argument.type_.is_non_null()
+                && argument.default_value.is_none()
+                && arguments.named(argument.name.item).is_none()
+        }) {
+            return self.report_unfixable(
+                definition,
+                format!(
+                    "`{}` requires the argument `{}`",
+                    replacement_name, argument.name.item
+                ),
+            );
+        }
+
+        // Unaliased selections get the old field name as alias, so the
+        // response key and the generated types stay the same.
+        let replacement_text = match alias {
+            Some(_) => replacement_name.to_string(),
+            None => format!("{field_name}: {replacement_name}"),
+        };
+        self.warnings.push(Diagnostic::hint_with_data(
+            ValidationMessageWithData::ReplaceDeprecatedField {
+                parent_name,
+                field_name,
+                replacement: replacement_text,
+            },
+            definition.location,
+            vec![],
+        ));
+    }
+
+    fn report_unfixable(&mut self, definition: &WithLocation<FieldID>, reason: String) {
+        let field = self.schema.field(definition.item);
+        self.warnings.push(Diagnostic::hint(
+            ValidationMessage::UnableToMigrateDeprecatedField {
+                parent_name: self.schema.get_type_name(field.parent_type.unwrap()),
+                field_name: field.name.item,
+                reason,
+            },
+            definition.location,
+            vec![],
+        ));
+    }
+}
+
+impl Validator for MigrateDeprecatedFields<'_> {
+    const NAME: &'static str = "MigrateDeprecatedFields";
+    const VALIDATE_ARGUMENTS: bool = false;
+    const VALIDATE_DIRECTIVES: bool = false;
+
+    fn validate_linked_field(&mut self, field: &LinkedField) -> DiagnosticsResult<()> {
+        self.migrate_field(&field.definition, field.alias, &field.arguments);
+        self.default_validate_linked_field(field)
+    }
+
+    fn validate_scalar_field(&mut self, field: &ScalarField) -> DiagnosticsResult<()> {
+        self.migrate_field(&field.definition, field.alias, &field.arguments);
+        Ok(())
+    }
+}
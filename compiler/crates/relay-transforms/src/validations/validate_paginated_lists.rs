@@ -0,0 +1,300 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::ArgumentName;
+use common::Diagnostic;
+use common::DiagnosticsResult;
+use common::NamedItem;
+use errors::validate;
+use errors::validate_map;
+use graphql_ir::ConstantValue;
+use graphql_ir::Directive;
+use graphql_ir::LinkedField;
+use graphql_ir::Program;
+use graphql_ir::Selection;
+use graphql_ir::Validator;
+use intern::Lookup;
+use schema::Field;
+use schema::Schema;
+use schema::Type;
+
+use crate::ValidationMessage;
+use crate::connections::ConnectionConstants;
+use crate::connections::extract_connection_directive;
+use crate::handle_fields::extract_handle_field_directive_args_for_connection;
+use crate::paginated_lists::PaginatedListInterface;
+use crate::paginated_lists::extract_paginated_list_directive;
+
+/// Validates fields annotated with `@paginatedList`, the offset based
+/// counterpart of `validate_connections`.
+pub fn validate_paginated_lists(
+    program: &Program,
+    paginated_list_interface: &PaginatedListInterface,
+) -> DiagnosticsResult<()> {
+    let mut validator = PaginatedListValidation {
+        connection_constants: ConnectionConstants::default(),
+        paginated_list_interface,
+        program,
+    };
+    validator.validate_program(program)
+}
+
+struct PaginatedListValidation<'s> {
+    connection_constants: ConnectionConstants,
+    paginated_list_interface: &'s PaginatedListInterface,
+    program: &'s Program,
+}
+
+impl PaginatedListValidation<'_> {
+    /// Validates that the list field is a non-plural, object or interface
+    /// type that isn't also a connection.
+    fn validate_list_field_type(
+        &self,
+        list_field: &LinkedField,
+        list_schema_field: &Field,
+        list_directive: &Directive,
+    ) -> DiagnosticsResult<Type> {
+        let schema = &self.program.schema;
+        if let Some(connection_directive) =
+            extract_connection_directive(&list_field.directives, self.connection_constants)
+        {
+            return Err(vec![
+                Diagnostic::error(
+                    ValidationMessage::PaginatedListWithConnection {
+                        field_name: list_schema_field.name.item,
+                        connection_directive_name: connection_directive.name.item,
+                    },
+                    list_directive.location,
+                )
+                .annotate("combined with", connection_directive.location),
+            ]);
+        }
+        let field_type = list_schema_field.type_.nullable_type();
+        if field_type.is_list() || !field_type.inner().is_object_or_interface() {
+            return Err(vec![Diagnostic::error(
+                ValidationMessage::InvalidPaginatedListFieldType {
+                    field_name: list_schema_field.name.item,
+                    type_string: schema.get_type_string(field_type),
+                },
+                list_field.definition.location,
+            )]);
+        }
+        Ok(field_type.inner())
+    }
+
+    /// Validates that the selection is a valid paginated list:
+    /// - Specifies a limit argument to prevent accidental, unconstrained data
+    ///   access.
+    /// - Has an `items` selection, otherwise there is nothing to paginate.
+    fn validate_list_selection(
+        &self,
+        list_field: &LinkedField,
+        list_schema_field: &Field,
+    ) -> DiagnosticsResult<()> {
+        let schema = &self.program.schema;
+        let limit_arg = ArgumentName(self.paginated_list_interface.limit);
+        if list_field.arguments.named(limit_arg).is_none() {
+            return Err(vec![Diagnostic::error(
+                ValidationMessage::ExpectedPaginatedListToHaveLimitArg {
+                    field_name: list_schema_field.name.item,
+                    limit_arg,
+                },
+                list_field.definition.location,
+            )]);
+        }
+
+        let items_selection_name = self.paginated_list_interface.items;
+        let has_items_selection = list_field.selections.iter().any(|selection| {
+            let field_id = match selection {
+                Selection::LinkedField(field) => field.definition.item,
+                Selection::ScalarField(field) => field.definition.item,
+                _ => return false,
+            };
+            schema.field(field_id).name.item == items_selection_name
+        });
+        if has_items_selection {
+            Ok(())
+        } else {
+            Err(vec![Diagnostic::error(
+                ValidationMessage::ExpectedPaginatedListToHaveItemsSelection {
+                    field_name: list_schema_field.name.item,
+                    items_selection_name,
+                },
+                list_field.definition.location,
+            )])
+        }
+    }
+
+    /// Validates that the type exposes a list `items` field and a scalar
+    /// `totalCount` field, and that the field accepts an `offset` argument.
+    fn validate_list_spec(
+        &self,
+        list_field: &LinkedField,
+        list_schema_field: &Field,
+        list_type: Type,
+    ) -> DiagnosticsResult<()> {
+        let schema = &self.program.schema;
+        let field_name = list_schema_field.name.item;
+        let type_name = schema.get_type_name(list_type);
+        let items_field_name = self.paginated_list_interface.items;
+        let total_count_field_name = self.paginated_list_interface.total_count;
+        let offset_arg = ArgumentName(self.paginated_list_interface.offset);
+
+        let items_result = match schema.named_field(list_type, items_field_name) {
+            Some(field_id) if schema.field(field_id).type_.nullable_type().is_list() => Ok(()),
+            _ => Err(vec![Diagnostic::error(
+                ValidationMessage::ExpectedPaginatedListToExposeValidItemsField {
+                    field_name,
+                    type_name,
+                    items_field_name,
+                },
+                list_field.definition.location,
+            )]),
+        };
+        let total_count_result = match schema.named_field(list_type, total_count_field_name) {
+            Some(field_id)
+                if !schema.field(field_id).type_.is_list()
+                    && schema.field(field_id).type_.inner().is_scalar() =>
+            {
+                Ok(())
+            }
+            _ => Err(vec![Diagnostic::error(
+                ValidationMessage::ExpectedPaginatedListToExposeValidTotalCountField {
+                    field_name,
+                    type_name,
+                    total_count_field_name,
+                },
+                list_field.definition.location,
+            )]),
+        };
+        let offset_result = if list_schema_field.arguments.named(offset_arg).is_some() {
+            Ok(())
+        } else {
+            Err(vec![Diagnostic::error(
+                ValidationMessage::ExpectedPaginatedListToAcceptOffsetArg {
+                    field_name,
+                    offset_arg,
+                },
+                list_field.definition.location,
+            )])
+        };
+
+        validate!(items_result, total_count_result, offset_result)
+    }
+
+    fn validate_list_arguments(
+        &self,
+        list_field: &LinkedField,
+        list_schema_field: &Field,
+        list_directive: &Directive,
+    ) -> DiagnosticsResult<()> {
+        let field_name = list_schema_field.name.item;
+        let args = extract_handle_field_directive_args_for_connection(list_directive);
+
+        let handler_result = match args.handler_arg {
+            Some((_, ConstantValue::String(_))) | None => Ok(()),
+            Some((arg, _)) => Err(vec![
+                Diagnostic::error(
+                    ValidationMessage::InvalidPaginatedListHandlerArg { field_name },
+                    arg.value.location,
+                )
+                .annotate("on paginated list field", list_field.definition.location),
+            ]),
+        };
+
+        let key_result = match args.key_arg {
+            Some((arg, ConstantValue::String(key))) => {
+                let field_alias_or_name = match list_field.alias {
+                    Some(alias) => alias.item,
+                    None => field_name,
+                };
+                let postfix = format!("_{field_alias_or_name}");
+                if key.lookup().ends_with(postfix.as_str()) {
+                    Ok(())
+                } else {
+                    Err(vec![
+                        Diagnostic::error(
+                            ValidationMessage::InvalidPaginatedListKeyArgPostfix {
+                                key_arg_value: *key,
+                                postfix,
+                            },
+                            arg.value.location,
+                        )
+                        .annotate("related location", list_field.definition.location),
+                    ])
+                }
+            }
+            Some((arg, _)) => Err(vec![
+                Diagnostic::error(
+                    ValidationMessage::InvalidPaginatedListKeyArg { field_name },
+                    arg.value.location,
+                )
+                .annotate("related location", list_field.definition.location),
+            ]),
+            None => Err(vec![Diagnostic::error(
+                ValidationMessage::InvalidPaginatedListKeyArg { field_name },
+                list_directive.location,
+            )]),
+        };
+
+        let filters_result = match args.filters_arg {
+            None => Ok(()),
+            Some((arg, ConstantValue::List(filters))) => {
+                validate_map(filters, |filter| match filter {
+                    ConstantValue::String(filter)
+                        if list_field.arguments.named(ArgumentName(*filter)).is_some() =>
+                    {
+                        Ok(())
+                    }
+                    ConstantValue::String(filter) => Err(vec![Diagnostic::error(
+                        ValidationMessage::InvalidPaginatedListFiltersArgNotAnArgument {
+                            field_name,
+                            invalid_name: *filter,
+                        },
+                        arg.value.location,
+                    )]),
+                    _ => Err(vec![Diagnostic::error(
+                        ValidationMessage::InvalidPaginatedListFiltersArg { field_name },
+                        arg.value.location,
+                    )]),
+                })
+            }
+            Some((arg, _)) => Err(vec![
+                Diagnostic::error(
+                    ValidationMessage::InvalidPaginatedListFiltersArg { field_name },
+                    arg.value.location,
+                )
+                .annotate("related location", list_field.definition.location),
+            ]),
+        };
+
+        validate!(handler_result, key_result, filters_result)
+    }
+}
+
+impl Validator for PaginatedListValidation<'_> {
+    const NAME: &'static str = "PaginatedListValidation";
+    const VALIDATE_ARGUMENTS: bool = false;
+    const VALIDATE_DIRECTIVES: bool = false;
+
+    fn validate_linked_field(&mut self, field: &LinkedField) -> DiagnosticsResult<()> {
+        if let Some(list_directive) = extract_paginated_list_directive(&field.directives) {
+            let list_schema_field = self.program.schema.field(field.definition.item);
+            let list_type =
+                self.validate_list_field_type(field, list_schema_field, list_directive)?;
+            self.validate_list_selection(field, list_schema_field)?;
+
+            validate!(
+                self.validate_list_spec(field, list_schema_field, list_type),
+                self.validate_list_arguments(field, list_schema_field, list_directive),
+                self.default_validate_linked_field(field)
+            )
+        } else {
+            self.default_validate_linked_field(field)
+        }
+    }
+}
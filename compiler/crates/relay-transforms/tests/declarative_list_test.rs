@@ -0,0 +1,90 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<8f9e18d9b6c88447dc0a53c8b2855673>>
+ */
+
+mod declarative_list;
+
+use declarative_list::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[tokio::test]
+async fn append_to_list() {
+    let input = include_str!("declarative_list/fixtures/append-to-list.graphql");
+    let expected = include_str!("declarative_list/fixtures/append-to-list.expected");
+    test_fixture(transform_fixture, file!(), "append-to-list.graphql", "declarative_list/fixtures/append-to-list.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn append_to_list_incompatible_type_invalid() {
+    let input = include_str!("declarative_list/fixtures/append-to-list-incompatible-type.invalid.graphql");
+    let expected = include_str!("declarative_list/fixtures/append-to-list-incompatible-type.invalid.expected");
+    test_fixture(transform_fixture, file!(), "append-to-list-incompatible-type.invalid.graphql", "declarative_list/fixtures/append-to-list-incompatible-type.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn append_to_list_invalid_field_arguments_invalid() {
+    let input = include_str!("declarative_list/fixtures/append-to-list-invalid-field-arguments.invalid.graphql");
+    let expected = include_str!("declarative_list/fixtures/append-to-list-invalid-field-arguments.invalid.expected");
+    test_fixture(transform_fixture, file!(), "append-to-list-invalid-field-arguments.invalid.graphql", "declarative_list/fixtures/append-to-list-invalid-field-arguments.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn append_to_list_not_a_list_invalid() {
+    let input = include_str!("declarative_list/fixtures/append-to-list-not-a-list.invalid.graphql");
+    let expected = include_str!("declarative_list/fixtures/append-to-list-not-a-list.invalid.expected");
+    test_fixture(transform_fixture, file!(), "append-to-list-not-a-list.invalid.graphql", "declarative_list/fixtures/append-to-list-not-a-list.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn append_to_list_unknown_field_invalid() {
+    let input = include_str!("declarative_list/fixtures/append-to-list-unknown-field.invalid.graphql");
+    let expected = include_str!("declarative_list/fixtures/append-to-list-unknown-field.invalid.expected");
+    test_fixture(transform_fixture, file!(), "append-to-list-unknown-field.invalid.graphql", "declarative_list/fixtures/append-to-list-unknown-field.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn append_to_list_with_field_arguments() {
+    let input = include_str!("declarative_list/fixtures/append-to-list-with-field-arguments.graphql");
+    let expected = include_str!("declarative_list/fixtures/append-to-list-with-field-arguments.expected");
+    test_fixture(transform_fixture, file!(), "append-to-list-with-field-arguments.graphql", "declarative_list/fixtures/append-to-list-with-field-arguments.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn conflicting_list_directives_invalid() {
+    let input = include_str!("declarative_list/fixtures/conflicting-list-directives.invalid.graphql");
+    let expected = include_str!("declarative_list/fixtures/conflicting-list-directives.invalid.expected");
+    test_fixture(transform_fixture, file!(), "conflicting-list-directives.invalid.graphql", "declarative_list/fixtures/conflicting-list-directives.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn delete_from_list() {
+    let input = include_str!("declarative_list/fixtures/delete-from-list.graphql");
+    let expected = include_str!("declarative_list/fixtures/delete-from-list.expected");
+    test_fixture(transform_fixture, file!(), "delete-from-list.graphql", "declarative_list/fixtures/delete-from-list.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn delete_from_list_on_linked_field_invalid() {
+    let input = include_str!("declarative_list/fixtures/delete-from-list-on-linked-field.invalid.graphql");
+    let expected = include_str!("declarative_list/fixtures/delete-from-list-on-linked-field.invalid.expected");
+    test_fixture(transform_fixture, file!(), "delete-from-list-on-linked-field.invalid.graphql", "declarative_list/fixtures/delete-from-list-on-linked-field.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn prepend_to_list_subtype() {
+    let input = include_str!("declarative_list/fixtures/prepend-to-list-subtype.graphql");
+    let expected = include_str!("declarative_list/fixtures/prepend-to-list-subtype.expected");
+    test_fixture(transform_fixture, file!(), "prepend-to-list-subtype.graphql", "declarative_list/fixtures/prepend-to-list-subtype.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn prepend_to_list_with_input_object_arguments() {
+    let input = include_str!("declarative_list/fixtures/prepend-to-list-with-input-object-arguments.graphql");
+    let expected = include_str!("declarative_list/fixtures/prepend-to-list-with-input-object-arguments.expected");
+    test_fixture(transform_fixture, file!(), "prepend-to-list-with-input-object-arguments.graphql", "declarative_list/fixtures/prepend-to-list-with-input-object-arguments.expected", input, expected).await;
+}
@@ -0,0 +1,34 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<eae47bbfabac85a2caabfe9d6e2a1d53>>
+ */
+
+mod migrate_deprecated_fields;
+
+use migrate_deprecated_fields::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[tokio::test]
+async fn explicit_replacements() {
+    let input = include_str!("migrate_deprecated_fields/fixtures/explicit_replacements.graphql");
+    let expected = include_str!("migrate_deprecated_fields/fixtures/explicit_replacements.expected");
+    test_fixture(transform_fixture, file!(), "explicit_replacements.graphql", "migrate_deprecated_fields/fixtures/explicit_replacements.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn replace_deprecated_fields() {
+    let input = include_str!("migrate_deprecated_fields/fixtures/replace_deprecated_fields.graphql");
+    let expected = include_str!("migrate_deprecated_fields/fixtures/replace_deprecated_fields.expected");
+    test_fixture(transform_fixture, file!(), "replace_deprecated_fields.graphql", "migrate_deprecated_fields/fixtures/replace_deprecated_fields.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn unfixable_deprecated_fields() {
+    let input = include_str!("migrate_deprecated_fields/fixtures/unfixable_deprecated_fields.graphql");
+    let expected = include_str!("migrate_deprecated_fields/fixtures/unfixable_deprecated_fields.expected");
+    test_fixture(transform_fixture, file!(), "unfixable_deprecated_fields.graphql", "migrate_deprecated_fields/fixtures/unfixable_deprecated_fields.expected", input, expected).await;
+}
@@ -10,7 +10,9 @@ use graphql_test_helpers::apply_transform_for_test;
 use relay_config::DeferStreamInterface;
 use relay_config::ProjectConfig;
 use relay_transforms::ConnectionInterface;
+use relay_transforms::PaginatedListInterface;
 use relay_transforms::transform_connections;
+use relay_transforms::transform_paginated_lists;
 use relay_transforms::transform_refetchable_fragment;
 
 pub async fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
@@ -21,6 +23,7 @@ pub async fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String>
             &DeferStreamInterface::default(),
             false,
         );
+        let program = transform_paginated_lists(&program, &PaginatedListInterface::default());
         let base_fragments = Default::default();
         let schema_config = if fixture.content.contains("// enable-token-field: true") {
             relay_config::SchemaConfig {
@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<2572fed0d3cabca4deaf835152a574bb>>
+ * @generated SignedSource<<fb9e502159270e9447990fa8ca65ca71>>
  */
 
 mod refetchable_fragment;
@@ -208,6 +208,20 @@ async fn refetchable_fragment_with_connection_with_stream() {
     test_fixture(transform_fixture, file!(), "refetchable-fragment-with-connection-with-stream.graphql", "refetchable_fragment/fixtures/refetchable-fragment-with-connection-with-stream.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn refetchable_fragment_with_paginated_list() {
+    let input = include_str!("refetchable_fragment/fixtures/refetchable-fragment-with-paginated-list.graphql");
+    let expected = include_str!("refetchable_fragment/fixtures/refetchable-fragment-with-paginated-list.expected");
+    test_fixture(transform_fixture, file!(), "refetchable-fragment-with-paginated-list.graphql", "refetchable_fragment/fixtures/refetchable-fragment-with-paginated-list.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn refetchable_fragment_with_paginated_list_literal_limit_invalid() {
+    let input = include_str!("refetchable_fragment/fixtures/refetchable-fragment-with-paginated-list-literal-limit.invalid.graphql");
+    let expected = include_str!("refetchable_fragment/fixtures/refetchable-fragment-with-paginated-list-literal-limit.invalid.expected");
+    test_fixture(transform_fixture, file!(), "refetchable-fragment-with-paginated-list-literal-limit.invalid.graphql", "refetchable_fragment/fixtures/refetchable-fragment-with-paginated-list-literal-limit.invalid.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn refetchable_interface() {
     let input = include_str!("refetchable_fragment/fixtures/refetchable-interface.graphql");
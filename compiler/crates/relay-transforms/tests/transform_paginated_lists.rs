@@ -0,0 +1,63 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::sync::Arc;
+
+use common::SourceLocationKey;
+use fixture_tests::Fixture;
+use graphql_ir::Program;
+use graphql_ir::build;
+use graphql_syntax::parse_executable;
+use graphql_test_helpers::diagnostics_to_sorted_string;
+use graphql_text_printer::PrinterOptions;
+use graphql_text_printer::print_fragment;
+use graphql_text_printer::print_operation;
+use relay_test_schema::get_test_schema;
+use relay_test_schema::get_test_schema_with_extensions;
+use relay_transforms::PaginatedListInterface;
+use relay_transforms::transform_paginated_lists;
+use relay_transforms::validate_paginated_lists;
+
+pub async fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let source_location = SourceLocationKey::standalone(fixture.file_name);
+
+    let parts: Vec<_> = fixture.content.split("%extensions%").collect();
+    let (base, schema) = match parts.as_slice() {
+        [base, extensions] => (base, get_test_schema_with_extensions(extensions)),
+        [base] => (base, get_test_schema()),
+        _ => panic!("Invalid fixture input {}", fixture.content),
+    };
+
+    let ast = parse_executable(base, source_location).unwrap();
+    let ir = build(&schema, &ast.definitions)
+        .map_err(|diagnostics| diagnostics_to_sorted_string(base, &diagnostics))?;
+
+    let program = Program::from_definitions(Arc::clone(&schema), ir);
+
+    let paginated_list_interface = PaginatedListInterface::default();
+
+    validate_paginated_lists(&program, &paginated_list_interface)
+        .map_err(|diagnostics| diagnostics_to_sorted_string(base, &diagnostics))?;
+
+    let next_program = transform_paginated_lists(&program, &paginated_list_interface);
+
+    let printer_options = PrinterOptions {
+        debug_directive_data: true,
+        ..Default::default()
+    };
+    let mut printed = next_program
+        .operations()
+        .map(|def| print_operation(&schema, def, printer_options))
+        .chain(
+            next_program
+                .fragments()
+                .map(|def| print_fragment(&schema, def, printer_options)),
+        )
+        .collect::<Vec<_>>();
+    printed.sort();
+    Ok(printed.join("\n\n"))
+}
@@ -0,0 +1,97 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<ed02d9c678cffe44a3baba7724c5fed1>>
+ */
+
+mod transform_paginated_lists;
+
+use transform_paginated_lists::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[tokio::test]
+async fn paginated_list() {
+    let input = include_str!("transform_paginated_lists/fixtures/paginated-list.graphql");
+    let expected = include_str!("transform_paginated_lists/fixtures/paginated-list.expected");
+    test_fixture(transform_fixture, file!(), "paginated-list.graphql", "transform_paginated_lists/fixtures/paginated-list.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn paginated_list_filters() {
+    let input = include_str!("transform_paginated_lists/fixtures/paginated-list-filters.graphql");
+    let expected = include_str!("transform_paginated_lists/fixtures/paginated-list-filters.expected");
+    test_fixture(transform_fixture, file!(), "paginated-list-filters.graphql", "transform_paginated_lists/fixtures/paginated-list-filters.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn paginated_list_filters_not_an_arg_invalid() {
+    let input = include_str!("transform_paginated_lists/fixtures/paginated-list-filters-not-an-arg.invalid.graphql");
+    let expected = include_str!("transform_paginated_lists/fixtures/paginated-list-filters-not-an-arg.invalid.expected");
+    test_fixture(transform_fixture, file!(), "paginated-list-filters-not-an-arg.invalid.graphql", "transform_paginated_lists/fixtures/paginated-list-filters-not-an-arg.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn paginated_list_in_plural_field() {
+    let input = include_str!("transform_paginated_lists/fixtures/paginated-list-in-plural-field.graphql");
+    let expected = include_str!("transform_paginated_lists/fixtures/paginated-list-in-plural-field.expected");
+    test_fixture(transform_fixture, file!(), "paginated-list-in-plural-field.graphql", "transform_paginated_lists/fixtures/paginated-list-in-plural-field.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn paginated_list_in_query() {
+    let input = include_str!("transform_paginated_lists/fixtures/paginated-list-in-query.graphql");
+    let expected = include_str!("transform_paginated_lists/fixtures/paginated-list-in-query.expected");
+    test_fixture(transform_fixture, file!(), "paginated-list-in-query.graphql", "transform_paginated_lists/fixtures/paginated-list-in-query.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn paginated_list_invalid_key_name_invalid() {
+    let input = include_str!("transform_paginated_lists/fixtures/paginated-list-invalid-key-name.invalid.graphql");
+    let expected = include_str!("transform_paginated_lists/fixtures/paginated-list-invalid-key-name.invalid.expected");
+    test_fixture(transform_fixture, file!(), "paginated-list-invalid-key-name.invalid.graphql", "transform_paginated_lists/fixtures/paginated-list-invalid-key-name.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn paginated_list_invalid_type_invalid() {
+    let input = include_str!("transform_paginated_lists/fixtures/paginated-list-invalid-type.invalid.graphql");
+    let expected = include_str!("transform_paginated_lists/fixtures/paginated-list-invalid-type.invalid.expected");
+    test_fixture(transform_fixture, file!(), "paginated-list-invalid-type.invalid.graphql", "transform_paginated_lists/fixtures/paginated-list-invalid-type.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn paginated_list_missing_items_selection_invalid() {
+    let input = include_str!("transform_paginated_lists/fixtures/paginated-list-missing-items-selection.invalid.graphql");
+    let expected = include_str!("transform_paginated_lists/fixtures/paginated-list-missing-items-selection.invalid.expected");
+    test_fixture(transform_fixture, file!(), "paginated-list-missing-items-selection.invalid.graphql", "transform_paginated_lists/fixtures/paginated-list-missing-items-selection.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn paginated_list_missing_limit_arg_invalid() {
+    let input = include_str!("transform_paginated_lists/fixtures/paginated-list-missing-limit-arg.invalid.graphql");
+    let expected = include_str!("transform_paginated_lists/fixtures/paginated-list-missing-limit-arg.invalid.expected");
+    test_fixture(transform_fixture, file!(), "paginated-list-missing-limit-arg.invalid.graphql", "transform_paginated_lists/fixtures/paginated-list-missing-limit-arg.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn paginated_list_with_aliased_items() {
+    let input = include_str!("transform_paginated_lists/fixtures/paginated-list-with-aliased-items.graphql");
+    let expected = include_str!("transform_paginated_lists/fixtures/paginated-list-with-aliased-items.expected");
+    test_fixture(transform_fixture, file!(), "paginated-list-with-aliased-items.graphql", "transform_paginated_lists/fixtures/paginated-list-with-aliased-items.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn paginated_list_with_connection_invalid() {
+    let input = include_str!("transform_paginated_lists/fixtures/paginated-list-with-connection.invalid.graphql");
+    let expected = include_str!("transform_paginated_lists/fixtures/paginated-list-with-connection.invalid.expected");
+    test_fixture(transform_fixture, file!(), "paginated-list-with-connection.invalid.graphql", "transform_paginated_lists/fixtures/paginated-list-with-connection.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn paginated_list_with_custom_handler() {
+    let input = include_str!("transform_paginated_lists/fixtures/paginated-list-with-custom-handler.graphql");
+    let expected = include_str!("transform_paginated_lists/fixtures/paginated-list-with-custom-handler.expected");
+    test_fixture(transform_fixture, file!(), "paginated-list-with-custom-handler.graphql", "transform_paginated_lists/fixtures/paginated-list-with-custom-handler.expected", input, expected).await;
+}
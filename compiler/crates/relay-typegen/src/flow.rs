@@ -149,6 +149,20 @@ impl Writer for FlowPrinter {
     fn write_any_type_definition(&mut self, name: &str) -> FmtResult {
         writeln!(&mut self.result, "type {name} = any;")
     }
+
+    // Flow types of artifacts are wrapped in a `/*::` comment, so doc comments
+    // are written as line comments.
+    fn write_doc_comment(&mut self, doc_comment: &str) -> FmtResult {
+        for line in doc_comment.lines() {
+            if line.is_empty() {
+                writeln!(&mut self.result, "//")?;
+            } else {
+                writeln!(&mut self.result, "// {line}")?;
+            }
+            self.write_indentation()?;
+        }
+        Ok(())
+    }
 }
 
 impl FlowPrinter {
@@ -243,6 +257,9 @@ impl FlowPrinter {
                     continue;
                 }
                 Prop::KeyValuePair(key_value_pair) => {
+                    if let Some(doc_comment) = &key_value_pair.doc_comment {
+                        self.write_doc_comment(doc_comment)?;
+                    }
                     if let AST::OtherTypename = key_value_pair.value {
                         writeln!(
                             &mut self.result,
@@ -438,6 +455,7 @@ mod tests {
                     optional: false,
                     read_only: false,
                     value: AST::String,
+                    doc_comment: None,
                 }),
             ],))),
             r"{
@@ -452,12 +470,14 @@ mod tests {
                     optional: true,
                     read_only: false,
                     value: AST::String,
+                    doc_comment: None,
                 }),
                 Prop::KeyValuePair(KeyValuePairProp {
                     key: intern!("bar"),
                     optional: false,
                     read_only: true,
                     value: AST::Number,
+                    doc_comment: None,
                 }),
             ],))),
             r"{
@@ -482,20 +502,24 @@ mod tests {
                             optional: true,
                             read_only: false,
                             value: AST::String,
+                            doc_comment: None,
                         }),
                         Prop::KeyValuePair(KeyValuePairProp {
                             key: intern!("nested_foo2"),
                             optional: false,
                             read_only: true,
                             value: AST::Number,
+                            doc_comment: None,
                         }),
                     ],)),
+                    doc_comment: None,
                 }),
                 Prop::KeyValuePair(KeyValuePairProp {
                     key: intern!("bar"),
                     optional: false,
                     read_only: true,
                     value: AST::Number,
+                    doc_comment: None,
                 }),
             ],))),
             r"{
@@ -526,6 +550,7 @@ mod tests {
                     optional: false,
                     read_only: false,
                     value: AST::String,
+                    doc_comment: None,
                 }),
             ]))),
             r"{
@@ -542,12 +567,14 @@ mod tests {
                     optional: false,
                     read_only: false,
                     value: AST::String,
+                    doc_comment: None,
                 }),
                 Prop::KeyValuePair(KeyValuePairProp {
                     key: intern!("bar"),
                     optional: true,
                     read_only: true,
                     value: AST::Number,
+                    doc_comment: None,
                 })
             ]))),
             r"{
@@ -568,12 +595,14 @@ mod tests {
                     optional: false,
                     read_only: false,
                     value: AST::String,
+                    doc_comment: None,
                 }),
                 Prop::KeyValuePair(KeyValuePairProp {
                     key: intern!("bar"),
                     optional: true,
                     read_only: true,
                     value: AST::Number,
+                    doc_comment: None,
                 })
             ]))),
             r"{
@@ -594,13 +623,15 @@ mod tests {
                         key: intern!("foo"),
                         value: AST::String,
                         read_only: false,
-                        optional: false
+                        optional: false,
+                        doc_comment: None,
                     }),
                     Prop::KeyValuePair(KeyValuePairProp {
                         key: intern!("bar"),
                         value: AST::Empty,
                         read_only: false,
-                        optional: true
+                        optional: true,
+                        doc_comment: None,
                     })
                 ])),
                 AST::ExactObject(ExactObject::new(vec![
@@ -608,13 +639,15 @@ mod tests {
                         key: intern!("foo"),
                         value: AST::Empty,
                         read_only: false,
-                        optional: true
+                        optional: true,
+                        doc_comment: None,
                     }),
                     Prop::KeyValuePair(KeyValuePairProp {
                         key: intern!("bar"),
                         value: AST::String,
                         read_only: false,
-                        optional: false
+                        optional: false,
+                        doc_comment: None,
                     })
                 ]))
             ]))),
@@ -637,6 +670,7 @@ mod tests {
                     optional: false,
                     read_only: false,
                     value: AST::OtherTypename,
+                    doc_comment: None,
                 })
             ],))),
             r#"{
@@ -79,4 +79,8 @@ impl Writer for JavaScriptPrinter {
     fn write_any_type_definition(&mut self, _name: &str) -> FmtResult {
         Ok(())
     }
+
+    fn write_doc_comment(&mut self, _doc_comment: &str) -> FmtResult {
+        Ok(())
+    }
 }
@@ -11,15 +11,18 @@
 
 mod flow;
 mod javascript;
+mod rescript;
 mod type_selection;
 mod typegen_state;
 mod typescript;
 mod visit;
 mod write;
 mod writer;
+mod zod;
 
 use std::sync::LazyLock;
 
+use ::intern::Lookup;
 use ::intern::string_key::Intern;
 use ::intern::string_key::StringKey;
 use common::DirectiveName;
@@ -32,14 +35,23 @@ use relay_config::ProjectConfig;
 pub use relay_config::TypegenConfig;
 pub use relay_config::TypegenLanguage;
 use relay_transforms::UPDATABLE_DIRECTIVE;
+use rescript::ReScriptPrinter;
+use schema::EnumID;
 use schema::SDLSchema;
+use schema::Schema;
 pub use typegen_state::FragmentLocations;
+use visit::make_enum_doc_comment;
+use write::enum_type_members;
 pub use write::has_raw_response_type_directive;
 use write::write_fragment_type_exports_section;
 use write::write_operation_type_exports_section;
 use write::write_split_operation_type_exports_section;
 use write::write_validator_function;
+use writer::AST;
+use writer::SortedASTList;
+use writer::Writer;
 use writer::new_writer_from_config;
+use zod::ZodPrinter;
 
 static RELAY_RUNTIME: &str = "relay-runtime";
 static LOCAL_3D_PAYLOAD: &str = "Local3DPayload";
@@ -155,6 +167,7 @@ fn generate_fragment_type_exports_section_impl(
         TypegenOptions {
             no_optional_fields_in_raw_response_type: false,
             is_extra_artifact_branch_module,
+            is_zod_schema: false,
         },
     );
     let mut writer = new_writer_from_config(&project_config.typegen_config);
@@ -181,6 +194,7 @@ pub fn generate_named_validator_export(
         TypegenOptions {
             no_optional_fields_in_raw_response_type: false,
             is_extra_artifact_branch_module: false,
+            is_zod_schema: false,
         },
     );
     let mut writer = new_writer_from_config(&project_config.typegen_config);
@@ -217,6 +231,7 @@ pub fn generate_operation_type_exports_section(
         TypegenOptions {
             no_optional_fields_in_raw_response_type: false,
             is_extra_artifact_branch_module: false,
+            is_zod_schema: false,
         },
     );
     let mut writer = new_writer_from_config(&project_config.typegen_config);
@@ -254,6 +269,7 @@ pub fn generate_split_operation_type_exports_section(
         TypegenOptions {
             no_optional_fields_in_raw_response_type,
             is_extra_artifact_branch_module: false,
+            is_zod_schema: false,
         },
     );
     let mut writer = new_writer_from_config(&project_config.typegen_config);
@@ -268,6 +284,176 @@ pub fn generate_split_operation_type_exports_section(
     writer.into_string()
 }
 
+/// Generates the Zod schemas mirroring the types exported by
+/// `generate_operation_type_exports_section`.
+pub fn generate_operation_zod_schema(
+    typegen_operation: &OperationDefinition,
+    normalization_operation: &OperationDefinition,
+    schema: &SDLSchema,
+    project_config: &ProjectConfig,
+    fragment_locations: &FragmentLocations,
+) -> String {
+    let typegen_context = TypegenContext::new(
+        schema,
+        project_config,
+        typegen_operation
+            .directives
+            .named(*UPDATABLE_DIRECTIVE)
+            .is_some(),
+        WithLocation::new(
+            typegen_operation.name.location,
+            typegen_operation.name.item.0,
+        ),
+        fragment_locations,
+        TypegenOptions {
+            no_optional_fields_in_raw_response_type: false,
+            is_extra_artifact_branch_module: false,
+            is_zod_schema: true,
+        },
+    );
+    let mut writer: Box<dyn Writer> = Box::new(ZodPrinter::new(
+        &project_config.typegen_config,
+        normalization_operation.name.item.0,
+    ));
+    // The provided variables type assertion has no Zod counterpart, so the
+    // printer ignores the provided variables object.
+    write_operation_type_exports_section(
+        &typegen_context,
+        typegen_operation,
+        normalization_operation,
+        &mut writer,
+        Some(String::new()),
+    )
+    .unwrap();
+    writer.into_string()
+}
+
+/// Generates the Zod schemas mirroring the types exported by
+/// `generate_fragment_type_exports_section`.
+pub fn generate_fragment_zod_schema(
+    fragment_definition: &FragmentDefinition,
+    schema: &SDLSchema,
+    project_config: &ProjectConfig,
+    fragment_locations: &FragmentLocations,
+) -> String {
+    let typegen_context = TypegenContext::new(
+        schema,
+        project_config,
+        fragment_definition
+            .directives
+            .named(*UPDATABLE_DIRECTIVE)
+            .is_some(),
+        fragment_definition.name.map(|x| x.0),
+        fragment_locations,
+        TypegenOptions {
+            no_optional_fields_in_raw_response_type: false,
+            is_extra_artifact_branch_module: false,
+            is_zod_schema: true,
+        },
+    );
+    let mut writer: Box<dyn Writer> = Box::new(ZodPrinter::new(
+        &project_config.typegen_config,
+        fragment_definition.name.item.0,
+    ));
+    write_fragment_type_exports_section(&typegen_context, fragment_definition, &mut writer)
+        .unwrap();
+    writer.into_string()
+}
+
+/// Generates the ReScript types, and their conversion helpers, mirroring the
+/// types exported by `generate_operation_type_exports_section`.
+pub fn generate_operation_rescript_types(
+    typegen_operation: &OperationDefinition,
+    normalization_operation: &OperationDefinition,
+    schema: &SDLSchema,
+    project_config: &ProjectConfig,
+    fragment_locations: &FragmentLocations,
+) -> String {
+    let typegen_context = TypegenContext::new(
+        schema,
+        project_config,
+        typegen_operation
+            .directives
+            .named(*UPDATABLE_DIRECTIVE)
+            .is_some(),
+        WithLocation::new(
+            typegen_operation.name.location,
+            typegen_operation.name.item.0,
+        ),
+        fragment_locations,
+        TypegenOptions {
+            no_optional_fields_in_raw_response_type: false,
+            is_extra_artifact_branch_module: false,
+            is_zod_schema: false,
+        },
+    );
+    let mut writer: Box<dyn Writer> = Box::new(ReScriptPrinter::new(typegen_operation.name.item.0));
+    write_operation_type_exports_section(
+        &typegen_context,
+        typegen_operation,
+        normalization_operation,
+        &mut writer,
+        Some(String::new()),
+    )
+    .unwrap();
+    writer.into_string()
+}
+
+/// Generates the ReScript types, and their conversion helpers, mirroring the
+/// types exported by `generate_fragment_type_exports_section`.
+pub fn generate_fragment_rescript_types(
+    fragment_definition: &FragmentDefinition,
+    schema: &SDLSchema,
+    project_config: &ProjectConfig,
+    fragment_locations: &FragmentLocations,
+) -> String {
+    let typegen_context = TypegenContext::new(
+        schema,
+        project_config,
+        fragment_definition
+            .directives
+            .named(*UPDATABLE_DIRECTIVE)
+            .is_some(),
+        fragment_definition.name.map(|x| x.0),
+        fragment_locations,
+        TypegenOptions {
+            no_optional_fields_in_raw_response_type: false,
+            is_extra_artifact_branch_module: false,
+            is_zod_schema: false,
+        },
+    );
+    let mut writer: Box<dyn Writer> =
+        Box::new(ReScriptPrinter::new(fragment_definition.name.item.0));
+    write_fragment_type_exports_section(&typegen_context, fragment_definition, &mut writer)
+        .unwrap();
+    writer.into_string()
+}
+
+/// Generates the type export of a generated enum module, i.e. the union of
+/// the enum's values as it would otherwise be defined inline in artifacts.
+pub fn generate_enum_module_type_export(
+    enum_id: EnumID,
+    schema: &SDLSchema,
+    project_config: &ProjectConfig,
+) -> String {
+    let typegen_config = &project_config.typegen_config;
+    let enum_type = schema.enum_(enum_id);
+    let mut writer = new_writer_from_config(typegen_config);
+    if let Some(doc_comment) = make_enum_doc_comment(typegen_config, enum_type) {
+        writer.write_doc_comment(&doc_comment).unwrap();
+    }
+    writer
+        .write_export_type(
+            enum_type.name.item.lookup(),
+            &AST::Union(SortedASTList::new(enum_type_members(
+                typegen_config,
+                enum_type,
+            ))),
+        )
+        .unwrap();
+    writer.into_string()
+}
+
 /// An immutable grab bag of configuration, etc. for type generation.
 /// A new `TypegenContext` is created for each operation, fragment, and so on.
 struct TypegenContext<'a> {
@@ -306,4 +492,7 @@ struct TypegenOptions {
     no_optional_fields_in_raw_response_type: bool,
     // Some extra artifacts require special type generation
     is_extra_artifact_branch_module: bool,
+    // Zod schemas reference custom scalars with a configured schema by name,
+    // and define enums inline rather than importing them
+    is_zod_schema: bool,
 }
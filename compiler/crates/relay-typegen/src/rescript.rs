@@ -0,0 +1,733 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Prints the typegen AST as ReScript types. All exported types of an
+//! artifact are printed as one recursive group of type definitions, with
+//! nested objects hoisted into records named after their path, e.g.
+//! `data_viewer_actor`.
+//!
+//! The ReScript representation differs from the JS one for nullable values
+//! (`None` is `undefined`, not `null`), selections on abstract types (which
+//! are polymorphic variants keyed by `__typename`) and fragment references
+//! (which are read from a `fragmentRefs` field). Conversion helpers are
+//! emitted for the `$data` and `$rawResponse` types.
+
+use std::fmt::Result as FmtResult;
+use std::fmt::Write;
+
+use ::intern::string_key::Intern;
+use ::intern::string_key::StringKey;
+use fnv::FnvHashMap;
+use fnv::FnvHashSet;
+use intern::Lookup;
+use itertools::Itertools;
+
+use crate::KEY_DATA;
+use crate::KEY_FRAGMENT_SPREADS;
+use crate::KEY_FRAGMENT_TYPE;
+use crate::KEY_TYPENAME;
+use crate::writer::AST;
+use crate::writer::Prop;
+use crate::writer::Writer;
+
+const RELAY_RUNTIME: &str = "relay-runtime";
+const FRAGMENT_REFS_FIELD: &str = "fragmentRefs";
+const UNSELECTED_UNION_MEMBER: &str = "UnselectedUnionMember";
+
+/// Converts between the JS and ReScript representations of a value. The
+/// instructions map a path (object keys joined by `.`, with `@Typename` for
+/// the members of abstract selections) to `u:<known typenames>` for abstract
+/// selections or `f` for objects with fragment spreads.
+const CONVERTER: &str = r#"function convert(value, instructions, path, toJs) {
+    if (value == null) {
+      return toJs ? null : undefined;
+    }
+    if (Array.isArray(value)) {
+      return value.map(function (item) {
+        return convert(item, instructions, path, toJs);
+      });
+    }
+    if (typeof value !== "object") {
+      return value;
+    }
+    var instruction = instructions[path];
+    if (instruction != null && instruction.startsWith("u:")) {
+      if (toJs) {
+        return value.NAME === "UnselectedUnionMember"
+          ? {__typename: value.VAL}
+          : convertObject(value.VAL, path + "@" + value.NAME);
+      }
+      var typename = value.__typename;
+      return instruction.slice(2).split(",").indexOf(typename) === -1
+        ? {NAME: "UnselectedUnionMember", VAL: typename}
+        : {NAME: typename, VAL: convertObject(value, path + "@" + typename)};
+    }
+    return convertObject(value, path);
+
+    function convertObject(object, objectPath) {
+      var result = {};
+      for (var key in object) {
+        if (!(toJs && key === "fragmentRefs")) {
+          result[key] = convert(
+            object[key],
+            instructions,
+            objectPath === "" ? key : objectPath + "." + key,
+            toJs,
+          );
+        }
+      }
+      if (!toJs && instructions[objectPath] === "f") {
+        result.fragmentRefs = object;
+      }
+      return result;
+    }
+  }"#;
+
+/// ReScript keywords, which cannot be used as record field names.
+const KEYWORDS: &[&str] = &[
+    "and",
+    "as",
+    "assert",
+    "async",
+    "await",
+    "constraint",
+    "else",
+    "exception",
+    "external",
+    "false",
+    "for",
+    "if",
+    "in",
+    "include",
+    "lazy",
+    "let",
+    "module",
+    "mutable",
+    "of",
+    "open",
+    "private",
+    "rec",
+    "switch",
+    "true",
+    "try",
+    "type",
+    "when",
+    "while",
+    "with",
+];
+
+enum ConversionDirection {
+    FromJs,
+    ToJs,
+}
+
+struct Converter {
+    type_name: String,
+    direction: ConversionDirection,
+    instructions: Vec<(String, String)>,
+}
+
+pub struct ReScriptPrinter {
+    definition_name: StringKey,
+    /// Maps imported type names to the ReScript module defining them, or to
+    /// `None` for types without a ReScript counterpart.
+    imported_types: FnvHashMap<StringKey, Option<String>>,
+    exports: Vec<(String, AST)>,
+    definitions: Vec<String>,
+    defined_names: FnvHashSet<String>,
+    instructions: Vec<(String, String)>,
+    converters: Vec<Converter>,
+}
+
+/// Only exported types are printed, so any other output of the shared
+/// typegen pipeline, such as resolver type assertions, is dropped.
+impl Write for ReScriptPrinter {
+    fn write_str(&mut self, _s: &str) -> FmtResult {
+        Ok(())
+    }
+}
+
+impl Writer for ReScriptPrinter {
+    fn into_string(mut self: Box<Self>) -> String {
+        self.print().unwrap()
+    }
+
+    fn get_runtime_fragment_import(&self) -> &'static str {
+        "FragmentRefs"
+    }
+
+    fn write(&mut self, _ast: &AST) -> FmtResult {
+        Ok(())
+    }
+
+    fn write_type_assertion(&mut self, _name: &str, _ast: &AST) -> FmtResult {
+        Ok(())
+    }
+
+    fn write_export_type(&mut self, name: &str, ast: &AST) -> FmtResult {
+        self.exports.push((name.to_string(), ast.clone()));
+        Ok(())
+    }
+
+    fn write_import_module_default(&mut self, _name: &str, _from: &str) -> FmtResult {
+        Ok(())
+    }
+
+    fn write_import_module_named(
+        &mut self,
+        _name: &str,
+        _import_as: Option<&str>,
+        _from: &str,
+    ) -> FmtResult {
+        Ok(())
+    }
+
+    fn write_import_type(&mut self, types: &[&str], from: &str) -> FmtResult {
+        self.add_imported_types(types, from);
+        Ok(())
+    }
+
+    fn write_import_fragment_type(&mut self, types: &[&str], from: &str) -> FmtResult {
+        self.add_imported_types(types, from);
+        Ok(())
+    }
+
+    // Fragment references are printed as polymorphic variants of fragment
+    // names, so there are no fragment types to export.
+    fn write_export_fragment_type(&mut self, _name: &str) -> FmtResult {
+        Ok(())
+    }
+
+    fn write_export_fragment_types(
+        &mut self,
+        _fragment_type_name_1: &str,
+        _fragment_type_name_2: &str,
+    ) -> FmtResult {
+        Ok(())
+    }
+
+    fn write_any_type_definition(&mut self, name: &str) -> FmtResult {
+        self.exports.push((name.to_string(), AST::Any));
+        Ok(())
+    }
+
+    fn write_doc_comment(&mut self, _doc_comment: &str) -> FmtResult {
+        Ok(())
+    }
+}
+
+impl ReScriptPrinter {
+    pub fn new(definition_name: StringKey) -> Self {
+        Self {
+            definition_name,
+            imported_types: Default::default(),
+            exports: Default::default(),
+            definitions: Default::default(),
+            defined_names: Default::default(),
+            instructions: Default::default(),
+            converters: Default::default(),
+        }
+    }
+
+    fn add_imported_types(&mut self, types: &[&str], from: &str) {
+        let module_name = if from == RELAY_RUNTIME {
+            None
+        } else {
+            Some(module_name(from))
+        };
+        for type_ in types {
+            self.imported_types
+                .insert(type_.intern(), module_name.clone());
+        }
+    }
+
+    fn print(&mut self) -> Result<String, std::fmt::Error> {
+        let exports = std::mem::take(&mut self.exports);
+        for (name, _) in &exports {
+            self.defined_names.insert(self.local_type_name(name));
+        }
+        for (name, ast) in &exports {
+            self.print_export(name, ast);
+        }
+
+        let mut result = String::new();
+        if self.definitions.is_empty() {
+            return Ok(result);
+        }
+        writeln!(&mut result, "@@warning(\"-30\")\n")?;
+        for (index, definition) in self.definitions.iter().enumerate() {
+            if index == 0 {
+                writeln!(&mut result, "type rec {definition}")?;
+            } else {
+                writeln!(&mut result, "and {definition}")?;
+            }
+        }
+
+        if !self.converters.is_empty() {
+            writeln!(&mut result, "\nmodule Internal = {{")?;
+            writeln!(
+                &mut result,
+                "  let convert: (Js.Json.t, Js.Dict.t<string>, string, bool) => Js.Json.t = %raw(`{CONVERTER}`)"
+            )?;
+            writeln!(&mut result, "}}")?;
+        }
+        for converter in &self.converters {
+            let instructions = if converter.instructions.is_empty() {
+                "Js.Dict.empty()".to_string()
+            } else {
+                format!(
+                    "Js.Dict.fromArray([{}])",
+                    converter
+                        .instructions
+                        .iter()
+                        .map(|(path, instruction)| format!("(\"{path}\", \"{instruction}\")"))
+                        .join(", ")
+                )
+            };
+            let type_name = &converter.type_name;
+            match converter.direction {
+                ConversionDirection::FromJs => writeln!(
+                    &mut result,
+                    "\n@live\nlet {type_name}FromJs = (value: Js.Json.t): {type_name} =>\n  Internal.convert(value, {instructions}, \"\", false)->Obj.magic"
+                )?,
+                ConversionDirection::ToJs => writeln!(
+                    &mut result,
+                    "\n@live\nlet {type_name}ToJs = (value: {type_name}): Js.Json.t =>\n  Internal.convert(value->Obj.magic, {instructions}, \"\", true)"
+                )?,
+            }
+        }
+        Ok(result)
+    }
+
+    fn print_export(&mut self, name: &str, ast: &AST) {
+        let type_name = self.local_type_name(name);
+        if name.ends_with("$key") {
+            // Keys are the polymorphic variant of the fragment's name, and
+            // accept `fragmentRefs` fields that contain it.
+            let key = self.print_key(ast);
+            self.definitions.push(format!("{type_name}<'refs> = {key}"));
+            return;
+        }
+
+        self.instructions.clear();
+        if let Some(props) = object_props(ast) {
+            self.print_record(&type_name, props, "");
+        } else {
+            // Unions that are printed as records take the name of the export
+            // instead of being aliased by it.
+            let index = self.reserve_definition();
+            if matches!(ast, AST::Union(_)) {
+                self.defined_names.remove(&type_name);
+            }
+            let type_ = self.print_type(ast, &type_name, "");
+            if type_ == type_name {
+                self.definitions.remove(index);
+            } else {
+                self.definitions[index] = format!("{type_name} = {type_}");
+                self.defined_names.insert(type_name.clone());
+            }
+        }
+
+        let direction = if name.ends_with("$data") {
+            Some(ConversionDirection::FromJs)
+        } else if name.ends_with("$rawResponse") {
+            Some(ConversionDirection::ToJs)
+        } else {
+            None
+        };
+        if let Some(direction) = direction {
+            self.converters.push(Converter {
+                type_name,
+                direction,
+                instructions: std::mem::take(&mut self.instructions),
+            });
+        }
+    }
+
+    fn print_key(&mut self, ast: &AST) -> String {
+        match ast {
+            AST::ReadOnlyArray(of_type) => format!("array<{}>", self.print_key(of_type)),
+            AST::ExactObject(_) | AST::InexactObject(_) => object_props(ast)
+                .unwrap()
+                .iter()
+                .find_map(|prop| match prop {
+                    Prop::KeyValuePair(key_value_pair) => match &key_value_pair.value {
+                        AST::FragmentReference(fragments) => Some(format!(
+                            "[> {}] as 'refs",
+                            fragments
+                                .iter()
+                                .map(|fragment| tag(fragment.lookup()))
+                                .join(" | ")
+                        )),
+                        _ => None,
+                    },
+                    _ => None,
+                })
+                .unwrap_or_else(|| "Js.Json.t".to_string()),
+            _ => "Js.Json.t".to_string(),
+        }
+    }
+
+    fn reserve_definition(&mut self) -> usize {
+        self.definitions.push(String::new());
+        self.definitions.len() - 1
+    }
+
+    /// Reserves a name for a hoisted record, adding a numeric suffix if the
+    /// name is already used.
+    fn unique_type_name(&mut self, base_name: &str) -> String {
+        let mut name = base_name.to_string();
+        let mut suffix = 1;
+        while self.defined_names.contains(&name) {
+            suffix += 1;
+            name = format!("{base_name}{suffix}");
+        }
+        self.defined_names.insert(name.clone());
+        name
+    }
+
+    fn print_type(&mut self, ast: &AST, name: &str, path: &str) -> String {
+        match ast {
+            AST::Any | AST::Mixed | AST::Empty => "Js.Json.t".to_string(),
+            AST::String | AST::OtherTypename => "string".to_string(),
+            AST::Number => "float".to_string(),
+            AST::Boolean => "bool".to_string(),
+            AST::StringLiteral(literal) => format!("[{}]", tag(literal.lookup())),
+            AST::Identifier(identifier) => self.type_reference(*identifier),
+            AST::RawType(raw_type) => match raw_type.lookup() {
+                "void" | "undefined" => "unit".to_string(),
+                _ if self.imported_types.contains_key(raw_type) => self.type_reference(*raw_type),
+                raw_type => raw_type.to_string(),
+            },
+            AST::Union(members) => self.print_union(members, name, path),
+            AST::ReadOnlyArray(of_type) => {
+                format!("array<{}>", self.print_type(of_type, name, path))
+            }
+            AST::Nullable(of_type) => {
+                format!("option<{}>", self.print_type(of_type, name, path))
+            }
+            AST::NonNullable(of_type) => match &**of_type {
+                AST::Nullable(inner) => self.print_type(inner, name, path),
+                of_type => self.print_type(of_type, name, path),
+            },
+            AST::ExactObject(_) | AST::InexactObject(_) => {
+                let record_name = self.unique_type_name(name);
+                self.print_record(&record_name, object_props(ast).unwrap(), path);
+                record_name
+            }
+            AST::Local3DPayload(_, of_type) => self.print_type(of_type, name, path),
+            AST::Callable(return_type) => {
+                format!("unit => {}", self.print_type(return_type, name, path))
+            }
+            AST::FragmentReference(fragments) => format!(
+                "[{}]",
+                fragments
+                    .iter()
+                    .map(|fragment| tag(fragment.lookup()))
+                    .join(" | ")
+            ),
+            AST::FragmentReferenceType(fragment) => format!("[{}]", tag(fragment.lookup())),
+            AST::GenericType { outer, inner } => {
+                let module_prefix = match self.imported_types.get(outer) {
+                    Some(None) => return "Js.Json.t".to_string(),
+                    Some(Some(module_name)) => format!("{module_name}."),
+                    None => String::new(),
+                };
+                let inner = inner
+                    .iter()
+                    .map(|inner| self.print_type(inner, name, path))
+                    .join(", ");
+                format!("{module_prefix}{outer}<{inner}>")
+            }
+            AST::ReturnTypeOfFunctionWithName(_)
+            | AST::ReturnTypeOfMethodCall(_, _)
+            | AST::AssertFunctionType(_)
+            | AST::PropertyType { .. } => "Js.Json.t".to_string(),
+        }
+    }
+
+    fn print_union(&mut self, members: &[AST], name: &str, path: &str) -> String {
+        if let [member] = members {
+            return self.print_type(member, name, path);
+        }
+
+        if members
+            .iter()
+            .all(|member| matches!(member, AST::StringLiteral(_)))
+        {
+            return format!(
+                "[{}]",
+                members
+                    .iter()
+                    .map(|member| match member {
+                        AST::StringLiteral(literal) => tag(literal.lookup()),
+                        _ => unreachable!(),
+                    })
+                    .join(" | ")
+            );
+        }
+
+        let objects = members.iter().filter_map(object_props).collect_vec();
+        if objects.len() != members.len() {
+            return "Js.Json.t".to_string();
+        }
+
+        if objects.iter().any(|props| typename(props).is_some()) {
+            self.print_abstract_selection(&objects, name, path)
+        } else {
+            // e.g. the members of a @oneOf input object, which are merged
+            // into a record with only optional fields.
+            let mut merged_props: Vec<Prop> = vec![];
+            for props in objects {
+                for prop in props {
+                    if let Prop::KeyValuePair(key_value_pair) = prop {
+                        if matches!(key_value_pair.value, AST::Empty) {
+                            continue;
+                        }
+                        if !merged_props.iter().any(|merged_prop| match merged_prop {
+                            Prop::KeyValuePair(merged) => merged.key == key_value_pair.key,
+                            _ => false,
+                        }) {
+                            let mut key_value_pair = key_value_pair.clone();
+                            key_value_pair.optional = true;
+                            merged_props.push(Prop::KeyValuePair(key_value_pair));
+                        }
+                    }
+                }
+            }
+            let record_name = self.unique_type_name(name);
+            self.print_record(&record_name, &merged_props, path);
+            record_name
+        }
+    }
+
+    /// Prints the members of a selection on an abstract type as a polymorphic
+    /// variant keyed by `__typename`, e.g. `[#User(data_node_User) | ...]`.
+    fn print_abstract_selection(&mut self, objects: &[&[Prop]], name: &str, path: &str) -> String {
+        let mut known_typenames = vec![];
+        let mut constructors = vec![];
+        for props in objects {
+            match typename(props) {
+                Some(Some(typename)) => {
+                    let record_name = self.unique_type_name(&format!("{name}_{typename}"));
+                    self.print_record(&record_name, props, &format!("{path}@{typename}"));
+                    constructors.push(format!("{}({record_name})", tag(typename.lookup())));
+                    known_typenames.push(typename);
+                }
+                Some(None) | None => {
+                    constructors.push(format!("#{UNSELECTED_UNION_MEMBER}(string)"));
+                }
+            }
+        }
+        self.instructions.push((
+            path.to_string(),
+            format!("u:{}", known_typenames.iter().join(",")),
+        ));
+        format!("[{}]", constructors.iter().unique().join(" | "))
+    }
+
+    fn print_record(&mut self, name: &str, props: &[Prop], path: &str) {
+        let index = self.reserve_definition();
+        let mut fields = vec![];
+        for prop in props {
+            match prop {
+                Prop::Spread(spread) => {
+                    fields.insert(0, format!("...{}", self.type_reference(spread.value)));
+                }
+                Prop::KeyValuePair(key_value_pair) => {
+                    let key = key_value_pair.key;
+                    // Fragment types and the `$data` of keys only brand the
+                    // Flow/TypeScript types, records are nominal already.
+                    if key == *KEY_FRAGMENT_TYPE
+                        || key == *KEY_DATA
+                        || matches!(key_value_pair.value, AST::Empty)
+                    {
+                        continue;
+                    }
+                    if key == *KEY_FRAGMENT_SPREADS {
+                        self.instructions.push((path.to_string(), "f".to_string()));
+                        let type_ = self.print_type(&key_value_pair.value, name, path);
+                        fields.push(format!("{FRAGMENT_REFS_FIELD}: {type_}"));
+                        continue;
+                    }
+                    let (value, optional) = match (&key_value_pair.value, key_value_pair.optional) {
+                        (AST::Nullable(of_type), true) => (&**of_type, "?"),
+                        (value, true) => (value, "?"),
+                        (value, false) => (value, ""),
+                    };
+                    let type_ =
+                        self.print_type(value, &format!("{name}_{key}"), &field_path(path, key));
+                    fields.push(format!("{}{optional}: {type_}", field_name(key.lookup())));
+                }
+                Prop::GetterSetterPair(getter_setter_pair) => {
+                    let key = getter_setter_pair.key;
+                    let type_ = self.print_type(
+                        &getter_setter_pair.getter_return_value,
+                        &format!("{name}_{key}"),
+                        &field_path(path, key),
+                    );
+                    fields.push(format!("{}: {type_}", field_name(key.lookup())));
+                }
+            }
+        }
+        // ReScript has no empty records.
+        self.definitions[index] = if fields.is_empty() {
+            format!("{name} = Js.Dict.t<Js.Json.t>")
+        } else {
+            format!(
+                "{name} = {{\n{}\n}}",
+                fields.iter().map(|field| format!("  {field},")).join("\n")
+            )
+        };
+    }
+
+    fn type_reference(&self, identifier: StringKey) -> String {
+        match self.imported_types.get(&identifier) {
+            Some(None) => "Js.Json.t".to_string(),
+            Some(Some(module_name)) => {
+                let base_name = module_name.strip_suffix("_graphql").unwrap_or(module_name);
+                format!(
+                    "{module_name}.{}",
+                    type_name(identifier.lookup(), base_name)
+                )
+            }
+            None => self.local_type_name(identifier.lookup()),
+        }
+    }
+
+    fn local_type_name(&self, name: &str) -> String {
+        type_name(name, self.definition_name.lookup())
+    }
+}
+
+/// The name of the ReScript type for an exported Flow/TypeScript type.
+/// Types named after the definition of their artifact drop that prefix, e.g.
+/// `MyQuery$variables` is `variables` and `MyQuery` is `t`.
+fn type_name(name: &str, definition_name: &str) -> String {
+    match name.strip_prefix(definition_name) {
+        Some("") => "t".to_string(),
+        Some(suffix) if suffix.starts_with('$') => sanitize(&suffix[1..]),
+        _ => sanitize(name),
+    }
+}
+
+/// Lowercases the leading capitals (`JSON` is `json` and `URLString` is
+/// `urlString`) and replaces characters that are not valid in ReScript type
+/// names.
+fn sanitize(name: &str) -> String {
+    let chars = name.chars().collect_vec();
+    let leading_capitals = chars
+        .iter()
+        .take_while(|char| char.is_ascii_uppercase())
+        .count();
+    let lowercased = if leading_capitals > 1 && leading_capitals < chars.len() {
+        leading_capitals - 1
+    } else {
+        leading_capitals
+    };
+    chars
+        .iter()
+        .enumerate()
+        .map(|(index, char)| {
+            if index < lowercased.max(1) {
+                char.to_ascii_lowercase()
+            } else {
+                *char
+            }
+        })
+        .map(|char| {
+            if char.is_ascii_alphanumeric() || char == '_' {
+                char
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Record fields that are not valid ReScript identifiers keep their JS name
+/// through `@as`.
+fn field_name(key: &str) -> String {
+    let sanitized = sanitize(key);
+    if sanitized == key && !KEYWORDS.contains(&key) {
+        sanitized
+    } else if KEYWORDS.contains(&sanitized.as_str()) {
+        format!("@as(\"{key}\") {sanitized}_")
+    } else {
+        format!("@as(\"{key}\") {sanitized}")
+    }
+}
+
+fn field_path(path: &str, key: StringKey) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{path}.{key}")
+    }
+}
+
+/// A polymorphic variant tag, e.g. `#User` or `#"%future added value"`.
+fn tag(name: &str) -> String {
+    let mut chars = name.chars();
+    let is_identifier = chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic())
+        && chars.all(|char| char.is_ascii_alphanumeric() || char == '_');
+    if is_identifier {
+        format!("#{name}")
+    } else {
+        format!("#\"{name}\"")
+    }
+}
+
+/// Returns `Some(Some(typename))` for objects with a literal `__typename`,
+/// `Some(None)` for the placeholder of typenames that are unknown at build
+/// time and `None` for objects without a typename.
+fn typename(props: &[Prop]) -> Option<Option<StringKey>> {
+    props.iter().find_map(|prop| match prop {
+        Prop::KeyValuePair(key_value_pair) if key_value_pair.key == *KEY_TYPENAME => {
+            match &key_value_pair.value {
+                AST::StringLiteral(literal) => Some(Some(literal.0)),
+                AST::OtherTypename => Some(None),
+                _ => None,
+            }
+        }
+        _ => None,
+    })
+}
+
+fn object_props(ast: &AST) -> Option<&[Prop]> {
+    match ast {
+        AST::ExactObject(object) => Some(object),
+        AST::InexactObject(object) => Some(object),
+        _ => None,
+    }
+}
+
+/// The ReScript module of an imported file, e.g. `MyFragment_graphql` for
+/// `./MyFragment.graphql`.
+fn module_name(from: &str) -> String {
+    let file_name = from.rsplit('/').next().unwrap_or(from);
+    let file_name = [".js", ".ts", ".res"]
+        .iter()
+        .find_map(|extension| file_name.strip_suffix(extension))
+        .unwrap_or(file_name);
+    let mut chars = file_name.chars();
+    chars
+        .next()
+        .map(|first| first.to_ascii_uppercase())
+        .into_iter()
+        .chain(chars)
+        .map(|char| {
+            if char.is_ascii_alphanumeric() || char == '_' {
+                char
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
@@ -163,6 +163,7 @@ pub(crate) struct TypeSelectionLinkedField {
     pub(crate) concrete_type: Option<Type>,
     pub(crate) abstract_type: Option<Type>,
     pub(crate) is_result_type: bool,
+    pub(crate) doc_comment: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -174,6 +175,7 @@ pub(crate) struct TypeSelectionScalarField {
     pub(crate) concrete_type: Option<Type>,
     pub(crate) abstract_type: Option<Type>,
     pub(crate) is_result_type: bool,
+    pub(crate) doc_comment: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -147,6 +147,25 @@ impl Writer for TypeScriptPrinter {
         Ok(())
     }
 
+    fn write_doc_comment(&mut self, doc_comment: &str) -> FmtResult {
+        if !doc_comment.contains('\n') {
+            writeln!(&mut self.result, "/** {doc_comment} */")?;
+        } else {
+            writeln!(&mut self.result, "/**")?;
+            for line in doc_comment.lines() {
+                self.write_indentation()?;
+                if line.is_empty() {
+                    writeln!(&mut self.result, " *")?;
+                } else {
+                    writeln!(&mut self.result, " * {line}")?;
+                }
+            }
+            self.write_indentation()?;
+            writeln!(&mut self.result, " */")?;
+        }
+        self.write_indentation()
+    }
+
     // In TypeScript, we don't export & import fragments. We just use the generic FragmentRefs type instead.
     fn write_import_fragment_type(&mut self, _types: &[&str], _from: &str) -> FmtResult {
         Ok(())
@@ -257,6 +276,9 @@ impl TypeScriptPrinter {
                 Prop::Spread(_) => continue,
                 Prop::KeyValuePair(key_value_pair) => {
                     self.write_indentation()?;
+                    if let Some(doc_comment) = &key_value_pair.doc_comment {
+                        self.write_doc_comment(doc_comment)?;
+                    }
                     if let AST::OtherTypename = key_value_pair.value {
                         writeln!(
                             &mut self.result,
@@ -472,6 +494,7 @@ mod tests {
                     optional: false,
                     read_only: false,
                     value: AST::String,
+                    doc_comment: None,
                 })
             ]))),
             r"{
@@ -486,12 +509,14 @@ mod tests {
                     optional: true,
                     read_only: false,
                     value: AST::String,
+                    doc_comment: None,
                 }),
                 Prop::KeyValuePair(KeyValuePairProp {
                     key: intern!("bar"),
                     optional: false,
                     read_only: true,
                     value: AST::Number,
+                    doc_comment: None,
                 }),
             ]))),
             r"{
@@ -516,20 +541,24 @@ mod tests {
                             optional: true,
                             read_only: false,
                             value: AST::String,
+                            doc_comment: None,
                         }),
                         Prop::KeyValuePair(KeyValuePairProp {
                             key: intern!("nested_foo2"),
                             optional: false,
                             read_only: true,
                             value: AST::Number,
+                            doc_comment: None,
                         }),
                     ])),
+                    doc_comment: None,
                 }),
                 Prop::KeyValuePair(KeyValuePairProp {
                     key: intern!("bar"),
                     optional: false,
                     read_only: true,
                     value: AST::Number,
+                    doc_comment: None,
                 }),
             ]))),
             r"{
@@ -557,6 +586,7 @@ mod tests {
                     optional: false,
                     read_only: false,
                     value: AST::String,
+                    doc_comment: None,
                 }),
             ]))),
             r"{
@@ -572,12 +602,14 @@ mod tests {
                     optional: false,
                     read_only: false,
                     value: AST::String,
+                    doc_comment: None,
                 }),
                 Prop::KeyValuePair(KeyValuePairProp {
                     key: intern!("bar"),
                     optional: true,
                     read_only: true,
                     value: AST::Number,
+                    doc_comment: None,
                 })
             ]))),
             r"{
@@ -597,13 +629,15 @@ mod tests {
                         key: intern!("foo"),
                         value: AST::String,
                         read_only: false,
-                        optional: false
+                        optional: false,
+                        doc_comment: None,
                     }),
                     Prop::KeyValuePair(KeyValuePairProp {
                         key: intern!("bar"),
                         value: AST::Empty,
                         read_only: false,
-                        optional: true
+                        optional: true,
+                        doc_comment: None,
                     })
                 ])),
                 AST::ExactObject(ExactObject::new(vec![
@@ -611,13 +645,15 @@ mod tests {
                         key: intern!("foo"),
                         value: AST::Empty,
                         read_only: false,
-                        optional: true
+                        optional: true,
+                        doc_comment: None,
                     }),
                     Prop::KeyValuePair(KeyValuePairProp {
                         key: intern!("bar"),
                         value: AST::String,
                         read_only: false,
-                        optional: false
+                        optional: false,
+                        doc_comment: None,
                     })
                 ]))
             ]))),
@@ -640,6 +676,7 @@ mod tests {
                     optional: false,
                     read_only: false,
                     value: AST::OtherTypename,
+                    doc_comment: None,
                 }),
             ]))),
             r#"{
@@ -24,6 +24,7 @@ use docblock_shared::KEY_RESOLVER_ID_FIELD;
 use docblock_shared::RELAY_RESOLVER_DIRECTIVE_NAME;
 use docblock_shared::RELAY_RESOLVER_MODEL_INSTANCE_FIELD;
 use docblock_shared::RESOLVER_VALUE_SCALAR_NAME;
+use fnv::FnvHashMap;
 use graphql_ir::Condition;
 use graphql_ir::Directive;
 use graphql_ir::FragmentDefinitionName;
@@ -34,6 +35,9 @@ use graphql_ir::OperationDefinition;
 use graphql_ir::OperationDefinitionName;
 use graphql_ir::ScalarField;
 use graphql_ir::Selection;
+use graphql_ir::Value;
+use graphql_ir::VariableName;
+use graphql_ir::Visitor;
 use indexmap::IndexMap;
 use indexmap::IndexSet;
 use indexmap::map::Entry;
@@ -42,6 +46,7 @@ use relay_config::CustomType;
 use relay_config::CustomTypeImport;
 use relay_config::OneOfGeneration;
 use relay_config::ResolverContextTypeInput;
+use relay_config::TypegenConfig;
 use relay_config::TypegenLanguage;
 use relay_schema::CUSTOM_SCALAR_DIRECTIVE_NAME;
 use relay_schema::EXPORT_NAME_CUSTOM_SCALAR_ARGUMENT_NAME;
@@ -64,6 +69,8 @@ use relay_transforms::ResolverOutputTypeInfo;
 use relay_transforms::TypeConditionInfo;
 use relay_transforms::UPDATABLE_DIRECTIVE_FOR_TYPEGEN;
 use relay_transforms::relay_resolvers::ResolverSchemaGenType;
+use schema::Argument;
+use schema::Enum;
 use schema::EnumID;
 use schema::Field;
 use schema::InputObject;
@@ -73,6 +80,7 @@ use schema::ScalarID;
 use schema::Schema;
 use schema::Type;
 use schema::TypeReference;
+use schema::definitions::Deprecation;
 use schema::definitions::TypeWithDirectives;
 
 use crate::FRAGMENT_PROP_NAME;
@@ -465,6 +473,7 @@ fn add_model_argument_for_interface_resolver(
             optional: false,
             read_only: false,
             value: interface_union_type,
+            doc_comment: None,
         });
     }
 }
@@ -482,7 +491,9 @@ fn get_resolver_arguments(
     context_import: Option<ResolverContextType>,
 ) -> Vec<KeyValuePairProp> {
     let void_type = match typegen_context.project_config.typegen_config.language {
-        TypegenLanguage::Flow | TypegenLanguage::JavaScript => AST::RawType(intern!("void")),
+        TypegenLanguage::Flow | TypegenLanguage::JavaScript | TypegenLanguage::ReScript => {
+            AST::RawType(intern!("void"))
+        }
         TypegenLanguage::TypeScript => AST::RawType(intern!("undefined")),
     };
 
@@ -527,6 +538,7 @@ fn get_resolver_arguments(
                         },
                         read_only: false,
                         optional: false,
+                        doc_comment: None,
                     });
                 }
             }
@@ -539,6 +551,7 @@ fn get_resolver_arguments(
                 value: AST::RawType(format!("{fragment_name}$key").intern()),
                 read_only: false,
                 optional: false,
+                doc_comment: None,
             });
         }
     }
@@ -556,6 +569,7 @@ fn get_resolver_arguments(
                 encountered_enums,
                 custom_scalars,
             ),
+            doc_comment: None,
         }));
     }
 
@@ -565,6 +579,7 @@ fn get_resolver_arguments(
             value: AST::ExactObject(ExactObject::new(args)),
             read_only: true,
             optional: false,
+            doc_comment: None,
         });
     } else if context_import.is_some() {
         resolver_arguments.push(KeyValuePairProp {
@@ -572,6 +587,7 @@ fn get_resolver_arguments(
             value: void_type,
             read_only: true,
             optional: false,
+            doc_comment: None,
         });
     }
 
@@ -581,6 +597,7 @@ fn get_resolver_arguments(
             value: AST::RawType(context_import.name),
             read_only: true,
             optional: false,
+            doc_comment: None,
         });
     }
 
@@ -789,8 +806,8 @@ fn visit_relay_resolver(
         imported_resolvers,
     );
 
-    let field_name = resolver_metadata.field_name(typegen_context.schema);
-    let key = resolver_metadata.field_alias.unwrap_or(field_name);
+    let field = resolver_metadata.field(typegen_context.schema);
+    let key = resolver_metadata.field_alias.unwrap_or(field.name.item);
 
     let live = resolver_metadata.live;
     let local_resolver_name =
@@ -815,6 +832,12 @@ fn visit_relay_resolver(
         concrete_type: None,
         abstract_type: None,
         is_result_type: false,
+        doc_comment: make_doc_comment(
+            typegen_context,
+            field.name.item,
+            field.description,
+            field.deprecated(),
+        ),
     }));
 }
 
@@ -910,6 +933,7 @@ fn visit_inline_fragment(
             concrete_type: None,
             abstract_type: None,
             is_result_type: false,
+            doc_comment: None,
         }));
         type_selections.push(TypeSelection::ScalarField(TypeSelectionScalarField {
             field_name_or_alias: *MODULE_COMPONENT,
@@ -919,6 +943,7 @@ fn visit_inline_fragment(
             concrete_type: None,
             abstract_type: None,
             is_result_type: false,
+            doc_comment: None,
         }));
         type_selections.push(TypeSelection::InlineFragment(TypeSelectionInlineFragment {
             fragment_name: name,
@@ -989,6 +1014,7 @@ fn visit_inline_fragment(
                 concrete_type: None,
                 abstract_type: None,
                 is_result_type,
+                doc_comment: None,
             })]
         } else {
             // If the inline fragment is on an abstract type, its selections must be
@@ -1137,6 +1163,12 @@ fn gen_visit_linked_field(
         concrete_type: None,
         abstract_type: None,
         is_result_type,
+        doc_comment: make_doc_comment(
+            typegen_context,
+            field.name.item,
+            field.description,
+            field.deprecated(),
+        ),
     }));
 }
 
@@ -1200,11 +1232,37 @@ fn visit_scalar_field(
             concrete_type: None,
             abstract_type: None,
             is_result_type: is_result_type_directive(&scalar_field.directives),
+            doc_comment: make_doc_comment(
+                typegen_context,
+                field.name.item,
+                field.description,
+                field.deprecated(),
+            ),
         }));
     }
 
+    let branded_id = if is_id_type(typegen_context.schema, &field.type_) {
+        let id_of_type = if schema_name
+            == typegen_context
+                .project_config
+                .schema_config
+                .node_interface_id_field
+        {
+            field.parent_type
+        } else {
+            None
+        };
+        branded_id_type(typegen_context, id_of_type, custom_scalars)
+    } else {
+        None
+    };
+
     let ast = transform_type_reference_into_ast(&field_type, |type_| {
-        expect_scalar_type(typegen_context, encountered_enums, custom_scalars, type_)
+        if let Some(branded_id) = &branded_id {
+            branded_id.clone()
+        } else {
+            expect_scalar_type(typegen_context, encountered_enums, custom_scalars, type_)
+        }
     });
 
     type_selections.push(TypeSelection::ScalarField(TypeSelectionScalarField {
@@ -1215,6 +1273,12 @@ fn visit_scalar_field(
         concrete_type: None,
         abstract_type: None,
         is_result_type,
+        doc_comment: make_doc_comment(
+            typegen_context,
+            field.name.item,
+            field.description,
+            field.deprecated(),
+        ),
     }));
 }
 
@@ -1483,6 +1547,7 @@ fn get_merged_object_with_optional_fields(
             optional: false,
             read_only: true,
             value: AST::FragmentReferenceType(fragment_type_name),
+            doc_comment: None,
         }));
     }
 
@@ -1543,6 +1608,7 @@ fn get_discriminated_union_ast(
                     read_only: true,
                     optional: false,
                     value: AST::OtherTypename,
+                    doc_comment: None,
                 })
             })
             .collect(),
@@ -1559,6 +1625,7 @@ fn get_discriminated_union_ast(
                         optional: false,
                         read_only: true,
                         value: AST::FragmentReferenceType(fragment_type_name),
+                        doc_comment: None,
                     }));
                 }
                 if mask_status == MaskStatus::Unmasked {
@@ -1905,9 +1972,9 @@ fn make_prop(
                         AST::RawType(intern!("[]"))
                     } else {
                         match typegen_context.project_config.typegen_config.language {
-                            TypegenLanguage::Flow | TypegenLanguage::JavaScript => {
-                                AST::RawType(intern!("null | void"))
-                            }
+                            TypegenLanguage::Flow
+                            | TypegenLanguage::JavaScript
+                            | TypegenLanguage::ReScript => AST::RawType(intern!("null | void")),
                             TypegenLanguage::TypeScript => {
                                 AST::RawType(intern!("null | undefined"))
                             }
@@ -1931,6 +1998,7 @@ fn make_prop(
                                               value,
                                               read_only: true,
                                               optional: false,
+                                              doc_comment: None,
                                           });
                                           let assignable_fragment_spread_ref = Prop::KeyValuePair(KeyValuePairProp {
                                               key: *KEY_FRAGMENT_SPREADS,
@@ -1939,12 +2007,14 @@ fn make_prop(
                                               ),
                                               read_only: true,
                                               optional: false,
+                                              doc_comment: None,
                                           });
                                           let client_id_field = Prop::KeyValuePair(KeyValuePairProp {
                                               key: "__id".intern(),
                                               value: AST::String,
                                               read_only: true,
                                               optional: false,
+                                              doc_comment: None,
                                           });
 
                                           AST::InexactObject(InexactObject::new(vec![
@@ -2007,6 +2077,7 @@ fn make_prop(
                     value,
                     optional,
                     read_only: true,
+                    doc_comment: linked_field.doc_comment,
                 })
             }
         }
@@ -2020,6 +2091,7 @@ fn make_prop(
                         )),
                         optional,
                         read_only: true,
+                        doc_comment: scalar_field.doc_comment,
                     })
                 } else {
                     Prop::KeyValuePair(KeyValuePairProp {
@@ -2027,6 +2099,7 @@ fn make_prop(
                         value: scalar_field.value,
                         optional,
                         read_only: true,
+                        doc_comment: scalar_field.doc_comment,
                     })
                 }
             } else {
@@ -2051,6 +2124,7 @@ fn make_prop(
                     // all special fields are read only
                     read_only: !typegen_context.generating_updatable_types
                         || scalar_field.special_field.is_some(),
+                    doc_comment: scalar_field.doc_comment,
                 })
             }
         }
@@ -2107,6 +2181,7 @@ fn raw_response_make_prop(
                 }),
                 read_only: true,
                 optional,
+                doc_comment: linked_field.doc_comment,
             })
         }
         TypeSelection::ScalarField(scalar_field) => {
@@ -2119,6 +2194,7 @@ fn raw_response_make_prop(
                         )),
                         read_only: true,
                         optional,
+                        doc_comment: scalar_field.doc_comment,
                     })
                 } else {
                     Prop::KeyValuePair(KeyValuePairProp {
@@ -2126,6 +2202,7 @@ fn raw_response_make_prop(
                         value: scalar_field.value,
                         read_only: true,
                         optional,
+                        doc_comment: scalar_field.doc_comment,
                     })
                 }
             } else {
@@ -2134,6 +2211,7 @@ fn raw_response_make_prop(
                     value: scalar_field.value,
                     read_only: true,
                     optional,
+                    doc_comment: scalar_field.doc_comment,
                 })
             }
         }
@@ -2144,6 +2222,90 @@ fn raw_response_make_prop(
     }
 }
 
+/// The doc comment of a property generated from a schema field or input
+/// field, if enabled: its description, followed by its deprecation if any.
+/// Introspection fields like `__typename` are not documented.
+fn make_doc_comment(
+    typegen_context: &TypegenContext<'_>,
+    field_name: StringKey,
+    description: Option<StringKey>,
+    deprecation: Option<Deprecation>,
+) -> Option<String> {
+    if !typegen_context
+        .project_config
+        .typegen_config
+        .emit_doc_comments
+        || field_name.lookup().starts_with("__")
+    {
+        return None;
+    }
+    let mut lines = description_lines(description);
+    if let Some(deprecation) = deprecation {
+        lines.push(match deprecation.reason {
+            Some(reason) => format!("@deprecated {}", reason.lookup().trim()),
+            None => "@deprecated".to_string(),
+        });
+    }
+    join_doc_comment_lines(lines)
+}
+
+/// The doc comment of the type of an enum: its description, followed by its
+/// deprecated values. Members of a union can't be documented, and marking the
+/// type itself `@deprecated` would flag every use of the enum.
+pub(crate) fn make_enum_doc_comment(
+    typegen_config: &TypegenConfig,
+    enum_type: &Enum,
+) -> Option<String> {
+    if !typegen_config.emit_doc_comments {
+        return None;
+    }
+    let mut lines = description_lines(enum_type.description);
+    let deprecated_values: Vec<String> = enum_type
+        .values
+        .iter()
+        .filter_map(|enum_value| {
+            enum_value
+                .deprecated()
+                .map(|deprecation| match deprecation.reason {
+                    Some(reason) => {
+                        format!("- \"{}\": {}", enum_value.value, reason.lookup().trim())
+                    }
+                    None => format!("- \"{}\"", enum_value.value),
+                })
+        })
+        .collect();
+    if !deprecated_values.is_empty() {
+        if !lines.is_empty() {
+            lines.push(String::new());
+        }
+        lines.push("Deprecated values:".to_string());
+        lines.extend(deprecated_values);
+    }
+    join_doc_comment_lines(lines)
+}
+
+fn description_lines(description: Option<StringKey>) -> Vec<String> {
+    description
+        .map(|description| {
+            description
+                .lookup()
+                .trim()
+                .lines()
+                .map(|line| line.trim_end().to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn join_doc_comment_lines(lines: Vec<String>) -> Option<String> {
+    if lines.is_empty() {
+        None
+    } else {
+        // The documentation must not close the comment it is printed in
+        Some(lines.join("\n").replace("*/", "*\\/"))
+    }
+}
+
 fn transform_type_reference_into_ast(
     type_reference: &TypeReference<Type>,
     transform_inner_type: impl FnOnce(&Type) -> AST,
@@ -2172,6 +2334,85 @@ fn transform_non_nullable_type_reference_into_ast(
     }
 }
 
+fn is_id_type(schema: &SDLSchema, type_: &TypeReference<Type>) -> bool {
+    match type_.inner() {
+        Type::Scalar(scalar_id) => schema.scalar(scalar_id).name.item == *TYPE_ID,
+        _ => false,
+    }
+}
+
+/// Returns the branded ID type, e.g. `ID<"User">`, for ids of objects of
+/// the given type, or `None` if `branded_id_type` is not configured. Abstract
+/// types are branded with the union of their possible object types, and ids
+/// of an unknown type with `ID<string>`, which accepts the ids of any type.
+fn branded_id_type(
+    typegen_context: &'_ TypegenContext<'_>,
+    id_of_type: Option<Type>,
+    custom_scalars: &mut CustomScalarsImports,
+) -> Option<AST> {
+    let branded_id_type = typegen_context
+        .project_config
+        .typegen_config
+        .branded_id_type
+        .as_ref()?;
+    let schema = typegen_context.schema;
+    let object_ids: Vec<ObjectID> = match id_of_type {
+        Some(Type::Object(object_id)) => vec![object_id],
+        Some(Type::Interface(interface_id)) => schema
+            .interface(interface_id)
+            .recursively_implementing_objects(schema)
+            .into_iter()
+            .collect(),
+        Some(Type::Union(union_id)) => schema.union(union_id).members.clone(),
+        Some(Type::Enum(_) | Type::InputObject(_) | Type::Scalar(_)) | None => vec![],
+    };
+    let mut brands: Vec<AST> = object_ids
+        .into_iter()
+        .map(|object_id| AST::StringLiteral(StringLiteral(schema.object(object_id).name.item.0)))
+        .collect();
+    let brand = match brands.len() {
+        0 => AST::String,
+        1 => brands.pop().unwrap(),
+        _ => AST::Union(SortedASTList::new(brands)),
+    };
+    custom_scalars.insert((branded_id_type.name, branded_id_type.path.clone()));
+    Some(AST::GenericType {
+        outer: branded_id_type.name,
+        inner: vec![brand],
+    })
+}
+
+/// The branded ID type of an input object field of type `ID` or a list of
+/// `ID`s. The schema doesn't tell which type the ids belong to, so ids of any
+/// type are accepted.
+fn input_field_branded_id(
+    typegen_context: &'_ TypegenContext<'_>,
+    field: &Argument,
+    custom_scalars: &mut CustomScalarsImports,
+) -> Option<AST> {
+    if !is_id_type(typegen_context.schema, &field.type_) {
+        return None;
+    }
+    branded_id_type(typegen_context, None, custom_scalars)
+}
+
+/// Transforms an input type of `ID`s, such as `[ID!]`, using the given
+/// branded ID type for its items.
+fn transform_branded_id_input_type(type_ref: &TypeReference<Type>, branded_id: AST) -> AST {
+    match type_ref {
+        TypeReference::NonNull(of_type) => match &**of_type {
+            TypeReference::List(item_type) => AST::ReadOnlyArray(Box::new(
+                transform_branded_id_input_type(item_type, branded_id),
+            )),
+            _ => branded_id,
+        },
+        TypeReference::List(item_type) => AST::Nullable(Box::new(AST::ReadOnlyArray(Box::new(
+            transform_branded_id_input_type(item_type, branded_id),
+        )))),
+        TypeReference::Named(_) => AST::Nullable(Box::new(branded_id)),
+    }
+}
+
 fn transform_graphql_scalar_type(
     typegen_context: &'_ TypegenContext<'_>,
     scalar: ScalarID,
@@ -2179,6 +2420,14 @@ fn transform_graphql_scalar_type(
 ) -> AST {
     let scalar_definition = typegen_context.schema.scalar(scalar);
     let scalar_name = scalar_definition.name;
+    if typegen_context.typegen_options.is_zod_schema
+        && let Some(zod_config) = &typegen_context.project_config.typegen_config.zod
+        && zod_config
+            .custom_scalar_schemas
+            .contains_key(&scalar_name.item)
+    {
+        return AST::Identifier(scalar_name.item.0);
+    }
     if let Some(directive) = scalar_definition
         .directives
         .named(DirectiveName(*CUSTOM_SCALAR_DIRECTIVE_NAME))
@@ -2403,7 +2652,13 @@ fn transform_non_nullable_input_type(
         ))),
         TypeReference::Named(named_type) => match named_type {
             Type::Scalar(scalar) => {
-                transform_graphql_scalar_type(typegen_context, *scalar, custom_scalars)
+                if is_id_type(typegen_context.schema, type_ref)
+                    && let Some(branded_id) = branded_id_type(typegen_context, None, custom_scalars)
+                {
+                    branded_id
+                } else {
+                    transform_graphql_scalar_type(typegen_context, *scalar, custom_scalars)
+                }
             }
             Type::Enum(enum_id) => {
                 transform_graphql_enum_type(typegen_context.schema, *enum_id, encountered_enums)
@@ -2478,12 +2733,23 @@ fn build_input_object(
                             .optional_input_fields
                             .contains(&field.name.item.0)
                         || field.default_value.is_some(),
-                    value: transform_input_type(
+                    value: match input_field_branded_id(typegen_context, field, custom_scalars) {
+                        Some(branded_id) => {
+                            transform_branded_id_input_type(&field.type_, branded_id)
+                        }
+                        None => transform_input_type(
+                            typegen_context,
+                            &field.type_,
+                            input_object_types,
+                            encountered_enums,
+                            custom_scalars,
+                        ),
+                    },
+                    doc_comment: make_doc_comment(
                         typegen_context,
-                        &field.type_,
-                        input_object_types,
-                        encountered_enums,
-                        custom_scalars,
+                        field.name.item.0,
+                        field.description,
+                        field.deprecated(),
                     ),
                 })
             })
@@ -2540,12 +2806,31 @@ fn build_one_of_cases(
                             key: field.name.item.0,
                             read_only: false,
                             optional: false,
-                            value: transform_non_nullable_input_type(
+                            value: match input_field_branded_id(
                                 typegen_context,
-                                &field.type_,
-                                input_object_types,
-                                encountered_enums,
+                                field,
                                 custom_scalars,
+                            ) {
+                                Some(branded_id) => {
+                                    match transform_branded_id_input_type(&field.type_, branded_id)
+                                    {
+                                        AST::Nullable(of_type) => *of_type,
+                                        ast => ast,
+                                    }
+                                }
+                                None => transform_non_nullable_input_type(
+                                    typegen_context,
+                                    &field.type_,
+                                    input_object_types,
+                                    encountered_enums,
+                                    custom_scalars,
+                                ),
+                            },
+                            doc_comment: make_doc_comment(
+                                typegen_context,
+                                field.name.item.0,
+                                field.description,
+                                field.deprecated(),
                             ),
                         })
                     } else {
@@ -2554,6 +2839,7 @@ fn build_one_of_cases(
                             read_only: false,
                             optional: true,
                             value: AST::Empty,
+                            doc_comment: None,
                         })
                     }
                 })
@@ -2594,27 +2880,141 @@ pub(crate) fn get_input_variables_type<'a>(
     encountered_enums: &'a mut EncounteredEnums,
     custom_scalars: &'a mut CustomScalarsImports,
 ) -> ExactObject {
+    let mut variable_id_types = if typegen_context
+        .project_config
+        .typegen_config
+        .branded_id_type
+        .is_some()
+    {
+        let mut visitor = VariableIdTypes::new(typegen_context);
+        visitor.visit_operation(node);
+        visitor.id_types
+    } else {
+        Default::default()
+    };
     ExactObject::new(
         node.variable_definitions
             .iter()
             .map(|var_def| {
-                Prop::KeyValuePair(KeyValuePairProp {
-                    key: var_def.name.item.0,
-                    read_only: false,
-                    optional: !var_def.type_.is_non_null(),
-                    value: transform_input_type(
+                let branded_id = if is_id_type(typegen_context.schema, &var_def.type_) {
+                    let id_of_type = variable_id_types.remove(&var_def.name.item).flatten();
+                    branded_id_type(typegen_context, id_of_type, custom_scalars)
+                } else {
+                    None
+                };
+                let value = match branded_id {
+                    Some(branded_id) => transform_branded_id_input_type(&var_def.type_, branded_id),
+                    None => transform_input_type(
                         typegen_context,
                         &var_def.type_,
                         input_object_types,
                         encountered_enums,
                         custom_scalars,
                     ),
+                };
+                Prop::KeyValuePair(KeyValuePairProp {
+                    key: var_def.name.item.0,
+                    read_only: false,
+                    optional: !var_def.type_.is_non_null(),
+                    value,
+                    doc_comment: None,
                 })
             })
             .collect(),
     )
 }
 
+/// Finds the variables passed as the `id` argument, or (items of) the `ids`
+/// argument, of a field returning a composite type, so that they can accept
+/// that type's branded ID. Other ID inputs accept ids of any type.
+///
+/// Variables used as ids of different types map to `None`.
+struct VariableIdTypes<'a> {
+    schema: &'a SDLSchema,
+    id_argument_name: ArgumentName,
+    ids_argument_name: ArgumentName,
+    id_types: FnvHashMap<VariableName, Option<Type>>,
+}
+
+impl<'a> VariableIdTypes<'a> {
+    fn new(typegen_context: &'a TypegenContext<'_>) -> Self {
+        let id_field = typegen_context
+            .project_config
+            .schema_config
+            .node_interface_id_field;
+        Self {
+            schema: typegen_context.schema,
+            id_argument_name: ArgumentName(id_field),
+            ids_argument_name: ArgumentName(format!("{id_field}s").intern()),
+            id_types: Default::default(),
+        }
+    }
+
+    fn visit_field_arguments(
+        &mut self,
+        field: &impl graphql_ir::Field,
+        returns_composite_type: bool,
+    ) {
+        let field_definition = self.schema.field(field.definition().item);
+        for argument in field.arguments() {
+            let Some(argument_definition) = field_definition.arguments.named(argument.name.item)
+            else {
+                continue;
+            };
+            if returns_composite_type
+                && (argument.name.item == self.id_argument_name
+                    || argument.name.item == self.ids_argument_name)
+            {
+                self.visit_value(
+                    &argument.value.item,
+                    &argument_definition.type_,
+                    field_definition.type_.inner(),
+                );
+            }
+        }
+    }
+
+    fn visit_value(&mut self, value: &Value, type_: &TypeReference<Type>, id_of_type: Type) {
+        match value {
+            Value::Variable(variable) => {
+                if is_id_type(self.schema, type_) {
+                    self.id_types
+                        .entry(variable.name.item)
+                        .and_modify(|existing| {
+                            if *existing != Some(id_of_type) {
+                                *existing = None;
+                            }
+                        })
+                        .or_insert(Some(id_of_type));
+                }
+            }
+            Value::List(items) => {
+                if let Some(item_type) = type_.nullable_type().list_item_type() {
+                    for item in items {
+                        self.visit_value(item, item_type, id_of_type);
+                    }
+                }
+            }
+            Value::Object(_) | Value::Constant(_) => {}
+        }
+    }
+}
+
+impl Visitor for VariableIdTypes<'_> {
+    const NAME: &'static str = "VariableIdTypes";
+    const VISIT_ARGUMENTS: bool = false;
+    const VISIT_DIRECTIVES: bool = false;
+
+    fn visit_linked_field(&mut self, field: &LinkedField) {
+        self.visit_field_arguments(field, true);
+        self.default_visit_linked_field(field);
+    }
+
+    fn visit_scalar_field(&mut self, field: &ScalarField) {
+        self.visit_field_arguments(field, false);
+    }
+}
+
 fn hashmap_into_values<K: Hash + Eq, V>(map: IndexMap<K, V>) -> impl Iterator<Item = V> {
     map.into_iter().map(|(_, val)| val)
 }
@@ -2756,6 +3156,7 @@ fn group_refs(props: impl Iterator<Item = TypeSelection>) -> impl Iterator<Item
                 concrete_type: None,
                 abstract_type: None,
                 is_result_type: false,
+                doc_comment: None,
             }));
         }
         if let Some(refs) = updatable_fragment_spreads.take() {
@@ -2768,6 +3169,7 @@ fn group_refs(props: impl Iterator<Item = TypeSelection>) -> impl Iterator<Item
                 concrete_type: None,
                 abstract_type: None,
                 is_result_type: false,
+                doc_comment: None,
             }));
         }
         None
@@ -2838,12 +3240,14 @@ pub(crate) fn get_operation_type_export(
             read_only: false,
             optional: false,
             value: AST::Identifier(variables_identifier_key),
+            doc_comment: None,
         }),
         Prop::KeyValuePair(KeyValuePairProp {
             key: *RESPONSE,
             read_only: false,
             optional: false,
             value: AST::Identifier(response_identifier_key),
+            doc_comment: None,
         }),
     ];
     if let Some(raw_response_prop) = raw_response_prop {
@@ -2872,6 +3276,7 @@ fn create_edge_to_return_type_ast(
         value: AST::RawType(*KEY_DATA_ID),
         read_only: true,
         optional: false,
+        doc_comment: None,
     })];
     if inner_type.is_abstract_type() && schema.is_extension_type(*inner_type) {
         let get_object_names = |members: &Vec<ObjectID>| {
@@ -2897,6 +3302,7 @@ fn create_edge_to_return_type_ast(
             )),
             read_only: true,
             optional: false,
+            doc_comment: None,
         }))
     }
 
@@ -17,8 +17,10 @@ use ::intern::string_key::StringKey;
 use common::DirectiveName;
 use common::InputObjectName;
 use common::NamedItem;
+use graphql_ir::Field;
 use graphql_ir::FragmentDefinition;
 use graphql_ir::FragmentDefinitionName;
+use graphql_ir::LinkedField;
 use graphql_ir::OperationDefinition;
 use graphql_ir::ProvidedVariableMetadata;
 use graphql_ir::Selection;
@@ -26,13 +28,17 @@ use indexmap::IndexMap;
 use itertools::Itertools;
 use relay_config::CustomTypeImport;
 use relay_config::JsModuleFormat;
+use relay_config::TypegenConfig;
 use relay_config::TypegenLanguage;
 use relay_transforms::ASSIGNABLE_DIRECTIVE;
 use relay_transforms::CATCH_DIRECTIVE_NAME;
 use relay_transforms::CHILDREN_CAN_BUBBLE_METADATA_KEY;
+use relay_transforms::PAGINATED_LIST_DIRECTIVE_NAME;
 use relay_transforms::RefetchableDerivedFromMetadata;
 use relay_transforms::RefetchableMetadata;
 use relay_transforms::RelayDirective;
+use schema::Enum;
+use schema::SDLSchema;
 use schema::Schema;
 
 use crate::FUTURE_ENUM_VALUE;
@@ -62,6 +68,7 @@ use crate::visit::get_operation_type_export;
 use crate::visit::has_explicit_catch_to_null;
 use crate::visit::is_result_type_directive;
 use crate::visit::make_custom_error_import;
+use crate::visit::make_enum_doc_comment;
 use crate::visit::make_result_type;
 use crate::visit::raw_response_selections_to_babel;
 use crate::visit::raw_response_visit_selections;
@@ -292,6 +299,7 @@ fn write_raw_response_and_get_raw_response_prop(
             read_only: false,
             optional: false,
             value: AST::Identifier(raw_response_identifier.intern()),
+            doc_comment: None,
         }))
     } else {
         Ok(None)
@@ -421,6 +429,7 @@ pub(crate) fn write_fragment_type_exports_section(
         optional: true,
         read_only: true,
         value: AST::Identifier(data_type_name.as_str().intern()),
+        doc_comment: None,
     });
     let fragment_name = fragment_definition.name.item.0;
     let ref_type_fragment_spreads_property = Prop::KeyValuePair(KeyValuePairProp {
@@ -432,6 +441,7 @@ pub(crate) fn write_fragment_type_exports_section(
         optional: false,
         read_only: true,
         value: AST::FragmentReference(SortedStringKeyList::new(vec![fragment_name])),
+        doc_comment: None,
     });
     let is_plural_fragment = is_plural(fragment_definition);
     let mut ref_type = AST::InexactObject(InexactObject::new(vec![
@@ -553,6 +563,12 @@ pub(crate) fn write_fragment_type_exports_section(
     if !is_assignable_fragment {
         writer.write_export_type(&data_type_name, &data_type)?;
         writer.write_export_type(&format!("{}$key", fragment_definition.name.item), &ref_type)?;
+        if refetchable_metadata.is_some()
+            && let Some(items_type) =
+                get_paginated_list_items_type(typegen_context, fragment_definition)
+        {
+            writer.write_export_type(&format!("{fragment_name}$items"), &items_type)?;
+        }
     } else if typegen_context
         .typegen_options
         .is_extra_artifact_branch_module
@@ -753,54 +769,68 @@ fn write_enum_definitions(
     writer: &mut Box<dyn Writer>,
 ) -> FmtResult {
     let enum_ids = encountered_enums.into_sorted_vec(typegen_context.schema);
-    let maybe_suffix = &typegen_context
-        .project_config
-        .typegen_config
-        .enum_module_suffix;
+    let typegen_config = &typegen_context.project_config.typegen_config;
     for enum_id in enum_ids {
         let enum_type = typegen_context.schema.enum_(enum_id);
-        if !enum_type.is_extension && maybe_suffix.is_some() {
-            // We can't chain `if let` statements, so we need to unwrap here.
-            let suffix = maybe_suffix.as_ref().unwrap();
-            writer.write_import_type(
-                &[enum_type.name.item.lookup()],
-                &format!("{}{}", enum_type.name.item, suffix),
-            )?;
-        } else {
-            let mut members: Vec<AST> = enum_type
-                .values
-                .iter()
-                .map(|enum_value| AST::StringLiteral(StringLiteral(enum_value.value)))
-                .collect();
-
-            // Users can specify a config option to disable the inclusion of
-            // FUTURE_ENUM_VALUE in the enum union. Additionally we want to avoid
-            // emitting FUTURE_ENUM_VALUE if the enum is actually defined on the
-            // client. For example in Client Schema Extensions or (some day)
-            // Relay Resolvers.
-            //
-            // In the case of a client defined enum, we don't need to enforce
-            // the breaking change semantics dictated by the GraphQL spec
-            // because new fields added to the client schema will simply result
-            // in fixable Flow/TypeScript errors elsewhere in the codebase.
-            if !(enum_type.is_extension
-                || typegen_context
-                    .project_config
-                    .typegen_config
-                    .no_future_proof_enums)
+        if !enum_type.is_extension
+            && let Some(suffix) = &typegen_config.enum_module_suffix
+            && !typegen_context.typegen_options.is_zod_schema
+        {
+            let module_name = format!("{}{}", enum_type.name.item, suffix);
+            // Generated enum modules live in the artifact directory, next to
+            // the artifacts importing them.
+            let from = if typegen_config.generate_enum_modules
+                && matches!(
+                    typegen_context.project_config.js_module_format,
+                    JsModuleFormat::CommonJS
+                )
+                && typegen_context.has_unified_output
             {
-                members.push(AST::StringLiteral(StringLiteral(*FUTURE_ENUM_VALUE)));
+                format!("./{module_name}")
+            } else {
+                module_name
+            };
+            writer.write_import_type(&[enum_type.name.item.lookup()], &from)?;
+        } else {
+            if let Some(doc_comment) = make_enum_doc_comment(typegen_config, enum_type) {
+                writer.write_doc_comment(&doc_comment)?;
             }
-
             writer.write_export_type(
                 enum_type.name.item.lookup(),
-                &AST::Union(SortedASTList::new(members)),
+                &AST::Union(SortedASTList::new(enum_type_members(
+                    typegen_config,
+                    enum_type,
+                ))),
             )?;
         }
     }
     Ok(())
 }
 
+/// The string literals making up the type of an enum.
+pub(crate) fn enum_type_members(typegen_config: &TypegenConfig, enum_type: &Enum) -> Vec<AST> {
+    let mut members: Vec<AST> = enum_type
+        .values
+        .iter()
+        .map(|enum_value| AST::StringLiteral(StringLiteral(enum_value.value)))
+        .collect();
+
+    // Users can specify a config option to disable the inclusion of
+    // FUTURE_ENUM_VALUE in the enum union. Additionally we want to avoid
+    // emitting FUTURE_ENUM_VALUE if the enum is actually defined on the
+    // client. For example in Client Schema Extensions or (some day)
+    // Relay Resolvers.
+    //
+    // In the case of a client defined enum, we don't need to enforce
+    // the breaking change semantics dictated by the GraphQL spec
+    // because new fields added to the client schema will simply result
+    // in fixable Flow/TypeScript errors elsewhere in the codebase.
+    if !(enum_type.is_extension || typegen_config.no_future_proof_enums) {
+        members.push(AST::StringLiteral(StringLiteral(*FUTURE_ENUM_VALUE)));
+    }
+    members
+}
+
 fn generate_provided_variables_type(
     typegen_context: &'_ TypegenContext<'_>,
     node: &OperationDefinition,
@@ -827,12 +857,14 @@ fn generate_provided_variables_type(
                 read_only: true,
                 optional: false,
                 value: provider_func,
+                doc_comment: None,
             });
             Some(Prop::KeyValuePair(KeyValuePairProp {
                 key: def.name.item.0,
                 read_only: true,
                 optional: false,
                 value: AST::ExactObject(ExactObject::new(vec![provider_module])),
+                doc_comment: None,
             }))
         })
         .collect_vec();
@@ -917,24 +949,28 @@ fn write_abstract_validator_function(
         value: AST::String,
         read_only: true,
         optional: false,
+        doc_comment: None,
     });
     let fragment_spread_prop = Prop::KeyValuePair(KeyValuePairProp {
         key: *KEY_FRAGMENT_SPREADS,
         value: AST::Identifier(format!("{}{}", fragment_name, *KEY_FRAGMENT_TYPE).intern()),
         read_only: true,
         optional: false,
+        doc_comment: None,
     });
     let parameter_discriminator = Prop::KeyValuePair(KeyValuePairProp {
         key: abstract_fragment_spread_marker,
         value: AST::String,
         read_only: true,
         optional: true,
+        doc_comment: None,
     });
     let return_value_discriminator = Prop::KeyValuePair(KeyValuePairProp {
         key: abstract_fragment_spread_marker,
         value: AST::String,
         read_only: true,
         optional: false,
+        doc_comment: None,
     });
 
     let parameter_type = AST::InexactObject(InexactObject::new(vec![
@@ -952,7 +988,9 @@ fn write_abstract_validator_function(
     ]));
 
     let (open_comment, close_comment) = match language {
-        TypegenLanguage::Flow | TypegenLanguage::JavaScript => ("/*", "*/"),
+        TypegenLanguage::Flow | TypegenLanguage::JavaScript | TypegenLanguage::ReScript => {
+            ("/*", "*/")
+        }
         TypegenLanguage::TypeScript => ("", ""),
     };
 
@@ -973,7 +1011,7 @@ fn write_abstract_validator_function(
     )?;
 
     match language {
-        TypegenLanguage::Flow | TypegenLanguage::JavaScript => {
+        TypegenLanguage::Flow | TypegenLanguage::JavaScript | TypegenLanguage::ReScript => {
             write!(writer, "(value{}:: as ", &open_comment)?;
             writer.write(&AST::Any)?;
             write!(writer, "{}) ", &close_comment)?;
@@ -1016,24 +1054,28 @@ fn write_concrete_validator_function(
         value: AST::String,
         read_only: true,
         optional: false,
+        doc_comment: None,
     });
     let fragment_spread_prop = Prop::KeyValuePair(KeyValuePairProp {
         key: *KEY_FRAGMENT_SPREADS,
         value: AST::Identifier(format!("{}{}", fragment_name, *KEY_FRAGMENT_TYPE).intern()),
         read_only: true,
         optional: false,
+        doc_comment: None,
     });
     let parameter_discriminator = Prop::KeyValuePair(KeyValuePairProp {
         key: *KEY_TYPENAME,
         value: AST::String,
         read_only: true,
         optional: false,
+        doc_comment: None,
     });
     let return_value_discriminator = Prop::KeyValuePair(KeyValuePairProp {
         key: *KEY_TYPENAME,
         value: AST::StringLiteral(StringLiteral(concrete_typename)),
         read_only: true,
         optional: false,
+        doc_comment: None,
     });
 
     let parameter_type = AST::InexactObject(InexactObject::new(vec![
@@ -1052,7 +1094,9 @@ fn write_concrete_validator_function(
 
     let typegen_language = typegen_context.project_config.typegen_config.language;
     let (open_comment, close_comment) = match typegen_language {
-        TypegenLanguage::Flow | TypegenLanguage::JavaScript => ("/*", "*/"),
+        TypegenLanguage::Flow | TypegenLanguage::JavaScript | TypegenLanguage::ReScript => {
+            ("/*", "*/")
+        }
         TypegenLanguage::TypeScript => ("", ""),
     };
 
@@ -1073,7 +1117,7 @@ fn write_concrete_validator_function(
     )?;
 
     match typegen_language {
-        TypegenLanguage::Flow | TypegenLanguage::JavaScript => {
+        TypegenLanguage::Flow | TypegenLanguage::JavaScript | TypegenLanguage::ReScript => {
             write!(writer, "(value{}:: as ", &open_comment)?;
             writer.write(&AST::Any)?;
             write!(writer, "{}) ", &close_comment)?;
@@ -1088,6 +1132,81 @@ fn write_concrete_validator_function(
     Ok(())
 }
 
+/// The type of the `items` of the `@paginatedList` field selected by a
+/// refetchable fragment, e.g. `NonNullable<Foo$data['list']>['items']`, so
+/// that pagination hooks can type the items they load.
+fn get_paginated_list_items_type(
+    typegen_context: &'_ TypegenContext<'_>,
+    fragment_definition: &FragmentDefinition,
+) -> Option<AST> {
+    let schema = typegen_context.schema;
+    let mut path = Vec::new();
+    let list_field = find_paginated_list_field(schema, &fragment_definition.selections, &mut path)?;
+    let items_name = typegen_context
+        .project_config
+        .schema_config
+        .paginated_list_interface
+        .items;
+    let items_alias = list_field.selections.iter().find_map(|selection| {
+        let (definition, alias) = match selection {
+            Selection::LinkedField(field) => (field.definition.item, field.alias_or_name(schema)),
+            Selection::ScalarField(field) => (field.definition.item, field.alias_or_name(schema)),
+            _ => return None,
+        };
+        (schema.field(definition).name.item == items_name).then_some(alias)
+    })?;
+    path.push(items_alias);
+
+    let mut items_type =
+        AST::Identifier(format!("{}$data", fragment_definition.name.item).intern());
+    for (index, property_name) in path.into_iter().enumerate() {
+        if index > 0 {
+            items_type = AST::NonNullable(Box::new(items_type));
+        }
+        items_type = AST::PropertyType {
+            type_: Box::new(items_type),
+            property_name,
+        };
+    }
+    Some(AST::NonNullable(Box::new(items_type)))
+}
+
+/// Finds the first `@paginatedList` field that is reachable through singular
+/// fields only, pushing the names of the fields leading to it onto `path`.
+fn find_paginated_list_field<'a>(
+    schema: &SDLSchema,
+    selections: &'a [Selection],
+    path: &mut Vec<StringKey>,
+) -> Option<&'a LinkedField> {
+    selections.iter().find_map(|selection| match selection {
+        Selection::LinkedField(field) => {
+            if schema.field(field.definition.item).type_.is_list() {
+                return None;
+            }
+            path.push(field.alias_or_name(schema));
+            if field
+                .directives
+                .named(*PAGINATED_LIST_DIRECTIVE_NAME)
+                .is_some()
+            {
+                return Some(field.as_ref());
+            }
+            let list_field = find_paginated_list_field(schema, &field.selections, path);
+            if list_field.is_none() {
+                path.pop();
+            }
+            list_field
+        }
+        Selection::InlineFragment(inline_fragment) => {
+            find_paginated_list_field(schema, &inline_fragment.selections, path)
+        }
+        Selection::Condition(condition) => {
+            find_paginated_list_field(schema, &condition.selections, path)
+        }
+        Selection::FragmentSpread(_) | Selection::ScalarField(_) => None,
+    })
+}
+
 fn is_plural(node: &FragmentDefinition) -> bool {
     RelayDirective::find(&node.directives).is_some_and(|relay_directive| relay_directive.plural)
 }
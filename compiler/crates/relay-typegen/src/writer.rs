@@ -260,6 +260,9 @@ pub struct KeyValuePairProp {
     pub value: AST,
     pub read_only: bool,
     pub optional: bool,
+    /// Documentation of the property, such as the description of the schema
+    /// field it is generated from, printed as a JSDoc comment
+    pub doc_comment: Option<String>,
 }
 
 impl Ord for Prop {
@@ -386,11 +389,18 @@ pub trait Writer: Write {
     ) -> FmtResult;
 
     fn write_any_type_definition(&mut self, name: &str) -> FmtResult;
+
+    /// Writes a doc comment, followed by the indentation of the next line.
+    fn write_doc_comment(&mut self, doc_comment: &str) -> FmtResult;
 }
 
 pub(crate) fn new_writer_from_config(config: &TypegenConfig) -> Box<dyn Writer> {
     match config.language {
-        TypegenLanguage::JavaScript => Box::<JavaScriptPrinter>::default(),
+        // ReScript types are emitted in a separate artifact, next to the
+        // plain JavaScript one.
+        TypegenLanguage::JavaScript | TypegenLanguage::ReScript => {
+            Box::<JavaScriptPrinter>::default()
+        }
         TypegenLanguage::Flow => Box::new(FlowPrinter::new()),
         TypegenLanguage::TypeScript => Box::new(TypeScriptPrinter::new(config)),
     }
@@ -414,12 +424,14 @@ mod tests {
             optional: false,
             read_only: true,
             value: AST::String,
+            doc_comment: None,
         })]);
         let obj_b = InexactObject::new(vec![Prop::KeyValuePair(KeyValuePairProp {
             key: "b".intern(),
             optional: false,
             read_only: true,
             value: AST::String,
+            doc_comment: None,
         })]);
         // This would stack overflow before the fix
         assert_eq!(obj_a.cmp(&obj_b), std::cmp::Ordering::Less);
@@ -436,12 +448,14 @@ mod tests {
                 optional: false,
                 read_only: true,
                 value: AST::StringLiteral(StringLiteral("User".intern())),
+                doc_comment: None,
             }),
             Prop::KeyValuePair(KeyValuePairProp {
                 key: "a".intern(),
                 optional: false,
                 read_only: true,
                 value: AST::String,
+                doc_comment: None,
             }),
         ]);
         let obj_b = InexactObject::new(vec![
@@ -450,12 +464,14 @@ mod tests {
                 optional: false,
                 read_only: true,
                 value: AST::StringLiteral(StringLiteral("User".intern())),
+                doc_comment: None,
             }),
             Prop::KeyValuePair(KeyValuePairProp {
                 key: "b".intern(),
                 optional: false,
                 read_only: true,
                 value: AST::String,
+                doc_comment: None,
             }),
         ]);
         // This would stack overflow before the fix
@@ -0,0 +1,510 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Prints the typegen AST as Zod schemas. Every exported type becomes an
+//! exported `<Name>Schema` constant, so that the schemas mirror the
+//! generated Flow or TypeScript types one to one.
+//!
+//! Types without a runtime representation, such as fragment references, are
+//! printed as `z.custom<T>()`, which accepts any value and (in TypeScript)
+//! infers the generated type. The `"%future added value"` and `"%other"`
+//! placeholders, as well as branded IDs, accept any string.
+//!
+//! In TypeScript, schemas referencing a schema defined further down, such as
+//! recursive input objects, are annotated with the generated type, imported
+//! from the artifact, as their type can't be inferred.
+
+use std::fmt::Result as FmtResult;
+use std::fmt::Write;
+
+use ::intern::string_key::Intern;
+use ::intern::string_key::StringKey;
+use fnv::FnvHashMap;
+use fnv::FnvHashSet;
+use intern::Lookup;
+use itertools::Itertools;
+use relay_config::TypegenConfig;
+use relay_config::TypegenLanguage;
+
+use crate::FUTURE_ENUM_VALUE;
+use crate::KEY_DATA;
+use crate::KEY_FRAGMENT_SPREADS;
+use crate::KEY_FRAGMENT_TYPE;
+use crate::KEY_TYPENAME;
+use crate::typescript::TypeScriptPrinter;
+use crate::writer::AST;
+use crate::writer::Prop;
+use crate::writer::Writer;
+
+pub struct ZodPrinter {
+    language: TypegenLanguage,
+    eager_es_modules: bool,
+    exclude_undefined_from_nullable_union: bool,
+    custom_scalar_schemas: FnvHashMap<StringKey, String>,
+    artifact_name: StringKey,
+    branded_id_type: Option<StringKey>,
+    type_imports: Vec<String>,
+    exports: Vec<(String, AST)>,
+    exported_names: FnvHashSet<StringKey>,
+    defined_names: FnvHashSet<StringKey>,
+    has_lazy_reference: bool,
+    result: String,
+    indentation: usize,
+}
+
+/// Zod schemas are only printed for exported types, so any other output of
+/// the shared typegen pipeline, such as resolver type assertions, is dropped.
+impl Write for ZodPrinter {
+    fn write_str(&mut self, _s: &str) -> FmtResult {
+        Ok(())
+    }
+}
+
+impl Writer for ZodPrinter {
+    fn into_string(mut self: Box<Self>) -> String {
+        self.print().unwrap();
+        self.result
+    }
+
+    fn get_runtime_fragment_import(&self) -> &'static str {
+        "FragmentRefs"
+    }
+
+    fn write(&mut self, _ast: &AST) -> FmtResult {
+        Ok(())
+    }
+
+    fn write_type_assertion(&mut self, _name: &str, _ast: &AST) -> FmtResult {
+        Ok(())
+    }
+
+    fn write_export_type(&mut self, name: &str, ast: &AST) -> FmtResult {
+        self.exports.push((name.to_string(), ast.clone()));
+        Ok(())
+    }
+
+    fn write_import_module_default(&mut self, name: &str, from: &str) -> FmtResult {
+        self.write_type_import(name, from);
+        Ok(())
+    }
+
+    fn write_import_module_named(
+        &mut self,
+        name: &str,
+        import_as: Option<&str>,
+        from: &str,
+    ) -> FmtResult {
+        let import = if let Some(import_as) = import_as {
+            format!("{{ {name} as {import_as} }}")
+        } else {
+            format!("{{ {name} }}")
+        };
+        self.write_type_import(&import, from);
+        Ok(())
+    }
+
+    fn write_import_type(&mut self, types: &[&str], from: &str) -> FmtResult {
+        self.write_type_import(&format!("{{ {} }}", types.iter().format(", ")), from);
+        Ok(())
+    }
+
+    // Fragment types are only referenced from `z.custom()` calls, which are
+    // typed through `FragmentRefs` in TypeScript and untyped otherwise.
+    fn write_import_fragment_type(&mut self, _types: &[&str], _from: &str) -> FmtResult {
+        Ok(())
+    }
+
+    fn write_export_fragment_type(&mut self, _name: &str) -> FmtResult {
+        Ok(())
+    }
+
+    fn write_export_fragment_types(
+        &mut self,
+        _fragment_type_name_1: &str,
+        _fragment_type_name_2: &str,
+    ) -> FmtResult {
+        Ok(())
+    }
+
+    fn write_any_type_definition(&mut self, _name: &str) -> FmtResult {
+        Ok(())
+    }
+
+    fn write_doc_comment(&mut self, _doc_comment: &str) -> FmtResult {
+        Ok(())
+    }
+}
+
+impl ZodPrinter {
+    pub fn new(config: &TypegenConfig, artifact_name: StringKey) -> Self {
+        Self {
+            language: config.language,
+            eager_es_modules: config.eager_es_modules,
+            exclude_undefined_from_nullable_union: config
+                .typescript_exclude_undefined_from_nullable_union,
+            custom_scalar_schemas: config
+                .zod
+                .iter()
+                .flat_map(|zod_config| zod_config.custom_scalar_schemas.iter())
+                .map(|(scalar_name, schema)| (scalar_name.0, schema.clone()))
+                .collect(),
+            artifact_name,
+            branded_id_type: config
+                .branded_id_type
+                .as_ref()
+                .map(|branded_id_type| branded_id_type.name),
+            type_imports: Default::default(),
+            exports: Default::default(),
+            exported_names: Default::default(),
+            defined_names: Default::default(),
+            has_lazy_reference: false,
+            result: String::new(),
+            indentation: 0,
+        }
+    }
+
+    fn is_es_module(&self) -> bool {
+        self.language == TypegenLanguage::TypeScript || self.eager_es_modules
+    }
+
+    /// Type imports are only needed to parameterize `z.custom<T>()` calls,
+    /// which is only possible in TypeScript.
+    fn write_type_import(&mut self, import: &str, from: &str) {
+        if self.language == TypegenLanguage::TypeScript {
+            let from_without_extension = from.strip_suffix(".ts").unwrap_or(from);
+            self.type_imports.push(format!(
+                "import type {import} from \"{from_without_extension}\";"
+            ));
+        }
+    }
+
+    fn print(&mut self) -> FmtResult {
+        let exports = std::mem::take(&mut self.exports);
+        self.exported_names = exports
+            .iter()
+            .map(|(name, _)| name.as_str().intern())
+            .collect();
+
+        let mut schemas = Vec::with_capacity(exports.len());
+        let mut annotated_names = Vec::new();
+        for (name, ast) in &exports {
+            self.has_lazy_reference = false;
+            self.write_schema(ast)?;
+            let is_annotated =
+                self.has_lazy_reference && self.language == TypegenLanguage::TypeScript;
+            if is_annotated {
+                annotated_names.push(name.as_str());
+            }
+            schemas.push((name, std::mem::take(&mut self.result), is_annotated));
+            self.defined_names.insert(name.as_str().intern());
+        }
+
+        if self.is_es_module() {
+            writeln!(&mut self.result, "import {{ z }} from \"zod\";")?;
+        } else {
+            writeln!(&mut self.result, "const {{ z }} = require(\"zod\");")?;
+        }
+        for type_import in std::mem::take(&mut self.type_imports) {
+            writeln!(&mut self.result, "{type_import}")?;
+        }
+        if !annotated_names.is_empty() {
+            writeln!(
+                &mut self.result,
+                "import type {{ {} }} from \"./{}.graphql\";",
+                annotated_names.iter().format(", "),
+                self.artifact_name
+            )?;
+        }
+        writeln!(&mut self.result)?;
+
+        for (name, schema, is_annotated) in schemas {
+            if self.is_es_module() {
+                write!(&mut self.result, "export ")?;
+            }
+            if is_annotated {
+                write!(&mut self.result, "const {name}Schema: z.ZodType<{name}> = ")?;
+            } else {
+                write!(&mut self.result, "const {name}Schema = ")?;
+            }
+            writeln!(&mut self.result, "{schema};")?;
+        }
+
+        if !self.is_es_module() && !exports.is_empty() {
+            writeln!(&mut self.result, "\nmodule.exports = {{")?;
+            for (name, _) in &exports {
+                writeln!(&mut self.result, "  {name}Schema,")?;
+            }
+            writeln!(&mut self.result, "}};")?;
+        }
+        Ok(())
+    }
+
+    fn write_indentation(&mut self) -> FmtResult {
+        self.result.write_str(&"  ".repeat(self.indentation))
+    }
+
+    fn write_schema(&mut self, ast: &AST) -> FmtResult {
+        match ast {
+            AST::Any => write!(&mut self.result, "z.any()"),
+            AST::Mixed => write!(&mut self.result, "z.unknown()"),
+            AST::Empty => write!(&mut self.result, "z.never()"),
+            AST::String => write!(&mut self.result, "z.string()"),
+            AST::Number => write!(&mut self.result, "z.number()"),
+            AST::Boolean => write!(&mut self.result, "z.boolean()"),
+            AST::StringLiteral(literal) => {
+                if **literal == *FUTURE_ENUM_VALUE {
+                    self.write_any_string(ast)
+                } else {
+                    write!(&mut self.result, "z.literal(\"{}\")", **literal)
+                }
+            }
+            AST::OtherTypename => self.write_any_string(ast),
+            AST::Identifier(identifier) => self.write_identifier(*identifier, ast),
+            AST::Union(members) => self.write_union(members),
+            AST::ReadOnlyArray(of_type) => {
+                write!(&mut self.result, "z.array(")?;
+                self.write_schema(of_type)?;
+                write!(&mut self.result, ")")
+            }
+            AST::Nullable(of_type) => {
+                self.write_schema(of_type)?;
+                let nullable_method = self.nullable_method();
+                write!(&mut self.result, ".{nullable_method}()")
+            }
+            AST::NonNullable(of_type) => match &**of_type {
+                AST::Nullable(inner) => self.write_schema(inner),
+                _ => self.write_opaque(ast),
+            },
+            AST::GenericType { outer, .. } if Some(*outer) == self.branded_id_type => {
+                self.write_any_string(ast)
+            }
+            AST::ExactObject(object) => self.write_object(object, false),
+            AST::InexactObject(object) => self.write_object(object, true),
+            AST::RawType(_)
+            | AST::Local3DPayload(_, _)
+            | AST::Callable(_)
+            | AST::FragmentReference(_)
+            | AST::FragmentReferenceType(_)
+            | AST::ReturnTypeOfFunctionWithName(_)
+            | AST::ReturnTypeOfMethodCall(_, _)
+            | AST::AssertFunctionType(_)
+            | AST::GenericType { .. }
+            | AST::PropertyType { .. } => self.write_opaque(ast),
+        }
+    }
+
+    fn nullable_method(&self) -> &'static str {
+        if self.language == TypegenLanguage::TypeScript
+            && self.exclude_undefined_from_nullable_union
+        {
+            "nullable"
+        } else {
+            "nullish"
+        }
+    }
+
+    fn write_identifier(&mut self, identifier: StringKey, ast: &AST) -> FmtResult {
+        if let Some(schema) = self.custom_scalar_schemas.get(&identifier) {
+            write!(&mut self.result, "{schema}")
+        } else if self.defined_names.contains(&identifier) {
+            write!(&mut self.result, "{identifier}Schema")
+        } else if self.exported_names.contains(&identifier) {
+            // Input objects may be recursive or defined further down.
+            self.has_lazy_reference = true;
+            write!(&mut self.result, "z.lazy(() => {identifier}Schema)")
+        } else {
+            self.write_opaque(ast)
+        }
+    }
+
+    /// Accepts any value. TypeScript infers the generated type.
+    fn write_opaque(&mut self, ast: &AST) -> FmtResult {
+        write!(&mut self.result, "z.custom")?;
+        self.write_type_argument(ast)?;
+        write!(&mut self.result, "()")
+    }
+
+    /// Accepts any string. Used for branded IDs and for the placeholders of
+    /// enum values and typenames that are unknown at build time.
+    fn write_any_string(&mut self, ast: &AST) -> FmtResult {
+        write!(&mut self.result, "z.custom")?;
+        self.write_type_argument(ast)?;
+        write!(&mut self.result, "((value) => typeof value === \"string\")")
+    }
+
+    fn write_type_argument(&mut self, ast: &AST) -> FmtResult {
+        if self.language == TypegenLanguage::TypeScript {
+            let mut printer = Box::new(TypeScriptPrinter::new(&TypegenConfig {
+                typescript_exclude_undefined_from_nullable_union: self
+                    .exclude_undefined_from_nullable_union,
+                ..Default::default()
+            }));
+            printer.write(ast)?;
+            write!(&mut self.result, "<{}>", printer.into_string())?;
+        }
+        Ok(())
+    }
+
+    fn write_union(&mut self, members: &[AST]) -> FmtResult {
+        if let [member] = members {
+            return self.write_schema(member);
+        }
+
+        if members
+            .iter()
+            .all(|member| matches!(member, AST::StringLiteral(_)))
+        {
+            return self.write_enum(members);
+        }
+
+        let (concrete_members, other_members): (Vec<_>, Vec<_>) =
+            members.iter().partition(|member| match member {
+                AST::ExactObject(object) => object.typename_literal().is_some(),
+                AST::InexactObject(object) => object.typename_literal().is_some(),
+                _ => false,
+            });
+        if concrete_members.len() < 2 {
+            return self.write_union_members("z.union(", members.iter());
+        }
+        if other_members.is_empty() {
+            return self.write_discriminated_union(&concrete_members);
+        }
+
+        // Objects whose typename is unknown at build time are matched after
+        // the discriminated concrete types.
+        writeln!(&mut self.result, "z.union([")?;
+        self.indentation += 1;
+        self.write_indentation()?;
+        self.write_discriminated_union(&concrete_members)?;
+        writeln!(&mut self.result, ",")?;
+        for member in other_members {
+            self.write_indentation()?;
+            self.write_schema(member)?;
+            writeln!(&mut self.result, ",")?;
+        }
+        self.indentation -= 1;
+        self.write_indentation()?;
+        write!(&mut self.result, "])")
+    }
+
+    fn write_discriminated_union(&mut self, members: &[&AST]) -> FmtResult {
+        let discriminated_union = format!("z.discriminatedUnion(\"{}\", ", *KEY_TYPENAME);
+        self.write_union_members(&discriminated_union, members.iter().copied())
+    }
+
+    fn write_union_members<'a>(
+        &mut self,
+        opening: &str,
+        members: impl Iterator<Item = &'a AST>,
+    ) -> FmtResult {
+        writeln!(&mut self.result, "{opening}[")?;
+        self.indentation += 1;
+        for member in members {
+            self.write_indentation()?;
+            self.write_schema(member)?;
+            writeln!(&mut self.result, ",")?;
+        }
+        self.indentation -= 1;
+        self.write_indentation()?;
+        write!(&mut self.result, "])")
+    }
+
+    fn write_enum(&mut self, members: &[AST]) -> FmtResult {
+        let (future_values, values): (Vec<_>, Vec<_>) =
+            members.iter().partition(|member| match member {
+                AST::StringLiteral(literal) => **literal == *FUTURE_ENUM_VALUE,
+                _ => false,
+            });
+        if values.is_empty() {
+            return self.write_any_string(members.first().unwrap());
+        }
+        write!(
+            &mut self.result,
+            "z.enum([{}])",
+            values
+                .iter()
+                .map(|value| match value {
+                    AST::StringLiteral(literal) => format!("\"{}\"", **literal),
+                    _ => unreachable!(),
+                })
+                .join(", ")
+        )?;
+        if let Some(future_value) = future_values.first() {
+            write!(&mut self.result, ".or(")?;
+            self.write_any_string(future_value)?;
+            write!(&mut self.result, ")")?;
+        }
+        Ok(())
+    }
+
+    fn write_object(&mut self, props: &[Prop], is_inexact: bool) -> FmtResult {
+        let key_value_props = props
+            .iter()
+            .filter(|prop| !matches!(prop, Prop::Spread(_)))
+            .collect_vec();
+        if key_value_props.is_empty() {
+            write!(&mut self.result, "z.object({{}})")?;
+        } else {
+            writeln!(&mut self.result, "z.object({{")?;
+            self.indentation += 1;
+            for prop in key_value_props {
+                self.write_indentation()?;
+                match prop {
+                    Prop::KeyValuePair(key_value_pair) => {
+                        self.write_key(key_value_pair.key)?;
+                        self.write_schema(&key_value_pair.value)?;
+                        let is_nullish = matches!(key_value_pair.value, AST::Nullable(_))
+                            && self.nullable_method() == "nullish";
+                        if key_value_pair.optional && !is_nullish {
+                            write!(&mut self.result, ".optional()")?;
+                        }
+                    }
+                    Prop::GetterSetterPair(getter_setter_pair) => {
+                        self.write_key(getter_setter_pair.key)?;
+                        self.write_schema(&getter_setter_pair.getter_return_value)?;
+                    }
+                    Prop::Spread(_) => unreachable!(),
+                }
+                writeln!(&mut self.result, ",")?;
+            }
+            self.indentation -= 1;
+            self.write_indentation()?;
+            write!(&mut self.result, "}})")?;
+        }
+        if is_inexact {
+            write!(&mut self.result, ".passthrough()")?;
+        }
+        for prop in props {
+            if let Prop::Spread(spread) = prop {
+                write!(&mut self.result, ".and(")?;
+                self.write_identifier(spread.value, &AST::Identifier(spread.value))?;
+                write!(&mut self.result, ")")?;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_key(&mut self, key: StringKey) -> FmtResult {
+        let is_fragment_key =
+            key == *KEY_FRAGMENT_SPREADS || key == *KEY_FRAGMENT_TYPE || key == *KEY_DATA;
+        if is_fragment_key && self.language == TypegenLanguage::TypeScript {
+            write!(&mut self.result, "\" {key}\": ")
+        } else if is_identifier(key.lookup()) {
+            write!(&mut self.result, "{key}: ")
+        } else {
+            write!(&mut self.result, "\"{key}\": ")
+        }
+    }
+}
+
+fn is_identifier(key: &str) -> bool {
+    let mut chars = key.chars();
+    chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_' || first == '$')
+        && chars.all(|char| char.is_ascii_alphanumeric() || char == '_' || char == '$')
+}
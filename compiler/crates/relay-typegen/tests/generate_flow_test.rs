@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<902ba7d30b889ac8e52b036c2f2bef08>>
+ * @generated SignedSource<<91109fea1e2dde96d2e88181b9fcfdbf>>
  */
 
 mod generate_flow;
@@ -61,6 +61,13 @@ async fn aliased_inline_fragment_spread_without_type_condition_query_root() {
     test_fixture(transform_fixture, file!(), "aliased-inline-fragment-spread-without-type-condition-query-root.graphql", "generate_flow/fixtures/aliased-inline-fragment-spread-without-type-condition-query-root.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn branded_ids() {
+    let input = include_str!("generate_flow/fixtures/branded-ids.graphql");
+    let expected = include_str!("generate_flow/fixtures/branded-ids.expected");
+    test_fixture(transform_fixture, file!(), "branded-ids.graphql", "generate_flow/fixtures/branded-ids.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn catch_no_arg_nested() {
     let input = include_str!("generate_flow/fixtures/catch-no-arg-nested.graphql");
@@ -222,6 +229,13 @@ async fn default_input() {
     test_fixture(transform_fixture, file!(), "default-input.graphql", "generate_flow/fixtures/default-input.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn doc_comments() {
+    let input = include_str!("generate_flow/fixtures/doc-comments.graphql");
+    let expected = include_str!("generate_flow/fixtures/doc-comments.expected");
+    test_fixture(transform_fixture, file!(), "doc-comments.graphql", "generate_flow/fixtures/doc-comments.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn fragment_spread() {
     let input = include_str!("generate_flow/fixtures/fragment-spread.graphql");
@@ -509,6 +523,13 @@ async fn refetchable_fragment() {
     test_fixture(transform_fixture, file!(), "refetchable-fragment.graphql", "generate_flow/fixtures/refetchable-fragment.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn refetchable_fragment_with_paginated_list() {
+    let input = include_str!("generate_flow/fixtures/refetchable-fragment-with-paginated-list.graphql");
+    let expected = include_str!("generate_flow/fixtures/refetchable-fragment-with-paginated-list.expected");
+    test_fixture(transform_fixture, file!(), "refetchable-fragment-with-paginated-list.graphql", "generate_flow/fixtures/refetchable-fragment-with-paginated-list.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn regular_query_with_assignable_fragment_spread() {
     let input = include_str!("generate_flow/fixtures/regular-query-with-assignable-fragment-spread.graphql");
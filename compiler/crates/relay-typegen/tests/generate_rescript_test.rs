@@ -0,0 +1,62 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<07dc9619b2333d01d663c24670c05a5e>>
+ */
+
+mod generate_rescript;
+
+use generate_rescript::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[tokio::test]
+async fn abstract_type() {
+    let input = include_str!("generate_rescript/fixtures/abstract-type.graphql");
+    let expected = include_str!("generate_rescript/fixtures/abstract-type.expected");
+    test_fixture(transform_fixture, file!(), "abstract-type.graphql", "generate_rescript/fixtures/abstract-type.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn custom_scalars() {
+    let input = include_str!("generate_rescript/fixtures/custom-scalars.graphql");
+    let expected = include_str!("generate_rescript/fixtures/custom-scalars.expected");
+    test_fixture(transform_fixture, file!(), "custom-scalars.graphql", "generate_rescript/fixtures/custom-scalars.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn fragment_references() {
+    let input = include_str!("generate_rescript/fixtures/fragment-references.graphql");
+    let expected = include_str!("generate_rescript/fixtures/fragment-references.expected");
+    test_fixture(transform_fixture, file!(), "fragment-references.graphql", "generate_rescript/fixtures/fragment-references.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn one_of_input() {
+    let input = include_str!("generate_rescript/fixtures/one-of-input.graphql");
+    let expected = include_str!("generate_rescript/fixtures/one-of-input.expected");
+    test_fixture(transform_fixture, file!(), "one-of-input.graphql", "generate_rescript/fixtures/one-of-input.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn query_with_variables_and_enums() {
+    let input = include_str!("generate_rescript/fixtures/query-with-variables-and-enums.graphql");
+    let expected = include_str!("generate_rescript/fixtures/query-with-variables-and-enums.expected");
+    test_fixture(transform_fixture, file!(), "query-with-variables-and-enums.graphql", "generate_rescript/fixtures/query-with-variables-and-enums.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn raw_response_type() {
+    let input = include_str!("generate_rescript/fixtures/raw-response-type.graphql");
+    let expected = include_str!("generate_rescript/fixtures/raw-response-type.expected");
+    test_fixture(transform_fixture, file!(), "raw-response-type.graphql", "generate_rescript/fixtures/raw-response-type.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn reserved_field_names() {
+    let input = include_str!("generate_rescript/fixtures/reserved-field-names.graphql");
+    let expected = include_str!("generate_rescript/fixtures/reserved-field-names.expected");
+    test_fixture(transform_fixture, file!(), "reserved-field-names.graphql", "generate_rescript/fixtures/reserved-field-names.expected", input, expected).await;
+}
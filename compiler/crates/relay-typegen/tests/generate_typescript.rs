@@ -106,6 +106,16 @@ pub async fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String>
                 .content
                 .contains("# typegen_config.use_import_type_syntax = true"),
             custom_error_type,
+            branded_id_type: fixture
+                .content
+                .contains("# typegen_config.branded_id_type = true")
+                .then(|| CustomTypeImport {
+                    name: "ID".intern(),
+                    path: "BrandedID".into(),
+                }),
+            emit_doc_comments: fixture
+                .content
+                .contains("# typegen_config.emit_doc_comments = true"),
             ..Default::default()
         },
         feature_flags: Arc::new(FeatureFlags {
@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<0cae3d9ae2292e1fcd67286661d1d350>>
+ * @generated SignedSource<<1410d9d1a3f148e4fe5a699b4e36b0cf>>
  */
 
 mod generate_typescript;
@@ -61,6 +61,13 @@ async fn aliased_inline_fragment_spread_without_type_condition_query_root() {
     test_fixture(transform_fixture, file!(), "aliased-inline-fragment-spread-without-type-condition-query-root.graphql", "generate_typescript/fixtures/aliased-inline-fragment-spread-without-type-condition-query-root.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn branded_ids() {
+    let input = include_str!("generate_typescript/fixtures/branded-ids.graphql");
+    let expected = include_str!("generate_typescript/fixtures/branded-ids.expected");
+    test_fixture(transform_fixture, file!(), "branded-ids.graphql", "generate_typescript/fixtures/branded-ids.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn catch_no_arg_nested() {
     let input = include_str!("generate_typescript/fixtures/catch-no-arg-nested.graphql");
@@ -152,6 +159,13 @@ async fn default_input() {
     test_fixture(transform_fixture, file!(), "default-input.graphql", "generate_typescript/fixtures/default-input.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn doc_comments() {
+    let input = include_str!("generate_typescript/fixtures/doc-comments.graphql");
+    let expected = include_str!("generate_typescript/fixtures/doc-comments.expected");
+    test_fixture(transform_fixture, file!(), "doc-comments.graphql", "generate_typescript/fixtures/doc-comments.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn fragment_spread() {
     let input = include_str!("generate_typescript/fixtures/fragment-spread.graphql");
@@ -390,6 +404,13 @@ async fn refetchable_fragment() {
     test_fixture(transform_fixture, file!(), "refetchable-fragment.graphql", "generate_typescript/fixtures/refetchable-fragment.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn refetchable_fragment_with_paginated_list() {
+    let input = include_str!("generate_typescript/fixtures/refetchable-fragment-with-paginated-list.graphql");
+    let expected = include_str!("generate_typescript/fixtures/refetchable-fragment-with-paginated-list.expected");
+    test_fixture(transform_fixture, file!(), "refetchable-fragment-with-paginated-list.graphql", "generate_typescript/fixtures/refetchable-fragment-with-paginated-list.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn regular_query_with_assignable_fragment_spread() {
     let input = include_str!("generate_typescript/fixtures/regular-query-with-assignable-fragment-spread.graphql");
@@ -0,0 +1,76 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<2b9cd0ad526086897ed096b20a121c32>>
+ */
+
+mod generate_zod;
+
+use generate_zod::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[tokio::test]
+async fn abstract_type() {
+    let input = include_str!("generate_zod/fixtures/abstract-type.graphql");
+    let expected = include_str!("generate_zod/fixtures/abstract-type.expected");
+    test_fixture(transform_fixture, file!(), "abstract-type.graphql", "generate_zod/fixtures/abstract-type.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn branded_ids() {
+    let input = include_str!("generate_zod/fixtures/branded-ids.graphql");
+    let expected = include_str!("generate_zod/fixtures/branded-ids.expected");
+    test_fixture(transform_fixture, file!(), "branded-ids.graphql", "generate_zod/fixtures/branded-ids.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn custom_scalars() {
+    let input = include_str!("generate_zod/fixtures/custom-scalars.graphql");
+    let expected = include_str!("generate_zod/fixtures/custom-scalars.expected");
+    test_fixture(transform_fixture, file!(), "custom-scalars.graphql", "generate_zod/fixtures/custom-scalars.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn flow() {
+    let input = include_str!("generate_zod/fixtures/flow.graphql");
+    let expected = include_str!("generate_zod/fixtures/flow.expected");
+    test_fixture(transform_fixture, file!(), "flow.graphql", "generate_zod/fixtures/flow.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn fragment_references() {
+    let input = include_str!("generate_zod/fixtures/fragment-references.graphql");
+    let expected = include_str!("generate_zod/fixtures/fragment-references.expected");
+    test_fixture(transform_fixture, file!(), "fragment-references.graphql", "generate_zod/fixtures/fragment-references.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn no_future_proof_enums() {
+    let input = include_str!("generate_zod/fixtures/no-future-proof-enums.graphql");
+    let expected = include_str!("generate_zod/fixtures/no-future-proof-enums.expected");
+    test_fixture(transform_fixture, file!(), "no-future-proof-enums.graphql", "generate_zod/fixtures/no-future-proof-enums.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn query_with_variables_and_enums() {
+    let input = include_str!("generate_zod/fixtures/query-with-variables-and-enums.graphql");
+    let expected = include_str!("generate_zod/fixtures/query-with-variables-and-enums.expected");
+    test_fixture(transform_fixture, file!(), "query-with-variables-and-enums.graphql", "generate_zod/fixtures/query-with-variables-and-enums.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn raw_response_type() {
+    let input = include_str!("generate_zod/fixtures/raw-response-type.graphql");
+    let expected = include_str!("generate_zod/fixtures/raw-response-type.expected");
+    test_fixture(transform_fixture, file!(), "raw-response-type.graphql", "generate_zod/fixtures/raw-response-type.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn recursive_input_objects() {
+    let input = include_str!("generate_zod/fixtures/recursive-input-objects.graphql");
+    let expected = include_str!("generate_zod/fixtures/recursive-input-objects.expected");
+    test_fixture(transform_fixture, file!(), "recursive-input-objects.graphql", "generate_zod/fixtures/recursive-input-objects.expected", input, expected).await;
+}
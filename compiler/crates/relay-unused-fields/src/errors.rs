@@ -0,0 +1,30 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use intern::string_key::StringKey;
+use thiserror::Error;
+
+#[derive(
+    Clone,
+    Debug,
+    Error,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    serde::Serialize
+)]
+pub enum UnusedFieldsMessage {
+    #[error(
+        "The field `{field_path}` is selected by fragment `{fragment_name}` but never read by this module. Consider removing it to avoid over-fetching."
+    )]
+    UnusedField {
+        fragment_name: StringKey,
+        field_path: String,
+    },
+}
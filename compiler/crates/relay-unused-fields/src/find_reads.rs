@@ -0,0 +1,497 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::ops::Range;
+
+use ::intern::string_key::Intern;
+use ::intern::string_key::StringKey;
+use indexmap::IndexMap;
+use rustc_hash::FxBuildHasher;
+use rustc_hash::FxHashMap;
+use rustc_hash::FxHashSet;
+
+use crate::tokenize::Token;
+use crate::tokenize::TokenKind;
+
+const USE_FRAGMENT: &str = "useFragment";
+const GRAPHQL_TAG: &str = "graphql";
+
+/// The properties read from fragment data, keyed by response key.
+#[derive(Debug, Default)]
+pub struct ReadTree {
+    /// Whether the value escapes, so that everything below it may be read.
+    pub fully_read: bool,
+    pub children: FxHashMap<StringKey, ReadTree>,
+}
+
+impl ReadTree {
+    fn insert(&mut self, path: &[StringKey], fully_read: bool) {
+        let mut node = self;
+        for key in path {
+            node = node.children.entry(*key).or_default();
+        }
+        node.fully_read |= fully_read;
+    }
+}
+
+/// Returns the properties read from the data of each fragment passed inline to
+/// `useFragment` and assigned to a variable, keyed by the range of the
+/// fragment's template literal.
+pub fn find_reads(tokens: &[Token<'_>]) -> IndexMap<Range<u32>, ReadTree, FxBuildHasher> {
+    let declarators = find_declarators(tokens);
+    let mut collector = ReadCollector::default();
+    for declarator in &declarators {
+        if let Init::UseFragment(fragment) = &declarator.init {
+            collector.fragments.entry(fragment.clone()).or_default();
+        }
+    }
+    // Variables aliasing fragment data are only known once the variable they
+    // alias is, which may be declared later in the module, so repeat until no
+    // more are found.
+    loop {
+        let binding_count = collector.binding_count();
+        for declarator in &declarators {
+            match &declarator.init {
+                Init::UseFragment(fragment) => collector.bind_pattern(
+                    &declarator.pattern,
+                    Binding {
+                        fragment: fragment.clone(),
+                        path: Vec::new(),
+                    },
+                ),
+                Init::Alias { name, path, .. } => {
+                    for binding in collector.resolve(*name, path) {
+                        collector.bind_pattern(&declarator.pattern, binding);
+                    }
+                }
+                Init::Other => {}
+            }
+        }
+        if collector.binding_count() == binding_count {
+            break;
+        }
+    }
+
+    // Declared variables and property keys of patterns are not reads, and
+    // neither are the initializers of declarations aliasing fragment data.
+    let mut declarations = FxHashSet::default();
+    for declarator in &declarators {
+        declarator.pattern.collect_declarations(&mut declarations);
+        if let Init::Alias { name, tokens, .. } = &declarator.init
+            && collector.bindings.contains_key(name)
+        {
+            declarations.extend(tokens.clone());
+        }
+    }
+    let mut index = 0;
+    while index < tokens.len() {
+        let token = &tokens[index];
+        index += 1;
+        if token.kind != TokenKind::Identifier
+            || declarations.contains(&(index - 1))
+            || index > 1 && is_property_access(&tokens[index - 2])
+        {
+            continue;
+        }
+        let name = token.text.intern();
+        if !collector.bindings.contains_key(&name) {
+            continue;
+        }
+        let (path, end) = member_chain(tokens, index);
+        index = end;
+        // Any use of a variable holding fragment data other than reading a
+        // property, such as passing it to a function, may read all of it.
+        collector.read_all(name, &path);
+    }
+
+    collector.fragments
+}
+
+/// A variable holding (part of) the data of a fragment.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+struct Binding {
+    fragment: Range<u32>,
+    path: Vec<StringKey>,
+}
+
+#[derive(Default)]
+struct ReadCollector {
+    fragments: IndexMap<Range<u32>, ReadTree, FxBuildHasher>,
+    /// Variables are tracked by name only: a shadowing variable with the same
+    /// name can only add reads.
+    bindings: FxHashMap<StringKey, FxHashSet<Binding>>,
+}
+
+impl ReadCollector {
+    fn binding_count(&self) -> usize {
+        self.bindings.values().map(|bindings| bindings.len()).sum()
+    }
+
+    fn read(&mut self, binding: &Binding, path: &[StringKey], fully_read: bool) {
+        let reads = self.fragments.entry(binding.fragment.clone()).or_default();
+        reads.insert(&[binding.path.as_slice(), path].concat(), fully_read);
+    }
+
+    fn read_all(&mut self, name: StringKey, path: &[StringKey]) {
+        if let Some(bindings) = self.bindings.get(&name) {
+            for binding in bindings.clone() {
+                self.read(&binding, path, true);
+            }
+        }
+    }
+
+    /// Binds the variables declared by `pattern` to the value of `binding`.
+    fn bind_pattern(&mut self, pattern: &Pattern, binding: Binding) {
+        match pattern {
+            Pattern::Identifier { name, .. } => {
+                self.bindings.entry(*name).or_default().insert(binding);
+            }
+            Pattern::Object(properties) => {
+                for property in properties {
+                    match property {
+                        PatternProperty::Key { key, value, .. } => {
+                            let mut path = binding.path.clone();
+                            path.push(*key);
+                            self.bind_pattern(
+                                value,
+                                Binding {
+                                    fragment: binding.fragment.clone(),
+                                    path,
+                                },
+                            );
+                        }
+                        PatternProperty::Other => self.read(&binding, &[], true),
+                    }
+                }
+            }
+            Pattern::Other => self.read(&binding, &[], true),
+        }
+    }
+
+    /// The bindings of the value of `path` read from the variable `name`.
+    fn resolve(&self, name: StringKey, path: &[StringKey]) -> Vec<Binding> {
+        self.bindings
+            .get(&name)
+            .into_iter()
+            .flatten()
+            .map(|binding| Binding {
+                fragment: binding.fragment.clone(),
+                path: [binding.path.as_slice(), path].concat(),
+            })
+            .collect()
+    }
+}
+
+/// A variable declarator, such as `{a, b: {c}} = useFragment(...)`.
+struct Declarator {
+    pattern: Pattern,
+    init: Init,
+}
+
+enum Init {
+    /// The range of the `graphql` template literal passed to `useFragment`
+    UseFragment(Range<u32>),
+    /// A chain of non-computed property accesses, such as `data.user`
+    Alias {
+        name: StringKey,
+        path: Vec<StringKey>,
+        tokens: Range<usize>,
+    },
+    Other,
+}
+
+enum Pattern {
+    Identifier {
+        name: StringKey,
+        token: usize,
+    },
+    Object(Vec<PatternProperty>),
+    /// Array patterns, and patterns that couldn't be parsed
+    Other,
+}
+
+enum PatternProperty {
+    Key {
+        key: StringKey,
+        key_token: usize,
+        value: Pattern,
+    },
+    /// Rest elements and computed keys
+    Other,
+}
+
+impl Pattern {
+    fn collect_declarations(&self, declarations: &mut FxHashSet<usize>) {
+        match self {
+            Pattern::Identifier { token, .. } => {
+                declarations.insert(*token);
+            }
+            Pattern::Object(properties) => {
+                for property in properties {
+                    if let PatternProperty::Key {
+                        key_token, value, ..
+                    } = property
+                    {
+                        declarations.insert(*key_token);
+                        value.collect_declarations(declarations);
+                    }
+                }
+            }
+            Pattern::Other => {}
+        }
+    }
+}
+
+fn is_property_access(token: &Token<'_>) -> bool {
+    token.is(".") || token.is("?.")
+}
+
+/// The property path of a chain of non-computed property accesses starting
+/// at `index`, such as `.user.name`, and the index of the token after it.
+fn member_chain(tokens: &[Token<'_>], mut index: usize) -> (Vec<StringKey>, usize) {
+    let mut path = Vec::new();
+    while let [access, property, ..] = &tokens[index..]
+        && is_property_access(access)
+        && property.kind == TokenKind::Identifier
+    {
+        path.push(property.text.intern());
+        index += 2;
+    }
+    (path, index)
+}
+
+/// Finds the declarators of `const`, `let` and `var` declarations.
+fn find_declarators(tokens: &[Token<'_>]) -> Vec<Declarator> {
+    let mut declarators = Vec::new();
+    for (index, token) in tokens.iter().enumerate() {
+        if !(token.is("const") || token.is("let") || token.is("var"))
+            || index > 0 && is_property_access(&tokens[index - 1])
+        {
+            continue;
+        }
+        let mut index = index + 1;
+        while let Some((pattern, end)) = parse_pattern(tokens, index) {
+            index = skip_type_annotation(tokens, end);
+            if !tokens.get(index).is_some_and(|token| token.is("=")) {
+                break;
+            }
+            let init = parse_init(tokens, index + 1);
+            declarators.push(Declarator { pattern, init });
+            // Further declarators of the same declaration
+            match find_next_declarator(tokens, index + 1) {
+                Some(next) => index = next,
+                None => break,
+            }
+        }
+    }
+    declarators
+}
+
+/// Parses a binding pattern starting at `index`, returning it and the index of
+/// the token after it.
+fn parse_pattern(tokens: &[Token<'_>], index: usize) -> Option<(Pattern, usize)> {
+    let token = tokens.get(index)?;
+    if token.kind == TokenKind::Identifier {
+        return Some((
+            Pattern::Identifier {
+                name: token.text.intern(),
+                token: index,
+            },
+            index + 1,
+        ));
+    }
+    if token.is("[") {
+        return Some((Pattern::Other, skip_balanced(tokens, index)?));
+    }
+    if !token.is("{") {
+        return None;
+    }
+    let mut properties = Vec::new();
+    let mut index = index + 1;
+    loop {
+        let token = tokens.get(index)?;
+        if token.is("}") {
+            return Some((Pattern::Object(properties), index + 1));
+        }
+        let key_token = index;
+        let key = match token.kind {
+            TokenKind::Identifier => Some(token.text.intern()),
+            TokenKind::Literal if token.text.starts_with(['"', '\'']) => {
+                Some(token.text[1..token.text.len() - 1].intern())
+            }
+            _ => None,
+        };
+        match key {
+            Some(key) => {
+                index += 1;
+                let value = if tokens.get(index)?.is(":") {
+                    let (value, end) = parse_pattern(tokens, index + 1)?;
+                    index = end;
+                    value
+                } else if token.kind == TokenKind::Identifier {
+                    // Shorthand `{key}`
+                    Pattern::Identifier {
+                        name: key,
+                        token: key_token,
+                    }
+                } else {
+                    return None;
+                };
+                properties.push(PatternProperty::Key {
+                    key,
+                    key_token,
+                    value,
+                });
+            }
+            None => {
+                // `...rest` or `[computed]: value`
+                properties.push(PatternProperty::Other);
+                if token.is("[") {
+                    index = skip_balanced(tokens, index)?;
+                }
+                while !tokens.get(index)?.is(",") && !tokens.get(index)?.is("}") {
+                    if tokens[index].is("{") || tokens[index].is("[") {
+                        index = skip_balanced(tokens, index)?;
+                    } else {
+                        index += 1;
+                    }
+                }
+            }
+        }
+        // A default value is an expression, whose reads are counted
+        while !tokens.get(index)?.is(",") && !tokens.get(index)?.is("}") {
+            if ["{", "[", "("].iter().any(|open| tokens[index].is(open)) {
+                index = skip_balanced(tokens, index)?;
+            } else {
+                index += 1;
+            }
+        }
+        if tokens[index].is(",") {
+            index += 1;
+        }
+    }
+}
+
+/// Skips a Flow or TypeScript type annotation of a declared variable.
+fn skip_type_annotation(tokens: &[Token<'_>], mut index: usize) -> usize {
+    if !tokens.get(index).is_some_and(|token| token.is(":")) {
+        return index;
+    }
+    let mut depth = 0;
+    while let Some(token) = tokens.get(index) {
+        if depth == 0 && (token.is("=") || token.is(";") || token.is(",")) {
+            break;
+        }
+        if token.is("{") || token.is("[") || token.is("(") || token.is("<") {
+            depth += 1;
+        } else if token.is("}") || token.is("]") || token.is(")") || token.is(">") {
+            depth -= 1;
+        } else if token.is(">>") {
+            depth -= 2;
+        }
+        index += 1;
+    }
+    index
+}
+
+/// Returns the index after the bracket closing the one at `index`.
+fn skip_balanced(tokens: &[Token<'_>], mut index: usize) -> Option<usize> {
+    let mut depth = 0;
+    loop {
+        let token = tokens.get(index)?;
+        if token.is("{") || token.is("[") || token.is("(") {
+            depth += 1;
+        } else if token.is("}") || token.is("]") || token.is(")") {
+            depth -= 1;
+        }
+        index += 1;
+        if depth == 0 {
+            return Some(index);
+        }
+    }
+}
+
+fn parse_init(tokens: &[Token<'_>], index: usize) -> Init {
+    let Some(token) = tokens.get(index) else {
+        return Init::Other;
+    };
+    if token.kind != TokenKind::Identifier {
+        return Init::Other;
+    }
+    if token.text == USE_FRAGMENT {
+        let mut call = index + 1;
+        // Type arguments
+        if tokens.get(call).is_some_and(|token| token.is("<")) {
+            while tokens.get(call).is_some_and(|token| !token.is("(")) {
+                call += 1;
+            }
+        }
+        return match &tokens[call.min(tokens.len())..] {
+            [open, tag, template, ..]
+                if open.is("(") && tag.is(GRAPHQL_TAG) && template.kind == TokenKind::Template =>
+            {
+                Init::UseFragment(template.start..template.end)
+            }
+            _ => Init::Other,
+        };
+    }
+    let (path, end) = member_chain(tokens, index + 1);
+    // The declarator must end after the chain
+    let ends = match tokens.get(end) {
+        None => true,
+        Some(next) => {
+            next.is(";")
+                || next.is(",")
+                || next.is("}")
+                || next.is(")")
+                || (next.newline_before && (next.kind == TokenKind::Identifier || next.is("{")))
+        }
+    };
+    if ends {
+        Init::Alias {
+            name: token.text.intern(),
+            path,
+            tokens: index..end,
+        }
+    } else {
+        Init::Other
+    }
+}
+
+/// Returns the index of the pattern following the `,` that ends the
+/// initializer starting at `index`, if any.
+fn find_next_declarator(tokens: &[Token<'_>], mut index: usize) -> Option<usize> {
+    loop {
+        let token = tokens.get(index)?;
+        if token.is(",") {
+            return Some(index + 1);
+        }
+        if token.is(";") || token.is("}") || token.is(")") || token.is("]") {
+            return None;
+        }
+        if token.is("{") || token.is("[") || token.is("(") {
+            index = skip_balanced(tokens, index)?;
+            // A new statement on the next line ends the declaration
+            if tokens
+                .get(index)
+                .is_some_and(|token| token.newline_before && token.kind == TokenKind::Identifier)
+            {
+                return None;
+            }
+        } else {
+            index += 1;
+            if tokens.get(index).is_some_and(|token| {
+                token.newline_before
+                    && token.kind == TokenKind::Identifier
+                    && !is_property_access(&tokens[index - 1])
+                    && tokens[index - 1].kind != TokenKind::Punctuator
+            }) {
+                return None;
+            }
+        }
+    }
+}
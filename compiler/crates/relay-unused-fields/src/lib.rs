@@ -0,0 +1,263 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Finds fields that a fragment selects but that the module owning the
+//! fragment never reads.
+//!
+//! The module is tokenized and the data returned by each `useFragment` call
+//! is followed through property accesses and destructuring. The analysis is
+//! conservative: once a value escapes, for example because it is passed to a
+//! function, spread into another object or read with a computed key, every
+//! field selected below it counts as read.
+
+#![deny(warnings)]
+#![deny(rust_2018_idioms)]
+#![deny(clippy::all)]
+
+mod errors;
+mod find_reads;
+mod tokenize;
+
+use std::path::Path;
+
+use ::intern::intern;
+use ::intern::string_key::StringKey;
+use common::Diagnostic;
+use common::DiagnosticTag;
+use common::Location;
+use common::SourceLocationKey;
+use common::Span;
+pub use errors::UnusedFieldsMessage;
+use find_reads::ReadTree;
+use find_reads::find_reads;
+use graphql_syntax::ConstantValue;
+use graphql_syntax::Directive;
+use graphql_syntax::ExecutableDefinition;
+use graphql_syntax::FragmentDefinition;
+use graphql_syntax::Selection;
+use graphql_syntax::Value;
+use graphql_syntax::parse_executable;
+use rustc_hash::FxHashSet;
+use tokenize::tokenize;
+
+/// Returns a warning for each field selected by a fragment passed inline to
+/// `useFragment` in `text` that the module never reads. The module is only
+/// tokenized, not parsed, so the warnings carry no fix removing the selection.
+/// Modules that can't be tokenized are skipped.
+pub fn find_unused_fields(text: &str, source_module_path: &str) -> Vec<Diagnostic> {
+    let source_location = SourceLocationKey::standalone(source_module_path);
+    // TypeScript modules can't contain JSX, and `<` may start a type assertion
+    let enable_jsx = !matches!(
+        Path::new(source_module_path)
+            .extension()
+            .and_then(|extension| extension.to_str()),
+        Some("ts" | "mts" | "cts")
+    );
+    let Some(tokens) = tokenize(text, enable_jsx) else {
+        return Vec::new();
+    };
+
+    let mut warnings = Vec::new();
+    for (template_range, reads) in find_reads(&tokens) {
+        // The range of the template literal includes its backticks
+        let start = template_range.start + 1;
+        let end = template_range.end - 1;
+        // Invalid documents are reported by the compiler
+        let Ok(document) = parse_executable(&text[start as usize..end as usize], source_location)
+        else {
+            continue;
+        };
+        if let [ExecutableDefinition::Fragment(fragment)] = document.definitions.as_slice() {
+            let mut finder = UnusedFieldFinder {
+                fragment,
+                source_location,
+                offset: start,
+                warnings: &mut warnings,
+            };
+            finder.report_unused_in_selection_set(&fragment.selections.items, &reads, None);
+        }
+    }
+    warnings.sort_by_key(|warning| warning.location());
+    warnings
+}
+
+struct UnusedFieldFinder<'a> {
+    fragment: &'a FragmentDefinition,
+    source_location: SourceLocationKey,
+    /// Offset of the GraphQL text within the module
+    offset: u32,
+    warnings: &'a mut Vec<Diagnostic>,
+}
+
+impl UnusedFieldFinder<'_> {
+    fn report_unused_in_selection_set(
+        &mut self,
+        selections: &[Selection],
+        reads: &ReadTree,
+        parent_path: Option<&str>,
+    ) {
+        if reads.fully_read {
+            return;
+        }
+        // Reads of properties we can't attribute to a selection, such as
+        // `data.map` on a plural fragment, mean that the data is used in ways
+        // we don't understand.
+        let mut keys = FxHashSet::default();
+        collect_response_keys(selections, &mut keys);
+        if reads.children.keys().any(|key| !keys.contains(key)) {
+            return;
+        }
+        self.report_unused_in_selections(selections, reads, parent_path);
+    }
+
+    fn report_unused_in_selections(
+        &mut self,
+        selections: &[Selection],
+        reads: &ReadTree,
+        parent_path: Option<&str>,
+    ) {
+        let mut unused = Vec::new();
+        for selection in selections {
+            match selection {
+                Selection::ScalarField(field) => {
+                    let key = field
+                        .alias
+                        .as_ref()
+                        .map_or(field.name.value, |alias| alias.alias.value);
+                    if field.name.value != intern!("__typename")
+                        && !reads.children.contains_key(&key)
+                    {
+                        unused.push((selection.span(), field_path(parent_path, key)));
+                    }
+                }
+                Selection::LinkedField(field) => {
+                    let key = field
+                        .alias
+                        .as_ref()
+                        .map_or(field.name.value, |alias| alias.alias.value);
+                    let path = field_path(parent_path, key);
+                    match reads.children.get(&key) {
+                        None => unused.push((selection.span(), path)),
+                        // The edges and page info of connections are required
+                        // by Relay even when not read
+                        Some(_) if is_connection(&field.directives) => {}
+                        Some(child_reads) => self.report_unused_in_selection_set(
+                            &field.selections.items,
+                            child_reads,
+                            Some(&path),
+                        ),
+                    }
+                }
+                Selection::InlineFragment(inline_fragment) => {
+                    if alias_directive(&inline_fragment.directives).is_none() {
+                        self.report_unused_in_selections(
+                            &inline_fragment.selections.items,
+                            reads,
+                            parent_path,
+                        );
+                    }
+                }
+                Selection::FragmentSpread(_) => {}
+            }
+        }
+        // Removing every selection would leave an invalid empty selection set
+        if unused.len() == selections.len() {
+            return;
+        }
+        for (span, field_path) in unused {
+            self.warnings.push(Diagnostic::warning(
+                UnusedFieldsMessage::UnusedField {
+                    fragment_name: self.fragment.name.value,
+                    field_path,
+                },
+                Location::new(
+                    self.source_location,
+                    Span::new(span.start + self.offset, span.end + self.offset),
+                ),
+                vec![DiagnosticTag::UNNECESSARY],
+            ));
+        }
+    }
+}
+
+/// Collects the keys under which `selections` appear in the fragment data.
+fn collect_response_keys(selections: &[Selection], keys: &mut FxHashSet<StringKey>) {
+    for selection in selections {
+        match selection {
+            Selection::ScalarField(field) => {
+                keys.insert(
+                    field
+                        .alias
+                        .as_ref()
+                        .map_or(field.name.value, |alias| alias.alias.value),
+                );
+            }
+            Selection::LinkedField(field) => {
+                keys.insert(
+                    field
+                        .alias
+                        .as_ref()
+                        .map_or(field.name.value, |alias| alias.alias.value),
+                );
+            }
+            Selection::InlineFragment(inline_fragment) => {
+                match alias_directive(&inline_fragment.directives) {
+                    Some(directive) => {
+                        if let Some(alias) = alias_name(directive).or_else(|| {
+                            inline_fragment
+                                .type_condition
+                                .as_ref()
+                                .map(|type_condition| type_condition.type_.value)
+                        }) {
+                            keys.insert(alias);
+                        }
+                    }
+                    None => collect_response_keys(&inline_fragment.selections.items, keys),
+                }
+            }
+            Selection::FragmentSpread(spread) => {
+                if let Some(directive) = alias_directive(&spread.directives) {
+                    keys.insert(alias_name(directive).unwrap_or(spread.name.value));
+                }
+            }
+        }
+    }
+}
+
+fn alias_directive(directives: &[Directive]) -> Option<&Directive> {
+    directives
+        .iter()
+        .find(|directive| directive.name.value == intern!("alias"))
+}
+
+/// The `as` argument of an `@alias` directive
+fn alias_name(directive: &Directive) -> Option<StringKey> {
+    directive
+        .arguments
+        .as_ref()?
+        .items
+        .iter()
+        .find(|argument| argument.name.value == intern!("as"))
+        .and_then(|argument| match &argument.value {
+            Value::Constant(ConstantValue::String(string)) => Some(string.value),
+            _ => None,
+        })
+}
+
+fn is_connection(directives: &[Directive]) -> bool {
+    directives.iter().any(|directive| {
+        directive.name.value == intern!("connection")
+            || directive.name.value == intern!("stream_connection")
+    })
+}
+
+fn field_path(parent_path: Option<&str>, key: StringKey) -> String {
+    match parent_path {
+        Some(parent_path) => format!("{parent_path}.{key}"),
+        None => key.to_string(),
+    }
+}
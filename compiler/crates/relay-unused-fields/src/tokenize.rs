@@ -0,0 +1,434 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! A tokenizer for JavaScript, Flow and TypeScript modules that is precise
+//! enough to follow reads of variables: it knows about comments, string,
+//! template and regular expression literals and JSX, so that their contents
+//! are not mistaken for code. Type annotations are tokenized like code.
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TokenKind {
+    /// An identifier or keyword.
+    Identifier,
+    /// A template literal without substitutions, including its backticks.
+    Template,
+    /// A piece of a template literal with substitutions.
+    TemplatePart,
+    Punctuator,
+    /// Numbers, strings, regular expressions and JSX names, text and
+    /// attribute values.
+    Literal,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Token<'a> {
+    pub kind: TokenKind,
+    pub text: &'a str,
+    pub start: u32,
+    pub end: u32,
+    /// Whether a line terminator precedes the token.
+    pub newline_before: bool,
+}
+
+impl Token<'_> {
+    pub fn is(&self, text: &str) -> bool {
+        self.kind != TokenKind::Literal && self.text == text
+    }
+}
+
+/// A construct the tokenizer is nested in.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Context {
+    /// `{` in code
+    Brace,
+    /// `${` in a template literal
+    Substitution,
+    /// `{` in a JSX tag or JSX children
+    JsxExpression,
+    /// Between `<Name` and `>` or `/>`
+    JsxTag,
+    /// Between the opening and closing tag of a JSX element
+    JsxChildren,
+}
+
+/// Multi-character punctuators, longest first.
+const PUNCTUATORS: &[&str] = &[
+    ">>>=", "...", "===", "!==", "**=", "<<=", ">>=", ">>>", "&&=", "||=", "??=", "=>", "==", "!=",
+    "<=", ">=", "&&", "||", "??", "?.", "++", "--", "+=", "-=", "*=", "/=", "%=", "&=", "|=", "^=",
+    "**", "<<", ">>",
+];
+
+/// Keywords after which an expression, and so a regular expression or JSX,
+/// may start.
+const EXPRESSION_KEYWORDS: &[&str] = &[
+    "await",
+    "case",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "in",
+    "instanceof",
+    "new",
+    "of",
+    "return",
+    "throw",
+    "typeof",
+    "void",
+    "yield",
+];
+
+/// Returns the tokens of `text`, or `None` if a literal or comment is not
+/// terminated or braces are unbalanced.
+pub fn tokenize(text: &str, enable_jsx: bool) -> Option<Vec<Token<'_>>> {
+    let mut tokenizer = Tokenizer {
+        text,
+        bytes: text.as_bytes(),
+        position: 0,
+        enable_jsx,
+        contexts: Vec::new(),
+        tokens: Vec::new(),
+        newline_before: false,
+    };
+    tokenizer.tokenize()?;
+    if tokenizer.contexts.is_empty() {
+        Some(tokenizer.tokens)
+    } else {
+        None
+    }
+}
+
+struct Tokenizer<'a> {
+    text: &'a str,
+    bytes: &'a [u8],
+    position: usize,
+    enable_jsx: bool,
+    contexts: Vec<Context>,
+    tokens: Vec<Token<'a>>,
+    newline_before: bool,
+}
+
+impl<'a> Tokenizer<'a> {
+    fn tokenize(&mut self) -> Option<()> {
+        while self.position < self.bytes.len() {
+            match self.contexts.last() {
+                Some(Context::JsxTag) => self.jsx_tag()?,
+                Some(Context::JsxChildren) => self.jsx_children()?,
+                _ => self.code()?,
+            }
+        }
+        Some(())
+    }
+
+    fn peek(&self, offset: usize) -> Option<u8> {
+        self.bytes.get(self.position + offset).copied()
+    }
+
+    fn push(&mut self, kind: TokenKind, start: usize) {
+        self.tokens.push(Token {
+            kind,
+            text: &self.text[start..self.position],
+            start: start as u32,
+            end: self.position as u32,
+            newline_before: self.newline_before,
+        });
+        self.newline_before = false;
+    }
+
+    /// Skips whitespace and comments.
+    fn skip_trivia(&mut self) -> Option<()> {
+        while let Some(byte) = self.peek(0) {
+            match byte {
+                b'\n' | b'\r' => {
+                    self.newline_before = true;
+                    self.position += 1;
+                }
+                b' ' | b'\t' | 0x0b | 0x0c => self.position += 1,
+                b'/' if self.peek(1) == Some(b'/') => {
+                    while self.peek(0).is_some_and(|byte| byte != b'\n') {
+                        self.position += 1;
+                    }
+                }
+                b'/' if self.peek(1) == Some(b'*') => {
+                    let end = self.text[self.position + 2..].find("*/")?;
+                    let comment = &self.text[self.position..self.position + end + 4];
+                    self.newline_before |= comment.contains('\n');
+                    self.position += end + 4;
+                }
+                _ if byte >= 0x80
+                    && self.text[self.position..].starts_with(char::is_whitespace) =>
+                {
+                    self.position += self.text[self.position..].chars().next()?.len_utf8();
+                }
+                _ => break,
+            }
+        }
+        Some(())
+    }
+
+    /// Whether the previous token ends an expression, so that `/` is a
+    /// division and `<` a comparison or type arguments.
+    fn after_expression(&self) -> bool {
+        match self.tokens.last() {
+            None => false,
+            Some(token) => match token.kind {
+                TokenKind::Identifier => !EXPRESSION_KEYWORDS.contains(&token.text),
+                TokenKind::Template | TokenKind::Literal => true,
+                TokenKind::TemplatePart => token.text.ends_with('`'),
+                TokenKind::Punctuator => matches!(token.text, ")" | "]" | "}"),
+            },
+        }
+    }
+
+    fn code(&mut self) -> Option<()> {
+        self.skip_trivia()?;
+        let Some(byte) = self.peek(0) else {
+            return Some(());
+        };
+        let start = self.position;
+        match byte {
+            b'a'..=b'z' | b'A'..=b'Z' | b'_' | b'$' | b'\\' | 0x80.. => {
+                self.identifier();
+                self.push(TokenKind::Identifier, start);
+            }
+            b'#' | b'@' => {
+                self.position += 1;
+                self.identifier();
+                self.push(TokenKind::Literal, start);
+            }
+            b'0'..=b'9' => {
+                self.number();
+                self.push(TokenKind::Literal, start);
+            }
+            b'.' if self.peek(1).is_some_and(|byte| byte.is_ascii_digit()) => {
+                self.number();
+                self.push(TokenKind::Literal, start);
+            }
+            b'"' | b'\'' => {
+                self.string(byte)?;
+                self.push(TokenKind::Literal, start);
+            }
+            b'`' => {
+                self.position += 1;
+                self.template_part(start)?;
+            }
+            b'{' => {
+                self.position += 1;
+                self.contexts.push(Context::Brace);
+                self.push(TokenKind::Punctuator, start);
+            }
+            b'}' => match self.contexts.pop()? {
+                Context::Substitution => {
+                    self.position += 1;
+                    self.template_part(start)?;
+                }
+                _ => {
+                    self.position += 1;
+                    self.push(TokenKind::Punctuator, start);
+                }
+            },
+            b'/' if !self.after_expression() => {
+                self.regex()?;
+                self.push(TokenKind::Literal, start);
+            }
+            b'<' if self.enable_jsx
+                && !self.after_expression()
+                && self.peek(1).is_some_and(|byte| {
+                    byte == b'>' || byte.is_ascii_alphabetic() || byte == b'_' || byte == b'$'
+                }) =>
+            {
+                self.position += 1;
+                self.push(TokenKind::Punctuator, start);
+                self.contexts.push(Context::JsxTag);
+            }
+            _ => {
+                let punctuator = PUNCTUATORS
+                    .iter()
+                    .find(|punctuator| self.text[self.position..].starts_with(*punctuator))
+                    // `a?.5:b` is a conditional
+                    .filter(|punctuator| {
+                        **punctuator != "?." || !self.peek(2).is_some_and(|b| b.is_ascii_digit())
+                    });
+                self.position += match punctuator {
+                    Some(punctuator) => punctuator.len(),
+                    None => self.text[self.position..].chars().next()?.len_utf8(),
+                };
+                self.push(TokenKind::Punctuator, start);
+            }
+        }
+        Some(())
+    }
+
+    fn identifier(&mut self) {
+        while let Some(byte) = self.peek(0) {
+            match byte {
+                b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'_' | b'$' | b'\\' => self.position += 1,
+                0x80.. => {
+                    let chr = self.text[self.position..].chars().next().unwrap();
+                    if chr.is_whitespace() {
+                        break;
+                    }
+                    self.position += chr.len_utf8();
+                }
+                _ => break,
+            }
+        }
+    }
+
+    fn number(&mut self) {
+        while let Some(byte) = self.peek(0) {
+            match byte {
+                b'0'..=b'9' | b'a'..=b'z' | b'A'..=b'Z' | b'_' | b'.' => self.position += 1,
+                b'+' | b'-' if matches!(self.bytes[self.position - 1], b'e' | b'E') => {
+                    self.position += 1
+                }
+                _ => break,
+            }
+        }
+    }
+
+    fn string(&mut self, quote: u8) -> Option<()> {
+        self.position += 1;
+        loop {
+            match self.peek(0)? {
+                b'\\' => self.position += 2,
+                b'\n' => return None,
+                byte => {
+                    self.position += 1;
+                    if byte == quote {
+                        return Some(());
+                    }
+                }
+            }
+        }
+    }
+
+    /// Scans a template literal from after its backtick or the `}` ending a
+    /// substitution, up to and including the next backtick or `${`.
+    fn template_part(&mut self, start: usize) -> Option<()> {
+        let complete = self.bytes[start] == b'`';
+        loop {
+            match self.peek(0)? {
+                b'\\' => self.position += 2,
+                b'`' => {
+                    self.position += 1;
+                    let kind = if complete {
+                        TokenKind::Template
+                    } else {
+                        TokenKind::TemplatePart
+                    };
+                    self.push(kind, start);
+                    return Some(());
+                }
+                b'$' if self.peek(1) == Some(b'{') => {
+                    self.position += 2;
+                    self.push(TokenKind::TemplatePart, start);
+                    self.contexts.push(Context::Substitution);
+                    return Some(());
+                }
+                _ => self.position += 1,
+            }
+        }
+    }
+
+    fn regex(&mut self) -> Option<()> {
+        self.position += 1;
+        let mut in_class = false;
+        loop {
+            match self.peek(0)? {
+                b'\\' => self.position += 2,
+                b'\n' => return None,
+                b'[' => {
+                    in_class = true;
+                    self.position += 1;
+                }
+                b']' => {
+                    in_class = false;
+                    self.position += 1;
+                }
+                b'/' if !in_class => {
+                    self.position += 1;
+                    self.identifier();
+                    return Some(());
+                }
+                _ => self.position += 1,
+            }
+        }
+    }
+
+    fn jsx_tag(&mut self) -> Option<()> {
+        self.skip_trivia()?;
+        let start = self.position;
+        match self.peek(0)? {
+            b'>' => {
+                self.position += 1;
+                self.push(TokenKind::Punctuator, start);
+                *self.contexts.last_mut()? = Context::JsxChildren;
+            }
+            b'/' if self.peek(1) == Some(b'>') => {
+                self.position += 2;
+                self.push(TokenKind::Punctuator, start);
+                self.contexts.pop();
+            }
+            b'{' => {
+                self.position += 1;
+                self.push(TokenKind::Punctuator, start);
+                self.contexts.push(Context::JsxExpression);
+            }
+            quote @ (b'"' | b'\'') => {
+                self.position += 1 + self.text[self.position + 1..].find(quote as char)? + 1;
+                self.push(TokenKind::Literal, start);
+            }
+            _ => {
+                // Names of elements and attributes, `=`, and type arguments
+                while let Some(byte) = self.peek(0) {
+                    if matches!(byte, b'>' | b'{' | b'"' | b'\'')
+                        || byte.is_ascii_whitespace()
+                        || (byte == b'/' && self.peek(1) == Some(b'>'))
+                    {
+                        break;
+                    }
+                    self.position += self.text[self.position..].chars().next()?.len_utf8();
+                }
+                if self.position == start {
+                    self.position += 1;
+                }
+                self.push(TokenKind::Literal, start);
+            }
+        }
+        Some(())
+    }
+
+    fn jsx_children(&mut self) -> Option<()> {
+        let start = self.position;
+        match self.peek(0)? {
+            b'{' => {
+                self.position += 1;
+                self.push(TokenKind::Punctuator, start);
+                self.contexts.push(Context::JsxExpression);
+            }
+            b'<' if self.peek(1) == Some(b'/') => {
+                self.position += self.text[self.position..].find('>')? + 1;
+                self.push(TokenKind::Literal, start);
+                self.contexts.pop();
+            }
+            b'<' => {
+                self.position += 1;
+                self.push(TokenKind::Punctuator, start);
+                self.contexts.push(Context::JsxTag);
+            }
+            _ => {
+                let end = self.text[self.position..]
+                    .find(['{', '<'])
+                    .map_or(self.bytes.len(), |end| self.position + end);
+                self.newline_before |= self.text[self.position..end].contains('\n');
+                self.position = end;
+            }
+        }
+        Some(())
+    }
+}
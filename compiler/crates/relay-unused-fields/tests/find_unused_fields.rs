@@ -0,0 +1,26 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use fixture_tests::Fixture;
+use relay_unused_fields::find_unused_fields;
+
+pub async fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let warnings = find_unused_fields(fixture.content, fixture.file_name);
+
+    Ok(warnings
+        .iter()
+        .map(|warning| {
+            let span = warning.location().span();
+            format!(
+                "{}\nSelection: `{}`",
+                warning.message(),
+                &fixture.content[span.start as usize..span.end as usize]
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n"))
+}
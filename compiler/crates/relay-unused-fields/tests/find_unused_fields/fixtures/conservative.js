@@ -0,0 +1,83 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+// Data passed to other functions or spread is fully read, and connections
+// keep their edges.
+function FriendList(props) {
+  const user = useFragment(
+    graphql`
+      fragment FriendList_user on User {
+        friends(first: 10) @connection(key: "FriendList_friends") {
+          edges {
+            cursor
+            node {
+              name
+            }
+          }
+        }
+        address {
+          city
+          street
+        }
+        nearbyFriends: friends(first: 3) {
+          count
+        }
+      }
+    `,
+    props.user,
+  );
+  const edges = user.friends.edges;
+  logAddress(user.address);
+  return <List items={edges} {...user.nearbyFriends} />;
+}
+
+// Computed keys may read any field.
+function ProfileField(props) {
+  const profile = useFragment(
+    graphql`
+      fragment ProfileField_user on User {
+        name
+        username
+      }
+    `,
+    props.user,
+  );
+  return <span>{profile[props.field]}</span>;
+}
+
+// Reads of properties that are not selected, such as array methods, can't be
+// attributed to fields.
+function UserNames(props) {
+  const users = useFragment(
+    graphql`
+      fragment UserNames_users on User @relay(plural: true) {
+        id
+        name
+      }
+    `,
+    props.users,
+  );
+  return users.map(item => <span key={item.id}>{item.name}</span>);
+}
+
+// Selection sets are never emptied.
+function ActorName(props) {
+  const {viewer} = useFragment(
+    graphql`
+      fragment ActorName_query on Query {
+        viewer {
+          actor {
+            name
+          }
+        }
+      }
+    `,
+    props.query,
+  );
+  const {actor} = viewer;
+  return null;
+}
@@ -0,0 +1,36 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+// Data passed to custom hooks may be read in any way by the hook.
+function Comment(props) {
+  const comment = useFragment(
+    graphql`
+      fragment Comment_comment on Comment {
+        body {
+          text
+        }
+        author {
+          name
+          url
+        }
+        feedback {
+          likeCount
+          commentCount
+        }
+      }
+    `,
+    props.comment,
+  );
+  const authorLink = useAuthorLink(comment.author);
+  const [isLiked, like] = useLikeState(comment.feedback);
+  return (
+    <div>
+      {authorLink}
+      <LikeButton isLiked={isLiked} onClick={like} />
+    </div>
+  );
+}
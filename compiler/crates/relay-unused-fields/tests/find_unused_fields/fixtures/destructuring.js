@@ -0,0 +1,43 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+function StoryHeader({story}) {
+  const {
+    title,
+    author: {name, ...author},
+    poster,
+    feedback,
+  } = useFragment(
+    graphql`
+      fragment StoryHeader_story on Story {
+        __typename
+        title
+        subtitle: summary
+        author {
+          name
+          url
+        }
+        poster {
+          name
+          url
+        }
+        feedback {
+          ... on Feedback {
+            likeCount
+            commentCount
+          }
+          viewerDidLike
+        }
+      }
+    `,
+    story,
+  );
+  const {likeCount} = feedback;
+  return (
+    <Header title={title} author={name} link={author} likes={likeCount} />
+  );
+}
@@ -0,0 +1,34 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+// Strings, comments, regular expressions, template literals and JSX text
+// don't read fields, even when they mention them.
+function StoryCard(props) {
+  const story = useFragment(
+    graphql`
+      fragment StoryCard_story on Story {
+        title
+        summary
+        author {
+          name
+          url
+        }
+        createdAt
+      }
+    `,
+    props.story,
+  );
+  // story.summary is shown elsewhere
+  const label = 'story.author.url';
+  const slug = story.title.replace(/story.createdAt[/]/g, '-');
+  return (
+    <Card title={`${story.author.name}'s story`} slug={slug} label={label}>
+      It's {story.title}, isn't it? /* story.summary */
+      <Date value={story.createdAt} />
+    </Card>
+  );
+}
@@ -0,0 +1,38 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+// Optional property accesses are reads like any other, and optional computed
+// accesses and calls may read everything below them.
+function StoryTitle(props) {
+  const story = useFragment(
+    graphql`
+      fragment StoryTitle_story on Story {
+        title
+        summary
+        author {
+          name
+          url
+        }
+        feedback {
+          likeCount
+          commentCount
+        }
+        actors {
+          name
+        }
+      }
+    `,
+    props.story,
+  );
+  const label = story?.feedback?.[props.countField];
+  return (
+    <h2 title={label}>
+      {story?.title} by {story?.author?.name}
+      {story.actors?.map?.(actor => actor.name)}
+    </h2>
+  );
+}
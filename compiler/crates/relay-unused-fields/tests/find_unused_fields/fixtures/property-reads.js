@@ -0,0 +1,25 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+function UserAvatar(props) {
+  const data = useFragment(
+    graphql`
+      fragment UserAvatar_user on User {
+        name
+        birthday
+        profilePicture(size: 32) {
+          uri
+          width
+          height
+        }
+      }
+    `,
+    props.user,
+  );
+  const {height} = data.profilePicture;
+  return <img alt={data.name} src={data.profilePicture?.uri} height={height} />;
+}
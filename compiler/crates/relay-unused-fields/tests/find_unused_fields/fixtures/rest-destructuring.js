@@ -0,0 +1,43 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+// Rest elements collect every other field, so nothing below the object
+// they're taken from is reported.
+function UserCard(props) {
+  const {name, ...details} = useFragment(
+    graphql`
+      fragment UserCard_user on User {
+        name
+        username
+        address {
+          city
+          street
+        }
+      }
+    `,
+    props.user,
+  );
+  return <Card title={name} {...details} />;
+}
+
+function UserAddress(props) {
+  const user = useFragment(
+    graphql`
+      fragment UserAddress_user on User {
+        name
+        address {
+          city
+          street
+          country
+        }
+      }
+    `,
+    props.user,
+  );
+  const {city, ...rest} = user.address;
+  return <Address city={city} {...rest} />;
+}
@@ -0,0 +1,33 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+// Values cast with `as` or `satisfies` may be read in any way by the code
+// using the cast.
+export function Profile({user}: Props): JSX.Element {
+  const data = useFragment(
+    graphql`
+      fragment Profile_user on User {
+        name
+        username
+        address {
+          city
+          street
+        }
+        profilePicture {
+          uri
+          width
+        }
+      }
+    `,
+    user,
+  ) as Profile_user$data;
+  const address = data.address as Address;
+  const picture = data.profilePicture satisfies Picture;
+  return (
+    <Header name={data.name} address={address} uri={picture.uri} />
+  );
+}
@@ -0,0 +1,24 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+type Props = {query: Title_query$key};
+
+export function Title({query}: Props): JSX.Element {
+  const data = useFragment(
+    graphql`
+      fragment Title_query on Query {
+        me {
+          name
+          lastName
+        }
+      }
+    `,
+    query,
+  );
+  const name: string = data.me?.name ?? '';
+  return <h1>{name}</h1>;
+}
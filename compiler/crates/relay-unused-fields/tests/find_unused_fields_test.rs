@@ -0,0 +1,76 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<7e08337f8e94c31208665dc2fdba3c48>>
+ */
+
+mod find_unused_fields;
+
+use find_unused_fields::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[tokio::test]
+async fn conservative() {
+    let input = include_str!("find_unused_fields/fixtures/conservative.js");
+    let expected = include_str!("find_unused_fields/fixtures/conservative.expected");
+    test_fixture(transform_fixture, file!(), "conservative.js", "find_unused_fields/fixtures/conservative.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn custom_hooks() {
+    let input = include_str!("find_unused_fields/fixtures/custom-hooks.js");
+    let expected = include_str!("find_unused_fields/fixtures/custom-hooks.expected");
+    test_fixture(transform_fixture, file!(), "custom-hooks.js", "find_unused_fields/fixtures/custom-hooks.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn destructuring() {
+    let input = include_str!("find_unused_fields/fixtures/destructuring.js");
+    let expected = include_str!("find_unused_fields/fixtures/destructuring.expected");
+    test_fixture(transform_fixture, file!(), "destructuring.js", "find_unused_fields/fixtures/destructuring.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn literals() {
+    let input = include_str!("find_unused_fields/fixtures/literals.js");
+    let expected = include_str!("find_unused_fields/fixtures/literals.expected");
+    test_fixture(transform_fixture, file!(), "literals.js", "find_unused_fields/fixtures/literals.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn optional_chaining() {
+    let input = include_str!("find_unused_fields/fixtures/optional-chaining.js");
+    let expected = include_str!("find_unused_fields/fixtures/optional-chaining.expected");
+    test_fixture(transform_fixture, file!(), "optional-chaining.js", "find_unused_fields/fixtures/optional-chaining.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn property_reads() {
+    let input = include_str!("find_unused_fields/fixtures/property-reads.js");
+    let expected = include_str!("find_unused_fields/fixtures/property-reads.expected");
+    test_fixture(transform_fixture, file!(), "property-reads.js", "find_unused_fields/fixtures/property-reads.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn rest_destructuring() {
+    let input = include_str!("find_unused_fields/fixtures/rest-destructuring.js");
+    let expected = include_str!("find_unused_fields/fixtures/rest-destructuring.expected");
+    test_fixture(transform_fixture, file!(), "rest-destructuring.js", "find_unused_fields/fixtures/rest-destructuring.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn type_casts() {
+    let input = include_str!("find_unused_fields/fixtures/type-casts.tsx");
+    let expected = include_str!("find_unused_fields/fixtures/type-casts.expected");
+    test_fixture(transform_fixture, file!(), "type-casts.tsx", "find_unused_fields/fixtures/type-casts.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn typescript() {
+    let input = include_str!("find_unused_fields/fixtures/typescript.tsx");
+    let expected = include_str!("find_unused_fields/fixtures/typescript.expected");
+    test_fixture(transform_fixture, file!(), "typescript.tsx", "find_unused_fields/fixtures/typescript.expected", input, expected).await;
+}
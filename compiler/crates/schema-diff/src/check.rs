@@ -24,7 +24,7 @@ use crate::definitions::Type;
 use crate::definitions::TypeChange;
 
 // This enum is very similar to the schema Type enum but uses StringKey instead of id
-#[derive(Eq, PartialEq, Hash)]
+#[derive(Clone, Copy, Eq, PartialEq, Hash)]
 pub enum IncrementalBuildSchemaChange {
     Enum(StringKey),
     InputObject(StringKey),
@@ -922,6 +922,12 @@ impl SetArgumentValue {
         ArgumentValue {
             name: self.name,
             value: self.value.clone(),
+            location: self
+                .definition
+                .locations
+                .first()
+                .copied()
+                .unwrap_or_else(Location::generated),
         }
     }
 
@@ -6,6 +6,7 @@
  */
 
 use common::ArgumentName;
+use common::DirectiveName;
 use common::InterfaceName;
 use common::ObjectName;
 use common::UnionName;
@@ -84,4 +85,39 @@ pub enum SchemaValidationError {
 
     #[error("Cyclic reference found for interface inheritance: {0}.")]
     CyclicInterfaceInheritance(String),
+
+    #[error("Unknown directive '@{0}'.")]
+    UnknownDirective(DirectiveName),
+
+    #[error("Directive '@{0}' may not be used on {1}.")]
+    MisplacedDirective(DirectiveName, String),
+
+    #[error("The directive '@{0}' can only be used once at this location.")]
+    RepeatedNonRepeatableDirective(DirectiveName),
+
+    #[error("Unknown argument '{1}' on directive '@{0}'.")]
+    UnknownDirectiveArgument(DirectiveName, ArgumentName),
+
+    #[error("Directive '@{0}' argument '{1}' of type '{2}' is required, but it was not provided.")]
+    MissingDirectiveArgument(DirectiveName, ArgumentName, String),
+
+    #[error("Directive '@{0}' argument '{1}' expects type '{2}' but got: {3}.")]
+    InvalidDirectiveArgumentValue(DirectiveName, ArgumentName, String, String),
+
+    #[error(
+        "Cannot reference Input Object '{0}' within itself through a series of non-null fields: '{1}'."
+    )]
+    InputObjectCycle(StringKey, String),
+
+    #[error("Default value of '{0}' expects type '{1}' but got: {2}.")]
+    InvalidDefaultValue(String, String, String),
+
+    #[error("OneOf input field '{0}.{1}' must be nullable.")]
+    NonNullableOneOfField(StringKey, ArgumentName),
+
+    #[error("OneOf input field '{0}.{1}' cannot have a default value.")]
+    OneOfFieldWithDefaultValue(StringKey, ArgumentName),
+
+    #[error("'{0}' cannot be deprecated because it is required.")]
+    DeprecatedRequiredArgument(String),
 }
@@ -21,11 +21,14 @@ use common::WithLocation;
 use errors::*;
 use fnv::FnvHashMap;
 use fnv::FnvHashSet;
+use graphql_syntax::ConstantValue;
 use intern::Lookup;
 use intern::string_key::Intern;
 use intern::string_key::StringKey;
 use rayon::prelude::*;
 use regex::Regex;
+use schema::Argument;
+use schema::DirectiveLocation;
 use schema::DirectiveValue;
 use schema::EnumID;
 use schema::Field;
@@ -60,9 +63,32 @@ static TYPE_NAME_REGEX: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"^[_a-zA-Z][_a-zA-Z0-9]*$").unwrap());
 static SEMANTIC_NON_NULL_DIRECTIVE: LazyLock<DirectiveName> =
     LazyLock::new(|| DirectiveName("semanticNonNull".intern()));
+static ONE_OF_DIRECTIVE: LazyLock<DirectiveName> =
+    LazyLock::new(|| DirectiveName("oneOf".intern()));
+/// Directives that schemas may apply without declaring them: the ones of the
+/// spec, and `@semanticNonNull`, which is interpreted by the validation.
+static BUILTIN_DIRECTIVES: LazyLock<FnvHashSet<DirectiveName>> = LazyLock::new(|| {
+    vec![
+        DirectiveName("deprecated".intern()),
+        DirectiveName("specifiedBy".intern()),
+        *ONE_OF_DIRECTIVE,
+        *SEMANTIC_NON_NULL_DIRECTIVE,
+    ]
+    .into_iter()
+    .collect()
+});
 
 pub struct SchemaValidationOptions {
     pub allow_introspection_names: bool,
+    /// Validate that applied directives are defined, allowed at their location
+    /// and given valid arguments.
+    pub validate_directive_applications: bool,
+    /// Directives, besides the ones of the spec, that may be applied without
+    /// being defined.
+    pub implicit_directives: FnvHashSet<DirectiveName>,
+    /// Validate default values, input object cycles, the fields of `@oneOf`
+    /// input objects, and that required arguments are not deprecated.
+    pub validate_input_values: bool,
 }
 
 pub fn validate(schema: &SDLSchema, options: SchemaValidationOptions) -> DiagnosticsResult<()> {
@@ -97,6 +123,9 @@ impl<'schema> ValidationContext<'schema> {
         self.validate_root_types();
         self.validate_directives();
         self.validate_types();
+        if self.options.validate_input_values {
+            self.validate_input_object_cycles();
+        }
     }
 
     fn validate_root_types(&mut self) {
@@ -146,6 +175,14 @@ impl<'schema> ValidationContext<'schema> {
                     continue;
                 }
                 arg_names.insert(argument.name.item, argument.name.location);
+
+                if is_input_type(&argument.type_) {
+                    self.validate_input_value(
+                        format!("@{}({}:)", directive.name.item, argument.name.item),
+                        argument,
+                        DirectiveLocation::ArgumentDefinition,
+                    );
+                }
             }
         }
     }
@@ -168,6 +205,32 @@ impl<'schema> ValidationContext<'schema> {
         if !is_introspection_type(type_, type_name) {
             self.validate_name(type_name, self.get_type_definition_location(*type_));
         }
+        // Ensure the directives applied to the type are valid.
+        let (directives, directive_location) = match type_ {
+            Type::Enum(id) => (&self.schema.enum_(*id).directives, DirectiveLocation::Enum),
+            Type::InputObject(id) => (
+                &self.schema.input_object(*id).directives,
+                DirectiveLocation::InputObject,
+            ),
+            Type::Interface(id) => (
+                &self.schema.interface(*id).directives,
+                DirectiveLocation::Interface,
+            ),
+            Type::Object(id) => (
+                &self.schema.object(*id).directives,
+                DirectiveLocation::Object,
+            ),
+            Type::Scalar(id) => (
+                &self.schema.scalar(*id).directives,
+                DirectiveLocation::Scalar,
+            ),
+            Type::Union(id) => (&self.schema.union(*id).directives, DirectiveLocation::Union),
+        };
+        self.validate_directive_values(
+            directives,
+            directive_location,
+            self.get_type_definition_location(*type_),
+        );
         match type_ {
             Type::Enum(id) => {
                 // Ensure Enums have valid values.
@@ -231,6 +294,12 @@ impl<'schema> ValidationContext<'schema> {
             // Ensure they are named correctly.
             self.validate_name(field.name.item, field.name.location);
 
+            self.validate_directive_values(
+                &field.directives,
+                DirectiveLocation::FieldDefinition,
+                field.name.location,
+            );
+
             // Ensure the type is an output type
             if !is_output_type(&field.type_) {
                 self.report_diagnostic(Diagnostic::error(
@@ -276,6 +345,12 @@ impl<'schema> ValidationContext<'schema> {
                         ),
                         argument.name.location, // Note: Schema does not retain location information for argument type reference
                     ));
+                } else {
+                    self.validate_input_value(
+                        format!("{}.{}({}:)", type_name, field.name.item, argument.name.item),
+                        argument,
+                        DirectiveLocation::ArgumentDefinition,
+                    );
                 }
             }
         }
@@ -314,6 +389,11 @@ impl<'schema> ValidationContext<'schema> {
         }
 
         for value in enum_.values.iter() {
+            self.validate_directive_values(
+                &value.directives,
+                DirectiveLocation::EnumValue,
+                enum_.name.location, // Schema does not track location information for individual enum values
+            );
             // Ensure valid name.
             self.validate_name(value.value, enum_.name.location); // Note: Schema does not have location for enum value
             let value_name = value.value.lookup();
@@ -335,6 +415,9 @@ impl<'schema> ValidationContext<'schema> {
             ));
         }
 
+        let is_one_of = self.options.validate_input_values
+            && input_object.directives.named(*ONE_OF_DIRECTIVE).is_some();
+
         // Ensure the arguments are valid
         for field in input_object.fields.iter() {
             // Ensure they are named correctly.
@@ -351,8 +434,347 @@ impl<'schema> ValidationContext<'schema> {
                     ),
                     field.name.location,
                 ));
+            } else {
+                self.validate_input_value(
+                    format!("{}.{}", input_object.name.item, field.name.item),
+                    field,
+                    DirectiveLocation::InputFieldDefinition,
+                );
+            }
+
+            // Ensure the fields of a @oneOf input object are nullable and
+            // have no default value.
+            if is_one_of {
+                if field.type_.is_non_null() {
+                    self.report_diagnostic(Diagnostic::error(
+                        SchemaValidationError::NonNullableOneOfField(
+                            input_object.name.item.0,
+                            field.name.item,
+                        ),
+                        field.name.location,
+                    ));
+                }
+                if field.default_value.is_some() {
+                    self.report_diagnostic(Diagnostic::error(
+                        SchemaValidationError::OneOfFieldWithDefaultValue(
+                            input_object.name.item.0,
+                            field.name.item,
+                        ),
+                        field.name.location,
+                    ));
+                }
+            }
+        }
+    }
+
+    /// Validates the directives and default value of an argument or input
+    /// field, and that it is not both required and deprecated.
+    fn validate_input_value(
+        &mut self,
+        coordinate: String,
+        argument: &Argument,
+        directive_location: DirectiveLocation,
+    ) {
+        self.validate_directive_values(
+            &argument.directives,
+            directive_location,
+            argument.name.location,
+        );
+        if !self.options.validate_input_values {
+            return;
+        }
+
+        if let Some(default_value) = &argument.default_value
+            && !self.is_valid_value(default_value, &argument.type_)
+        {
+            self.report_diagnostic(Diagnostic::error(
+                SchemaValidationError::InvalidDefaultValue(
+                    coordinate.clone(),
+                    self.schema.get_type_string(&argument.type_),
+                    default_value.to_string(),
+                ),
+                argument.name.location, // Note: Schema does not retain location information for default values
+            ));
+        }
+
+        if argument.type_.is_non_null()
+            && argument.default_value.is_none()
+            && argument.deprecated().is_some()
+        {
+            self.report_diagnostic(Diagnostic::error(
+                SchemaValidationError::DeprecatedRequiredArgument(coordinate),
+                argument.name.location,
+            ));
+        }
+    }
+
+    /// Validates directives applied at `directive_location`. Since the schema
+    /// does not track the location of applied directives, errors are reported
+    /// at `location`, the definition they are applied to, except for errors
+    /// about an argument, which are reported at the argument.
+    fn validate_directive_values(
+        &mut self,
+        directives: &[DirectiveValue],
+        directive_location: DirectiveLocation,
+        location: Location,
+    ) {
+        if !self.options.validate_directive_applications {
+            return;
+        }
+        let mut directive_names: FnvHashSet<DirectiveName> = FnvHashSet::default();
+        for directive_value in directives {
+            let Some(directive) = self.schema.get_directive(directive_value.name) else {
+                if !BUILTIN_DIRECTIVES.contains(&directive_value.name)
+                    && !self
+                        .options
+                        .implicit_directives
+                        .contains(&directive_value.name)
+                {
+                    self.report_diagnostic(Diagnostic::error(
+                        SchemaValidationError::UnknownDirective(directive_value.name),
+                        location,
+                    ));
+                }
+                continue;
+            };
+
+            // Ensure the directive is allowed at this location.
+            if !directive.locations.contains(&directive_location) {
+                self.report_diagnostic(
+                    Diagnostic::error(
+                        SchemaValidationError::MisplacedDirective(
+                            directive_value.name,
+                            directive_location.to_string(),
+                        ),
+                        location,
+                    )
+                    .annotate("The directive is defined here:", directive.name.location),
+                );
+            }
+
+            // Ensure non-repeatable directives are used once.
+            if !directive_names.insert(directive_value.name) && !directive.repeatable {
+                self.report_diagnostic(Diagnostic::error(
+                    SchemaValidationError::RepeatedNonRepeatableDirective(directive_value.name),
+                    location,
+                ));
+                continue;
+            }
+
+            // Ensure the arguments are defined and of the expected type.
+            for argument_value in directive_value.arguments.iter() {
+                let argument_location = if argument_value.location.source_location().is_generated()
+                {
+                    location
+                } else {
+                    argument_value.location
+                };
+                match directive.arguments.named(argument_value.name) {
+                    Some(argument) => {
+                        if !self.is_valid_value(&argument_value.value, &argument.type_) {
+                            self.report_diagnostic(
+                                Diagnostic::error(
+                                    SchemaValidationError::InvalidDirectiveArgumentValue(
+                                        directive_value.name,
+                                        argument_value.name,
+                                        self.schema.get_type_string(&argument.type_),
+                                        argument_value.value.to_string(),
+                                    ),
+                                    argument_location,
+                                )
+                                .annotate(
+                                    "The directive argument is defined here:",
+                                    argument.name.location,
+                                ),
+                            );
+                        }
+                    }
+                    None => {
+                        self.report_diagnostic(
+                            Diagnostic::error(
+                                SchemaValidationError::UnknownDirectiveArgument(
+                                    directive_value.name,
+                                    argument_value.name,
+                                ),
+                                argument_location,
+                            )
+                            .annotate("The directive is defined here:", directive.name.location),
+                        );
+                    }
+                }
+            }
+
+            // Ensure the required arguments are provided.
+            for argument in directive.arguments.iter() {
+                if argument.type_.is_non_null()
+                    && argument.default_value.is_none()
+                    && directive_value
+                        .arguments
+                        .named(argument.name.item)
+                        .is_none()
+                {
+                    self.report_diagnostic(
+                        Diagnostic::error(
+                            SchemaValidationError::MissingDirectiveArgument(
+                                directive_value.name,
+                                argument.name.item,
+                                self.schema.get_type_string(&argument.type_),
+                            ),
+                            location,
+                        )
+                        .annotate(
+                            "The directive argument is defined here:",
+                            argument.name.location,
+                        ),
+                    );
+                }
+            }
+        }
+    }
+
+    /// Returns whether a literal is a valid input value of `type_`, following
+    /// the input coercion rules of the spec. Custom scalars accept any value.
+    fn is_valid_value(&self, value: &ConstantValue, type_: &TypeReference<Type>) -> bool {
+        match (type_, value) {
+            (TypeReference::NonNull(_), ConstantValue::Null(_)) => false,
+            (_, ConstantValue::Null(_)) => true,
+            (TypeReference::NonNull(of_type), value) => self.is_valid_value(value, of_type),
+            (TypeReference::List(of_type), ConstantValue::List(list)) => list
+                .items
+                .iter()
+                .all(|item| self.is_valid_value(item, of_type)),
+            // A single value is coerced to a list of one item.
+            (TypeReference::List(of_type), value) => self.is_valid_value(value, of_type),
+            (TypeReference::Named(named_type), value) => {
+                self.is_valid_named_type_value(value, *named_type)
+            }
+        }
+    }
+
+    fn is_valid_named_type_value(&self, value: &ConstantValue, type_: Type) -> bool {
+        match type_ {
+            Type::Scalar(id) => match (self.schema.scalar(id).name.item.0.lookup(), value) {
+                ("Int", ConstantValue::Int(int)) => i32::try_from(int.value).is_ok(),
+                ("Float", ConstantValue::Int(_) | ConstantValue::Float(_))
+                | ("String", ConstantValue::String(_))
+                | ("Boolean", ConstantValue::Boolean(_))
+                | ("ID", ConstantValue::String(_) | ConstantValue::Int(_)) => true,
+                ("Int" | "Float" | "String" | "Boolean" | "ID", _) => false,
+                _ => true,
+            },
+            Type::Enum(id) => match value {
+                ConstantValue::Enum(enum_value) => self
+                    .schema
+                    .enum_(id)
+                    .values
+                    .iter()
+                    .any(|value| value.value == enum_value.value),
+                _ => false,
+            },
+            Type::InputObject(id) => {
+                let ConstantValue::Object(object) = value else {
+                    return false;
+                };
+                let input_object = self.schema.input_object(id);
+                let are_fields_valid = object.items.iter().all(|field| {
+                    input_object
+                        .fields
+                        .named(ArgumentName(field.name.value))
+                        .is_some_and(|definition| {
+                            self.is_valid_value(&field.value, &definition.type_)
+                        })
+                });
+                let are_required_fields_provided = input_object.fields.iter().all(|definition| {
+                    !definition.type_.is_non_null()
+                        || definition.default_value.is_some()
+                        || object
+                            .items
+                            .iter()
+                            .any(|field| field.name.value == definition.name.item.0)
+                });
+                let is_one_of_valid = input_object.directives.named(*ONE_OF_DIRECTIVE).is_none()
+                    || matches!(
+                        object.items.as_slice(),
+                        [field] if !matches!(field.value, ConstantValue::Null(_))
+                    );
+                are_fields_valid && are_required_fields_provided && is_one_of_valid
+            }
+            Type::Interface(_) | Type::Object(_) | Type::Union(_) => false,
+        }
+    }
+
+    /// Ensures input objects do not reference themselves through non-null
+    /// fields, which would make them impossible to provide.
+    fn validate_input_object_cycles(&mut self) {
+        let mut input_objects = self
+            .schema
+            .get_type_map()
+            .filter_map(|(_, type_)| type_.get_input_object_id())
+            .collect::<Vec<_>>();
+        input_objects.sort_by_key(|id| self.schema.input_object(*id).name.item.0);
+
+        let mut visited = FnvHashSet::default();
+        let mut path = Vec::new();
+        let mut path_index_by_input_object = FnvHashMap::default();
+        for id in input_objects {
+            self.detect_input_object_cycle(
+                id,
+                &mut visited,
+                &mut path,
+                &mut path_index_by_input_object,
+            );
+        }
+    }
+
+    fn detect_input_object_cycle(
+        &mut self,
+        id: InputObjectID,
+        visited: &mut FnvHashSet<InputObjectID>,
+        path: &mut Vec<WithLocation<ArgumentName>>,
+        path_index_by_input_object: &mut FnvHashMap<InputObjectID, usize>,
+    ) {
+        if !visited.insert(id) {
+            return;
+        }
+        path_index_by_input_object.insert(id, path.len());
+        let input_object = self.schema.input_object(id);
+        for field in input_object.fields.iter() {
+            let TypeReference::NonNull(of_type) = &field.type_ else {
+                continue;
+            };
+            let TypeReference::Named(Type::InputObject(field_type_id)) = &**of_type else {
+                continue;
+            };
+            path.push(field.name);
+            match path_index_by_input_object.get(field_type_id) {
+                Some(cycle_index) => {
+                    let cycle = &path[*cycle_index..];
+                    let mut diagnostic = Diagnostic::error(
+                        SchemaValidationError::InputObjectCycle(
+                            self.schema.input_object(*field_type_id).name.item.0,
+                            cycle
+                                .iter()
+                                .map(|field_name| field_name.item.0.lookup())
+                                .collect::<Vec<_>>()
+                                .join("."),
+                        ),
+                        cycle[0].location,
+                    );
+                    for field_name in &cycle[1..] {
+                        diagnostic = diagnostic.annotate("->", field_name.location);
+                    }
+                    self.report_diagnostic(diagnostic);
+                }
+                None => self.detect_input_object_cycle(
+                    *field_type_id,
+                    visited,
+                    path,
+                    path_index_by_input_object,
+                ),
             }
+            path.pop();
         }
+        path_index_by_input_object.remove(&id);
     }
 
     fn validate_type_with_interfaces<T: TypeWithFields + Named>(&mut self, type_: &T) {
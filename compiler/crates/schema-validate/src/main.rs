@@ -35,6 +35,9 @@ pub fn main() {
                 &schema,
                 SchemaValidationOptions {
                     allow_introspection_names: false,
+                    validate_directive_applications: true,
+                    implicit_directives: Default::default(),
+                    validate_input_values: true,
                 },
             ) {
                 let printer = DiagnosticPrinter::new(sources);
@@ -26,6 +26,9 @@ pub async fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String>
             &schema,
             SchemaValidationOptions {
                 allow_introspection_names: false,
+                validate_directive_applications: true,
+                implicit_directives: Default::default(),
+                validate_input_values: true,
             },
         )
     });
@@ -25,6 +25,7 @@ use common::DirectiveName;
 use common::EnumName;
 use common::InputObjectName;
 use common::InterfaceName;
+use common::Location;
 use common::ObjectName;
 use common::ScalarName;
 use common::UnionName;
@@ -605,7 +606,11 @@ impl<'a> Reader<'a> {
     fn r_arg_val(&mut self) -> ArgumentValue {
         let name = ArgumentName(self.r_sk());
         let value = self.r_const_val();
-        ArgumentValue { name, value }
+        ArgumentValue {
+            name,
+            value,
+            location: Location::generated(),
+        }
     }
 
     fn r_dir_val(&mut self) -> DirectiveValue {
@@ -626,6 +626,9 @@ impl Named for Argument {
 pub struct ArgumentValue {
     pub name: ArgumentName,
     pub value: ConstantValue,
+    /// The location of the argument, generated for schemas that are not
+    /// built from SDL.
+    pub location: Location,
 }
 
 impl ArgumentValue {
@@ -15,6 +15,7 @@ use common::DirectiveName;
 use common::EnumName;
 use common::InputObjectName;
 use common::InterfaceName;
+use common::Location;
 use common::ObjectName;
 use common::ScalarName;
 use common::Span;
@@ -445,6 +446,7 @@ impl<'fb> FlatBufferSchema<'fb> {
         Some(ArgumentValue {
             name: ArgumentName(argument.name()?.intern()),
             value: self.parse_const_value(argument.value()?)?,
+            location: Location::generated(),
         })
     }
 
@@ -430,7 +430,8 @@ impl SchemaWrapper {
                             .as_ref()
                             .map(|default_value| default_value.value.clone()),
                         description: None,
-                        directives: self.build_directive_values(&arg_def.directives),
+                        directives: self
+                            .build_directive_values(&arg_def.directives, source_location_key),
                     })
                 })
                 .collect();
@@ -440,7 +441,11 @@ impl SchemaWrapper {
         }
     }
 
-    fn build_directive_values(&self, directives: &[ConstantDirective]) -> Vec<DirectiveValue> {
+    fn build_directive_values(
+        &self,
+        directives: &[ConstantDirective],
+        location_key: SourceLocationKey,
+    ) -> Vec<DirectiveValue> {
         directives
             .iter()
             .map(|directive| {
@@ -451,6 +456,7 @@ impl SchemaWrapper {
                         .map(|argument| ArgumentValue {
                             name: ArgumentName(argument.name.value),
                             value: argument.value.clone(),
+                            location: Location::new(location_key, argument.span),
                         })
                         .collect()
                 } else {
@@ -510,7 +516,8 @@ impl SchemaWrapper {
                     ]);
                 }
                 let arguments = self.build_arguments(&field_def.arguments, source_location_key)?;
-                let directives = self.build_directive_values(&field_def.directives);
+                let directives =
+                    self.build_directive_values(&field_def.directives, source_location_key);
                 let type_ = self.build_type_reference(&field_def.type_, source_location_key)?;
                 let description = field_def.description.as_ref().map(|desc| desc.value);
                 let hack_source = field_def
@@ -556,7 +563,7 @@ impl SchemaWrapper {
         self.overlay_type_map
             .insert(scalar_name.item, Type::Scalar(scalar_id));
 
-        let directives = self.build_directive_values(&scalar.directives);
+        let directives = self.build_directive_values(&scalar.directives, location_key);
         let description = scalar.description.as_ref().map(|desc| desc.value);
 
         self.overlay_scalars.push(Scalar {
@@ -624,7 +631,7 @@ impl SchemaWrapper {
             Some(object_type),
         )?;
 
-        let directives = self.build_directive_values(&object.directives);
+        let directives = self.build_directive_values(&object.directives, location_key);
         let description = object.description.as_ref().map(|desc| desc.value);
 
         self.overlay_objects.push(Object {
@@ -709,7 +716,7 @@ impl SchemaWrapper {
 
         extend_without_duplicates(&mut obj.interfaces, built_interfaces);
 
-        let built_directives = self.build_directive_values(&ext.directives);
+        let built_directives = self.build_directive_values(&ext.directives, location_key);
         extend_without_duplicates(&mut obj.directives, built_directives);
 
         // Update the object (in cache or overlay)
@@ -773,7 +780,7 @@ impl SchemaWrapper {
             .collect::<DiagnosticsResult<Vec<_>>>()?;
         extend_without_duplicates(&mut iface.interfaces, built_interfaces);
 
-        let built_directives = self.build_directive_values(&ext.directives);
+        let built_directives = self.build_directive_values(&ext.directives, location_key);
         extend_without_duplicates(&mut iface.directives, built_directives);
 
         // Update the interface (in cache or overlay)
@@ -1422,7 +1422,7 @@ impl InMemorySchema {
                 // Retain every applied directive (including duplicates) so the
                 // post-build validation pass can detect a non-repeatable
                 // directive applied more than once.
-                let directive_values = self.build_directive_values(directives);
+                let directive_values = self.build_directive_values(directives, *location_key);
                 self.directives.insert(
                     DirectiveName(name.value),
                     Directive {
@@ -1445,7 +1445,7 @@ impl InMemorySchema {
                 directives,
                 ..
             }) => {
-                let directive_values = self.build_directive_values(directives);
+                let directive_values = self.build_directive_values(directives, *location_key);
                 match self.directives.get_mut(&DirectiveName(name.value)) {
                     Some(directive) => {
                         // Retain duplicates so the post-build validation pass can
@@ -1483,7 +1483,7 @@ impl InMemorySchema {
                     .iter()
                     .map(|name| self.build_interface_id(name, location_key))
                     .collect::<DiagnosticsResult<Vec<_>>>()?;
-                let directives = self.build_directive_values(directives);
+                let directives = self.build_directive_values(directives, *location_key);
                 self.objects.push(Object {
                     name: WithLocation::new(
                         Location::new(*location_key, name.span),
@@ -1519,7 +1519,7 @@ impl InMemorySchema {
                     .iter()
                     .map(|name| self.build_interface_id(name, location_key))
                     .collect::<DiagnosticsResult<Vec<_>>>()?;
-                let directives = self.build_directive_values(directives);
+                let directives = self.build_directive_values(directives, *location_key);
                 self.interfaces.push(Interface {
                     name: WithLocation::new(
                         Location::new(*location_key, name.span),
@@ -1545,7 +1545,7 @@ impl InMemorySchema {
                     .iter()
                     .map(|name| self.build_object_id(name.value))
                     .collect::<DiagnosticsResult<Vec<_>>>()?;
-                let directives = self.build_directive_values(directives);
+                let directives = self.build_directive_values(directives, *location_key);
                 self.unions.push(Union {
                     name: WithLocation::new(
                         Location::new(*location_key, name.span),
@@ -1565,7 +1565,7 @@ impl InMemorySchema {
                 ..
             }) => {
                 let fields = self.build_arguments(fields, *location_key)?;
-                let directives = self.build_directive_values(directives);
+                let directives = self.build_directive_values(directives, *location_key);
                 self.input_objects.push(InputObject {
                     name: WithLocation::new(
                         Location::new(*location_key, name.span),
@@ -1584,14 +1584,15 @@ impl InMemorySchema {
                 values,
                 ..
             }) => {
-                let directives = self.build_directive_values(directives);
+                let directives = self.build_directive_values(directives, *location_key);
                 let values = if let Some(values) = values {
                     values
                         .items
                         .iter()
                         .map(|enum_def| EnumValue {
                             value: enum_def.name.value,
-                            directives: self.build_directive_values(&enum_def.directives),
+                            directives: self
+                                .build_directive_values(&enum_def.directives, *location_key),
                             description: enum_def.description.as_ref().map(|d| d.value),
                         })
                         .collect()
@@ -1615,7 +1616,7 @@ impl InMemorySchema {
                 directives,
                 ..
             }) => {
-                let directives = self.build_directive_values(directives);
+                let directives = self.build_directive_values(directives, *location_key);
                 self.scalars.push(Scalar {
                     name: WithLocation::new(
                         Location::new(*location_key, name.span),
@@ -1676,7 +1677,7 @@ impl InMemorySchema {
                         built_interfaces,
                     );
 
-                    let built_directives = self.build_directive_values(directives);
+                    let built_directives = self.build_directive_values(directives, *location_key);
                     self.objects[index].directives.extend(built_directives);
                 }
                 _ => {
@@ -1725,7 +1726,7 @@ impl InMemorySchema {
                         built_interfaces,
                     );
 
-                    let built_directives = self.build_directive_values(directives);
+                    let built_directives = self.build_directive_values(directives, *location_key);
                     self.interfaces[index].directives.extend(built_directives);
                 }
                 _ => {
@@ -1758,7 +1759,10 @@ impl InMemorySchema {
                                 .iter()
                                 .map(|enum_def| EnumValue {
                                     value: enum_def.name.value,
-                                    directives: self.build_directive_values(&enum_def.directives),
+                                    directives: self.build_directive_values(
+                                        &enum_def.directives,
+                                        *location_key,
+                                    ),
                                     description: enum_def.description.as_ref().map(|d| d.value),
                                 })
                                 .collect::<Vec<_>>();
@@ -1767,7 +1771,8 @@ impl InMemorySchema {
                                 updated_values,
                             );
                         }
-                        let built_directives = self.build_directive_values(directives);
+                        let built_directives =
+                            self.build_directive_values(directives, *location_key);
                         self.enums[index].directives.extend(built_directives);
                     }
                     _ => {
@@ -1808,7 +1813,7 @@ impl InMemorySchema {
                         .collect::<DiagnosticsResult<Vec<_>>>()?;
                     extend_without_duplicates(&mut self.unions[index].members, client_members);
 
-                    let built_directives = self.build_directive_values(directives);
+                    let built_directives = self.build_directive_values(directives, *location_key);
                     self.unions[index].directives.extend(built_directives);
                 }
                 _ => {
@@ -1854,7 +1859,7 @@ impl InMemorySchema {
                     }
                     self.input_objects[index].fields.0.extend(client_fields);
 
-                    let built_directives = self.build_directive_values(directives);
+                    let built_directives = self.build_directive_values(directives, *location_key);
                     self.input_objects[index]
                         .directives
                         .extend(built_directives);
@@ -1879,7 +1884,7 @@ impl InMemorySchema {
                             Location::new(*location_key, name.span),
                         )]
                     })?;
-                    let built_directives = self.build_directive_values(directives);
+                    let built_directives = self.build_directive_values(directives, *location_key);
                     self.scalars[index].directives.extend(built_directives);
                 }
                 _ => {
@@ -2009,7 +2014,8 @@ impl InMemorySchema {
                     let arguments =
                         self.build_arguments(&field_def.arguments, field_location_key)?;
                     let type_ = self.build_type_reference(&field_def.type_, field_location_key)?;
-                    let directives = self.build_directive_values(&field_def.directives);
+                    let directives =
+                        self.build_directive_values(&field_def.directives, field_location_key);
                     let description = field_def.description.as_ref().map(|desc| desc.value);
                     let hack_source = field_def
                         .hack_source
@@ -2054,7 +2060,8 @@ impl InMemorySchema {
                     ]);
                 }
                 let arguments = self.build_arguments(&field_def.arguments, source_location_key)?;
-                let directives = self.build_directive_values(&field_def.directives);
+                let directives =
+                    self.build_directive_values(&field_def.directives, source_location_key);
                 let type_ = self.build_type_reference(&field_def.type_, source_location_key)?;
                 let description = field_def.description.as_ref().map(|desc| desc.value);
                 let hack_source = field_def
@@ -2100,8 +2107,9 @@ impl InMemorySchema {
                             .default_value
                             .as_ref()
                             .map(|default_value| default_value.value.clone()),
-                        description: None,
-                        directives: self.build_directive_values(&arg_def.directives),
+                        description: arg_def.description.as_ref().map(|node| node.value),
+                        directives: self
+                            .build_directive_values(&arg_def.directives, source_location_key),
                     })
                 })
                 .collect();
@@ -2164,7 +2172,11 @@ impl InMemorySchema {
         })
     }
 
-    fn build_directive_values(&mut self, directives: &[ConstantDirective]) -> Vec<DirectiveValue> {
+    fn build_directive_values(
+        &mut self,
+        directives: &[ConstantDirective],
+        location_key: SourceLocationKey,
+    ) -> Vec<DirectiveValue> {
         directives
             .iter()
             .map(|directive| {
@@ -2175,6 +2187,7 @@ impl InMemorySchema {
                         .map(|argument| ArgumentValue {
                             name: ArgumentName(argument.name.value),
                             value: argument.value.clone(),
+                            location: Location::new(location_key, argument.span),
                         })
                         .collect()
                 } else {
@@ -35,6 +35,7 @@ const {
   Observable,
   PreloadableQueryRegistry,
   graphql,
+  loadLazyRequest,
 } = require('relay-runtime');
 const {
   createMockEnvironment,
@@ -945,4 +946,95 @@ describe('loadQuery', () => {
       });
     });
   });
+
+  describe('when passed a lazy query AST', () => {
+    let lazyQuery;
+    beforeEach(() => {
+      lazyQuery = {
+        kind: 'LazyRequest',
+        fragment: query.fragment,
+        operationModuleProvider: jest.fn(() =>
+          Promise.resolve({default: query.operation}),
+        ),
+        params: query.params,
+      };
+    });
+
+    it('makes a network request while the AST loads', async () => {
+      const {source} = loadQuery(
+        environment,
+        // $FlowFixMe[incompatible-type]
+        lazyQuery,
+        variables,
+        {
+          fetchPolicy: 'network-only',
+        },
+      );
+      const nextCallback = jest.fn<[unknown], unknown>();
+      // $FlowFixMe[incompatible-use]
+      source.subscribe({next: nextCallback});
+      expect(fetch).toHaveBeenCalled();
+      // $FlowFixMe[method-unbinding] added when improving typing for this parameters
+      expect(environment.executeWithSource).not.toHaveBeenCalled();
+
+      await loadLazyRequest(lazyQuery);
+      // $FlowFixMe[method-unbinding] added when improving typing for this parameters
+      expect(environment.executeWithSource).toHaveBeenCalledTimes(1);
+      // $FlowFixMe[method-unbinding] added when improving typing for this parameters
+      expect(environment.retain).toHaveBeenCalled();
+
+      sink.next(response);
+      expect(nextCallback).toHaveBeenCalledWith(response);
+    });
+
+    it('checks the store once the AST is loaded', async () => {
+      await loadLazyRequest(lazyQuery);
+      // $FlowFixMe[incompatible-type]
+      loadQuery(environment, lazyQuery, variables, {
+        fetchPolicy: 'store-or-network',
+      });
+      // $FlowFixMe[method-unbinding] added when improving typing for this parameters
+      expect(environment.check).toHaveBeenCalled();
+      expect(fetch).not.toHaveBeenCalled();
+    });
+
+    it('calling dispose before the AST loads does not execute the query', async () => {
+      const preloadedQuery = loadQuery(
+        environment,
+        // $FlowFixMe[incompatible-type]
+        lazyQuery,
+        variables,
+        {
+          fetchPolicy: 'network-only',
+        },
+      );
+      preloadedQuery.dispose();
+      expect(networkUnsubscribe).toHaveBeenCalledTimes(1);
+
+      await loadLazyRequest(lazyQuery);
+      // $FlowFixMe[method-unbinding] added when improving typing for this parameters
+      expect(environment.executeWithSource).not.toHaveBeenCalled();
+      // $FlowFixMe[method-unbinding] added when improving typing for this parameters
+      expect(environment.retain).not.toHaveBeenCalled();
+    });
+
+    it('marks failures to load the AST', async () => {
+      lazyQuery.operationModuleProvider.mockImplementation(() =>
+        Promise.reject(new Error('Module load failed')),
+      );
+      const preloadedQuery = loadQuery(
+        environment,
+        // $FlowFixMe[incompatible-type]
+        lazyQuery,
+        variables,
+        {
+          fetchPolicy: 'network-only',
+        },
+      );
+      await expect(loadLazyRequest(lazyQuery)).rejects.toThrow(
+        'Module load failed',
+      );
+      expect(preloadedQuery.networkError?.message).toBe('Module load failed');
+    });
+  });
 });
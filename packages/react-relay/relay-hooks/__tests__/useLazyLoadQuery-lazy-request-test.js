@@ -0,0 +1,68 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @flow
+ * @format
+ * @oncall relay
+ * @jest-environment jsdom
+ */
+
+'use strict';
+
+const RelayEnvironmentProvider = require('../RelayEnvironmentProvider');
+const useLazyLoadQuery = require('../useLazyLoadQuery');
+const ReactTestingLibrary = require('@testing-library/react');
+const React = require('react');
+const {act} = require('react');
+const {loadLazyRequest} = require('relay-runtime');
+const {
+  createMockEnvironment,
+  disallowConsoleErrors,
+  disallowWarnings,
+} = require('relay-test-utils-internal');
+
+disallowWarnings();
+disallowConsoleErrors();
+
+test('suspends while the normalization AST of a lazy request loads', async () => {
+  const query = require('./__generated__/loadQueryTestQuery.graphql');
+  const lazyQuery = {
+    kind: 'LazyRequest',
+    fragment: query.fragment,
+    operationModuleProvider: jest.fn(() =>
+      Promise.resolve({default: query.operation}),
+    ),
+    params: query.params,
+  };
+  const environment = createMockEnvironment();
+
+  function InnerTestComponent() {
+    // $FlowFixMe[incompatible-type]
+    const data = useLazyLoadQuery(lazyQuery, {id: '4'});
+    return data.node?.id ?? 'Unknown node';
+  }
+
+  let renderer;
+  await act(() => {
+    renderer = ReactTestingLibrary.render(
+      <RelayEnvironmentProvider environment={environment}>
+        <React.Suspense fallback="Loading...">
+          <InnerTestComponent />
+        </React.Suspense>
+      </RelayEnvironmentProvider>,
+    );
+  });
+  expect(renderer?.container.textContent).toEqual('Loading...');
+  expect(lazyQuery.operationModuleProvider).toHaveBeenCalledTimes(1);
+
+  await act(() => loadLazyRequest(lazyQuery));
+  await act(() => {
+    environment.mock.resolveMostRecentOperation({
+      data: {node: {__typename: 'User', id: '4'}},
+    });
+  });
+  expect(renderer?.container.textContent).toEqual('4');
+});
@@ -39,6 +39,9 @@ const {
   createOperationDescriptor,
   getRequest,
   getRequestIdentifier,
+  getRequestParams,
+  isRequestLoaded,
+  loadLazyRequest,
 } = require('relay-runtime');
 
 let fetchKey = 100001;
@@ -279,6 +282,25 @@ function loadQuery<
     }
   };
 
+  // Executes the operation once the query AST is loaded, with the network
+  // request started before it was available
+  const executeWithLoadedRequest = (
+    concreteRequest: ConcreteRequest,
+    networkObservable: ?Observable<GraphQLResponse>,
+  ) => {
+    const operation = createOperationDescriptor(
+      concreteRequest,
+      variables,
+      networkCacheConfig,
+    );
+    retainReference = environment.retain(operation);
+    if (networkObservable != null) {
+      executeDeduped(operation, () =>
+        executeWithNetworkSource(operation, networkObservable),
+      );
+    }
+  };
+
   let params;
   let cancelOnLoadCallback: () => void;
   let queryId;
@@ -313,27 +335,44 @@ function loadQuery<
         queryId,
         preloadedModule => {
           cancelOnLoadCallback();
-          const operation = createOperationDescriptor(
-            preloadedModule,
-            variables,
-            networkCacheConfig,
-          );
-          retainReference = environment.retain(operation);
-          if (networkObservable != null) {
-            executeDeduped(operation, () =>
-              executeWithNetworkSource(operation, networkObservable),
-            );
-          }
+          executeWithLoadedRequest(preloadedModule, networkObservable);
         },
       ));
     }
   } else {
     const graphQlTaggedNode: GraphQLTaggedNode =
       preloadableRequest as $FlowFixMe;
-    const request = getRequest(graphQlTaggedNode);
-    params = request.params;
-    queryId = params.cacheID != null ? params.cacheID : params.id;
-    checkAvailabilityAndExecute(request);
+    if (isRequestLoaded(graphQlTaggedNode)) {
+      const request = getRequest(graphQlTaggedNode);
+      params = request.params;
+      queryId = params.cacheID != null ? params.cacheID : params.id;
+      checkAvailabilityAndExecute(request);
+    } else {
+      // The normalization AST of a lazy request isn't loaded yet: as for a
+      // preloadable query whose module isn't available, the network request
+      // is started while the AST loads.
+      params = getRequestParams(graphQlTaggedNode);
+      queryId = params.cacheID != null ? params.cacheID : params.id;
+      const networkObservable =
+        fetchPolicy === 'store-only' ? null : makeNetworkRequest(params);
+      let isLoadCancelled = false;
+      cancelOnLoadCallback = () => {
+        isLoadCancelled = true;
+      };
+      loadLazyRequest(graphQlTaggedNode).then(
+        request => {
+          if (!isLoadCancelled) {
+            executeWithLoadedRequest(request, networkObservable);
+          }
+        },
+        error => {
+          if (!isLoadCancelled) {
+            networkError = error;
+            executionSubject.error(error);
+          }
+        },
+      );
+    }
   }
 
   let isDisposed = false;
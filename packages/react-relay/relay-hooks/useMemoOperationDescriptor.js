@@ -20,7 +20,12 @@ import type {
 
 const useMemoVariables = require('./useMemoVariables');
 const React = require('react');
-const {createOperationDescriptor, getRequest} = require('relay-runtime');
+const {
+  createOperationDescriptor,
+  getRequest,
+  isRequestLoaded,
+  loadLazyRequest,
+} = require('relay-runtime');
 
 const {useMemo} = React;
 
@@ -31,6 +36,10 @@ hook useMemoOperationDescriptor(
 ): OperationDescriptor {
   const memoVariables = useMemoVariables(variables);
   const memoCacheConfig = useMemoVariables(cacheConfig || {});
+  if (!isRequestLoaded(gqlQuery)) {
+    // Suspend while the normalization AST of a lazy request loads
+    throw loadLazyRequest(gqlQuery);
+  }
   return useMemo(
     () =>
       createOperationDescriptor(
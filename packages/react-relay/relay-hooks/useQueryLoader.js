@@ -28,7 +28,7 @@ const useIsMountedRef = require('./useIsMountedRef');
 const useQueryLoader_EXPERIMENTAL = require('./useQueryLoader_EXPERIMENTAL');
 const useRelayEnvironment = require('./useRelayEnvironment');
 const {useCallback, useEffect, useRef, useState} = require('react');
-const {RelayFeatureFlags, getRequest} = require('relay-runtime');
+const {RelayFeatureFlags, getRequestParams} = require('relay-runtime');
 
 export type LoaderFn<TQuery extends OperationType> = (
   variables: TQuery['variables'],
@@ -67,8 +67,8 @@ function requestIsLiveQuery<
   if (preloadableRequest.kind === 'PreloadableConcreteRequest') {
     return preloadableRequest.params.metadata.live !== undefined;
   }
-  const request = getRequest(preloadableRequest);
-  return request.params.metadata.live !== undefined;
+  const params = getRequestParams(preloadableRequest);
+  return params.metadata.live !== undefined;
 }
 
 export type UseQueryLoaderHookReturnType<
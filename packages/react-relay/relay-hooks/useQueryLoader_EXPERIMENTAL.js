@@ -33,7 +33,7 @@ const {
   useRef,
   useState,
 } = require('react');
-const {getRequest} = require('relay-runtime');
+const {getRequestParams} = require('relay-runtime');
 
 const initialNullQueryReferenceState: NullQueryReference = {
   kind: 'NullQueryReference',
@@ -56,8 +56,8 @@ function requestIsLiveQuery<
   if (preloadableRequest.kind === 'PreloadableConcreteRequest') {
     return preloadableRequest.params.metadata.live !== undefined;
   }
-  const request = getRequest(preloadableRequest);
-  return request.params.metadata.live !== undefined;
+  const params = getRequestParams(preloadableRequest);
+  return params.metadata.live !== undefined;
 }
 
 const CLEANUP_TIMEOUT = 1000 * 60 * 5; // 5 minutes;
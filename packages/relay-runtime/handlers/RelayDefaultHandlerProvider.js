@@ -15,6 +15,7 @@ import type {Handler} from '../store/RelayStoreTypes';
 
 const ConnectionHandler = require('./connection/ConnectionHandler');
 const MutationHandlers = require('./connection/MutationHandlers');
+const PaginatedListHandler = require('./paginatedList/PaginatedListHandler');
 const invariant = require('invariant');
 
 export type HandlerProvider = (name: string) => ?Handler;
@@ -35,6 +36,14 @@ function RelayDefaultHandlerProvider(handle: string): Handler {
       return MutationHandlers.AppendNodeHandler;
     case 'prependNode':
       return MutationHandlers.PrependNodeHandler;
+    case 'appendToList':
+      return MutationHandlers.AppendToListHandler;
+    case 'prependToList':
+      return MutationHandlers.PrependToListHandler;
+    case 'deleteFromList':
+      return MutationHandlers.DeleteFromListHandler;
+    case 'paginatedList':
+      return PaginatedListHandler;
   }
   invariant(
     false,
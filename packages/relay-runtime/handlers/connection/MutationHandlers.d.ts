@@ -14,4 +14,7 @@ export const MutationHandlers: {
     PrependEdgeHandler: Handler;
     AppendNodeHandler: Handler;
     PrependNodeHandler: Handler;
+    AppendToListHandler: Handler;
+    PrependToListHandler: Handler;
+    DeleteFromListHandler: Handler;
 };
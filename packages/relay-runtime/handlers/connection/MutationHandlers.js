@@ -91,6 +91,40 @@ const PrependNodeHandler: Handler = {
   update: nodeUpdater(ConnectionHandler.insertEdgeBefore),
 };
 
+const AppendToListHandler: Handler = {
+  update: listUpdater((items, newItems) => items.concat(newItems)),
+};
+
+const PrependToListHandler: Handler = {
+  update: listUpdater((items, newItems) => newItems.concat(items)),
+};
+
+const DeleteFromListHandler = {
+  update: (store: RecordSourceProxy, payload: HandleFieldPayload) => {
+    const record = store.get(payload.dataID);
+    if (record == null) {
+      return;
+    }
+    const parent = getListParent(store, payload);
+    if (parent == null) {
+      return;
+    }
+    const {fieldName, fieldArgs} = payload.handleArgs;
+    const items = parent.getLinkedRecords(fieldName, fieldArgs);
+    if (items == null) {
+      return;
+    }
+    const idOrIds = record.getValue(payload.fieldKey);
+    const idList = Array.isArray(idOrIds) ? idOrIds : [idOrIds];
+    const nextItems = items.filter(
+      item => item == null || !idList.includes(item.getDataID()),
+    );
+    if (nextItems.length !== items.length) {
+      parent.setLinkedRecords(nextItems, fieldName, fieldArgs);
+    }
+  },
+};
+
 function edgeUpdater(
   insertFn: (RecordProxy, RecordProxy, ?string) => void,
 ): (RecordSourceProxy, HandleFieldPayload) => void {
@@ -236,6 +270,82 @@ function nodeUpdater(
   };
 }
 
+function listUpdater(
+  insertFn: (Array<?RecordProxy>, Array<RecordProxy>) => Array<?RecordProxy>,
+): (RecordSourceProxy, HandleFieldPayload) => void {
+  return (store: RecordSourceProxy, payload: HandleFieldPayload) => {
+    const record = store.get(payload.dataID);
+    if (record == null) {
+      return;
+    }
+    const parent = getListParent(store, payload);
+    if (parent == null) {
+      return;
+    }
+    let singleServerRecord;
+    let serverRecords;
+    try {
+      singleServerRecord = record.getLinkedRecord(payload.fieldKey);
+    } catch {}
+    if (!singleServerRecord) {
+      try {
+        serverRecords = record.getLinkedRecords(payload.fieldKey);
+      } catch {}
+    }
+    if (singleServerRecord == null && serverRecords == null) {
+      warning(false, 'MutationHandlers: Expected target record to exist.');
+      return;
+    }
+    const {fieldName, fieldArgs} = payload.handleArgs;
+    const items = parent.getLinkedRecords(fieldName, fieldArgs);
+    if (items == null) {
+      // The list has not been fetched, so adding the records to it would
+      // make it look complete.
+      return;
+    }
+    const newItems = [];
+    for (const serverRecord of serverRecords ?? [singleServerRecord]) {
+      if (serverRecord == null) {
+        continue;
+      }
+      const serverRecordID = serverRecord.getDataID();
+      const recordAlreadyExistsInList =
+        items.some(item => item?.getDataID() === serverRecordID) ||
+        newItems.some(item => item.getDataID() === serverRecordID);
+      if (!recordAlreadyExistsInList) {
+        newItems.push(serverRecord);
+      }
+    }
+    if (newItems.length > 0) {
+      parent.setLinkedRecords(insertFn(items, newItems), fieldName, fieldArgs);
+    }
+  };
+}
+
+/**
+ * Returns the record holding the list field of `@appendToList`,
+ * `@prependToList` and `@deleteFromList`.
+ */
+function getListParent(
+  store: RecordSourceProxy,
+  payload: HandleFieldPayload,
+): ?RecordProxy {
+  const {parentID, fieldName} = payload.handleArgs;
+  invariant(
+    parentID != null,
+    'MutationHandlers: Expected the parent ID of the list to be specified.',
+  );
+  invariant(
+    typeof fieldName === 'string',
+    'MutationHandlers: Expected the list field name to be specified.',
+  );
+  const parent = store.get(parentID);
+  if (parent == null) {
+    warning(false, "[Relay] The record with id `%s` doesn't exist.", parentID);
+  }
+  return parent;
+}
+
 module.exports = {
   AppendEdgeHandler,
   DeleteRecordHandler,
@@ -243,4 +353,7 @@ module.exports = {
   AppendNodeHandler,
   PrependNodeHandler,
   DeleteEdgeHandler,
+  AppendToListHandler,
+  PrependToListHandler,
+  DeleteFromListHandler,
 };
@@ -0,0 +1,125 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @format
+ * @oncall relay
+ */
+
+'use strict';
+
+const RelayRecordSourceMutator = require('../../../mutations/RelayRecordSourceMutator');
+const RelayRecordSourceProxy = require('../../../mutations/RelayRecordSourceProxy');
+const defaultGetDataID = require('../../../store/defaultGetDataID');
+const RelayRecordSource = require('../../../store/RelayRecordSource');
+const RelayStoreUtils = require('../../../store/RelayStoreUtils');
+const {
+  AppendToListHandler,
+  DeleteFromListHandler,
+  PrependToListHandler,
+} = require('../MutationHandlers');
+
+const {ID_KEY, REF_KEY, REFS_KEY, TYPENAME_KEY, getStableStorageKey} =
+  RelayStoreUtils;
+
+describe('list mutation handlers', () => {
+  const listKey = getStableStorageKey('usernames', {names: ['zuck']});
+  const handleArgs = {
+    parentID: 'client:root',
+    fieldName: 'usernames',
+    fieldArgs: {names: ['zuck']},
+  };
+
+  let baseSource;
+  let sinkSource;
+  let proxy;
+
+  function getItemIDs() {
+    const root = {
+      ...baseSource.get('client:root'),
+      ...sinkSource.get('client:root'),
+    };
+    return root[listKey]?.[REFS_KEY];
+  }
+
+  beforeEach(() => {
+    baseSource = RelayRecordSource.create({
+      'client:root': {
+        [ID_KEY]: 'client:root',
+        [TYPENAME_KEY]: '__Root',
+        [listKey]: {[REFS_KEY]: ['1', '2']},
+      },
+      '1': {[ID_KEY]: '1', [TYPENAME_KEY]: 'User'},
+      '2': {[ID_KEY]: '2', [TYPENAME_KEY]: 'User'},
+      '3': {[ID_KEY]: '3', [TYPENAME_KEY]: 'User'},
+      'client:mutation': {
+        [ID_KEY]: 'client:mutation',
+        [TYPENAME_KEY]: 'ActorSubscribeResponsePayload',
+        subscribee: {[REF_KEY]: '3'},
+        existing: {[REF_KEY]: '2'},
+        deletedID: '1',
+        deletedIDs: ['1', '2'],
+      },
+    });
+    sinkSource = RelayRecordSource.create();
+    proxy = new RelayRecordSourceProxy(
+      new RelayRecordSourceMutator(baseSource, sinkSource),
+      defaultGetDataID,
+    );
+  });
+
+  function update(handler, fieldKey) {
+    handler.update(proxy, {
+      args: {},
+      dataID: 'client:mutation',
+      fieldKey,
+      handle: '',
+      handleKey: '',
+      handleArgs,
+    });
+  }
+
+  it('appends the record to the list', () => {
+    update(AppendToListHandler, 'subscribee');
+    expect(getItemIDs()).toEqual(['1', '2', '3']);
+  });
+
+  it('prepends the record to the list', () => {
+    update(PrependToListHandler, 'subscribee');
+    expect(getItemIDs()).toEqual(['3', '1', '2']);
+  });
+
+  it('does not add records that are already in the list', () => {
+    update(AppendToListHandler, 'existing');
+    expect(getItemIDs()).toEqual(['1', '2']);
+  });
+
+  it('does not add records to a list that was not fetched', () => {
+    baseSource.set('client:root', {
+      [ID_KEY]: 'client:root',
+      [TYPENAME_KEY]: '__Root',
+    });
+    update(AppendToListHandler, 'subscribee');
+    expect(getItemIDs()).toBe(undefined);
+  });
+
+  it('removes the record with the given ID from the list', () => {
+    update(DeleteFromListHandler, 'deletedID');
+    expect(getItemIDs()).toEqual(['2']);
+  });
+
+  it('removes the records with the given IDs from the list', () => {
+    update(DeleteFromListHandler, 'deletedIDs');
+    expect(getItemIDs()).toEqual([]);
+  });
+
+  it('warns if the parent record does not exist', () => {
+    baseSource.delete('client:root');
+    expect(() => update(AppendToListHandler, 'subscribee')).toWarn([
+      "[Relay] The record with id `%s` doesn't exist.",
+      'client:root',
+    ]);
+  });
+});
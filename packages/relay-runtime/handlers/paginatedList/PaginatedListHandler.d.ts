@@ -0,0 +1,30 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+import {
+  HandleFieldPayload,
+  ReadOnlyRecordProxy,
+  RecordProxy,
+  RecordSourceProxy,
+} from '../../store/RelayStoreTypes';
+import {DataID, Variables} from '../../util/RelayRuntimeTypes';
+
+export interface PaginatedListMetadata {
+    path: readonly string[] | null | undefined;
+    offset: string | null | undefined;
+    limit: string | null | undefined;
+}
+
+export function getPaginatedList(
+    record: ReadOnlyRecordProxy,
+    key: string,
+    filters?: Variables | null,
+): RecordProxy | null | undefined;
+
+export function getPaginatedListID(recordID: DataID, key: string, filters?: Variables | null): DataID;
+
+export function update(store: RecordSourceProxy, payload: HandleFieldPayload): void;
@@ -0,0 +1,187 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @flow
+ * @format
+ * @oncall relay
+ */
+
+'use strict';
+
+import type {
+  HandleFieldPayload,
+  ReadOnlyRecordProxy,
+  RecordProxy,
+  RecordSourceProxy,
+} from '../../store/RelayStoreTypes';
+import type {DataID, Variables} from '../../util/RelayRuntimeTypes';
+
+const {generateClientID} = require('../../store/ClientID');
+const {getStableStorageKey} = require('../../store/RelayStoreUtils');
+const getRelayHandleKey = require('../../util/getRelayHandleKey');
+const PaginatedListInterface = require('./PaginatedListInterface');
+const warning = require('warning');
+
+export type PaginatedListMetadata = {
+  path: ?Array<string>,
+  offset: ?string,
+  limit: ?string,
+  ...
+};
+
+const PAGINATED_LIST = 'paginatedList';
+
+/**
+ * @public
+ *
+ * A default runtime handler for offset based list fields annotated with
+ * `@paginatedList`. The items of each fetched page are written at their
+ * offset into a single client list, so that loading the next page appends
+ * its items, and refetching from offset 0 replaces them.
+ */
+function update(store: RecordSourceProxy, payload: HandleFieldPayload): void {
+  const record = store.get(payload.dataID);
+  if (!record) {
+    return;
+  }
+
+  const {ITEMS, OFFSET} = PaginatedListInterface.get();
+
+  const serverList = record.getLinkedRecord(payload.fieldKey);
+  if (!serverList) {
+    record.setValue(
+      null,
+      payload.handleKey,
+      undefined,
+      record.getErrors(payload.fieldKey),
+    );
+    return;
+  }
+  // As for connections, the handleKey field may be unset even though the
+  // client list record exists, in which case items are still merged into it.
+  const clientListID = generateClientID(record.getDataID(), payload.handleKey);
+  const clientListField = record.getLinkedRecord(payload.handleKey);
+  const clientList = clientListField ?? store.get(clientListID);
+  if (!clientList) {
+    // Initial fetch with data: copy fields from the server record
+    const list = store.create(clientListID, serverList.getType());
+    list.copyFieldsFrom(serverList);
+    record.setLinkedRecord(list, payload.handleKey);
+    return;
+  }
+
+  const serverItems = serverList.getLinkedRecords(ITEMS);
+  const prevItems = clientList.getLinkedRecords(ITEMS);
+  const offset = payload.args[OFFSET];
+  let nextItems = serverItems;
+  if (typeof offset === 'number' && offset > 0) {
+    if (prevItems == null || serverItems == null) {
+      nextItems = prevItems ?? serverItems;
+    } else if (offset <= prevItems.length) {
+      // Next page, or a page that overlaps the loaded items: replace the
+      // items at the fetched offsets, de-duplicating by id.
+      nextItems = [];
+      const itemIDs = new Set<DataID>();
+      mergeItems(prevItems.slice(0, offset), nextItems, itemIDs);
+      mergeItems(serverItems, nextItems, itemIDs);
+      mergeItems(
+        prevItems.slice(offset + serverItems.length),
+        nextItems,
+        itemIDs,
+      );
+    } else {
+      warning(
+        false,
+        'Relay: Unexpected offset `%s`, items of a paginated list must be ' +
+          'fetched from the end of the list (`%s`).',
+        offset,
+        prevItems.length,
+      );
+      return;
+    }
+  }
+
+  if (clientListField == null) {
+    // If the handleKey field was unset but the client list record existed,
+    // update the field to point to the record
+    record.setLinkedRecord(clientList, payload.handleKey);
+  }
+  // Update the other fields of the list, e.g. the total count
+  clientList.copyFieldsFrom(serverList);
+  if (nextItems != null) {
+    clientList.setLinkedRecords(nextItems, ITEMS);
+  }
+}
+
+/**
+ * @public
+ *
+ * Given a record and the key of a `@paginatedList` field, returns the linked
+ * client list record, which holds the items of all the pages loaded so far.
+ *
+ * ```
+ * store => {
+ *   const user = store.get('<id>');
+ *   const friends = PaginatedListHandler.getPaginatedList(user, 'FriendsFragment_friendsList');
+ *   const items = friends?.getLinkedRecords('items');
+ * }
+ * ```
+ */
+function getPaginatedList(
+  record: ReadOnlyRecordProxy,
+  key: string,
+  filters?: ?Variables,
+): ?RecordProxy {
+  const handleKey = getRelayHandleKey(PAGINATED_LIST, key, null);
+  return record.getLinkedRecord(handleKey, filters);
+}
+
+/**
+ * @public
+ *
+ * Given a record ID, the key of a `@paginatedList` field, and optional filters
+ * used to identify the list, returns the ID of the client list record.
+ */
+function getPaginatedListID(
+  recordID: DataID,
+  key: string,
+  filters?: ?Variables,
+): DataID {
+  const handleKey = getRelayHandleKey(PAGINATED_LIST, key, null);
+  const storageKey = getStableStorageKey(handleKey, filters);
+  return generateClientID(recordID, storageKey);
+}
+
+/**
+ * @internal
+ *
+ * Adds the source items to the target items, skipping null items and items
+ * whose id was already added.
+ */
+function mergeItems(
+  sourceItems: ReadonlyArray<?RecordProxy>,
+  targetItems: Array<?RecordProxy>,
+  itemIDs: Set<DataID>,
+): void {
+  for (let ii = 0; ii < sourceItems.length; ii++) {
+    const item = sourceItems[ii];
+    if (!item) {
+      continue;
+    }
+    const itemID = item.getDataID();
+    if (itemIDs.has(itemID)) {
+      continue;
+    }
+    itemIDs.add(itemID);
+    targetItems.push(item);
+  }
+}
+
+module.exports = {
+  getPaginatedList,
+  getPaginatedListID,
+  update,
+};
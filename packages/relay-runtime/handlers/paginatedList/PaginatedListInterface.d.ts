@@ -0,0 +1,21 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+interface PaginatedListConfig {
+    ITEMS: string;
+    LIMIT: string;
+    OFFSET: string;
+    TOTAL_COUNT: string;
+}
+
+declare const PaginatedListInterface: {
+    get(): PaginatedListConfig;
+
+    inject(newConfig: PaginatedListConfig): void;
+};
+
+export default PaginatedListInterface;
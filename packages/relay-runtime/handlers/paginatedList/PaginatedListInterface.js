@@ -0,0 +1,45 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @flow strict-local
+ * @format
+ * @oncall relay
+ */
+
+'use strict';
+
+type PaginatedListConfig = {
+  ITEMS: string,
+  LIMIT: string,
+  OFFSET: string,
+  TOTAL_COUNT: string,
+};
+
+let config: PaginatedListConfig = {
+  ITEMS: 'items',
+  LIMIT: 'limit',
+  OFFSET: 'offset',
+  TOTAL_COUNT: 'totalCount',
+};
+
+/**
+ * @internal
+ *
+ * Defines the field and argument names of offset based lists annotated with
+ * `@paginatedList`. Must match the `paginatedListInterface` of the compiler's
+ * schema config.
+ */
+const PaginatedListInterface = {
+  inject(newConfig: PaginatedListConfig) {
+    config = newConfig;
+  },
+
+  get(): PaginatedListConfig {
+    return config;
+  },
+};
+
+module.exports = PaginatedListInterface;
@@ -0,0 +1,186 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @format
+ * @oncall relay
+ */
+
+'use strict';
+
+const RelayRecordSourceMutator = require('../../../mutations/RelayRecordSourceMutator');
+const RelayRecordSourceProxy = require('../../../mutations/RelayRecordSourceProxy');
+const defaultGetDataID = require('../../../store/defaultGetDataID');
+const RelayRecordSource = require('../../../store/RelayRecordSource');
+const RelayStoreUtils = require('../../../store/RelayStoreUtils');
+const getRelayHandleKey = require('../../../util/getRelayHandleKey');
+const PaginatedListHandler = require('../PaginatedListHandler');
+
+const {ID_KEY, REF_KEY, REFS_KEY, TYPENAME_KEY, getStableStorageKey} =
+  RelayStoreUtils;
+
+describe('PaginatedListHandler', () => {
+  const handleKey = getRelayHandleKey(
+    'paginatedList',
+    'FriendsList_friendsList',
+    'friendsList',
+  );
+  const clientListID = 'client:4:' + handleKey;
+
+  let baseSource;
+  let sinkSource;
+  let proxy;
+
+  // Writes the page of a list fetched with the given offset and limit, and
+  // returns the payload of its handle field.
+  function writePage(offset, ids, totalCount = 5) {
+    const args = {limit: ids.length, offset};
+    const fieldKey = getStableStorageKey('friendsList', args);
+    const listID = 'client:4:' + fieldKey;
+    baseSource.set(listID, {
+      [ID_KEY]: listID,
+      [TYPENAME_KEY]: 'FriendsList',
+      items: {[REFS_KEY]: ids},
+      totalCount,
+    });
+    for (const id of ids) {
+      baseSource.set(id, {[ID_KEY]: id, [TYPENAME_KEY]: 'User'});
+    }
+    baseSource.set('4', {
+      ...baseSource.get('4'),
+      [fieldKey]: {[REF_KEY]: listID},
+    });
+    return {
+      args,
+      dataID: '4',
+      fieldKey,
+      handle: 'paginatedList',
+      handleKey,
+      handleArgs: {},
+    };
+  }
+
+  function update(payload) {
+    PaginatedListHandler.update(proxy, payload);
+    // Later updates read the fields written by earlier ones
+    const records = baseSource.toJSON();
+    const changes = sinkSource.toJSON();
+    for (const dataID of Object.keys(changes)) {
+      records[dataID] =
+        changes[dataID] == null
+          ? changes[dataID]
+          : {...records[dataID], ...changes[dataID]};
+    }
+    baseSource = RelayRecordSource.create(records);
+    sinkSource = RelayRecordSource.create();
+    proxy = new RelayRecordSourceProxy(
+      new RelayRecordSourceMutator(baseSource, sinkSource),
+      defaultGetDataID,
+    );
+  }
+
+  beforeEach(() => {
+    baseSource = RelayRecordSource.create({
+      '4': {[ID_KEY]: '4', [TYPENAME_KEY]: 'User'},
+    });
+    sinkSource = RelayRecordSource.create();
+    proxy = new RelayRecordSourceProxy(
+      new RelayRecordSourceMutator(baseSource, sinkSource),
+      defaultGetDataID,
+    );
+  });
+
+  describe('getPaginatedListID()', () => {
+    it('returns the list ID when no filters are specified', () => {
+      expect(
+        PaginatedListHandler.getPaginatedListID('4', 'FriendsList_friendsList'),
+      ).toBe('client:4:__FriendsList_friendsList_paginatedList');
+    });
+
+    it('returns the list ID when filters are specified', () => {
+      expect(
+        PaginatedListHandler.getPaginatedListID(
+          '4',
+          'FriendsList_friendsList',
+          {orderby: ['name']},
+        ),
+      ).toBe(
+        'client:4:__FriendsList_friendsList_paginatedList(orderby:["name"])',
+      );
+    });
+  });
+
+  describe('update()', () => {
+    it('creates a client list with the items of the first page', () => {
+      update(writePage(0, ['1', '2']));
+      expect(baseSource.get('4')[handleKey]).toEqual({
+        [REF_KEY]: clientListID,
+      });
+      expect(baseSource.get(clientListID)).toEqual({
+        [ID_KEY]: clientListID,
+        [TYPENAME_KEY]: 'FriendsList',
+        items: {[REFS_KEY]: ['1', '2']},
+        totalCount: 5,
+      });
+    });
+
+    it('appends the items of the next page', () => {
+      update(writePage(0, ['1', '2']));
+      update(writePage(2, ['3', '4'], 6));
+      expect(baseSource.get(clientListID).items).toEqual({
+        [REFS_KEY]: ['1', '2', '3', '4'],
+      });
+      expect(baseSource.get(clientListID).totalCount).toBe(6);
+    });
+
+    it('replaces the items of a page that was fetched again', () => {
+      update(writePage(0, ['1', '2']));
+      update(writePage(2, ['3', '4']));
+      update(writePage(1, ['5', '3']));
+      expect(baseSource.get(clientListID).items).toEqual({
+        [REFS_KEY]: ['1', '5', '3', '4'],
+      });
+    });
+
+    it('replaces the items when the list is fetched from the start', () => {
+      update(writePage(0, ['1', '2']));
+      update(writePage(2, ['3', '4']));
+      update(writePage(0, ['5']));
+      expect(baseSource.get(clientListID).items).toEqual({
+        [REFS_KEY]: ['5'],
+      });
+    });
+
+    it('ignores pages fetched past the end of the list', () => {
+      update(writePage(0, ['1', '2']));
+      expect(() => update(writePage(3, ['4', '5']))).toWarn([
+        'Relay: Unexpected offset `%s`, items of a paginated list must be ' +
+          'fetched from the end of the list (`%s`).',
+        3,
+        2,
+      ]);
+      expect(baseSource.get(clientListID).items).toEqual({
+        [REFS_KEY]: ['1', '2'],
+      });
+    });
+
+    it('sets the handle field to null when the server list is null', () => {
+      baseSource.set('4', {
+        [ID_KEY]: '4',
+        [TYPENAME_KEY]: 'User',
+        'friendsList(limit:2,offset:0)': null,
+      });
+      update({
+        args: {limit: 2, offset: 0},
+        dataID: '4',
+        fieldKey: 'friendsList(limit:2,offset:0)',
+        handle: 'paginatedList',
+        handleKey,
+        handleArgs: {},
+      });
+      expect(baseSource.get('4')[handleKey]).toBe(null);
+    });
+  });
+});
@@ -7,6 +7,8 @@
 
 import * as ConnectionHandler from './handlers/connection/ConnectionHandler';
 import ConnectionInterface from './handlers/connection/ConnectionInterface';
+import * as PaginatedListHandler from './handlers/paginatedList/PaginatedListHandler';
+import PaginatedListInterface from './handlers/paginatedList/PaginatedListInterface';
 // Extensions
 import RelayDefaultHandlerProvider from './handlers/RelayDefaultHandlerProvider';
 import QueryResponseCache from './network/RelayQueryResponseCache';
@@ -17,6 +19,8 @@ import withProvidedVariables from './util/withProvidedVariables';
 export { ConnectionInterface };
 export { ConnectionMetadata } from './handlers/connection/ConnectionHandler';
 export { EdgeRecord, PageInfo } from './handlers/connection/ConnectionInterface';
+export { PaginatedListInterface };
+export { PaginatedListMetadata } from './handlers/paginatedList/PaginatedListHandler';
 export { OptimisticMutationConfig } from './mutations/applyOptimisticMutation';
 export { MutationConfig, MutationParameters } from './mutations/commitMutation';
 export {
@@ -55,11 +59,15 @@ export {
     getPaginationFragment,
     getRefetchableFragment,
     getRequest,
+    getRequestParams,
     graphql,
     GraphQLTaggedNode,
     isFragment,
     isInlineDataFragment,
+    isLazyRequest,
     isRequest,
+    isRequestLoaded,
+    loadLazyRequest,
 } from './query/GraphQLTag';
 export { generateClientID, generateUniqueClientID, isClientID } from './store/ClientID';
 export { TaskScheduler } from './store/OperationExecutor';
@@ -153,6 +161,7 @@ export {
     ConcreteRequest,
     ConcreteUpdatableQuery,
     GeneratedNode,
+    LazyConcreteRequest,
     PreloadableConcreteRequest,
     RequestParameters,
 } from './util/RelayConcreteNode';
@@ -203,6 +212,7 @@ export { RelayDefaultHandlerProvider as DefaultHandlerProvider };
 export declare function getDefaultMissingFieldHandlers(): import('./store/RelayStoreTypes').MissingFieldHandler[];
 export { ConnectionHandler };
 export { MutationHandlers } from './handlers/connection/MutationHandlers';
+export { PaginatedListHandler };
 export { VIEWER_ID, VIEWER_TYPE } from './store/ViewerPattern';
 // Helpers (can be implemented via the above API)
 export { applyOptimisticMutation } from './mutations/applyOptimisticMutation';
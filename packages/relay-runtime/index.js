@@ -21,6 +21,8 @@ const {isErrorResult, isValueResult} = require('./experimental');
 const ConnectionHandler = require('./handlers/connection/ConnectionHandler');
 const ConnectionInterface = require('./handlers/connection/ConnectionInterface');
 const MutationHandlers = require('./handlers/connection/MutationHandlers');
+const PaginatedListHandler = require('./handlers/paginatedList/PaginatedListHandler');
+const PaginatedListInterface = require('./handlers/paginatedList/PaginatedListInterface');
 const RelayDefaultHandlerProvider = require('./handlers/RelayDefaultHandlerProvider');
 const applyOptimisticMutation = require('./mutations/applyOptimisticMutation');
 const commitLocalUpdate = require('./mutations/commitLocalUpdate');
@@ -93,6 +95,7 @@ export type {
   EdgeRecord,
   PageInfo,
 } from './handlers/connection/ConnectionInterface';
+export type {PaginatedListMetadata} from './handlers/paginatedList/PaginatedListHandler';
 export type {
   DeclarativeMutationConfig,
   MutationType,
@@ -223,6 +226,7 @@ export type {
   ClientRequest,
   ConcreteUpdatableQuery,
   GeneratedNode,
+  LazyConcreteRequest,
   RequestParameters,
 } from './util/RelayConcreteNode';
 export type {
@@ -311,10 +315,14 @@ const {
   getPaginationFragment,
   getRefetchableFragment,
   getRequest,
+  getRequestParams,
   graphql,
   isFragment,
   isInlineDataFragment,
+  isLazyRequest,
   isRequest,
+  isRequestLoaded,
+  loadLazyRequest,
 } = GraphQLTag;
 
 const {readFragment} = ResolverFragments;
@@ -375,6 +383,7 @@ module.exports = {
   getPluralSelector,
   getRefetchableFragment,
   getRequest,
+  getRequestParams,
   getRequestIdentifier: getRequestIdentifier,
   getSelector,
   getSelectorsFromObject,
@@ -390,9 +399,12 @@ module.exports = {
   isValueResult: isValueResult,
   isFragment,
   isInlineDataFragment,
+  isLazyRequest,
   isSuspenseSentinel,
   suspenseSentinel,
   isRequest,
+  isRequestLoaded,
+  loadLazyRequest,
   readInlineData,
   readFragment,
 
@@ -404,6 +416,7 @@ module.exports = {
   DefaultHandlerProvider: RelayDefaultHandlerProvider,
   ConnectionHandler,
   MutationHandlers,
+  PaginatedListHandler,
   VIEWER_ID,
   VIEWER_TYPE,
 
@@ -418,6 +431,7 @@ module.exports = {
 
   // Configuration interface for legacy or special uses
   ConnectionInterface,
+  PaginatedListInterface,
 
   // Utilities
   PreloadableQueryRegistry,
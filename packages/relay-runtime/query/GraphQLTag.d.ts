@@ -11,12 +11,13 @@ import {
   ReaderPaginationFragment,
   ReaderRefetchableFragment,
 } from '../util/ReaderNode';
-import { ConcreteRequest } from '../util/RelayConcreteNode';
+import { ConcreteRequest, LazyConcreteRequest, RequestParameters } from '../util/RelayConcreteNode';
 
 // The type of a graphql`...` tagged template expression.
 export type GraphQLTaggedNode =
     | ReaderFragment
     | ConcreteRequest
+    | LazyConcreteRequest
     | ReaderInlineDataFragment
     | (() => ReaderFragment | ConcreteRequest | ReaderInlineDataFragment);
 
@@ -32,6 +33,8 @@ export function isFragment(node: GraphQLTaggedNode): boolean;
 
 export function isRequest(node: GraphQLTaggedNode): boolean;
 
+export function isLazyRequest(node: GraphQLTaggedNode): boolean;
+
 export function isInlineDataFragment(node: GraphQLTaggedNode): boolean;
 
 export function getFragment(taggedNode: GraphQLTaggedNode): ReaderFragment;
@@ -42,4 +45,10 @@ export function getRefetchableFragment(taggedNode: GraphQLTaggedNode): ReaderRef
 
 export function getRequest(taggedNode: GraphQLTaggedNode): ConcreteRequest;
 
+export function getRequestParams(taggedNode: GraphQLTaggedNode): RequestParameters;
+
+export function isRequestLoaded(taggedNode: GraphQLTaggedNode): boolean;
+
+export function loadLazyRequest(taggedNode: GraphQLTaggedNode): Promise<ConcreteRequest>;
+
 export function getInlineDataFragment(taggedNode: GraphQLTaggedNode): ReaderInlineDataFragment;
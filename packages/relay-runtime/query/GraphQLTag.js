@@ -18,8 +18,11 @@ import type {
   ReaderRefetchableFragment,
 } from '../util/ReaderNode';
 import type {
+  ClientRequestParameters,
   ConcreteRequest,
   ConcreteUpdatableQuery,
+  LazyConcreteRequest,
+  RequestParameters,
 } from '../util/RelayConcreteNode';
 
 const RelayConcreteNode = require('../util/RelayConcreteNode');
@@ -31,6 +34,7 @@ export type GraphQLTaggedNode =
   | ReaderFragment
   | ReaderInlineDataFragment
   | ConcreteRequest
+  | LazyConcreteRequest
   | ConcreteUpdatableQuery
   | {
       // This is this case when we `require()` a generated ES6 module
@@ -38,10 +42,18 @@ export type GraphQLTaggedNode =
         | ReaderFragment
         | ReaderInlineDataFragment
         | ConcreteRequest
+        | LazyConcreteRequest
         | ConcreteUpdatableQuery,
       ...
     };
 
+const lazyRequestCache: WeakMap<
+  LazyConcreteRequest,
+  Promise<ConcreteRequest>,
+> = new WeakMap();
+const loadedLazyRequests: WeakMap<LazyConcreteRequest, ConcreteRequest> =
+  new WeakMap();
+
 /**
  * Runtime function to correspond to the `graphql` tagged template function.
  * All calls to this function should be transformed by the plugin.
@@ -62,6 +74,7 @@ function getNode(
   | ReaderFragment
   | ReaderInlineDataFragment
   | ConcreteRequest
+  | LazyConcreteRequest
   | ConcreteUpdatableQuery {
   let node = taggedNode;
   if (typeof node === 'function') {
@@ -100,6 +113,17 @@ function isRequest(node: GraphQLTaggedNode): boolean {
   );
 }
 
+function isLazyRequest(node: GraphQLTaggedNode): boolean {
+  const request = getNode(node);
+  return (
+    typeof request === 'object' &&
+    /* $FlowFixMe[invalid-compare] Error discovered during Constant Condition
+     * roll out. See https://fburl.com/workplace/5whu3i34. */
+    request !== null &&
+    request.kind === RelayConcreteNode.LAZY_REQUEST
+  );
+}
+
 function isUpdatableQuery(node: GraphQLTaggedNode): boolean {
   const updatableQuery = getNode(node);
   return (
@@ -166,6 +190,16 @@ function getRefetchableFragment(
 
 function getRequest(taggedNode: GraphQLTaggedNode): ConcreteRequest {
   const request = getNode(taggedNode);
+  if (request.kind === RelayConcreteNode.LAZY_REQUEST) {
+    const loadedRequest = loadedLazyRequests.get(request);
+    invariant(
+      loadedRequest != null,
+      'GraphQLTag: The normalization AST of the lazy request `%s` is not ' +
+        'loaded yet. Load it with `loadLazyRequest()` first.',
+      request.params.name,
+    );
+    return loadedRequest;
+  }
   invariant(
     isRequest(request),
     'GraphQLTag: Expected a request, got `%s`.',
@@ -174,6 +208,78 @@ function getRequest(taggedNode: GraphQLTaggedNode): ConcreteRequest {
   return request as any;
 }
 
+/**
+ * Whether `getRequest()` can return the request synchronously: the request
+ * isn't lazy, or the normalization AST of the lazy request is loaded.
+ */
+function isRequestLoaded(taggedNode: GraphQLTaggedNode): boolean {
+  const request = getNode(taggedNode);
+  return (
+    request.kind !== RelayConcreteNode.LAZY_REQUEST ||
+    loadedLazyRequests.has(request)
+  );
+}
+
+/**
+ * The parameters of a request, available without loading the normalization
+ * AST of a lazy request.
+ */
+function getRequestParams(
+  taggedNode: GraphQLTaggedNode,
+): RequestParameters | ClientRequestParameters {
+  const request = getNode(taggedNode);
+  if (request.kind === RelayConcreteNode.LAZY_REQUEST) {
+    return request.params;
+  }
+  return getRequest(request).params;
+}
+
+/**
+ * Resolves a request for the query, loading the normalization AST of a lazy
+ * request with its `operationModuleProvider`. Requests that are not lazy are
+ * returned as-is.
+ */
+function loadLazyRequest(
+  taggedNode: GraphQLTaggedNode,
+): Promise<ConcreteRequest> {
+  const node = getNode(taggedNode);
+  if (node.kind !== RelayConcreteNode.LAZY_REQUEST) {
+    return Promise.resolve(getRequest(node));
+  }
+  const lazyRequest = node;
+  const loadedRequest = loadedLazyRequests.get(lazyRequest);
+  if (loadedRequest != null) {
+    return Promise.resolve(loadedRequest);
+  }
+  let request = lazyRequestCache.get(lazyRequest);
+  if (request == null) {
+    const moduleOrReference: any = lazyRequest.operationModuleProvider();
+    const module =
+      typeof moduleOrReference.load === 'function'
+        ? moduleOrReference.load()
+        : moduleOrReference;
+    request = Promise.resolve(module).then(
+      loadedModule => {
+        const loadedRequest: ConcreteRequest = {
+          kind: 'Request',
+          fragment: lazyRequest.fragment,
+          operation: loadedModule.default ?? loadedModule,
+          params: lazyRequest.params as any,
+        };
+        loadedLazyRequests.set(lazyRequest, loadedRequest);
+        return loadedRequest;
+      },
+      error => {
+        // Allow the next call to retry loading the module
+        lazyRequestCache.delete(lazyRequest);
+        throw error;
+      },
+    );
+    lazyRequestCache.set(lazyRequest, request);
+  }
+  return request;
+}
+
 function getUpdatableQuery(
   taggedNode: GraphQLTaggedNode,
 ): ConcreteUpdatableQuery {
@@ -205,10 +311,14 @@ module.exports = {
   getPaginationFragment,
   getRefetchableFragment,
   getRequest,
+  getRequestParams,
   getUpdatableQuery,
   graphql,
   isFragment,
   isInlineDataFragment,
+  isLazyRequest,
   isRequest,
+  isRequestLoaded,
   isUpdatableQuery,
+  loadLazyRequest,
 };
@@ -15,9 +15,13 @@ const {
   getPaginationFragment,
   getRefetchableFragment,
   getRequest,
+  getRequestParams,
   graphql,
   isFragment,
+  isLazyRequest,
   isRequest,
+  isRequestLoaded,
+  loadLazyRequest,
 } = require('../GraphQLTag');
 
 beforeEach(() => {
@@ -72,6 +76,85 @@ describe('isRequest/getRequest()', () => {
   });
 });
 
+describe('isLazyRequest/loadLazyRequest()', () => {
+  let query;
+  let lazyQuery;
+
+  beforeEach(() => {
+    query = require('./__generated__/GraphQLTagTest1Query.graphql');
+    lazyQuery = {
+      kind: 'LazyRequest',
+      fragment: query.fragment,
+      operationModuleProvider: jest.fn(() =>
+        Promise.resolve({default: query.operation}),
+      ),
+      params: query.params,
+    };
+  });
+
+  it('detects lazy requests', () => {
+    expect(isLazyRequest(lazyQuery)).toBe(true);
+    expect(isRequest(lazyQuery)).toBe(false);
+    expect(isLazyRequest(query)).toBe(false);
+  });
+
+  it('throws when reading a lazy request that is not loaded', () => {
+    expect(isRequestLoaded(lazyQuery)).toBe(false);
+    expect(() => getRequest(lazyQuery)).toThrow(
+      'GraphQLTag: The normalization AST of the lazy request ' +
+        '`GraphQLTagTest1Query` is not loaded yet.',
+    );
+  });
+
+  it('reads the params of lazy requests that are not loaded', () => {
+    expect(getRequestParams(lazyQuery)).toBe(query.params);
+    expect(getRequestParams(query)).toBe(query.params);
+    expect(lazyQuery.operationModuleProvider).not.toHaveBeenCalled();
+  });
+
+  it('returns lazy requests synchronously once loaded', async () => {
+    expect(isRequestLoaded(query)).toBe(true);
+    const request = await loadLazyRequest(lazyQuery);
+    expect(isRequestLoaded(lazyQuery)).toBe(true);
+    expect(getRequest(lazyQuery)).toBe(request);
+  });
+
+  it('returns concrete queries as-is', async () => {
+    expect(await loadLazyRequest(query)).toBe(query);
+  });
+
+  it('loads the operation of lazy requests once', async () => {
+    const request = await loadLazyRequest(lazyQuery);
+    expect(request).toEqual({
+      kind: 'Request',
+      fragment: query.fragment,
+      operation: query.operation,
+      params: query.params,
+    });
+    expect(await loadLazyRequest(lazyQuery)).toBe(request);
+    expect(lazyQuery.operationModuleProvider).toHaveBeenCalledTimes(1);
+  });
+
+  it('loads the operation of lazy requests from a JSResource', async () => {
+    lazyQuery.operationModuleProvider = () => ({
+      getModuleId: () => 'GraphQLTagTest1Query$normalization.graphql',
+      getModuleIfRequired: () => null,
+      load: () => Promise.resolve(query.operation),
+    });
+    const request = await loadLazyRequest(lazyQuery);
+    expect(request.operation).toBe(query.operation);
+  });
+
+  it('retries loading after a failure', async () => {
+    lazyQuery.operationModuleProvider
+      .mockImplementationOnce(() => Promise.reject(new Error('Network error')))
+      .mockImplementationOnce(() => Promise.resolve(query.operation));
+    await expect(loadLazyRequest(lazyQuery)).rejects.toThrow('Network error');
+    const request = await loadLazyRequest(lazyQuery);
+    expect(request.operation).toBe(query.operation);
+  });
+});
+
 describe('getRefetchableFragment()', () => {
   it('returns null for non-refetchable fragments', () => {
     const fragment = graphql`
@@ -24,6 +24,7 @@ const {
   createOperationDescriptor,
   getRequest,
   graphql,
+  loadLazyRequest,
 } = require('relay-runtime');
 const {
   createMockEnvironment,
@@ -150,6 +151,32 @@ describe('fetchQuery', () => {
     subscription.unsubscribe();
   });
 
+  it('loads the normalization AST of lazy requests before fetching', async () => {
+    const request = getRequest(query);
+    const lazyQuery = {
+      kind: 'LazyRequest',
+      fragment: request.fragment,
+      operationModuleProvider: jest.fn(() =>
+        Promise.resolve({default: request.operation}),
+      ),
+      params: request.params,
+    };
+    const next = jest.fn<[fetchQueryTest1Query$data], unknown>();
+    // $FlowFixMe[incompatible-type]
+    fetchQuery(environment, lazyQuery, variables).subscribe({next});
+    expect(environment.execute).not.toBeCalled();
+
+    await loadLazyRequest(lazyQuery);
+    expect(lazyQuery.operationModuleProvider).toHaveBeenCalledTimes(1);
+    expect(
+      environment.mock.isLoading(request, variables, {force: true}),
+    ).toEqual(true);
+    environment.mock.nextValue(request, response);
+    environment.mock.complete(request);
+    expect(next).toBeCalledWith({node: {id: '4'}});
+    expect(retained.length).toEqual(0);
+  });
+
   describe('.toPromise()', () => {
     it('fetches request and does not retain query data', async () => {
       const promise = fetchQuery(environment, query, variables).toPromise();
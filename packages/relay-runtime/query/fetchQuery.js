@@ -32,7 +32,11 @@ const {
   handlePotentialSnapshotErrors,
 } = require('../util/handlePotentialSnapshotErrors');
 const fetchQueryInternal = require('./fetchQueryInternal');
-const {getRequest} = require('./GraphQLTag');
+const {
+  getRequest,
+  isRequestLoaded,
+  loadLazyRequest,
+} = require('./GraphQLTag');
 const invariant = require('invariant');
 
 /**
@@ -121,6 +125,12 @@ function fetchQuery<TVariables extends Variables, TData, TRawResponse>(
     networkCacheConfig?: CacheConfig,
   }>,
 ): RelayObservable<TData> {
+  if (!isRequestLoaded(query)) {
+    // The normalization AST of a lazy request is loaded before fetching
+    return RelayObservable.from(loadLazyRequest(query)).mergeMap(request =>
+      fetchQuery(environment, request as $FlowFixMe, variables, options),
+    );
+  }
   const queryNode = getRequest(query);
   invariant(
     queryNode.params.operationKind === 'query',
@@ -18,7 +18,11 @@ import type {GraphQLTaggedNode} from './GraphQLTag';
 const {
   createOperationDescriptor,
 } = require('../store/RelayModernOperationDescriptor');
-const {getRequest} = require('./GraphQLTag');
+const {
+  getRequest,
+  isRequestLoaded,
+  loadLazyRequest,
+} = require('./GraphQLTag');
 
 /**
  * A helper function to fetch the results of a query. Note that results for
@@ -32,6 +36,12 @@ function fetchQuery_DEPRECATED<T extends OperationType>(
   variables: T['variables'],
   cacheConfig?: ?CacheConfig,
 ): Promise<T['response']> {
+  if (!isRequestLoaded(taggedNode)) {
+    // The normalization AST of a lazy request is loaded before fetching
+    return loadLazyRequest(taggedNode).then(request =>
+      fetchQuery_DEPRECATED<T>(environment, request, variables, cacheConfig),
+    );
+  }
   const query = getRequest(taggedNode);
   if (query.params.operationKind !== 'query') {
     throw new Error('fetchQuery: Expected query operation');
@@ -6,6 +6,7 @@
  */
 
 import type { ConnectionMetadata } from '../handlers/connection/ConnectionHandler';
+import type { PaginatedListMetadata } from '../handlers/paginatedList/PaginatedListHandler';
 import type { ConcreteRequest } from './RelayConcreteNode';
 
 export interface ReaderFragmentSpread {
@@ -40,6 +41,7 @@ export interface ReaderFragment {
         | {
             readonly connection?: readonly ConnectionMetadata[];
             readonly mask?: boolean;
+            readonly paginatedList?: readonly PaginatedListMetadata[];
             readonly plural?: boolean;
             readonly refetch?: ReaderRefetchMetadata;
         }
@@ -77,6 +79,7 @@ export interface ReaderRefetchMetadata {
     readonly operation: string | ConcreteRequest;
     readonly fragmentPathInResult: string[];
     readonly identifierInfo?: RefetchableIdentifierInfo | null | undefined;
+    readonly paginatedList?: ReaderPaginatedListMetadata | null | undefined;
 }
 
 // Stricter form of ConnectionMetadata
@@ -92,6 +95,13 @@ export interface ReaderPaginationMetadata {
     readonly path: readonly string[];
 }
 
+// Stricter form of PaginatedListMetadata
+export interface ReaderPaginatedListMetadata {
+    readonly limit: string;
+    readonly offset: string;
+    readonly path: readonly string[];
+}
+
 export interface ReaderInlineDataFragment {
     readonly kind: 'InlineDataFragment';
     readonly name: string;
@@ -12,6 +12,7 @@
 'use strict';
 
 import type {ConnectionMetadata} from '../handlers/connection/ConnectionHandler';
+import type {PaginatedListMetadata} from '../handlers/paginatedList/PaginatedListHandler';
 import type {NormalizationSelectableNode} from './NormalizationNode';
 import type {ConcreteRequest} from './RelayConcreteNode';
 import type {JSResourceReference} from 'JSResourceReference';
@@ -43,6 +44,7 @@ export type ReaderFragment = {
     readonly catchTo?: CatchFieldTo,
     readonly hasClientEdges?: boolean,
     readonly mask?: boolean,
+    readonly paginatedList?: ReadonlyArray<PaginatedListMetadata>,
     readonly plural?: boolean,
     readonly refetch?: ReaderRefetchMetadata,
     readonly vultureHash?: string,
@@ -85,6 +87,7 @@ export type ReaderRefetchMetadata = {
   readonly fragmentPathInResult: Array<string>,
   readonly identifierInfo?: ?RefetchableIdentifierInfo,
   readonly edgesFragment?: ReaderFragment,
+  readonly paginatedList?: ?ReaderPaginatedListMetadata,
 };
 
 // Stricter form of ConnectionMetadata
@@ -100,6 +103,13 @@ export type ReaderPaginationMetadata = {
   readonly path: ReadonlyArray<string>,
 };
 
+// Stricter form of PaginatedListMetadata
+export type ReaderPaginatedListMetadata = {
+  readonly limit: string,
+  readonly offset: string,
+  readonly path: ReadonlyArray<string>,
+};
+
 export type ReaderInlineDataFragment = {
   readonly kind: 'InlineDataFragment',
   readonly name: string,
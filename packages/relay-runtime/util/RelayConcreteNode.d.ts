@@ -5,7 +5,7 @@
  * LICENSE file in the root directory of this source tree.
  */
 
-import {NormalizationOperation, NormalizationSplitOperation} from './NormalizationNode';
+import {JSResourceReference, NormalizationOperation, NormalizationSplitOperation} from './NormalizationNode';
 import {ReaderFragment, ReaderInlineDataFragment} from './ReaderNode';
 import { OperationType } from './RelayRuntimeTypes';
 
@@ -33,6 +33,20 @@ export interface PreloadableConcreteRequest<_TQuery extends OperationType> {
     readonly params: RequestParameters;
 }
 
+/**
+ * A request whose `operation` is split into its own module and loaded with
+ * the `operationModuleProvider`; see `loadLazyRequest`.
+ */
+export interface LazyConcreteRequest {
+    readonly kind: 'LazyRequest';
+    readonly fragment: ReaderFragment;
+    readonly operationModuleProvider: () =>
+        | NormalizationOperation
+        | Promise<NormalizationOperation | { readonly default: NormalizationOperation }>
+        | JSResourceReference<NormalizationOperation>;
+    readonly params: RequestParameters;
+}
+
 export interface ConcreteUpdatableQuery {
     readonly kind: 'UpdatableQuery';
     readonly fragment: ReaderFragment;
@@ -67,6 +81,7 @@ export type RequestParameters =
 
 export type GeneratedNode =
     | ConcreteRequest
+    | LazyConcreteRequest
     | ReaderFragment
     | ReaderInlineDataFragment
     | NormalizationSplitOperation
@@ -89,6 +104,7 @@ export const RelayConcreteNode: {
     INLINE_FRAGMENT: 'InlineFragment';
     LINKED_FIELD: 'LinkedField';
     LINKED_HANDLE: 'LinkedHandle';
+    LAZY_REQUEST: 'LazyRequest';
     LITERAL: 'Literal';
     LIST_VALUE: 'ListValue';
     LOCAL_ARGUMENT: 'LocalArgument';
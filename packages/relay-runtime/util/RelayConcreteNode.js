@@ -11,6 +11,7 @@
 
 'use strict';
 
+import type {JSResourceReference} from 'JSResourceReference';
 import type {
   NormalizationOperation,
   NormalizationSplitOperation,
@@ -30,6 +31,22 @@ export type ConcreteRequest = {
   readonly params: RequestParameters,
 };
 
+/**
+ * A request whose `operation` is split into its own module and loaded with
+ * the `operationModuleProvider`; see `loadLazyRequest`.
+ */
+export type LazyConcreteRequest = {
+  readonly kind: 'LazyRequest',
+  readonly fragment: ReaderFragment,
+  readonly operationModuleProvider: () =>
+    | NormalizationOperation
+    | Promise<
+        NormalizationOperation | {readonly default: NormalizationOperation, ...},
+      >
+    | JSResourceReference<NormalizationOperation>,
+  readonly params: RequestParameters | ClientRequestParameters,
+};
+
 export type ConcreteUpdatableQuery = {
   readonly kind: 'UpdatableQuery',
   readonly fragment: ReaderFragment,
@@ -90,6 +107,7 @@ export type ClientRequest = {
 
 export type GeneratedNode =
   | ConcreteRequest
+  | LazyConcreteRequest
   | ReaderFragment
   | ReaderInlineDataFragment
   | NormalizationSplitOperation
@@ -112,6 +130,7 @@ const RelayConcreteNode = {
   INLINE_FRAGMENT: 'InlineFragment',
   LINKED_FIELD: 'LinkedField',
   LINKED_HANDLE: 'LinkedHandle',
+  LAZY_REQUEST: 'LazyRequest',
   LITERAL: 'Literal',
   LIST_VALUE: 'ListValue',
   LOCAL_ARGUMENT: 'LocalArgument',